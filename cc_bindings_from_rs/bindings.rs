@@ -10,7 +10,9 @@ use code_gen_utils::{
 use itertools::Itertools;
 use proc_macro2::{Literal, TokenStream};
 use quote::{format_ident, quote};
-use rustc_hir::{AssocItemKind, ImplItemKind, ImplicitSelfKind, Item, ItemKind, Node, Unsafety};
+use rustc_hir::{
+    AssocItemKind, ImplItemKind, ImplicitSelfKind, Item, ItemKind, Node, Unsafety, VariantData,
+};
 use rustc_middle::dep_graph::DepContext;
 use rustc_middle::mir::Mutability;
 use rustc_middle::ty::{self, Ty, TyCtxt}; // See <internal link>/ty.html#import-conventions
@@ -37,8 +39,12 @@ pub struct Input<'tcx> {
     // TODO(b/262878759): Provide a set of enabled/disabled Crubit features.
     pub _features: (),
 
-    // TODO(b/258261328): Provide a map from crate name into C++ header path with crate bindings.
-    pub _crate_to_include_map: (),
+    /// A map from the name of a dependency crate into the C++ header that
+    /// defines the C++ types of the crate.  Currently this is only used for
+    /// crates generated by `rs_bindings_from_cc` (see `get_cc_type_identity`).
+    //
+    // TODO(b/258261328): Also cover the headers generated by `cc_bindings_from_rs`.
+    pub crate_to_include_map: HashMap<Rc<str>, CcInclude>,
 }
 
 pub struct Output {
@@ -369,8 +375,18 @@ fn format_ty_for_cc(input: &Input, ty: Ty) -> Result<CcSnippet> {
         ty::TyKind::Adt(adt, substs) => {
             ensure!(substs.len() == 0, "Generic types are not supported yet (b/259749095)");
 
-            // Verify if definition of `ty` can be succesfully imported and bail otherwise.
+            // Types generated by `rs_bindings_from_cc` are bindings of an existing C++ type, which
+            // should be used directly, rather than wrapped again.
             let def_id = adt.did();
+            if def_id.krate != LOCAL_CRATE {
+                if let Some(cc_name) = get_cc_type_identity(input.tcx, def_id) {
+                    return format_cc_type_identity(input, def_id, &cc_name)
+                        .with_context(|| format!(
+                                "Failed to format the C++ type of `{ty}`"));
+                }
+            }
+
+            // Verify if definition of `ty` can be succesfully imported and bail otherwise.
             format_adt_core(input.tcx, def_id)
                 .with_context(|| format!(
                         "Failed to generate bindings for the definition of `{ty}`"))?;
@@ -413,6 +429,75 @@ fn format_ty_for_cc(input: &Input, ty: Ty) -> Result<CcSnippet> {
     })
 }
 
+/// Decodes the string of a `forward_declare::symbol!` - a type like
+/// `Symbol<(C<'F'>, C<'o'>, C<'o'>)>`.  Returns `None` if `ty` is not a symbol.
+fn decode_symbol(tcx: TyCtxt, ty: Ty) -> Option<String> {
+    let chars = match ty.kind() {
+        ty::TyKind::Adt(adt, substs) if tcx.item_name(adt.did()).as_str() == "Symbol" => {
+            match substs.types().next()?.kind() {
+                ty::TyKind::Tuple(chars) => *chars,
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    chars
+        .iter()
+        .map(|char_ty| match char_ty.kind() {
+            ty::TyKind::Adt(adt, substs) if tcx.item_name(adt.did()).as_str() == "C" => {
+                let scalar = substs.consts().next()?.kind().try_to_scalar_int()?;
+                char::from_u32(scalar.try_to_u32().ok()?)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the name of the C++ type that the Rust type `def_id` provides
+/// bindings for, if `def_id` has been generated by `rs_bindings_from_cc`.
+///
+/// The Rust types generated by `rs_bindings_from_cc` are registered by
+/// `forward_declare::unsafe_define!`, which implements the
+/// `forward_declare::internal::CcType` trait with a `Name` that spells the name
+/// of the C++ type using `forward_declare::symbol!`.
+//
+// TODO(b/259724276): This function's results should be memoized.
+fn get_cc_type_identity(tcx: TyCtxt, def_id: DefId) -> Option<String> {
+    let cc_type_trait = tcx
+        .crates(())
+        .iter()
+        .filter(|&&krate| tcx.crate_name(krate).as_str() == "forward_declare")
+        .flat_map(|&krate| tcx.traits_in_crate(krate).iter())
+        .copied()
+        .find(|&trait_id| tcx.item_name(trait_id).as_str() == "CcType")?;
+    let ty = tcx.type_of(def_id);
+    tcx.all_impls(cc_type_trait)
+        .filter(|&impl_id| tcx.type_of(impl_id) == ty)
+        .flat_map(|impl_id| tcx.associated_items(impl_id).in_definition_order())
+        .find(|item| item.name.as_str() == "Name")
+        .and_then(|name| decode_symbol(tcx, tcx.type_of(name.def_id)))
+}
+
+/// Formats the C++ type `cc_name` that the Rust type `def_id` (generated by
+/// `rs_bindings_from_cc`) provides bindings for.  `rs_bindings_from_cc` maps
+/// C++ namespaces into Rust modules, so the C++ namespace of the type is
+/// derived from the module path of `def_id`.
+fn format_cc_type_identity(input: &Input, def_id: DefId, cc_name: &str) -> Result<CcSnippet> {
+    let tcx = input.tcx;
+    let krate = tcx.crate_name(def_id.krate);
+    let FullyQualifiedName { mod_path, .. } = FullyQualifiedName::new(tcx, def_id);
+    ensure!(
+        !mod_path.names().iter().any(|name| name.starts_with("__cc_template_instantiations")),
+        "Class template instantiations are not supported yet"
+    );
+    let include = input.crate_to_include_map.get(krate.as_str()).ok_or_else(|| {
+        anyhow!("No C++ header is known for the `{krate}` crate (see `--crate-header`)")
+    })?;
+    let ns_path = mod_path.format_for_cc()?;
+    let name = format_cc_ident(cc_name)?;
+    Ok(CcSnippet::with_include(quote! { :: #ns_path #name }, include.clone()))
+}
+
 /// Formats `ty` for Rust - to be used in `..._cc_api_impl.rs` (e.g. as a type
 /// of a parameter in a Rust thunk).  Because `..._cc_api_impl.rs` is a
 /// distinct, separate crate, the returned `TokenStream` uses crate-qualified
@@ -924,6 +1009,54 @@ fn format_adt_core(tcx: TyCtxt, def_id: DefId) -> Result<AdtCoreBindings> {
     })
 }
 
+/// Formats the fields of a `#[repr(C)]` struct as public C++ data members.
+///
+/// Returns 1) the member declarations and 2) `static_assert`s that verify the
+/// offset of each member.  Returns an error if any of the fields can't be
+/// exposed to C++ - in this case the caller should fall back to representing
+/// the fields as an opaque blob of bytes.
+fn format_repr_c_fields(input: &Input, core: &AdtCoreBindings) -> Result<(CcSnippet, CcSnippet)> {
+    let tcx = input.tcx;
+    let local_def_id = core.def_id.expect_local();
+    let adt = tcx.adt_def(core.def_id);
+    ensure!(adt.is_struct() && adt.repr().c(), "Only fields of `#[repr(C)]` structs are supported");
+    ensure!(
+        matches!(
+            tcx.hir().expect_item(local_def_id).kind,
+            ItemKind::Struct(VariantData::Struct(..), _)
+        ),
+        "Fields of tuple structs are not supported"
+    );
+
+    let layout = tcx
+        .layout_of(ty::ParamEnv::empty().and(tcx.type_of(core.def_id)))
+        .map_err(|layout_err| anyhow!("Error computing the layout: {layout_err}"))?
+        .layout;
+    let cc_name = &core.cc_name;
+    let mut decls = CcSnippet::default();
+    let mut offset_asserts = CcSnippet::with_include(quote! {}, CcInclude::cstddef());
+    for (index, field) in adt.non_enum_variant().fields.iter().enumerate() {
+        ensure!(field.vis.is_public(), "Non-public fields are not supported (`{}`)", field.name);
+        let field_name = format_cc_ident(field.name.as_str())
+            .with_context(|| format!("Error formatting the name of the `{}` field", field.name))?;
+        let field_ty = format_ty_for_cc(input, tcx.type_of(field.did))
+            .with_context(|| format!("Error formatting the type of the `{}` field", field.name))?
+            .into_tokens(&mut decls.prereqs);
+        let doc_comment = format_doc_comment(tcx, field.did.expect_local());
+        let offset = Literal::u64_unsuffixed(layout.fields().offset(index).bytes());
+        decls.tokens.extend(quote! {
+            #doc_comment
+            #field_ty #field_name;
+        });
+        offset_asserts.tokens.extend(quote! {
+            static_assert(
+                offsetof(#cc_name, #field_name) == #offset,
+                "Verify that struct layout didn't change since this header got generated");
+        });
+    }
+    Ok((decls, offset_asserts))
+}
+
 /// Formats an algebraic data type (an ADT - a struct, an enum, or a union)
 /// represented by `core`.  This function is infallible - after
 /// `format_adt_core` returns success we have committed to emitting C++ bindings
@@ -965,9 +1098,17 @@ fn format_adt(input: &Input, core: &AdtCoreBindings) -> Vec<(SnippetKey, MixedSn
     let alignment = Literal::u64_unsuffixed(core.alignment_in_bytes);
     let size = Literal::u64_unsuffixed(core.size_in_bytes);
     let cc_name = &core.cc_name;
+    let fields = format_repr_c_fields(input, core).ok();
     let main_api = {
         let doc_comment = format_doc_comment(tcx, core.def_id.expect_local());
         let keyword = &core.keyword;
+        // Identifies the Rust type, so that `rs_bindings_from_cc` can use it
+        // (rather than generate new bindings) when the C++ type is used in a
+        // C++ API.
+        let rust_type_attr = {
+            let rs_name = core.rs_name.to_string().replace(' ', "");
+            quote! { [[clang::annotate("crubit_internal_rust_type", #rs_name)]] }
+        };
         let core = &core.core;
 
         let mut prereqs = CcPrerequisites::default();
@@ -984,18 +1125,31 @@ fn format_adt(input: &Input, core: &AdtCoreBindings) -> Vec<(SnippetKey, MixedSn
                     #( #tokens )*
             }
         };
+        let field_decls = match &fields {
+            Some((decls, _)) => {
+                prereqs += decls.prereqs.clone();
+                let tokens = &decls.tokens;
+                quote! {
+                    public:
+                        #tokens
+                }
+            }
+            // TODO(b/258233850): Emit individual fields of non-`#[repr(C)]` structs.
+            None => quote! {
+                private:
+                    unsigned char opaque_blob_of_bytes[#size];
+            },
+        };
         prereqs.fwd_decls.remove(&local_def_id);
 
         CcSnippet {
             prereqs,
             tokens: quote! {
                 __NEWLINE__ #doc_comment
-                #keyword alignas(#alignment) #cc_name final {
+                #keyword alignas(#alignment) #rust_type_attr #cc_name final {
                     #core
                     #impl_item_decls
-                    private:
-                        // TODO(b/258233850): Emit individual fields.
-                        unsigned char opaque_blob_of_bytes[#size];
+                    #field_decls
                 };
                 __NEWLINE__
             },
@@ -1012,6 +1166,10 @@ fn format_adt(input: &Input, core: &AdtCoreBindings) -> Vec<(SnippetKey, MixedSn
                 "Verify that struct layout didn't change since this header got generated");
             __NEWLINE__
         });
+        if let Some((_, offset_asserts)) = fields {
+            cc.tokens.extend(offset_asserts.into_tokens(&mut cc.prereqs));
+            cc.tokens.extend(quote! { __NEWLINE__ });
+        }
         cc.prereqs.defs.insert(local_def_id);
         let rs = {
             let rs_name = &core.rs_name;
@@ -1376,7 +1534,7 @@ pub mod tests {
                 quote! {
                     namespace rust_out {
                         ...
-                        struct alignas(4) Point final {
+                        struct alignas(4) [[...]] Point final {
                             // No point replicating test coverage of
                             // `test_format_item_struct_with_fields`.
                             ...
@@ -1546,7 +1704,7 @@ pub mod tests {
                         ...
                         inline void f(const ::rust_out::S* __param_0);
                         ...
-                        struct alignas(...) S final { ... }
+                        struct alignas(...) [[...]] S final { ... }
                         ...
                         inline void f(const ::rust_out::S* __param_0) { ... }
                         ...
@@ -1592,7 +1750,7 @@ pub mod tests {
                         ...
                         extern "C" bool f(::rust_out::S s);
                         ...
-                        struct alignas(...) S final { ... }
+                        struct alignas(...) [[...]] S final { ... }
                         ...
                     }  // namespace rust_out
                 }
@@ -1674,11 +1832,11 @@ pub mod tests {
                         inline void f3 ...
 
                         namespace a { ...
-                        struct alignas(...) S1 final { ... } ...
-                        struct alignas(...) S2 final { ... } ...
+                        struct alignas(...) [[...]] S1 final { ... } ...
+                        struct alignas(...) [[...]] S2 final { ... } ...
                         } ...
                         namespace b { ...
-                        struct alignas(...) S3 final { ... } ...
+                        struct alignas(...) [[...]] S3 final { ... } ...
                         } ...
                     }  // namespace rust_out
                 }
@@ -2824,7 +2982,7 @@ pub mod tests {
                 main_api.tokens,
                 quote! {
                     ...
-                    struct alignas(4) SomeStruct final {
+                    struct alignas(4) [[...]] SomeStruct final {
                         public:
                             // In this test there is no `Default` implementation.
                             SomeStruct() = delete;
@@ -2882,7 +3040,7 @@ pub mod tests {
                 main_api.tokens,
                 quote! {
                     ...
-                    struct alignas(4) TupleStruct final {
+                    struct alignas(4) [[...]] TupleStruct final {
                         public:
                             // In this test there is no `Default` implementation.
                             TupleStruct() = delete;
@@ -2922,6 +3080,101 @@ pub mod tests {
         });
     }
 
+    #[test]
+    fn test_format_item_repr_c_struct_with_fields() {
+        let test_src = r#"
+                #[repr(C)]
+                pub struct Point {
+                    /// Doc comment of `x`.
+                    pub x: i32,
+                    pub y: f64,
+                    pub next: *const Point,
+                }
+            "#;
+        test_format_item(test_src, "Point", |result| {
+            let result = result.unwrap();
+            let main_api = get_main_api_snippet(&result);
+            let impl_details = get_impl_details_snippet(&result);
+            assert!(main_api.prereqs.fwd_decls.is_empty());
+            assert_cc_matches!(
+                main_api.tokens,
+                quote! {
+                    ...
+                    struct alignas(8)
+                        [[clang::annotate("crubit_internal_rust_type", "::rust_out::Point")]]
+                        Point final {
+                        public:
+                            ...
+                            ~Point() = default;
+                        public:
+                            ... std::int32_t x;
+                            ... double y;
+                            ... const ::rust_out::Point* next;
+                    };
+                }
+            );
+            assert!(main_api.tokens.to_string().contains("Doc comment of `x`."));
+            assert_cc_not_matches!(main_api.tokens, quote! { opaque_blob_of_bytes });
+            assert!(impl_details.cc.prereqs.includes.contains(&CcInclude::cstddef()));
+            assert_cc_matches!(
+                impl_details.cc.tokens,
+                quote! {
+                    static_assert(sizeof(Point) == 24, ...);
+                    static_assert(alignof(Point) == 8, ...);
+                    static_assert(offsetof(Point, x) == 0, ...);
+                    static_assert(offsetof(Point, y) == 8, ...);
+                    static_assert(offsetof(Point, next) == 16, ...);
+                }
+            );
+        });
+    }
+
+    #[test]
+    fn test_format_item_repr_c_struct_with_private_field() {
+        let test_src = r#"
+                #[repr(C)]
+                pub struct SomeStruct {
+                    pub x: i32,
+                    y: i32,
+                }
+            "#;
+        test_format_item(test_src, "SomeStruct", |result| {
+            let result = result.unwrap();
+            let main_api = get_main_api_snippet(&result);
+            let impl_details = get_impl_details_snippet(&result);
+            assert_cc_matches!(
+                main_api.tokens,
+                quote! {
+                    ...
+                    private:
+                        unsigned char opaque_blob_of_bytes[8];
+                    ...
+                }
+            );
+            assert_cc_not_matches!(impl_details.cc.tokens, quote! { offsetof });
+        });
+    }
+
+    /// `decode_symbol` is tested with local stand-ins for the types from the
+    /// `forward_declare` crate, which is not available in the tests.
+    #[test]
+    fn test_decode_symbol() {
+        let test_src = r#"
+                pub struct Symbol<T>(std::marker::PhantomData<T>);
+                pub struct C<const CHAR: char>;
+
+                pub type FooSymbol = Symbol<(C<'F'>, C<'o'>, C<'o'>)>;
+                pub type NotASymbol = (C<'F'>, C<'o'>, C<'o'>);
+            "#;
+        run_compiler_for_testing(test_src, |tcx| {
+            let symbol = tcx.type_of(find_def_id_by_name(tcx, "FooSymbol"));
+            assert_eq!(Some("Foo".to_string()), decode_symbol(tcx, symbol));
+
+            let not_a_symbol = tcx.type_of(find_def_id_by_name(tcx, "NotASymbol"));
+            assert_eq!(None, decode_symbol(tcx, not_a_symbol));
+        });
+    }
+
     #[test]
     fn test_format_item_static_method() {
         let test_src = r#"
@@ -3268,7 +3521,7 @@ pub mod tests {
                 main_api.tokens,
                 quote! {
                     ...
-                    struct alignas(1) SomeEnum final {
+                    struct alignas(1) [[...]] SomeEnum final {
                         public:
                             // In this test there is no `Default` implementation.
                             SomeEnum() = delete;
@@ -3330,7 +3583,7 @@ pub mod tests {
                 main_api.tokens,
                 quote! {
                     ...
-                    struct alignas(4) Point final {
+                    struct alignas(4) [[...]] Point final {
                         public:
                             // In this test there is no `Default` implementation.
                             Point() = delete;
@@ -3405,7 +3658,7 @@ pub mod tests {
                 main_api.tokens,
                 quote! {
                     ...
-                    struct alignas(8) SomeUnion final {
+                    struct alignas(8) [[...]] SomeUnion final {
                         public:
                             // In this test there is no `Default` implementation.
                             SomeUnion() = delete;
@@ -3657,7 +3910,7 @@ pub mod tests {
                 main_api.tokens,
                 quote! {
                     ...
-                    struct alignas(4) SomeStruct final {
+                    struct alignas(4) [[...]] SomeStruct final {
                         ...
                         __COMMENT__ #unsupported_msg
                         ...
@@ -4196,7 +4449,7 @@ pub mod tests {
             tcx,
             crubit_support_path: "crubit/support/for/tests".into(),
             _features: (),
-            _crate_to_include_map: (),
+            crate_to_include_map: HashMap::new(),
         }
     }
 
@@ -25,6 +25,7 @@ mod cmdline;
 mod run_compiler;
 
 use anyhow::Context;
+use code_gen_utils::CcInclude;
 use itertools::Itertools;
 use rustc_middle::ty::TyCtxt; // See also <internal link>/ty.html#import-conventions
 use std::path::Path;
@@ -44,7 +45,14 @@ fn run_with_tcx(cmdline: &Cmdline, tcx: TyCtxt) -> anyhow::Result<()> {
     use bindings::*;
     let Output { h_body, rs_body } = {
         let crubit_support_path = cmdline.crubit_support_path.as_str().into();
-        let input = Input { tcx, crubit_support_path, _features: (), _crate_to_include_map: () };
+        let crate_to_include_map = cmdline
+            .crate_header
+            .iter()
+            .map(|(krate, header)| {
+                (krate.as_str().into(), CcInclude::user_header(header.as_str().into()))
+            })
+            .collect();
+        let input = Input { tcx, crubit_support_path, _features: (), crate_to_include_map };
         generate_bindings(&input)?
     };
 
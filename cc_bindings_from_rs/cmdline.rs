@@ -2,7 +2,7 @@
 // Exceptions. See /LICENSE for license information.
 // SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use clap::Parser;
 use std::path::PathBuf;
 
@@ -42,11 +42,27 @@ pub struct Cmdline {
     #[clap(long, value_parser, value_name = "FILE")]
     pub rustfmt_config_path: Option<PathBuf>,
 
+    /// C++ header that defines the C++ types of a dependency crate (e.g.
+    /// `foo=foo/foo.h`).
+    #[clap(long, value_parser = parse_crate_header, value_name = "CRATE=HEADER")]
+    // This is a `String` rather than `PathBuf` for the same reasons as
+    // `crubit_support_path` above.
+    pub crate_header: Vec<(String, String)>,
+
     /// Command line arguments of the Rust compiler.
     #[clap(last = true, value_parser)]
     pub rustc_args: Vec<String>,
 }
 
+fn parse_crate_header(arg: &str) -> Result<(String, String)> {
+    match arg.split_once('=') {
+        Some((krate, header)) if !krate.is_empty() && !header.is_empty() => {
+            Ok((krate.to_string(), header.to_string()))
+        }
+        _ => bail!("Expected `<crate name>=<header path>`, but got `{arg}`"),
+    }
+}
+
 impl Cmdline {
     pub fn new(args: &[String]) -> Result<Self> {
         assert_ne!(
@@ -111,10 +127,47 @@ mod tests {
         assert_eq!(Path::new("clang-format.exe"), cmdline.clang_format_exe_path);
         assert_eq!(Path::new("rustfmt.exe"), cmdline.rustfmt_exe_path);
         assert!(cmdline.rustfmt_config_path.is_none());
+        assert!(cmdline.crate_header.is_empty());
         // Ignoring `rustc_args` in this test - they are covered in a separate
         // test below: `test_rustc_args_happy_path`.
     }
 
+    #[test]
+    fn test_crate_header() {
+        let cmdline = new_cmdline([
+            "--h-out=foo.h",
+            "--rs-out=foo_impl.rs",
+            "--crubit-support-path=crubit/support/for/tests",
+            "--clang-format-exe-path=clang-format.exe",
+            "--rustfmt-exe-path=rustfmt.exe",
+            "--crate-header=foo=foo/foo.h",
+            "--crate-header=bar_cc_api=bar/bar.h",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                ("foo".to_string(), "foo/foo.h".to_string()),
+                ("bar_cc_api".to_string(), "bar/bar.h".to_string()),
+            ],
+            cmdline.crate_header
+        );
+    }
+
+    #[test]
+    fn test_crate_header_invalid() {
+        let err = new_cmdline([
+            "--h-out=foo.h",
+            "--rs-out=foo_impl.rs",
+            "--crubit-support-path=crubit/support/for/tests",
+            "--clang-format-exe-path=clang-format.exe",
+            "--rustfmt-exe-path=rustfmt.exe",
+            "--crate-header=foo",
+        ])
+        .unwrap_err();
+        assert!(format!("{err:#}").contains("Expected `<crate name>=<header path>`"));
+    }
+
     #[test]
     fn test_rustc_args_happy_path() {
         // Note that this test would fail without the `--` separator.
@@ -177,6 +230,9 @@ OPTIONS:
             Path to a clang-format executable that will be used to format the C++ header files
             generated by the tool
 
+        --crate-header <CRATE=HEADER>
+            C++ header that defines the C++ types of a dependency crate (e.g. `foo=foo/foo.h`)
+
         --crubit-support-path <STRING>
             Path to the `crubit/support` directory in a format that should be used in the `#include`
             directives inside the generated C++ files. Example: "crubit/support"
@@ -32,18 +32,75 @@ pub fn format_cc_ident(ident: &str) -> Result<TokenStream> {
     )
 }
 
-/// Makes an 'Ident' to be used in the Rust source code. Escapes Rust keywords.
-/// Panics if `ident` is empty or is otherwise an invalid identifier.
+/// Makes an 'Ident' to be used in the Rust source code. Escapes Rust keywords
+/// as raw identifiers (e.g. `r#type`), and renames the keywords which can't be
+/// raw identifiers by appending an underscore (e.g. `self_` for `self`, or `__`
+/// for `_`). Panics if `ident` is empty or is otherwise an invalid identifier.
 pub fn make_rs_ident(ident: &str) -> Ident {
+    if NON_RAW_RS_KEYWORDS.contains(&ident) {
+        return format_ident!("{}_", ident);
+    }
+    if is_rs_keyword(ident) {
+        format_ident!("r#{}", ident)
+    } else {
+        format_ident!("{}", ident)
+    }
+}
+
+/// Makes a lifetime (e.g. `'a`) to be used in the Rust source code. Renames
+/// Rust keywords, which can't be the names of lifetimes, by appending an
+/// underscore (e.g. `'type_` for `type`), except for `'static` and `'_`.
+/// Panics if `name` is empty or is otherwise an invalid identifier.
+pub fn make_rs_lifetime(name: &str) -> syn::Lifetime {
+    let lifetime = if name != "static" && name != "_" && is_rs_keyword(name) {
+        format!("'{name}_")
+    } else {
+        format!("'{name}")
+    };
+    syn::Lifetime::new(&lifetime, proc_macro2::Span::call_site())
+}
+
+/// The Rust keywords which can't be raw identifiers (see
+/// https://doc.rust-lang.org/reference/identifiers.html#raw-identifiers), and
+/// `_`, which isn't an identifier at all.
+const NON_RAW_RS_KEYWORDS: [&str; 5] = ["self", "Self", "super", "crate", "_"];
+
+/// Returns whether `ident` is a Rust keyword (or is otherwise not a valid
+/// non-raw identifier).
+fn is_rs_keyword(ident: &str) -> bool {
     // TODO(https://github.com/dtolnay/syn/pull/1098): Remove the hardcoded list once syn recognizes
     // 2018 and 2021 keywords.
-    if ["async", "await", "try", "dyn"].contains(&ident) {
-        return format_ident!("r#{}", ident);
-    }
-    match syn::parse_str::<syn::Ident>(ident) {
-        Ok(_) => format_ident!("{}", ident),
-        Err(_) => format_ident!("r#{}", ident),
+    ["async", "await", "try", "dyn"].contains(&ident)
+        || syn::parse_str::<syn::Ident>(ident).is_err()
+}
+
+/// Converts a (CamelCase or mixedCase) C++ identifier to snake_case, e.g.
+/// `GetHTTPServer` to `get_http_server`. Underscores and digits are kept, and
+/// identifiers that are already snake_case are returned unchanged.
+pub fn to_snake_case(ident: &str) -> String {
+    let chars = ident.chars().collect_vec();
+    let mut snake_case = String::with_capacity(ident.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev = i.checked_sub(1).map(|i| chars[i]);
+            let next = chars.get(i + 1).copied();
+            // A word starts at an uppercase letter following a lowercase letter or a
+            // digit (`fooBar`, `foo2Bar`), or at the last uppercase letter of an
+            // acronym followed by a lowercase letter (`HTTPServer`).
+            let starts_word = prev.is_some_and(|prev| {
+                prev.is_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_uppercase() && next.is_some_and(char::is_lowercase))
+            });
+            if starts_word {
+                snake_case.push('_');
+            }
+            snake_case.extend(c.to_lowercase());
+        } else {
+            snake_case.push(c);
+        }
     }
+    snake_case
 }
 
 /// Representation of `foo::bar::baz` where each component is either the name
@@ -63,6 +120,11 @@ impl NamespaceQualifier {
         Self(iter.into_iter().map(Into::into).collect())
     }
 
+    /// Returns the names of the namespaces, from the outermost one.
+    pub fn names(&self) -> &[Rc<str>] {
+        &self.0
+    }
+
     /// Returns `foo::bar::baz::` (escaping Rust keywords as needed).
     pub fn format_for_rs(&self) -> TokenStream {
         let namespace_rs_idents = self.0.iter().map(|ns| make_rs_ident(ns));
@@ -176,12 +238,33 @@ impl CcInclude {
         Self::SystemHeader("cstdint")
     }
 
+    /// Creates a `CcInclude` that represents `#include <functional>` and
+    /// provides C++ functions like `std::invoke`.
+    /// See also https://en.cppreference.com/w/cpp/header/functional
+    pub fn functional() -> Self {
+        Self::SystemHeader("functional")
+    }
+
     /// Creates a `CcInclude` that represents `#include <memory>`.
     /// See also https://en.cppreference.com/w/cpp/header/memory
     pub fn memory() -> Self {
         Self::SystemHeader("memory")
     }
 
+    /// Creates a `CcInclude` that represents `#include <sstream>` and provides
+    /// C++ types like `std::istringstream`.
+    /// See also https://en.cppreference.com/w/cpp/header/sstream
+    pub fn sstream() -> Self {
+        Self::SystemHeader("sstream")
+    }
+
+    /// Creates a `CcInclude` that represents `#include <type_traits>` and
+    /// provides C++ type traits like `std::is_trivially_copyable_v`.
+    /// See also https://en.cppreference.com/w/cpp/header/type_traits
+    pub fn type_traits() -> Self {
+        Self::SystemHeader("type_traits")
+    }
+
     /// Creates a `CcInclude` that represents `#include <utility>` and provides
     /// C++ functions like `std::move` and C++ types like `std::tuple`.
     /// See also https://en.cppreference.com/w/cpp/header/utility
@@ -340,6 +423,21 @@ pub mod tests {
     use token_stream_matchers::{assert_cc_matches, assert_rs_matches};
     use token_stream_printer::cc_tokens_to_formatted_string_for_tests;
 
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!(to_snake_case("foo"), "foo");
+        assert_eq!(to_snake_case("foo_bar"), "foo_bar");
+        assert_eq!(to_snake_case("_foo"), "_foo");
+        assert_eq!(to_snake_case("Foo"), "foo");
+        assert_eq!(to_snake_case("MemberFunction"), "member_function");
+        assert_eq!(to_snake_case("memberFunction"), "member_function");
+        assert_eq!(to_snake_case("Get_Foo"), "get_foo");
+        assert_eq!(to_snake_case("GetHTTPServer"), "get_http_server");
+        assert_eq!(to_snake_case("ToUTF8"), "to_utf8");
+        assert_eq!(to_snake_case("Foo2Bar"), "foo2_bar");
+        assert_eq!(to_snake_case("FOO"), "foo");
+    }
+
     #[test]
     fn test_format_cc_ident_basic() {
         assert_cc_matches!(
@@ -441,6 +539,26 @@ pub mod tests {
         assert_rs_matches!(quote! { #id }, quote! { r#impl });
     }
 
+    #[test]
+    fn test_make_rs_ident_non_raw_keyword() {
+        for (ident, expected) in
+            [("self", "self_"), ("Self", "Self_"), ("super", "super_"), ("crate", "crate_")]
+        {
+            assert_eq!(make_rs_ident(ident).to_string(), expected);
+        }
+        assert_eq!(make_rs_ident("_").to_string(), "__");
+    }
+
+    #[test]
+    fn test_make_rs_lifetime() {
+        assert_eq!(make_rs_lifetime("a").to_string(), "'a");
+        assert_eq!(make_rs_lifetime("static").to_string(), "'static");
+        assert_eq!(make_rs_lifetime("_").to_string(), "'_");
+        assert_eq!(make_rs_lifetime("type").to_string(), "'type_");
+        assert_eq!(make_rs_lifetime("self").to_string(), "'self_");
+        assert_eq!(make_rs_lifetime("async").to_string(), "'async_");
+    }
+
     #[test]
     #[should_panic]
     fn test_make_rs_ident_unfinished_group() {
@@ -0,0 +1,183 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#include "common/text_diff.h"
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <string>
+#include <vector>
+
+#include "absl/strings/str_cat.h"
+#include "absl/strings/str_split.h"
+#include "absl/strings/strip.h"
+#include "absl/strings/string_view.h"
+
+namespace crubit {
+
+namespace {
+
+// The maximum size of the table of the longest common subsequence of the
+// changed lines. If the table would be larger, the changed lines are reported
+// as deleted and inserted as a whole.
+constexpr size_t kMaxLcsTableSize = size_t{1} << 22;
+
+enum class EditKind { kEqual, kDelete, kInsert };
+
+struct Edit {
+  EditKind kind;
+  // The index of the line in the old text (for `kEqual` and `kDelete`) or of
+  // the next line of the old text (for `kInsert`).
+  size_t old_index;
+  // The index of the line in the new text (for `kEqual` and `kInsert`) or of
+  // the next line of the new text (for `kDelete`).
+  size_t new_index;
+};
+
+std::vector<absl::string_view> SplitLines(absl::string_view text) {
+  if (text.empty()) {
+    return {};
+  }
+  // The final newline terminates the last line rather than starting a new one.
+  absl::ConsumeSuffix(&text, "\n");
+  return absl::StrSplit(text, '\n');
+}
+
+// Returns the edits which turn `old_lines` into `new_lines`, in order.
+std::vector<Edit> ComputeEdits(const std::vector<absl::string_view>& old_lines,
+                               const std::vector<absl::string_view>& new_lines) {
+  // Generated files usually change in a few places, so the common prefix and
+  // suffix are skipped before computing the longest common subsequence.
+  size_t prefix = 0;
+  while (prefix < old_lines.size() && prefix < new_lines.size() &&
+         old_lines[prefix] == new_lines[prefix]) {
+    ++prefix;
+  }
+  size_t suffix = 0;
+  while (suffix < old_lines.size() - prefix &&
+         suffix < new_lines.size() - prefix &&
+         old_lines[old_lines.size() - 1 - suffix] ==
+             new_lines[new_lines.size() - 1 - suffix]) {
+    ++suffix;
+  }
+  size_t old_size = old_lines.size() - prefix - suffix;
+  size_t new_size = new_lines.size() - prefix - suffix;
+
+  std::vector<Edit> edits;
+  for (size_t i = 0; i < prefix; ++i) {
+    edits.push_back({EditKind::kEqual, i, i});
+  }
+  size_t i = 0;
+  size_t j = 0;
+  if (new_size == 0 || old_size <= kMaxLcsTableSize / new_size) {
+    // `lcs[i][j]` is the length of the longest common subsequence of the
+    // changed old lines starting at `i` and the changed new lines starting at
+    // `j`.
+    std::vector<std::vector<uint32_t>> lcs(
+        old_size + 1, std::vector<uint32_t>(new_size + 1, 0));
+    for (size_t old_i = old_size; old_i-- > 0;) {
+      for (size_t new_i = new_size; new_i-- > 0;) {
+        lcs[old_i][new_i] =
+            old_lines[prefix + old_i] == new_lines[prefix + new_i]
+                ? lcs[old_i + 1][new_i + 1] + 1
+                : std::max(lcs[old_i + 1][new_i], lcs[old_i][new_i + 1]);
+      }
+    }
+    while (i < old_size && j < new_size) {
+      if (old_lines[prefix + i] == new_lines[prefix + j]) {
+        edits.push_back({EditKind::kEqual, prefix + i, prefix + j});
+        ++i;
+        ++j;
+      } else if (lcs[i + 1][j] >= lcs[i][j + 1]) {
+        edits.push_back({EditKind::kDelete, prefix + i, prefix + j});
+        ++i;
+      } else {
+        edits.push_back({EditKind::kInsert, prefix + i, prefix + j});
+        ++j;
+      }
+    }
+  }
+  for (; i < old_size; ++i) {
+    edits.push_back({EditKind::kDelete, prefix + i, prefix + j});
+  }
+  for (; j < new_size; ++j) {
+    edits.push_back({EditKind::kInsert, prefix + i, prefix + j});
+  }
+  for (size_t k = 0; k < suffix; ++k) {
+    edits.push_back({EditKind::kEqual, prefix + old_size + k,
+                     prefix + new_size + k});
+  }
+  return edits;
+}
+
+// Formats the start of a range of lines in a hunk header: the 1-based index of
+// the first line, or the index of the line before an empty range.
+size_t HunkStart(size_t first_index, size_t count) {
+  return count == 0 ? first_index : first_index + 1;
+}
+
+}  // namespace
+
+std::string UnifiedDiff(absl::string_view old_text, absl::string_view new_text,
+                        absl::string_view old_name, absl::string_view new_name,
+                        int context_lines) {
+  if (old_text == new_text) {
+    return "";
+  }
+  std::vector<absl::string_view> old_lines = SplitLines(old_text);
+  std::vector<absl::string_view> new_lines = SplitLines(new_text);
+  std::vector<Edit> edits = ComputeEdits(old_lines, new_lines);
+  size_t context = static_cast<size_t>(std::max(context_lines, 0));
+
+  std::string diff = absl::StrCat("--- ", old_name, "\n+++ ", new_name, "\n");
+  size_t k = 0;
+  while (k < edits.size()) {
+    if (edits[k].kind == EditKind::kEqual) {
+      ++k;
+      continue;
+    }
+    // A hunk extends up to the last change which is followed by at most
+    // `2 * context` unchanged lines.
+    size_t hunk_begin = k >= context ? k - context : 0;
+    size_t last_change = k;
+    for (size_t next = k + 1;
+         next < edits.size() && next <= last_change + 2 * context + 1; ++next) {
+      if (edits[next].kind != EditKind::kEqual) {
+        last_change = next;
+      }
+    }
+    size_t hunk_end = std::min(edits.size(), last_change + context + 1);
+
+    size_t old_count = 0;
+    size_t new_count = 0;
+    std::string lines;
+    for (size_t e = hunk_begin; e < hunk_end; ++e) {
+      const Edit& edit = edits[e];
+      switch (edit.kind) {
+        case EditKind::kEqual:
+          ++old_count;
+          ++new_count;
+          absl::StrAppend(&lines, " ", old_lines[edit.old_index], "\n");
+          break;
+        case EditKind::kDelete:
+          ++old_count;
+          absl::StrAppend(&lines, "-", old_lines[edit.old_index], "\n");
+          break;
+        case EditKind::kInsert:
+          ++new_count;
+          absl::StrAppend(&lines, "+", new_lines[edit.new_index], "\n");
+          break;
+      }
+    }
+    absl::StrAppend(
+        &diff, "@@ -", HunkStart(edits[hunk_begin].old_index, old_count), ",",
+        old_count, " +", HunkStart(edits[hunk_begin].new_index, new_count),
+        ",", new_count, " @@\n", lines);
+    k = hunk_end;
+  }
+  return diff;
+}
+
+}  // namespace crubit
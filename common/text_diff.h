@@ -0,0 +1,24 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#ifndef CRUBIT_COMMON_TEXT_DIFF_H_
+#define CRUBIT_COMMON_TEXT_DIFF_H_
+
+#include <string>
+
+#include "absl/strings/string_view.h"
+
+namespace crubit {
+
+// Returns a line-based diff of `old_text` and `new_text` in the unified
+// format (as printed by `diff -u`, with `context_lines` lines of context
+// around the changes), or an empty string if the texts are equal. The headers
+// of the diff name the texts `old_name` and `new_name`.
+std::string UnifiedDiff(absl::string_view old_text, absl::string_view new_text,
+                        absl::string_view old_name, absl::string_view new_name,
+                        int context_lines = 3);
+
+}  // namespace crubit
+
+#endif  // CRUBIT_COMMON_TEXT_DIFF_H_
@@ -0,0 +1,67 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#include "common/text_diff.h"
+
+#include "gmock/gmock.h"
+#include "gtest/gtest.h"
+
+namespace crubit {
+namespace {
+
+TEST(TextDiffTest, EqualTexts) {
+  EXPECT_EQ(UnifiedDiff("a\nb\n", "a\nb\n", "old", "new"), "");
+}
+
+TEST(TextDiffTest, ChangedLine) {
+  EXPECT_EQ(UnifiedDiff("1\n2\n3\n4\n5\n6\n7\n8\n",
+                        "1\n2\n3\n4\nfive\n6\n7\n8\n", "old", "new"),
+            "--- old\n"
+            "+++ new\n"
+            "@@ -2,7 +2,7 @@\n"
+            " 2\n"
+            " 3\n"
+            " 4\n"
+            "-5\n"
+            "+five\n"
+            " 6\n"
+            " 7\n"
+            " 8\n");
+}
+
+TEST(TextDiffTest, InsertedAndDeletedLines) {
+  EXPECT_EQ(UnifiedDiff("a\nb\nc\n", "a\nc\nd\n", "old", "new",
+                        /*context_lines=*/0),
+            "--- old\n"
+            "+++ new\n"
+            "@@ -2,1 +1,0 @@\n"
+            "-b\n"
+            "@@ -3,0 +3,1 @@\n"
+            "+d\n");
+}
+
+TEST(TextDiffTest, NearbyChangesShareAHunk) {
+  EXPECT_EQ(UnifiedDiff("a\nb\nc\nd\n", "A\nb\nc\nD\n", "old", "new",
+                        /*context_lines=*/1),
+            "--- old\n"
+            "+++ new\n"
+            "@@ -1,4 +1,4 @@\n"
+            "-a\n"
+            "+A\n"
+            " b\n"
+            " c\n"
+            "-d\n"
+            "+D\n");
+}
+
+TEST(TextDiffTest, MissingOldText) {
+  EXPECT_EQ(UnifiedDiff("", "a\n", "old", "new"),
+            "--- old\n"
+            "+++ new\n"
+            "@@ -0,0 +1,1 @@\n"
+            "+a\n");
+}
+
+}  // namespace
+}  // namespace crubit
@@ -2,13 +2,15 @@
 // Exceptions. See /LICENSE for license information.
 // SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
 
-use anyhow::{bail, Result};
-use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use anyhow::{anyhow, bail, Context, Result};
+use proc_macro2::{Delimiter, Group, Punct, Spacing, TokenStream, TokenTree};
+use quote::quote;
 use std::ffi::{OsStr, OsString};
 use std::fmt::Write as _;
 use std::io::Write as _;
 use std::path::{Path, PathBuf};
-use std::process::{Command, Stdio};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::thread::JoinHandle;
 
 // TODO(b/231320237): The `RustfmtConfig` struct should be replaced with
 // `rustfmt_nightly::Config` once we switch to using rustfmt as a library
@@ -88,12 +90,28 @@ pub fn rs_tokens_to_formatted_string_for_tests(input: TokenStream) -> Result<Str
     rs_tokens_to_formatted_string(input, &RustfmtConfig::for_testing())
 }
 
-/// Like `tokens_to_string` but also runs the result through `clang-format`.
+/// Like `tokens_to_string`, but formats the Rust source code in-process with
+/// `prettyplease` (see `RsPrettyPrinter`). This is much faster than `rustfmt`,
+/// though the output is less polished (e.g. the arguments of macros aren't
+/// formatted).
+pub fn rs_tokens_to_pretty_string(tokens: TokenStream) -> Result<String> {
+    let mut printer = RsPrettyPrinter::new();
+    printer.print(tokens)?;
+    printer.finish()
+}
+
+/// Produces the source code of the token stream, without formatting it.
+pub fn tokens_to_unformatted_string(tokens: TokenStream) -> Result<String> {
+    tokens_to_string(tokens)
+}
+
+/// Like `cc_tokens_to_pretty_string` but also runs the result through
+/// `clang-format`.
 pub fn cc_tokens_to_formatted_string(
     tokens: TokenStream,
     clang_format_exe_path: &Path,
 ) -> Result<String> {
-    clang_format(tokens_to_string(tokens)?, clang_format_exe_path)
+    clang_format(cc_tokens_to_pretty_string(tokens)?, clang_format_exe_path)
 }
 
 /// Like `cc_tokens_to_formatted_string`, but always using a hardcoded path to
@@ -101,9 +119,201 @@ pub fn cc_tokens_to_formatted_string(
 /// should only be called by tests - product code should take the path to the
 /// `clang-format` binary as a cmdline argument.
 pub fn cc_tokens_to_formatted_string_for_tests(tokens: TokenStream) -> Result<String> {
-    clang_format(tokens_to_string(tokens)?, Path::new(CLANG_FORMAT_EXE_PATH_FOR_TESTING))
+    clang_format(cc_tokens_to_pretty_string(tokens)?, Path::new(CLANG_FORMAT_EXE_PATH_FOR_TESTING))
+}
+
+/// Like `tokens_to_string`, but formats the C++ source code in-process (see
+/// `CcPrettyPrinter`). Unlike `tokens_to_string`, this supports the C++ string
+/// and character literals which can't be written as Rust ones (e.g. `"\001"`).
+pub fn cc_tokens_to_pretty_string(tokens: TokenStream) -> Result<String> {
+    let mut printer = CcPrettyPrinter::new();
+    printer.print(tokens)?;
+    Ok(printer.finish())
+}
+
+/// A `rustfmt` or `clang-format` process which formats the source code that
+/// is written into it, and copies the formatted source code into an output.
+///
+/// This makes it possible to produce a large source file chunk by chunk,
+/// without building all of its source code (or all of its tokens) in memory.
+pub struct FormattingWriter {
+    exe_name: &'static str,
+    /// Formats the C++ source code before it is written into `clang-format`,
+    /// so that it has the placeholders and literals of `CcPrettyPrinter`.
+    cc_printer: Option<CcPrettyPrinter>,
+    child: Child,
+    stdin: ChildStdin,
+    copy_output: JoinHandle<std::io::Result<u64>>,
+}
+
+impl FormattingWriter {
+    /// Spawns `rustfmt`, which writes the formatted Rust source code to
+    /// `output`.
+    pub fn rustfmt(config: &RustfmtConfig, output: Box<dyn std::io::Write + Send>) -> Result<Self> {
+        Self::spawn(
+            "rustfmt",
+            &config.exe_path,
+            config.cmdline_args.iter().map(OsString::as_os_str),
+            output,
+        )
+    }
+
+    /// Spawns `clang-format`, which writes the formatted C++ source code to
+    /// `output`.
+    pub fn clang_format(
+        clang_format_exe_path: &Path,
+        output: Box<dyn std::io::Write + Send>,
+    ) -> Result<Self> {
+        Ok(Self {
+            cc_printer: Some(CcPrettyPrinter::new()),
+            ..Self::spawn(
+                "clang-format",
+                clang_format_exe_path,
+                [OsStr::new("--style=google")],
+                output,
+            )?
+        })
+    }
+
+    fn spawn<'a>(
+        exe_name: &'static str,
+        exe_path: &Path,
+        args: impl IntoIterator<Item = &'a OsStr>,
+        mut output: Box<dyn std::io::Write + Send>,
+    ) -> Result<Self> {
+        let mut child = Command::new(exe_path)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .with_context(|| format!("Failed to spawn {exe_name} at {exe_path:?}"))?;
+        let stdin = child.stdin.take().ok_or_else(|| anyhow!("Failed to open {exe_name} stdin"))?;
+        let mut stdout =
+            child.stdout.take().ok_or_else(|| anyhow!("Failed to open {exe_name} stdout"))?;
+        let copy_output = std::thread::spawn(move || {
+            let copied = std::io::copy(&mut stdout, &mut output)?;
+            output.flush()?;
+            Ok(copied)
+        });
+        Ok(Self { exe_name, cc_printer: None, child, stdin, copy_output })
+    }
+
+    /// Writes the source code of `tokens` (see `tokens_to_string` and
+    /// `CcPrettyPrinter`).
+    pub fn write_tokens(&mut self, tokens: TokenStream) -> Result<()> {
+        let source_code = match &mut self.cc_printer {
+            Some(printer) => {
+                printer.print(tokens)?;
+                printer.take_output()
+            }
+            None => tokens_to_string(tokens)?,
+        };
+        self.write_str(&source_code)
+    }
+
+    /// Writes `source_code` verbatim.
+    pub fn write_str(&mut self, source_code: &str) -> Result<()> {
+        let exe_name = self.exe_name;
+        self.stdin
+            .write_all(source_code.as_bytes())
+            .with_context(|| format!("Failed to write to {exe_name} stdin"))
+    }
+
+    /// Closes the input of the formatter, and waits until all of the formatted
+    /// source code has been written to the output.
+    pub fn finish(mut self) -> Result<()> {
+        if let Some(printer) = self.cc_printer.take() {
+            let source_code = printer.finish();
+            self.write_str(&source_code)?;
+        }
+        let Self { exe_name, child, stdin, copy_output, .. } = self;
+        drop(stdin);
+        let output =
+            child.wait_with_output().with_context(|| format!("Failed to wait for {exe_name}"))?;
+        copy_output
+            .join()
+            .map_err(|_| anyhow!("Failed to copy the output of {exe_name}"))?
+            .with_context(|| format!("Failed to copy the output of {exe_name}"))?;
+        if !output.status.success() {
+            bail!("{exe_name} reported an error: {}", String::from_utf8_lossy(&output.stderr));
+        }
+        Ok(())
+    }
 }
 
+/// Writes Rust source code into an output chunk by chunk, like
+/// `FormattingWriter::rustfmt`, but formats it in-process (or not at all).
+pub struct RsWriter {
+    /// `None` if the source code is written without formatting it.
+    printer: Option<RsPrettyPrinter>,
+    output: Box<dyn std::io::Write + Send>,
+}
+
+impl RsWriter {
+    /// Creates a writer which formats the source code with `RsPrettyPrinter`.
+    pub fn pretty(output: Box<dyn std::io::Write + Send>) -> Self {
+        Self { printer: Some(RsPrettyPrinter::new()), output }
+    }
+
+    /// Creates a writer which doesn't format the source code at all.
+    pub fn unformatted(output: Box<dyn std::io::Write + Send>) -> Self {
+        Self { printer: None, output }
+    }
+
+    /// Writes the source code of `tokens`.
+    pub fn write_tokens(&mut self, tokens: TokenStream) -> Result<()> {
+        let source_code = match &mut self.printer {
+            Some(printer) => {
+                printer.print(tokens)?;
+                printer.take_output()
+            }
+            None => tokens_to_string(tokens)?,
+        };
+        self.write_str(&source_code)
+    }
+
+    /// Writes the source code of `header`, followed by an opening brace. The
+    /// source code written until the matching `close_brace` is enclosed in the
+    /// braces.
+    pub fn open_brace(&mut self, header: TokenStream) -> Result<()> {
+        let source_code = match &mut self.printer {
+            Some(printer) => {
+                printer.open_brace(header)?;
+                printer.take_output()
+            }
+            None => tokens_to_string(header)? + " { ",
+        };
+        self.write_str(&source_code)
+    }
+
+    /// Writes the closing brace matching the last `open_brace`.
+    pub fn close_brace(&mut self) -> Result<()> {
+        let source_code = match &mut self.printer {
+            Some(printer) => {
+                printer.close_brace()?;
+                printer.take_output()
+            }
+            None => " }\n".to_string(),
+        };
+        self.write_str(&source_code)
+    }
+
+    /// Writes the end of the source code, and flushes the output.
+    pub fn finish(mut self) -> Result<()> {
+        if let Some(printer) = self.printer.take() {
+            let source_code = printer.finish()?;
+            self.write_str(&source_code)?;
+        }
+        self.output.flush().context("Failed to flush the Rust source code")
+    }
+
+    fn write_str(&mut self, source_code: &str) -> Result<()> {
+        self.output
+            .write_all(source_code.as_bytes())
+            .context("Failed to write the Rust source code")
+    }
+}
 
 /// Produces source code out of the token stream.
 ///
@@ -201,6 +411,953 @@ fn is_ident_or_literal(tt: &TokenTree) -> bool {
     }
 }
 
+/// Formats Rust source code in-process with `prettyplease`, as an alternative
+/// to `rustfmt`.
+///
+/// `prettyplease` formats whole items, so the tokens are split into items, and
+/// the placeholders of `tokens_to_string` between them are handled here:
+/// * at most one blank line is kept where there are several consecutive
+///   `__NEWLINE__` placeholders,
+/// * the `__COMMENT__`s are put on lines of their own (the ones in items are
+///   turned into doc comments marked with `COMMENT_MARKER`, and back), and
+/// * the contents of the braces of `open_brace` are indented by 4 spaces.
+///
+/// The items which `prettyplease` can't parse are written unformatted.
+struct RsPrettyPrinter {
+    output: String,
+    /// Whether each of the enclosing braces of `open_brace` is an `extern`
+    /// block, whose items can't be parsed elsewhere.
+    braces: Vec<bool>,
+    /// The tokens of the item being written, which may span several calls
+    /// (e.g. an attribute of the header of `open_brace`).
+    item: Vec<TokenTree>,
+    /// The number of `__NEWLINE__` placeholders since the last item.
+    pending_newlines: usize,
+    /// Whether a blank line may be written before the next item (there are no
+    /// blank lines at the beginning of the file or of a block).
+    blank_line_allowed: bool,
+}
+
+/// Marks the doc comments which are `__COMMENT__`s in the formatted items.
+const COMMENT_MARKER: char = '\0';
+
+impl RsPrettyPrinter {
+    fn new() -> Self {
+        Self {
+            output: String::new(),
+            braces: vec![],
+            item: vec![],
+            pending_newlines: 0,
+            blank_line_allowed: false,
+        }
+    }
+
+    /// Writes the source code of `tokens`.
+    fn print(&mut self, tokens: TokenStream) -> Result<()> {
+        let mut tokens = tokens.into_iter().peekable();
+        while let Some(token) = tokens.next() {
+            if self.item.is_empty() {
+                match &token {
+                    TokenTree::Ident(ident) if ident == "__NEWLINE__" => {
+                        self.pending_newlines += 1;
+                        continue;
+                    }
+                    TokenTree::Ident(ident) if ident == "__SPACE__" => continue,
+                    TokenTree::Ident(ident) if ident == "__COMMENT__" => {
+                        let comment = comment_value(tokens.next())?;
+                        self.write_lines(&format!("// {}", comment.replace('\n', "\n// ")));
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+            // Items end with a `;`, or with braces which aren't followed by a
+            // `;` (unlike e.g. `const _: () = { ... };`). The inner attributes
+            // are put on lines of their own.
+            let ends_item = match &token {
+                TokenTree::Punct(punct) => punct.as_char() == ';',
+                TokenTree::Group(group) => match group.delimiter() {
+                    Delimiter::Brace => !matches!(
+                        tokens.peek(),
+                        Some(TokenTree::Punct(punct)) if punct.as_char() == ';'
+                    ),
+                    Delimiter::Bracket => matches!(
+                        &self.item[..],
+                        [TokenTree::Punct(hash), TokenTree::Punct(bang)]
+                            if hash.as_char() == '#' && bang.as_char() == '!'
+                    ),
+                    _ => false,
+                },
+                _ => false,
+            };
+            self.item.push(token);
+            if ends_item {
+                self.print_item()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the source code of `header`, followed by an opening brace.
+    fn open_brace(&mut self, header: TokenStream) -> Result<()> {
+        let is_extern = header
+            .clone()
+            .into_iter()
+            .any(|token| matches!(&token, TokenTree::Ident(ident) if ident == "extern"));
+        self.print(header)?;
+        let header: TokenStream = self.item.drain(..).collect();
+        let formatted = unparse(quote! { #header {} }, self.in_extern_block())?;
+        let source_code = match formatted.as_deref().and_then(|item| item.strip_suffix("{}\n")) {
+            Some(header) => format!("{header}{{"),
+            None => tokens_to_string(header)? + " {",
+        };
+        self.write_lines(&source_code);
+        self.braces.push(is_extern);
+        self.blank_line_allowed = false;
+        Ok(())
+    }
+
+    /// Writes the closing brace matching the last `open_brace`.
+    fn close_brace(&mut self) -> Result<()> {
+        if self.braces.is_empty() {
+            bail!("Unmatched closing brace in the Rust source code");
+        }
+        self.print_item()?;
+        self.braces.pop();
+        // Like `rustfmt`, there are no blank lines at the end of a block.
+        self.pending_newlines = 0;
+        self.write_lines("}");
+        Ok(())
+    }
+
+    /// Returns the source code written so far, and forgets it.
+    fn take_output(&mut self) -> String {
+        std::mem::take(&mut self.output)
+    }
+
+    /// Returns the rest of the source code, which ends with a newline.
+    fn finish(mut self) -> Result<String> {
+        if !self.braces.is_empty() {
+            bail!("Unclosed brace in the Rust source code");
+        }
+        self.print_item()?;
+        Ok(self.output)
+    }
+
+    fn in_extern_block(&self) -> bool {
+        self.braces.last() == Some(&true)
+    }
+
+    /// Writes the item whose tokens have been collected, if any.
+    fn print_item(&mut self) -> Result<()> {
+        if self.item.is_empty() {
+            return Ok(());
+        }
+        let item: TokenStream = self.item.drain(..).collect();
+        let source_code = match unparse(item.clone(), self.in_extern_block())? {
+            Some(source_code) => source_code,
+            None => tokens_to_string(item)?,
+        };
+        self.write_lines(&source_code);
+        Ok(())
+    }
+
+    /// Writes `source_code` on lines of its own, indented by the enclosing
+    /// braces.
+    fn write_lines(&mut self, source_code: &str) {
+        if self.pending_newlines >= 2 && self.blank_line_allowed {
+            self.output.push('\n');
+        }
+        for line in source_code.lines() {
+            let line = line.trim_end();
+            if !line.is_empty() {
+                for _ in 0..self.braces.len() {
+                    self.output.push_str("    ");
+                }
+                self.output.push_str(line);
+            }
+            self.output.push('\n');
+        }
+        self.pending_newlines = 0;
+        self.blank_line_allowed = true;
+    }
+}
+
+/// Formats the items of `tokens` with `prettyplease` (as the items of an
+/// `extern` block if `in_extern_block`), or returns `None` if they can't be
+/// parsed.
+fn unparse(tokens: TokenStream, in_extern_block: bool) -> Result<Option<String>> {
+    let tokens = replace_placeholders(tokens)?;
+    let tokens = if in_extern_block {
+        quote! { extern "C" { #tokens } }
+    } else {
+        tokens
+    };
+    let file: syn::File = match syn::parse2(tokens) {
+        Ok(file) => file,
+        Err(_) => return Ok(None),
+    };
+    let source_code = prettyplease::unparse(&file);
+    let mut lines: Vec<&str> = source_code.lines().collect();
+    if in_extern_block {
+        // Removes the `extern "C" {` and `}` lines.
+        if lines.len() < 2 {
+            return Ok(None);
+        }
+        lines = lines[1..lines.len() - 1].to_vec();
+    }
+    let comment_prefix = format!("///{COMMENT_MARKER}");
+    Ok(Some(
+        lines
+            .into_iter()
+            .map(|line| {
+                let line =
+                    if in_extern_block { line.strip_prefix("    ").unwrap_or(line) } else { line };
+                match line.trim_start().strip_prefix(&comment_prefix) {
+                    Some(comment) => {
+                        let indent = &line[..line.len() - line.trim_start().len()];
+                        format!("{indent}//{comment}\n")
+                    }
+                    None => format!("{line}\n"),
+                }
+            })
+            .collect(),
+    ))
+}
+
+/// Removes the `__NEWLINE__` and `__SPACE__` placeholders, which `prettyplease`
+/// doesn't need, and turns the `__COMMENT__`s into doc comments marked with
+/// `COMMENT_MARKER`.
+///
+/// The doc comments of several lines are split into one attribute per line,
+/// which `prettyplease` writes as `///` comments (instead of `/** */`).
+fn replace_placeholders(tokens: TokenStream) -> Result<TokenStream> {
+    let mut result = TokenStream::new();
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(hash) if hash.as_char() == '#' => {
+                let bang = tokens.next_if(
+                    |token| matches!(token, TokenTree::Punct(punct) if punct.as_char() == '!'),
+                );
+                let doc = match tokens.peek() {
+                    Some(TokenTree::Group(attribute)) => doc_attribute_value(attribute),
+                    _ => None,
+                };
+                match doc {
+                    Some(doc) if doc.contains('\n') => {
+                        tokens.next();
+                        for line in doc.split('\n') {
+                            result.extend([TokenTree::Punct(hash.clone())]);
+                            result.extend(bang.clone());
+                            result.extend(quote! { [doc = #line] });
+                        }
+                    }
+                    _ => {
+                        result.extend([TokenTree::Punct(hash)]);
+                        result.extend(bang);
+                    }
+                }
+            }
+            TokenTree::Ident(ident) if ident == "__NEWLINE__" || ident == "__SPACE__" => {}
+            TokenTree::Ident(ident) if ident == "__HASH_TOKEN__" => {
+                result.extend([TokenTree::Punct(Punct::new('#', Spacing::Alone))])
+            }
+            TokenTree::Ident(ident) if ident == "__COMMENT__" => {
+                for line in comment_value(tokens.next())?.split('\n') {
+                    let doc = format!("{COMMENT_MARKER} {line}");
+                    result.extend(quote! { #[doc = #doc] });
+                }
+            }
+            TokenTree::Group(group) => {
+                let mut replaced =
+                    Group::new(group.delimiter(), replace_placeholders(group.stream())?);
+                replaced.set_span(group.span());
+                result.extend([TokenTree::Group(replaced)]);
+            }
+            token => result.extend([token]),
+        }
+    }
+    Ok(result)
+}
+
+/// Returns the value of a `#[doc = "..."]` attribute.
+fn doc_attribute_value(attribute: &Group) -> Option<String> {
+    if attribute.delimiter() != Delimiter::Bracket {
+        return None;
+    }
+    let tokens: Vec<TokenTree> = attribute.stream().into_iter().collect();
+    match &tokens[..] {
+        [TokenTree::Ident(doc), TokenTree::Punct(eq), TokenTree::Literal(value)]
+            if doc == "doc" && eq.as_char() == '=' =>
+        {
+            string_literal_value(&value.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Returns the text of a `__COMMENT__`, from the string literal `token`
+/// following it.
+fn comment_value(token: Option<TokenTree>) -> Result<String> {
+    match token {
+        Some(TokenTree::Literal(literal)) => string_literal_value(&literal.to_string()),
+        _ => None,
+    }
+    .ok_or_else(|| anyhow!("__COMMENT__ must be followed by a string literal"))
+}
+
+/// Returns the value of a string literal (e.g. `a"b` for `"a\"b"`), or `None`
+/// if `literal` isn't a string literal.
+fn string_literal_value(literal: &str) -> Option<String> {
+    if let Some(raw) = literal.strip_prefix('r') {
+        let hashes = &raw[..raw.len() - raw.trim_start_matches('#').len()];
+        let value =
+            raw[hashes.len()..].strip_prefix('"')?.strip_suffix(&format!("\"{hashes}") as &str)?;
+        return Some(value.to_string());
+    }
+    let mut chars = literal.strip_prefix('"')?.strip_suffix('"')?.chars();
+    let mut value = String::new();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        let escaped = match chars.next()? {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '0' => '\0',
+            c @ ('\\' | '\'' | '"') => c,
+            'x' => {
+                let code: String = chars.by_ref().take(2).collect();
+                char::from(u8::from_str_radix(&code, 16).ok()?)
+            }
+            'u' => {
+                let code: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                char::from_u32(u32::from_str_radix(code.strip_prefix('{')?, 16).ok()?)?
+            }
+            '\n' => {
+                // A line continuation, which skips the leading whitespace of
+                // the next line.
+                while chars.clone().next().is_some_and(char::is_whitespace) {
+                    chars.next();
+                }
+                continue;
+            }
+            _ => return None,
+        };
+        value.push(escaped);
+    }
+    Some(value)
+}
+
+/// Where a token stream is printed, which determines where the lines break.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Layout {
+    /// At the top level, or in braces: the declarations, statements and
+    /// members are put on lines of their own.
+    Block,
+    /// In parentheses: the braces (e.g. of closures) still span several
+    /// lines, but the rest is on the current line.
+    Parens,
+    /// In brackets (e.g. in attributes): everything is on the current line.
+    Brackets,
+}
+
+/// The C++ keywords which are separated by a space from the following
+/// parenthesis, bracket or operator (e.g. `if (`, `return *x`, `new (p)`).
+const CC_SPACED_KEYWORDS: [&str; 15] = [
+    "case",
+    "catch",
+    "co_return",
+    "delete",
+    "do",
+    "else",
+    "for",
+    "if",
+    "new",
+    "return",
+    "switch",
+    "template",
+    "throw",
+    "try",
+    "while",
+];
+
+/// The C++ keywords which are directly followed by a block (e.g. `try {`).
+const CC_BLOCK_KEYWORDS: [&str; 3] = ["do", "else", "try"];
+
+/// The C++ access specifiers, which are printed as labels (e.g. `public:`).
+const CC_ACCESS_SPECIFIERS: [&str; 3] = ["private", "protected", "public"];
+
+/// The C++ operators made of several punctuation characters, longest first.
+const CC_MULTI_CHAR_OPS: [&str; 25] = [
+    "...", "<<=", ">>=", "->*", "::", "->", "==", "!=", "<=", ">=", "&&", "||", "++", "--", "+=",
+    "-=", "*=", "/=", "%=", "^=", "&=", "|=", "<<", ">>", ".*",
+];
+
+/// What `CcPrettyPrinter` wrote last, which determines whether the next token
+/// is separated from it by a space.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CcLast {
+    /// Nothing has been written on the current line yet.
+    LineStart,
+    /// An identifier.
+    Word,
+    /// A literal, which unlike an identifier isn't a type (e.g. `1 && x`, but
+    /// `T&& x`).
+    Literal,
+    /// One of `CC_SPACED_KEYWORDS`.
+    Keyword,
+    /// The `operator` keyword, which is followed by the overloaded operator
+    /// without a space (e.g. `operator==`).
+    Operator,
+    /// An opening parenthesis, bracket or brace.
+    Open,
+    /// A closing parenthesis, bracket or brace.
+    Close,
+    /// An operator which is followed by a space (e.g. `=`, `?` or `,`).
+    SpacedOp,
+    /// An operator which isn't followed by a space (e.g. `::`, `!` or `->`).
+    PrefixOp,
+    /// A `*`, `&`, `&&` or `...` which is attached to the preceding type
+    /// (e.g. `T* x` or `Args&&... args`).
+    PtrOp,
+    /// A closing angle bracket, which is only followed by a space before a
+    /// word (e.g. `std::vector<T> x`, but `std::vector<T>::iterator`).
+    CloseAngle,
+}
+
+/// The state of the declaration or statement being written by
+/// `CcPrettyPrinter`, which determines whether its braces are a block (e.g.
+/// `void f() { ... }`) or an initializer list (e.g. `S s = {1, 2};`).
+#[derive(Default)]
+struct CcStatement {
+    /// Whether each of the open angle brackets follows `template`, i.e.
+    /// whether it starts a template parameter list.
+    angles: Vec<bool>,
+    /// Whether the statement declares a class, a union or an enum.
+    declares_type: bool,
+    /// Whether the statement declares a namespace or a linkage specification
+    /// (e.g. `extern "C" {`), whose contents aren't indented.
+    declares_namespace: bool,
+    /// Whether the statement has an `=`.
+    has_assignment: bool,
+    /// Whether the statement has parentheses (e.g. of function parameters).
+    has_parens: bool,
+    /// Whether the statement has a `new` expression.
+    has_new: bool,
+    /// Whether the member initializers of a constructor are being written.
+    in_member_initializers: bool,
+    /// Whether a `case` or `default` label is being written.
+    in_case_label: bool,
+}
+
+/// Formats C++ source code in-process, before it is passed to `clang-format`
+/// (or instead of it):
+/// * the declarations and statements are put on lines of their own, and the
+///   contents of braces (except for namespaces and `extern "C"` blocks) are
+///   indented by 2 spaces,
+/// * the operators and template angle brackets are spaced like in the Google
+///   C++ style in most cases,
+/// * `__HASH_TOKEN__` starts a preprocessor directive, which ends at the next
+///   `__NEWLINE__`,
+/// * `__COMMENT__` placeholders are turned into `//` comments,
+/// * the Rust string and character literals are turned into C++ ones (see
+///   `cc_literal`), and
+/// * at most one blank line is kept where the tokens have several
+///   consecutive `__NEWLINE__` placeholders (see `tokens_to_string`).
+///
+/// Long lines aren't wrapped.
+struct CcPrettyPrinter {
+    output: String,
+    /// The number of enclosing indented braces.
+    indent: usize,
+    last: CcLast,
+    /// The number of `__NEWLINE__` placeholders since the last token.
+    pending_newlines: usize,
+    /// Whether a blank line may be written before the next line (there are no
+    /// blank lines at the beginning of a block).
+    blank_line_allowed: bool,
+    /// Whether nothing has been written yet. Like with `tokens_to_string`, a
+    /// single `__NEWLINE__` makes a blank line at the beginning of the file.
+    at_file_start: bool,
+    /// The name of the preprocessor directive being written (e.g. `include`),
+    /// or an empty string right after its `#`.
+    directive: Option<String>,
+    /// Whether the path of an `#include <...>` is being written.
+    in_include_path: bool,
+}
+
+impl CcPrettyPrinter {
+    fn new() -> Self {
+        Self {
+            output: String::new(),
+            indent: 0,
+            last: CcLast::LineStart,
+            pending_newlines: 0,
+            blank_line_allowed: false,
+            at_file_start: true,
+            directive: None,
+            in_include_path: false,
+        }
+    }
+
+    /// Writes the source code of `tokens`.
+    fn print(&mut self, tokens: TokenStream) -> Result<()> {
+        self.print_tokens(tokens, Layout::Block)
+    }
+
+    /// Returns the source code written so far, and forgets it.
+    fn take_output(&mut self) -> String {
+        std::mem::take(&mut self.output)
+    }
+
+    /// Returns the rest of the source code, which ends with a newline.
+    fn finish(mut self) -> String {
+        self.newline();
+        self.output
+    }
+
+    fn print_tokens(&mut self, tokens: TokenStream, layout: Layout) -> Result<()> {
+        let mut statement = CcStatement::default();
+        let mut tokens = tokens.into_iter().peekable();
+        while let Some(token) = tokens.next() {
+            match token {
+                TokenTree::Ident(ident) if ident == "__NEWLINE__" => {
+                    if self.directive.take().is_some() {
+                        self.in_include_path = false;
+                        self.newline();
+                    }
+                    self.pending_newlines += 1;
+                }
+                TokenTree::Ident(ident) if ident == "__SPACE__" => {
+                    if self.last != CcLast::LineStart && !self.output.ends_with(' ') {
+                        self.output.push(' ');
+                    }
+                }
+                TokenTree::Ident(ident) if ident == "__HASH_TOKEN__" => {
+                    // The preprocessor directives are on lines of their own,
+                    // and aren't indented.
+                    self.newline();
+                    self.in_include_path = false;
+                    self.directive = Some(String::new());
+                    self.write(false, "#", CcLast::PrefixOp);
+                }
+                TokenTree::Ident(ident) if ident == "__COMMENT__" => {
+                    let comment = match tokens.next() {
+                        Some(TokenTree::Literal(literal)) => {
+                            string_literal_value(&literal.to_string())
+                        }
+                        _ => None,
+                    };
+                    match comment {
+                        Some(comment) => self.print_comment(&comment),
+                        None => bail!("__COMMENT__ must be followed by a string literal"),
+                    }
+                }
+                // E.g. `sys/types.h` in `#include <sys/types.h>`.
+                token if self.in_include_path => match token {
+                    TokenTree::Punct(punct) if punct.as_char() == '>' => {
+                        self.in_include_path = false;
+                        self.write(false, ">", CcLast::Word);
+                    }
+                    token => self.write(false, &token.to_string(), CcLast::PrefixOp),
+                },
+                TokenTree::Ident(ident) => {
+                    let word = ident.to_string();
+                    let space_before = !matches!(self.last, CcLast::Open | CcLast::PrefixOp);
+                    let before_colon = matches!(
+                        tokens.peek(),
+                        Some(TokenTree::Punct(punct))
+                            if punct.as_char() == ':'
+                                && punct.spacing() == proc_macro2::Spacing::Alone
+                    );
+                    if self.directive.as_deref() == Some("") {
+                        self.directive = Some(word.clone());
+                    }
+                    match word.as_str() {
+                        _ if before_colon && CC_ACCESS_SPECIFIERS.contains(&word.as_str()) => {
+                            tokens.next();
+                            self.print_access_specifier(&word);
+                            continue;
+                        }
+                        "case" => statement.in_case_label = true,
+                        "default" if before_colon => statement.in_case_label = true,
+                        "class" | "enum" | "struct" | "union" => statement.declares_type = true,
+                        "extern" | "namespace" => statement.declares_namespace = true,
+                        "new" => statement.has_new = true,
+                        _ => {}
+                    }
+                    let last = if word == "operator" {
+                        CcLast::Operator
+                    } else if CC_SPACED_KEYWORDS.contains(&word.as_str()) {
+                        CcLast::Keyword
+                    } else {
+                        CcLast::Word
+                    };
+                    self.write(space_before, &word, last);
+                }
+                TokenTree::Literal(literal) => {
+                    let space_before = !matches!(self.last, CcLast::Open | CcLast::PrefixOp);
+                    self.write(space_before, &cc_literal(&literal.to_string()), CcLast::Literal);
+                }
+                TokenTree::Punct(punct) => {
+                    // Consecutive punctuation characters may form several
+                    // operators (e.g. `>::` in `std::vector<T>::iterator`).
+                    let mut chars = punct.as_char().to_string();
+                    let mut spacing = punct.spacing();
+                    while spacing == proc_macro2::Spacing::Joint {
+                        match tokens.peek() {
+                            Some(TokenTree::Punct(next)) => {
+                                chars.push(next.as_char());
+                                spacing = next.spacing();
+                                tokens.next();
+                            }
+                            _ => break,
+                        }
+                    }
+                    let mut rest = chars.as_str();
+                    while !rest.is_empty() {
+                        let op_len = CC_MULTI_CHAR_OPS
+                            .iter()
+                            .find(|op| rest.starts_with(*op))
+                            .map_or(1, |op| op.len());
+                        self.print_op(&rest[..op_len], layout, &mut statement);
+                        rest = &rest[op_len..];
+                    }
+                }
+                TokenTree::Group(group) => {
+                    let is_brace = group.delimiter() == Delimiter::Brace;
+                    let is_block = is_brace && self.is_block(&statement, layout);
+                    let indented = !statement.declares_namespace || statement.has_parens;
+                    if group.delimiter() == Delimiter::Parenthesis {
+                        statement.has_parens = true;
+                    }
+                    self.print_group(&group, layout, is_block, indented)?;
+                    if !is_block {
+                        continue;
+                    }
+                    statement = CcStatement::default();
+                    // Like the declarations and statements, the blocks are
+                    // followed by a line break, unless they are part of an
+                    // expression or a statement (e.g. `};`, `} else {` or
+                    // `} catch (...) {`).
+                    let continued = match tokens.peek() {
+                        Some(TokenTree::Punct(_)) => true,
+                        Some(TokenTree::Ident(ident)) => {
+                            ident == "else" || ident == "catch" || ident == "while"
+                        }
+                        _ => false,
+                    };
+                    if layout == Layout::Block && !continued {
+                        self.newline();
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the braces which follow the tokens of `statement` written so
+    /// far are a block (e.g. of a function, a class or a lambda), rather than
+    /// an initializer list (e.g. `S{1, 2}` or `= {1, 2}`).
+    fn is_block(&self, statement: &CcStatement, layout: Layout) -> bool {
+        if layout == Layout::Brackets || self.directive.is_some() {
+            return false;
+        }
+        match self.last {
+            CcLast::LineStart | CcLast::Close => true,
+            CcLast::Keyword => {
+                CC_BLOCK_KEYWORDS.iter().any(|keyword| self.output.ends_with(keyword))
+            }
+            _ if (statement.declares_type || statement.declares_namespace)
+                && !statement.has_assignment =>
+            {
+                true
+            }
+            // E.g. `void f() const {` or `[]() mutable {`, but not
+            // `new (p) S{1, 2}` or `S() : x_{1} {}`.
+            CcLast::Word => {
+                statement.has_parens && !statement.has_new && !statement.in_member_initializers
+            }
+            _ => false,
+        }
+    }
+
+    fn print_op(&mut self, op: &str, layout: Layout, statement: &mut CcStatement) {
+        // E.g. `operator==` or `operator<<`.
+        if self.last == CcLast::Operator {
+            self.write(false, op, CcLast::Word);
+            return;
+        }
+        let after_operand = matches!(self.last, CcLast::Word | CcLast::Literal | CcLast::Close);
+        let after_separator = matches!(self.last, CcLast::Keyword | CcLast::SpacedOp);
+        match op {
+            "," => self.write(false, op, CcLast::SpacedOp),
+            ";" => {
+                self.write(false, op, CcLast::SpacedOp);
+                *statement = CcStatement::default();
+                if layout == Layout::Block && self.directive.is_none() {
+                    self.newline();
+                }
+            }
+            ":" if statement.in_case_label => {
+                statement.in_case_label = false;
+                self.write(false, op, CcLast::SpacedOp);
+                if layout == Layout::Block {
+                    self.newline();
+                }
+            }
+            ":" => {
+                if self.last == CcLast::Close && statement.has_parens {
+                    statement.in_member_initializers = true;
+                }
+                self.write(self.last != CcLast::LineStart, op, CcLast::SpacedOp);
+            }
+            "->" if self.last == CcLast::Close => {
+                // A trailing return type, e.g. `auto f() -> int`.
+                self.write(true, op, CcLast::SpacedOp);
+            }
+            "." | "->" | ".*" | "->*" => self.write(false, op, CcLast::PrefixOp),
+            "::" => {
+                let space_before =
+                    matches!(self.last, CcLast::Keyword | CcLast::SpacedOp | CcLast::PtrOp);
+                self.write(space_before, op, CcLast::PrefixOp);
+            }
+            "<" if self.directive.as_deref() == Some("include") => {
+                self.in_include_path = true;
+                self.write(true, op, CcLast::PrefixOp);
+            }
+            "<" if self.last == CcLast::Word || self.output.ends_with("template") => {
+                let after_template = self.last == CcLast::Keyword;
+                statement.angles.push(after_template);
+                self.write(after_template, op, CcLast::PrefixOp);
+            }
+            ">>" if statement.angles.len() == 1 => {
+                // E.g. `a<b>> 1`.
+                self.print_op(">", layout, statement);
+                self.print_op(">", layout, statement);
+            }
+            ">" | ">>" if !statement.angles.is_empty() => {
+                let mut after_template = false;
+                for _ in 0..op.len() {
+                    after_template = statement.angles.pop().unwrap_or(false);
+                }
+                self.write(false, op, CcLast::CloseAngle);
+                // The template parameter lists are on lines of their own.
+                if after_template && layout == Layout::Block {
+                    self.newline();
+                }
+            }
+            "*" | "&" | "&&"
+                if matches!(self.last, CcLast::Word | CcLast::CloseAngle | CcLast::PtrOp) =>
+            {
+                self.write(false, op, CcLast::PtrOp)
+            }
+            "..." => self.write(false, op, CcLast::PtrOp),
+            "++" | "--" if after_operand => self.write(false, op, CcLast::Word),
+            "*" | "&" | "&&" | "+" | "-" | "++" | "--" if !after_operand => {
+                self.write(after_separator, op, CcLast::PrefixOp)
+            }
+            "!" | "~" => self.write(after_separator, op, CcLast::PrefixOp),
+            _ => {
+                if op == "=" {
+                    statement.has_assignment = true;
+                }
+                let space_before = !matches!(self.last, CcLast::LineStart | CcLast::Open);
+                self.write(space_before, op, CcLast::SpacedOp);
+            }
+        }
+    }
+
+    fn print_group(
+        &mut self,
+        group: &proc_macro2::Group,
+        layout: Layout,
+        is_block: bool,
+        indented: bool,
+    ) -> Result<()> {
+        let (open, close, inner_layout) = match group.delimiter() {
+            Delimiter::Parenthesis => ("(", ")", Layout::Parens),
+            Delimiter::Bracket => ("[", "]", Layout::Brackets),
+            Delimiter::Brace => {
+                if is_block {
+                    let space_before = !matches!(self.last, CcLast::LineStart | CcLast::Open);
+                    if group.stream().is_empty() {
+                        self.write(space_before, "{}", CcLast::Close);
+                    } else {
+                        self.open_block(indented);
+                        self.print_tokens(group.stream(), Layout::Block)?;
+                        self.close_block(indented);
+                    }
+                } else {
+                    // E.g. `S{1, 2}`, `new S{}` or `= {1, 2}`.
+                    let space_before = !matches!(
+                        self.last,
+                        CcLast::LineStart
+                            | CcLast::Word
+                            | CcLast::Open
+                            | CcLast::PrefixOp
+                            | CcLast::CloseAngle
+                    );
+                    self.write(space_before, "{", CcLast::Open);
+                    self.print_tokens(group.stream(), Layout::Brackets)?;
+                    self.write(false, "}", CcLast::Close);
+                }
+                return Ok(());
+            }
+            Delimiter::None => return self.print_tokens(group.stream(), layout),
+        };
+        // E.g. `[[nodiscard]]`.
+        let is_attribute = group.delimiter() == Delimiter::Bracket
+            && matches!(
+                &group.stream().into_iter().collect::<Vec<_>>()[..],
+                [TokenTree::Group(inner)] if inner.delimiter() == Delimiter::Bracket
+            );
+        let space_before = if is_attribute {
+            !matches!(self.last, CcLast::LineStart | CcLast::Open)
+        } else {
+            matches!(self.last, CcLast::Keyword | CcLast::SpacedOp)
+        };
+        self.write(space_before, open, CcLast::Open);
+        self.print_tokens(group.stream(), inner_layout)?;
+        self.write(false, close, CcLast::Close);
+        if is_attribute {
+            self.last = CcLast::SpacedOp;
+        }
+        Ok(())
+    }
+
+    fn print_access_specifier(&mut self, access_specifier: &str) {
+        self.newline();
+        self.write(false, &format!("{access_specifier}:"), CcLast::Word);
+        // Like in the Google C++ style, the access specifiers are indented by
+        // one space less than the members.
+        let start = self.output.len() - access_specifier.len() - 2;
+        if self.output[start..].starts_with(' ') {
+            self.output.remove(start);
+        }
+        self.newline();
+    }
+
+    fn print_comment(&mut self, comment: &str) {
+        for (index, line) in comment.split('\n').enumerate() {
+            if index > 0 {
+                self.newline();
+            } else if self.last != CcLast::LineStart {
+                // Like in the Google C++ style, the comments at the end of a
+                // line are separated from the code by two spaces.
+                self.output.truncate(self.output.trim_end_matches(' ').len());
+                self.output.push_str("  ");
+            }
+            self.write(false, format!("// {line}").trim_end(), CcLast::Word);
+        }
+        self.newline();
+        // Like with `tokens_to_string`, the comment ends with a newline, so a
+        // single `__NEWLINE__` after it makes a blank line.
+        self.pending_newlines = 1;
+    }
+
+    fn open_block(&mut self, indented: bool) {
+        let space_before = !matches!(self.last, CcLast::LineStart | CcLast::Open);
+        self.write(space_before, "{", CcLast::Open);
+        if indented {
+            self.indent += 1;
+        }
+        self.blank_line_allowed = false;
+        self.newline();
+    }
+
+    fn close_block(&mut self, indented: bool) {
+        self.newline();
+        if indented {
+            self.indent -= 1;
+        }
+        self.pending_newlines = 0;
+        self.write(false, "}", CcLast::Close);
+    }
+
+    fn write(&mut self, space_before: bool, text: &str, last: CcLast) {
+        if self.last == CcLast::LineStart {
+            let blank_line = if self.at_file_start {
+                self.pending_newlines >= 1
+            } else {
+                self.pending_newlines >= 2 && self.blank_line_allowed
+            };
+            if blank_line {
+                self.output.push('\n');
+            }
+            if self.directive.is_none() {
+                for _ in 0..self.indent {
+                    self.output.push_str("  ");
+                }
+            }
+        } else if (space_before || cc_tokens_would_merge(&self.output, text))
+            && !self.output.ends_with(' ')
+        {
+            self.output.push(' ');
+        }
+        self.output.push_str(text);
+        self.last = last;
+        self.pending_newlines = 0;
+        self.blank_line_allowed = true;
+        self.at_file_start = false;
+    }
+
+    fn newline(&mut self) {
+        if self.last == CcLast::LineStart {
+            return;
+        }
+        self.output.truncate(self.output.trim_end_matches(' ').len());
+        self.output.push('\n');
+        self.last = CcLast::LineStart;
+    }
+}
+
+/// Whether writing `text` right after `output` would merge their tokens (e.g.
+/// `- -x` and `--x`, or `a / /* b */`).
+fn cc_tokens_would_merge(output: &str, text: &str) -> bool {
+    let (Some(last), Some(first)) = (output.chars().next_back(), text.chars().next()) else {
+        return false;
+    };
+    let pair = format!("{last}{first}");
+    pair == "//" || pair == "/*" || CC_MULTI_CHAR_OPS.iter().any(|op| op.starts_with(&pair))
+}
+
+/// Returns the C++ source code of a literal: the Rust string and character
+/// literals are turned into C++ ones (e.g. `"\u{1}"` into `"\001"`), and the
+/// other literals are returned as is.
+fn cc_literal(literal: &str) -> String {
+    let (value, quote) = match literal.strip_prefix('\'').and_then(|l| l.strip_suffix('\'')) {
+        Some(inner) => (string_literal_value(&format!("\"{inner}\"")), '\''),
+        None => (string_literal_value(literal), '"'),
+    };
+    let Some(value) = value else {
+        return literal.to_string();
+    };
+    let mut result = quote.to_string();
+    for c in value.chars() {
+        match c {
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c == quote => {
+                result.push('\\');
+                result.push(c);
+            }
+            // The octal escapes have at most 3 digits, so unlike the
+            // hexadecimal ones, they can be followed by any character.
+            c if c.is_ascii_control() => result.push_str(&format!("\\{:03o}", u32::from(c))),
+            c => result.push(c),
+        }
+    }
+    result.push(quote);
+    result
+}
+
 fn pipe_string_through_process<'a>(
     input: String,
     exe_name: &str,
@@ -446,6 +1603,364 @@ fn foo(
         Ok(())
     }
 
+    #[test]
+    fn test_formatting_writer() -> Result<()> {
+        let tmpdir = tempdir()?;
+        let output_path = tmpdir.path().join("output.rs");
+        let mut writer = FormattingWriter::rustfmt(
+            &RustfmtConfig::for_testing(),
+            Box::new(std::fs::File::create(&output_path)?),
+        )?;
+        writer.write_tokens(quote! { fn bar() {} })?;
+        writer.write_str("\n")?;
+        writer.write_tokens(quote! { fn foo(x: i32, y: i32) -> i32 { x + y } })?;
+        writer.finish()?;
+        assert_eq!(
+            std::fs::read_to_string(&output_path)?,
+            r#"fn bar() {}
+fn foo(x: i32, y: i32) -> i32 {
+    x + y
+}
+"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_formatting_writer_error() -> Result<()> {
+        let mut writer =
+            FormattingWriter::rustfmt(&RustfmtConfig::for_testing(), Box::new(std::io::sink()))?;
+        writer.write_str("fn foo(")?;
+        let error = writer.finish().unwrap_err();
+        assert!(error.to_string().starts_with("rustfmt reported an error"), "{error}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_rs_tokens_to_pretty_string() -> Result<()> {
+        let input = quote! {
+            #[repr(C)]
+            pub struct S<'a, T: Copy> {
+                pub x: i32,
+                y: &'a mut [u8; 4],
+            }
+            impl<'a, T: Copy> ::core::default::Default for S<'a, T> {
+                fn default() -> Self { if true { loop {} } else { unimplemented!() } }
+            }
+            fn foo(x: i32, y: i32) -> i32 { x + y }
+        };
+        assert_eq!(
+            rs_tokens_to_pretty_string(input)?,
+            r#"#[repr(C)]
+pub struct S<'a, T: Copy> {
+    pub x: i32,
+    y: &'a mut [u8; 4],
+}
+impl<'a, T: Copy> ::core::default::Default for S<'a, T> {
+    fn default() -> Self {
+        if true { loop {} } else { unimplemented!() }
+    }
+}
+fn foo(x: i32, y: i32) -> i32 {
+    x + y
+}
+"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rs_tokens_to_pretty_string_generated_items() -> Result<()> {
+        let input = quote! {
+            #[doc = " Generated from: foo.h;l=3"]
+            #[derive(Clone, Copy)]
+            #[repr(C, align(4))]
+            pub struct S {
+                __NEWLINE__ __COMMENT__ "foo : 2 bits" __NEWLINE__
+                __bitfields0: [::std::mem::MaybeUninit<u8>; 1],
+                pub x: i32,
+            }
+            forward_declare::unsafe_define!(forward_declare::symbol!("S"), S);
+            __NEWLINE__ __NEWLINE__
+
+            impl Default for S {
+                #[inline(always)]
+                fn default() -> Self {
+                    let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+                    unsafe {
+                        crate::detail::__rust_thunk___ZN1SC1Ev(&mut tmp);
+                        tmp.assume_init()
+                    }
+                }
+            }
+            __NEWLINE__ __NEWLINE__
+
+            const _: () = { assert!(S::X == 4); };
+        };
+        assert_eq!(
+            rs_tokens_to_pretty_string(input)?,
+            r#"/// Generated from: foo.h;l=3
+#[derive(Clone, Copy)]
+#[repr(C, align(4))]
+pub struct S {
+    // foo : 2 bits
+    __bitfields0: [::std::mem::MaybeUninit<u8>; 1],
+    pub x: i32,
+}
+forward_declare::unsafe_define!(forward_declare::symbol!("S"), S);
+
+impl Default for S {
+    #[inline(always)]
+    fn default() -> Self {
+        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            crate::detail::__rust_thunk___ZN1SC1Ev(&mut tmp);
+            tmp.assume_init()
+        }
+    }
+}
+
+const _: () = {
+    assert!(S::X == 4);
+};
+"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rs_tokens_to_pretty_string_unparsable_items() -> Result<()> {
+        // The tokens which aren't items are written unformatted.
+        assert_eq!(
+            rs_tokens_to_pretty_string(quote! { struct A; let x = 1; struct B; })?,
+            "struct A;\nlet x=1;\nstruct B;\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rs_tokens_to_pretty_string_doc_comments() -> Result<()> {
+        assert_eq!(
+            rs_tokens_to_pretty_string(quote! {
+                #![doc = " Crate."]
+                #[doc = " hello\n world"]
+                struct X {}
+            })?,
+            "//! Crate.\n/// hello\n/// world\nstruct X {}\n"
+        );
+        assert_eq!(
+            rs_tokens_to_pretty_string(quote! { #[doc(hidden)] struct X {} })?,
+            "#[doc(hidden)]\nstruct X {}\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rs_tokens_to_pretty_string_placeholders() -> Result<()> {
+        // Like with `rustfmt`, a single newline doesn't make a blank line, and
+        // there are no blank lines at the beginning or at the end of a block.
+        assert_eq!(
+            rs_tokens_to_pretty_string(quote! {
+                __NEWLINE__ __NEWLINE__ struct A; __NEWLINE__ struct B; __NEWLINE__ __NEWLINE__
+                __NEWLINE__ mod m { __NEWLINE__ __NEWLINE__ struct C; __NEWLINE__ __NEWLINE__ }
+            })?,
+            "struct A;\nstruct B;\n\nmod m {\n    struct C;\n}\n"
+        );
+        assert_eq!(
+            rs_tokens_to_pretty_string(quote! {
+                mod m { __COMMENT__ "line1\nline2" struct C; }
+            })?,
+            "mod m {\n    // line1\n    // line2\n    struct C;\n}\n"
+        );
+        assert!(rs_tokens_to_pretty_string(quote! { __COMMENT__ ident }).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_literal_value() {
+        assert_eq!(string_literal_value(r#""a\"b\n\\\u{e9}\x41""#).unwrap(), "a\"b\n\\\u{e9}A");
+        assert_eq!(string_literal_value(r###"r#"a"b"#"###).unwrap(), "a\"b");
+        assert_eq!(string_literal_value("42"), None);
+    }
+
+    #[test]
+    fn test_rs_writer() -> Result<()> {
+        let tmpdir = tempdir()?;
+        let output_path = tmpdir.path().join("output.rs");
+        let mut writer = RsWriter::pretty(Box::new(std::fs::File::create(&output_path)?));
+        writer.write_tokens(quote! { fn bar() {} __NEWLINE__ __NEWLINE__ })?;
+        writer.open_brace(quote! { mod m })?;
+        writer.write_tokens(quote! { fn foo(x: i32, y: i32) -> i32 { x + y } })?;
+        writer.close_brace()?;
+        writer.finish()?;
+        assert_eq!(
+            std::fs::read_to_string(&output_path)?,
+            r#"fn bar() {}
+
+mod m {
+    fn foo(x: i32, y: i32) -> i32 {
+        x + y
+    }
+}
+"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rs_writer_extern_block() -> Result<()> {
+        let tmpdir = tempdir()?;
+        let output_path = tmpdir.path().join("output.rs");
+        let mut writer = RsWriter::pretty(Box::new(std::fs::File::create(&output_path)?));
+        writer.write_tokens(quote! { #![allow(non_snake_case)] __NEWLINE__ })?;
+        writer.open_brace(quote! { mod detail })?;
+        writer.write_tokens(quote! {
+            #[allow(unused_imports)]
+            use super::*;
+            #[link(name = "foo")]
+        })?;
+        writer.open_brace(quote! { extern "C" })?;
+        writer.write_tokens(quote! {
+            #[link_name = "_Z3Addii"]
+            pub(crate) fn __rust_thunk___Z3Addii(x: i32, y: i32) -> i32;
+        })?;
+        writer.write_tokens(quote! { pub(crate) fn __rust_thunk___Z1fv(); })?;
+        writer.close_brace()?;
+        writer.close_brace()?;
+        writer.finish()?;
+        assert_eq!(
+            std::fs::read_to_string(&output_path)?,
+            r#"#![allow(non_snake_case)]
+mod detail {
+    #[allow(unused_imports)]
+    use super::*;
+    #[link(name = "foo")]
+    extern "C" {
+        #[link_name = "_Z3Addii"]
+        pub(crate) fn __rust_thunk___Z3Addii(x: i32, y: i32) -> i32;
+        pub(crate) fn __rust_thunk___Z1fv();
+    }
+}
+"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rs_writer_unformatted() -> Result<()> {
+        let tmpdir = tempdir()?;
+        let output_path = tmpdir.path().join("output.rs");
+        let mut writer = RsWriter::unformatted(Box::new(std::fs::File::create(&output_path)?));
+        writer.open_brace(quote! { mod m })?;
+        writer.write_tokens(quote! { fn foo() {} })?;
+        writer.close_brace()?;
+        writer.finish()?;
+        assert_eq!(std::fs::read_to_string(&output_path)?, "mod m { fn foo(){  } }\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_rs_writer_unmatched_braces() -> Result<()> {
+        let mut writer = RsWriter::pretty(Box::new(std::io::sink()));
+        assert!(writer.close_brace().is_err());
+        writer.open_brace(quote! { mod m })?;
+        assert!(writer.finish().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_cc_tokens_to_pretty_string() -> Result<()> {
+        let input = quote! {
+            __HASH_TOKEN__ include <sys/types.h> __NEWLINE__
+            __HASH_TOKEN__ include "foo/bar.h" __NEWLINE__ __NEWLINE__
+            namespace ns {
+            template <typename T, typename... Args>
+            class S final : public Base<T> {
+              public:
+                S(int x) : x_{x} {}
+                static std::vector<T>* Make(Args&&... args);
+              private:
+                int x_;
+            };
+            }
+            extern "C" void __rust_thunk_f(struct S* __this, char** __exception) noexcept {
+                try {
+                    new (__this) auto(ns::S<int>::Make(std::move(*__this)));
+                } catch (...) {
+                    *__exception = nullptr;
+                }
+                auto add = [&](int a, int b) -> int { return a + -b; };
+                static_assert(sizeof(S) == 1 && alignof(S) != 2);
+            }
+        };
+        assert_eq!(
+            cc_tokens_to_pretty_string(input)?,
+            r#"#include <sys/types.h>
+#include "foo/bar.h"
+
+namespace ns {
+template <typename T, typename... Args>
+class S final : public Base<T> {
+ public:
+  S(int x) : x_{x} {}
+  static std::vector<T>* Make(Args&&... args);
+ private:
+  int x_;
+};
+}
+extern "C" void __rust_thunk_f(struct S* __this, char** __exception) noexcept {
+  try {
+    new (__this) auto(ns::S<int>::Make(std::move(*__this)));
+  } catch (...) {
+    *__exception = nullptr;
+  }
+  auto add = [&](int a, int b) -> int {
+    return a + -b;
+  };
+  static_assert(sizeof(S) == 1 && alignof(S) != 2);
+}
+"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cc_tokens_to_pretty_string_spacing() -> Result<()> {
+        let pretty = |tokens| cc_tokens_to_pretty_string(tokens).unwrap();
+        assert_eq!(pretty(quote! { x = - -y; }), "x = - -y;\n");
+        assert_eq!(
+            pretty(quote! { return &__this->operator=(*x); }),
+            "return &__this->operator=(*x);\n"
+        );
+        assert_eq!(pretty(quote! { S s = {1, 2}; }), "S s = {1, 2};\n");
+        assert_eq!(pretty(quote! { [[nodiscard]] int f(); }), "[[nodiscard]] int f();\n");
+        assert_eq!(
+            pretty(quote! { switch (x) { case A::B: return 1; default: break; } }),
+            "switch (x) {\n  case A::B:\n  return 1;\n  default:\n  break;\n}\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cc_tokens_to_pretty_string_placeholders() -> Result<()> {
+        assert_eq!(
+            cc_tokens_to_pretty_string(quote! {
+                __NEWLINE__ __HASH_TOKEN__ pragma once __NEWLINE__ __NEWLINE__ __NEWLINE__
+                __COMMENT__ "line1\nline2"
+                struct S { __NEWLINE__ __NEWLINE__ int x; __COMMENT__ "y" int y; };
+                enum E { A, __COMMENT__ "a" B };
+            })?,
+            "\n#pragma once\n\n// line1\n// line2\nstruct S {\n  int x;\n  // y\n  int y;\n};\n\
+             enum E {\n  A,  // a\n  B\n};\n"
+        );
+        assert_eq!(
+            cc_tokens_to_pretty_string(quote! { f("a\"b\\\u{1}\u{e9}\n", '\'', r"\"); })?,
+            "f(\"a\\\"b\\\\\\001\u{e9}\\n\", '\\'', \"\\\\\");\n"
+        );
+        assert!(cc_tokens_to_pretty_string(quote! { __COMMENT__ ident }).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_cc_tokens_to_formatted_string_for_tests() {
         let input = quote! {
@@ -252,14 +252,19 @@ llvm::Expected<FunctionLifetimes> GetLifetimeAnnotationsInternal(
         clang::QualType return_type, clang::TypeLoc return_type_loc,
         const llvm::SmallVector<ValueLifetimes>& param_lifetimes,
         const std::optional<ValueLifetimes>& this_lifetimes) const override {
-      // TODO(veluca): adapt to lifetime elision for function pointers.
-
       std::optional<Lifetime> input_lifetime =
           GetSingleInputLifetime(param_lifetimes, this_lifetimes);
 
+      // A function outlives all the calls of the function that returns a
+      // pointer or reference to it, so the elided lifetimes of a returned
+      // function pointer or reference are `static`.
+      clang::QualType pointee_type = PointeeType(return_type);
+      bool returns_function =
+          !pointee_type.isNull() && pointee_type->isFunctionType();
+
       return ValueLifetimes::Create(
           return_type, return_type_loc,
-          [&input_lifetime,
+          [&input_lifetime, returns_function,
            this](const clang::Expr* name) -> llvm::Expected<Lifetime> {
             if (name) {
               Lifetime lifetime;
@@ -276,6 +281,10 @@ llvm::Expected<FunctionLifetimes> GetLifetimeAnnotationsInternal(
                                func->getNameAsString(), "'"));
             }
 
+            if (returns_function) {
+              return Lifetime::Static();
+            }
+
             // If we have a single input lifetime, its lifetime is assigned to
             // all output lifetimes.
             if (input_lifetime.has_value()) {
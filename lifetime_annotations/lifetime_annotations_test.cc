@@ -323,6 +323,19 @@ TEST_F(LifetimeAnnotationsTest,
               IsOkAndHolds(LifetimesAre({{"f", "a"}})));
 }
 
+TEST_F(LifetimeAnnotationsTest, LifetimeElision_ReturnFunctionPointer) {
+  EXPECT_THAT(GetNamedLifetimeAnnotations(R"(
+        #pragma clang lifetime_elision
+        typedef void (*FunctionPointer)();
+        FunctionPointer f1();
+        void (*f2(int*, int*))();
+        void (&f3())();
+  )"),
+              IsOkAndHolds(LifetimesAre({{"f1", "-> static"},
+                                         {"f2", "a, b -> static"},
+                                         {"f3", "-> static"}})));
+}
+
 TEST_F(LifetimeAnnotationsTest, LifetimeElision_FunctionReferenceLifetimes) {
   EXPECT_THAT(GetNamedLifetimeAnnotations(R"(
         #pragma clang lifetime_elision
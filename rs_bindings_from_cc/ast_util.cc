@@ -4,8 +4,18 @@
 
 #include "rs_bindings_from_cc/ast_util.h"
 
+#include <optional>
+#include <string>
+#include <vector>
+
+#include "absl/strings/string_view.h"
+#include "clang/AST/Attr.h"
+#include "clang/AST/DeclBase.h"
 #include "clang/AST/DeclCXX.h"
 #include "clang/AST/DeclTemplate.h"
+#include "clang/AST/Expr.h"
+#include "clang/AST/Type.h"
+#include "clang/Basic/Specifiers.h"
 
 namespace crubit {
 
@@ -25,4 +35,78 @@ bool IsFullClassTemplateSpecializationOrChild(const clang::Decl* decl) {
   return false;
 }
 
+bool HasAnnotation(const clang::Decl& decl, absl::string_view annotation) {
+  for (const auto* attr : decl.specific_attrs<clang::AnnotateAttr>()) {
+    if (attr->getAnnotation() == annotation) return true;
+  }
+  return false;
+}
+
+std::optional<std::string> GetAnnotationArg(const clang::Decl& decl,
+                                            absl::string_view annotation) {
+  for (const auto* attr : decl.specific_attrs<clang::AnnotateAttr>()) {
+    if (attr->getAnnotation() != annotation || attr->args_size() != 1) continue;
+    const clang::Expr* arg = (*attr->args_begin())->IgnoreParenImpCasts();
+    if (const auto* literal = clang::dyn_cast<clang::StringLiteral>(arg)) {
+      return literal->getString().str();
+    }
+  }
+  return std::nullopt;
+}
+
+std::vector<std::string> GetAnnotationArgs(const clang::Decl& decl,
+                                           absl::string_view annotation) {
+  std::vector<std::string> args;
+  for (const auto* attr : decl.specific_attrs<clang::AnnotateAttr>()) {
+    if (attr->getAnnotation() != annotation) continue;
+    for (const clang::Expr* arg : attr->args()) {
+      if (const auto* literal = clang::dyn_cast<clang::StringLiteral>(
+              arg->IgnoreParenImpCasts())) {
+        args.push_back(literal->getString().str());
+      }
+    }
+  }
+  return args;
+}
+
+std::optional<std::string> GetDeprecationMessage(const clang::Decl& decl) {
+  if (const auto* attr = decl.getAttr<clang::DeprecatedAttr>()) {
+    return attr->getMessage().str();
+  }
+  return std::nullopt;
+}
+
+std::optional<clang::NullabilityKind> GetPointerNullability(
+    const clang::Type* type) {
+  while (true) {
+    if (const auto* attributed_type =
+            clang::dyn_cast<clang::AttributedType>(type)) {
+      if (std::optional<clang::NullabilityKind> nullability =
+              attributed_type->getImmediateNullability()) {
+        return nullability;
+      }
+    } else if (const auto* tst_type =
+                   clang::dyn_cast<clang::TemplateSpecializationType>(type);
+               tst_type != nullptr && tst_type->isTypeAlias()) {
+      if (const clang::TemplateDecl* alias_template =
+              tst_type->getTemplateName().getAsTemplateDecl()) {
+        std::string name = alias_template->getQualifiedNameAsString();
+        if (name == "absl::Nonnull") return clang::NullabilityKind::NonNull;
+        if (name == "absl::Nullable") return clang::NullabilityKind::Nullable;
+        if (name == "absl::NullabilityUnknown") {
+          return clang::NullabilityKind::Unspecified;
+        }
+      }
+    }
+    // Look through type sugar (e.g. typedefs) one step at a time, so that the
+    // outermost annotation wins.
+    clang::QualType desugared =
+        type->getLocallyUnqualifiedSingleStepDesugaredType();
+    if (desugared.getTypePtr() == type) {
+      return std::nullopt;
+    }
+    type = desugared.getTypePtr();
+  }
+}
+
 }  // namespace crubit
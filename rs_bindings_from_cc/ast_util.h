@@ -5,7 +5,15 @@
 #ifndef CRUBIT_RS_BINDINGS_FROM_CC_AST_UTIL_H_
 #define CRUBIT_RS_BINDINGS_FROM_CC_AST_UTIL_H_
 
+#include <optional>
+#include <string>
+#include <vector>
+
+#include "absl/strings/string_view.h"
+#include "clang/AST/DeclBase.h"
 #include "clang/AST/DeclCXX.h"
+#include "clang/AST/Type.h"
+#include "clang/Basic/Specifiers.h"
 
 namespace crubit {
 
@@ -14,6 +22,35 @@ namespace crubit {
 // function decl) nested inside a ClassTemplateSpecializationDecl.
 bool IsFullClassTemplateSpecializationOrChild(const clang::Decl* decl);
 
+// Returns true if `decl` is annotated with `[[clang::annotate(annotation)]]`
+// (see `support/annotations.h`).
+bool HasAnnotation(const clang::Decl& decl, absl::string_view annotation);
+
+// Returns the argument of the `[[clang::annotate(annotation, "arg")]]`
+// attribute of `decl`, or `std::nullopt` if `decl` has no such attribute with a
+// single string literal argument (see `support/annotations.h`).
+std::optional<std::string> GetAnnotationArg(const clang::Decl& decl,
+                                            absl::string_view annotation);
+
+// Returns the string literal arguments of the
+// `[[clang::annotate(annotation, "arg1", "arg2", ...)]]` attribute of `decl`,
+// or an empty vector if `decl` has no such attribute.
+std::vector<std::string> GetAnnotationArgs(const clang::Decl& decl,
+                                           absl::string_view annotation);
+
+// Returns the message of the `[[deprecated("message")]]` attribute of `decl`
+// (an empty string if the attribute has no message), or `std::nullopt` if
+// `decl` isn't deprecated.
+std::optional<std::string> GetDeprecationMessage(const clang::Decl& decl);
+
+// Returns the nullability that `type` (a pointer type) is annotated with,
+// either via a `_Nonnull` / `_Nullable` / `_Null_unspecified` attribute, or via
+// one of the `absl::Nonnull<T*>` / `absl::Nullable<T*>` /
+// `absl::NullabilityUnknown<T*>` alias templates. Returns `std::nullopt` if
+// there are no nullability annotations.
+std::optional<clang::NullabilityKind> GetPointerNullability(
+    const clang::Type* type);
+
 }  // namespace crubit
 
 #endif  // CRUBIT_RS_BINDINGS_FROM_CC_AST_UTIL_H_
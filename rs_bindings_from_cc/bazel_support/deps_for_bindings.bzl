@@ -25,21 +25,29 @@ load(
     "DepsForBindingsInfo",
 )
 
+def _dep_variant_info(dep):
+    return DepVariantInfo(
+        crate_info = dep[CrateInfo] if CrateInfo in dep else None,
+        dep_info = dep[DepInfo] if DepInfo in dep else None,
+        cc_info = dep[CcInfo] if CcInfo in dep else None,
+        build_info = None,
+    )
+
 def _deps_for_bindings_impl(ctx):
-    dep_variant_infos = [
-        DepVariantInfo(
-            crate_info = dep[CrateInfo] if CrateInfo in dep else None,
-            dep_info = dep[DepInfo] if DepInfo in dep else None,
-            cc_info = dep[CcInfo] if CcInfo in dep else None,
-            build_info = None,
-        )
-        for dep in ctx.attr.deps_for_generated_rs_file
-    ]
+    deps_for_crubit_features = {}
+    for dep, feature in ctx.attr.deps_for_crubit_features.items():
+        deps_for_crubit_features.setdefault(feature, []).append(_dep_variant_info(dep))
 
     return [
         DepsForBindingsInfo(
-            deps_for_rs_file = dep_variant_infos,
+            deps_for_rs_file = [_dep_variant_info(dep) for dep in ctx.attr.deps_for_generated_rs_file],
             deps_for_cc_file = [dep[CcInfo] for dep in ctx.attr.deps_for_generated_cc_file],
+            deps_for_crubit_features = deps_for_crubit_features,
+            deps_for_exception_handling = [
+                _dep_variant_info(dep)
+                for dep in ctx.attr.deps_for_exception_handling
+            ],
+            deps_for_trace_calls = [_dep_variant_info(dep) for dep in ctx.attr.deps_for_trace_calls],
         ),
     ]
 
@@ -53,6 +61,21 @@ deps_for_bindings = rule(
             doc = "C++ dependencies that are needed to compile the generated .cc file.",
             default = [],
         ),
+        "deps_for_crubit_features": attr.label_keyed_string_dict(
+            doc = "Rust dependencies of the generated _impl.rs file that are only needed when " +
+                  "the Crubit feature they are mapped to (e.g. `bridging`) is enabled.",
+            default = {},
+        ),
+        "deps_for_exception_handling": attr.label_list(
+            doc = "Rust dependencies of the generated _impl.rs file that are only needed when " +
+                  "the bindings catch C++ exceptions (see `--exception_handling`).",
+            default = [],
+        ),
+        "deps_for_trace_calls": attr.label_list(
+            doc = "Rust dependencies of the generated _impl.rs file that are only needed when " +
+                  "the bindings trace their calls (see `--trace_calls`).",
+            default = [],
+        ),
         "_allowlist_function_transition": attr.label(
             default = "@bazel_tools//tools/allowlists/function_transition_allowlist",
         ),
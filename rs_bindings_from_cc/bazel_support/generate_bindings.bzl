@@ -17,6 +17,77 @@ def _get_hdrs_command_line(hdrs):
 def _get_extra_rs_srcs_command_line(extra_rs_srcs):
     return ["--extra_rs_srcs=" + ",".join([x.path for x in extra_rs_srcs])]
 
+def _get_preprocessor_overrides_command_line(ctx):
+    overrides = []
+    for entry in ctx.attr._preprocessor_overrides[BuildSettingInfo].value:
+        target, _, override = entry.partition("=")
+        if target == str(ctx.label):
+            overrides.append(override)
+    if not overrides:
+        return []
+    return ["--preprocessor_overrides=" + ",".join(overrides)]
+
+# The maturity of each Crubit feature (see `CrubitFeature` in `src_code_gen.rs`).
+# The features which aren't listed are only enabled by their name.
+_CRUBIT_FEATURE_MATURITIES = {
+    "bridging": "experimental",
+    "member_pointers": "experimental",
+    "operators": "supported",
+    "templates": "experimental",
+}
+
+def _get_crubit_features(ctx, attr):
+    tags = getattr(attr, "tags", [])
+    features = []
+    if "crubit_no_default_features" not in tags:
+        features += ctx.attr._default_crubit_features[BuildSettingInfo].value
+    for tag in tags:
+        if tag.startswith("crubit_feature:"):
+            features.append(tag[len("crubit_feature:"):])
+    return features
+
+def _get_crubit_features_command_line(ctx, attr):
+    return ["--crubit_features=" + ",".join(_get_crubit_features(ctx, attr))]
+
+def get_optional_deps_for_rs_file(ctx, attr, deps_for_bindings):
+    """Returns the dependencies of the generated Rust file that depend on the configuration.
+
+    These are the support crates used only by the enabled Crubit features, and
+    by the exception handling and call tracing modes.
+
+    Args:
+      ctx: The rule context.
+      attr: The current rule's attributes.
+      deps_for_bindings: The DepsForBindingsInfo of the bindings.
+
+    Returns:
+      list[DepVariantInfo]: The dependencies.
+    """
+    names = _get_crubit_features(ctx, attr)
+    deps = []
+    for feature, feature_deps in deps_for_bindings.deps_for_crubit_features.items():
+        if feature in names or _CRUBIT_FEATURE_MATURITIES.get(feature) in names:
+            deps += feature_deps
+    if ctx.attr._exception_handling[BuildSettingInfo].value != "none":
+        deps += deps_for_bindings.deps_for_exception_handling
+    if ctx.attr._trace_calls[BuildSettingInfo].value:
+        deps += deps_for_bindings.deps_for_trace_calls
+    return deps
+
+def _get_coverage_command_line(ctx, attr):
+    flags = []
+    if ctx.attr._print_bindings_coverage[BuildSettingInfo].value:
+        flags.append("--print_bindings_coverage")
+    fail_on_unsupported = []
+    for tag in getattr(attr, "tags", []):
+        if tag.startswith("crubit_min_bindings_coverage:"):
+            flags.append("--min_bindings_coverage=" + tag[len("crubit_min_bindings_coverage:"):])
+        elif tag.startswith("crubit_fail_on_unsupported:"):
+            fail_on_unsupported.append(tag[len("crubit_fail_on_unsupported:"):])
+    if fail_on_unsupported:
+        flags.append("--fail_on_unsupported=" + ",".join(fail_on_unsupported))
+    return flags
+
 def generate_bindings(
         ctx,
         attr,
@@ -44,12 +115,14 @@ def generate_bindings(
       extra_rs_srcs: A list of extra source files to add.
 
     Returns:
-      tuple(cc_output, rs_output, namespaces_output, error_report_output): The generated source files.
+      tuple(cc_output, rs_output, namespaces_output, error_report_output, bindings_report_output):
+        The generated source files.
     """
     cc_output = ctx.actions.declare_file(ctx.label.name + "_rust_api_impl.cc")
     rs_output = ctx.actions.declare_file(ctx.label.name + "_rust_api.rs")
     namespaces_output = ctx.actions.declare_file(ctx.label.name + "_namespaces.json")
     error_report_output = None
+    bindings_report_output = None
 
     rs_bindings_from_cc_flags = [
         "--stderrthreshold=2",
@@ -69,12 +142,54 @@ def generate_bindings(
         "--rustfmt_config_path",
         ctx.file._rustfmt_cfg.path,
     ]
+    if ctx.attr._deny_warnings[BuildSettingInfo].value:
+        rs_bindings_from_cc_flags.append("--deny_warnings")
+    rs_bindings_from_cc_flags.append(
+        "--exception_handling=" + ctx.attr._exception_handling[BuildSettingInfo].value,
+    )
+    rs_bindings_from_cc_flags.append(
+        "--thunk_linkage=" + ctx.attr._thunk_linkage[BuildSettingInfo].value,
+    )
+    rs_bindings_from_cc_flags.append(
+        "--rs_formatter=" + ctx.attr._rs_formatter[BuildSettingInfo].value,
+    )
+    if ctx.attr._inline_trivial_accessors[BuildSettingInfo].value:
+        rs_bindings_from_cc_flags.append("--inline_trivial_accessors")
+    if ctx.attr._canonical_item_order[BuildSettingInfo].value:
+        rs_bindings_from_cc_flags.append("--canonical_item_order")
+    if ctx.attr._line_directives[BuildSettingInfo].value:
+        rs_bindings_from_cc_flags.append("--line_directives")
+    if ctx.attr._readable_thunk_names[BuildSettingInfo].value:
+        rs_bindings_from_cc_flags.append("--readable_thunk_names")
+    if ctx.attr._generate_smoke_tests[BuildSettingInfo].value:
+        rs_bindings_from_cc_flags.append("--generate_smoke_tests")
+    if ctx.attr._rename_overloads[BuildSettingInfo].value:
+        rs_bindings_from_cc_flags.append("--rename_overloads")
+    if ctx.attr._trace_calls[BuildSettingInfo].value:
+        rs_bindings_from_cc_flags.append("--trace_calls")
+    if str(ctx.label) in ctx.attr._friend_crate_targets[BuildSettingInfo].value:
+        rs_bindings_from_cc_flags.append("--friend_crate")
+    if str(ctx.label) in ctx.attr._field_accessors_targets[BuildSettingInfo].value:
+        rs_bindings_from_cc_flags.append("--field_accessors")
+    if str(ctx.label) in ctx.attr._snake_case_function_names_targets[BuildSettingInfo].value:
+        rs_bindings_from_cc_flags.append("--snake_case_function_names")
+    if str(ctx.label) in ctx.attr._windows_dll_targets[BuildSettingInfo].value:
+        rs_bindings_from_cc_flags.append("--windows_import_lib=" + ctx.label.name)
+    rs_bindings_from_cc_flags += _get_preprocessor_overrides_command_line(ctx)
+    rs_bindings_from_cc_flags += _get_crubit_features_command_line(ctx, attr)
+    rs_bindings_from_cc_flags += _get_coverage_command_line(ctx, attr)
     if ctx.attr._generate_error_report[BuildSettingInfo].value:
         error_report_output = ctx.actions.declare_file(ctx.label.name + "_rust_api_error_report.json")
         rs_bindings_from_cc_flags += [
             "--error_report_out",
             error_report_output.path,
         ]
+    if ctx.attr._generate_bindings_report[BuildSettingInfo].value:
+        bindings_report_output = ctx.actions.declare_file(ctx.label.name + "_rust_api_bindings_report.json")
+        rs_bindings_from_cc_flags += [
+            "--bindings_report_out",
+            bindings_report_output.path,
+        ]
 
     variables = cc_common.create_compile_variables(
         feature_configuration = feature_configuration,
@@ -123,7 +238,7 @@ def generate_bindings(
             ] + ctx.files._rustfmt_cfg + extra_rs_srcs,
             transitive = [action_inputs],
         ),
-        additional_outputs = [x for x in [rs_output, namespaces_output, error_report_output] if x != None],
+        additional_outputs = [x for x in [rs_output, namespaces_output, error_report_output, bindings_report_output] if x != None],
         variables = variables,
     )
-    return (cc_output, rs_output, namespaces_output, error_report_output)
+    return (cc_output, rs_output, namespaces_output, error_report_output, bindings_report_output)
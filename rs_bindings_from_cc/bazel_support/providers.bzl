@@ -38,5 +38,11 @@ DepsForBindingsInfo = provider(
     fields = {
         "deps_for_rs_file": "list[DepVariantInfo]",
         "deps_for_cc_file": "list[CcInfo]",
+        "deps_for_crubit_features": "dict[str, list[DepVariantInfo]]: Rust dependencies " +
+                                    "only needed with a Crubit feature, keyed by its name",
+        "deps_for_exception_handling": "list[DepVariantInfo]: Rust dependencies only " +
+                                       "needed when exceptions are caught",
+        "deps_for_trace_calls": "list[DepVariantInfo]: Rust dependencies only needed " +
+                                "when calls are traced",
     },
 )
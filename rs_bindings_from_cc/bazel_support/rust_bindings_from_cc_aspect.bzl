@@ -22,6 +22,10 @@ load(
     "bindings_attrs",
     "generate_and_compile_bindings",
 )
+load(
+    "//rs_bindings_from_cc/bazel_support:generate_bindings.bzl",
+    "get_optional_deps_for_rs_file",
+)
 
 # <internal link>/127#naming-header-files-h-and-inc recommends declaring textual headers either in the
 # `textual_hdrs` attribute of the Bazel C++ rules, or using the `.inc` file extension. Therefore
@@ -161,6 +165,7 @@ def _rust_bindings_from_cc_aspect_impl(target, ctx):
         header_includes.append("-include")
         header_includes.append(hdr.short_path)
 
+    deps_for_bindings = ctx.attr._deps_for_bindings[DepsForBindingsInfo]
     return generate_and_compile_bindings(
         ctx,
         ctx.rule.attr,
@@ -179,12 +184,16 @@ def _rust_bindings_from_cc_aspect_impl(target, ctx):
             dep[RustBindingsFromCcInfo].cc_info
             for dep in all_deps
             if RustBindingsFromCcInfo in dep
-        ] + ctx.attr._deps_for_bindings[DepsForBindingsInfo].deps_for_cc_file,
+        ] + deps_for_bindings.deps_for_cc_file,
         deps_for_rs_file = [
             dep[RustBindingsFromCcInfo].dep_variant_info
             for dep in all_deps
             if RustBindingsFromCcInfo in dep
-        ] + ctx.attr._deps_for_bindings[DepsForBindingsInfo].deps_for_rs_file,
+        ] + deps_for_bindings.deps_for_rs_file + get_optional_deps_for_rs_file(
+            ctx,
+            ctx.rule.attr,
+            deps_for_bindings,
+        ),
         extra_cc_compilation_action_inputs = extra_cc_compilation_action_inputs,
     )
 
@@ -59,7 +59,7 @@ def generate_and_compile_bindings(
         unsupported_features = ctx.disabled_features + ["module_maps"],
     )
 
-    cc_output, rs_output, namespaces_output, error_report_output = generate_bindings(
+    cc_output, rs_output, namespaces_output, error_report_output, bindings_report_output = generate_bindings(
         ctx = ctx,
         attr = attr,
         cc_toolchain = cc_toolchain,
@@ -111,7 +111,7 @@ def generate_and_compile_bindings(
             rust_file = rs_output,
             namespaces_file = namespaces_output,
         ),
-        OutputGroupInfo(out = depset([x for x in [cc_output, rs_output, namespaces_output, error_report_output] if x != None])),
+        OutputGroupInfo(out = depset([x for x in [cc_output, rs_output, namespaces_output, error_report_output, bindings_report_output] if x != None])),
     ]
 
 bindings_attrs = {
@@ -170,4 +170,61 @@ bindings_attrs = {
     "_generate_error_report": attr.label(
         default = "//rs_bindings_from_cc/bazel_support:generate_error_report",
     ),
+    "_generate_bindings_report": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:generate_bindings_report",
+    ),
+    "_print_bindings_coverage": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:print_bindings_coverage",
+    ),
+    "_deny_warnings": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:deny_warnings",
+    ),
+    "_exception_handling": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:exception_handling",
+    ),
+    "_thunk_linkage": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:thunk_linkage",
+    ),
+    "_rs_formatter": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:rs_formatter",
+    ),
+    "_canonical_item_order": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:canonical_item_order",
+    ),
+    "_line_directives": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:line_directives",
+    ),
+    "_readable_thunk_names": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:readable_thunk_names",
+    ),
+    "_inline_trivial_accessors": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:inline_trivial_accessors",
+    ),
+    "_generate_smoke_tests": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:generate_smoke_tests",
+    ),
+    "_rename_overloads": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:rename_overloads",
+    ),
+    "_trace_calls": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:trace_calls",
+    ),
+    "_friend_crate_targets": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:friend_crate_targets",
+    ),
+    "_field_accessors_targets": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:field_accessors_targets",
+    ),
+    "_snake_case_function_names_targets": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:snake_case_function_names_targets",
+    ),
+    "_windows_dll_targets": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:windows_dll_targets",
+    ),
+    "_preprocessor_overrides": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:preprocessor_overrides",
+    ),
+    "_default_crubit_features": attr.label(
+        default = "//rs_bindings_from_cc/bazel_support:default_crubit_features",
+    ),
 }
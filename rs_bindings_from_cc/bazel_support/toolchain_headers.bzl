@@ -18,6 +18,10 @@ load(
     "bindings_attrs",
     "generate_and_compile_bindings",
 )
+load(
+    "//rs_bindings_from_cc/bazel_support:generate_bindings.bzl",
+    "get_optional_deps_for_rs_file",
+)
 
 def _has_suffix(input, suffices):
     for suffix in suffices:
@@ -69,6 +73,7 @@ def _bindings_for_toolchain_headers_impl(ctx):
         header_includes.append("-include")
         header_includes.append(hdr)
 
+    deps_for_bindings = ctx.attr._deps_for_bindings[DepsForBindingsInfo]
     return [RustToolchainHeadersInfo(headers = std_and_builtin_files)] + generate_and_compile_bindings(
         ctx,
         ctx.attr,
@@ -78,8 +83,9 @@ def _bindings_for_toolchain_headers_impl(ctx):
         action_inputs = std_and_builtin_files,
         targets_and_headers = targets_and_headers,
         extra_rs_srcs = ctx.files.extra_rs_srcs,
-        deps_for_cc_file = ctx.attr._deps_for_bindings[DepsForBindingsInfo].deps_for_cc_file,
-        deps_for_rs_file = ctx.attr._deps_for_bindings[DepsForBindingsInfo].deps_for_rs_file,
+        deps_for_cc_file = deps_for_bindings.deps_for_cc_file,
+        deps_for_rs_file = deps_for_bindings.deps_for_rs_file +
+                           get_optional_deps_for_rs_file(ctx, ctx.attr, deps_for_bindings),
     )
 
 bindings_for_toolchain_headers = rule(
@@ -0,0 +1,484 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+//! The binary encoding of the IR (see `IrToBinary` in `ir.h`).
+//!
+//! The encoding follows the data model of JSON, so that the IR deserializes
+//! the same from both formats, but it is faster to parse: each value starts
+//! with a tag byte, and strings, arrays, and objects are prefixed with their
+//! length. Lengths and integers are LEB128 varints (signed integers
+//! zigzag-encoded first):
+//!
+//! * `NULL`, `FALSE`, `TRUE`
+//! * `INT` followed by an `i64`, `UINT` followed by a `u64`, `FLOAT` followed
+//!   by a little-endian `f64`
+//! * `STRING` followed by the length and the UTF-8 bytes of the string
+//! * `ARRAY` followed by the number of elements and the elements
+//! * `OBJECT` followed by the number of members and the members, whose keys
+//!   are encoded without a tag (the length and the UTF-8 bytes), and are
+//!   sorted like in the JSON IR
+
+use serde::de::{
+    self, value::BorrowedStrDeserializer, DeserializeSeed, Deserializer as _, IntoDeserializer,
+    Visitor,
+};
+use serde::Deserialize;
+use std::fmt::{self, Display, Formatter};
+
+pub const NULL: u8 = 0;
+pub const FALSE: u8 = 1;
+pub const TRUE: u8 = 2;
+pub const INT: u8 = 3;
+pub const UINT: u8 = 4;
+pub const FLOAT: u8 = 5;
+pub const STRING: u8 = 6;
+pub const ARRAY: u8 = 7;
+pub const OBJECT: u8 = 8;
+
+/// An error of the deserialization of a binary IR.
+#[derive(Debug)]
+pub struct Error(String);
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl de::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+/// Deserializes a `T` from the binary encoding of a JSON value.
+pub fn from_slice<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<T, Error> {
+    let mut deserializer = Deserializer { input: bytes };
+    let value = T::deserialize(&mut deserializer)?;
+    if !deserializer.input.is_empty() {
+        return Err(Error(format!(
+            "{} trailing bytes after the binary IR",
+            deserializer.input.len()
+        )));
+    }
+    Ok(value)
+}
+
+/// Returns the binary encoding of a JSON value.
+pub fn from_json(value: &serde_json::Value) -> Vec<u8> {
+    let mut bytes = vec![];
+    encode(value, &mut bytes);
+    bytes
+}
+
+fn encode_varint(mut value: u64, bytes: &mut Vec<u8>) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+fn encode_len(len: usize, bytes: &mut Vec<u8>) {
+    encode_varint(len as u64, bytes);
+}
+
+fn encode_str(s: &str, bytes: &mut Vec<u8>) {
+    encode_len(s.len(), bytes);
+    bytes.extend(s.as_bytes());
+}
+
+fn encode(value: &serde_json::Value, bytes: &mut Vec<u8>) {
+    match value {
+        serde_json::Value::Null => bytes.push(NULL),
+        serde_json::Value::Bool(false) => bytes.push(FALSE),
+        serde_json::Value::Bool(true) => bytes.push(TRUE),
+        serde_json::Value::Number(number) => {
+            if let Some(i) = number.as_i64() {
+                bytes.push(INT);
+                encode_varint(((i << 1) ^ (i >> 63)) as u64, bytes);
+            } else if let Some(u) = number.as_u64() {
+                bytes.push(UINT);
+                encode_varint(u, bytes);
+            } else {
+                bytes.push(FLOAT);
+                bytes.extend(number.as_f64().unwrap_or_default().to_le_bytes());
+            }
+        }
+        serde_json::Value::String(s) => {
+            bytes.push(STRING);
+            encode_str(s, bytes);
+        }
+        serde_json::Value::Array(elements) => {
+            bytes.push(ARRAY);
+            encode_len(elements.len(), bytes);
+            for element in elements {
+                encode(element, bytes);
+            }
+        }
+        serde_json::Value::Object(members) => {
+            bytes.push(OBJECT);
+            encode_len(members.len(), bytes);
+            // The members of a `serde_json::Map` are sorted by key.
+            for (key, member) in members {
+                encode_str(key, bytes);
+                encode(member, bytes);
+            }
+        }
+    }
+}
+
+struct Deserializer<'de> {
+    input: &'de [u8],
+}
+
+impl<'de> Deserializer<'de> {
+    fn take(&mut self, len: usize) -> Result<&'de [u8], Error> {
+        if self.input.len() < len {
+            return Err(Error("Unexpected end of the binary IR".to_string()));
+        }
+        let (bytes, rest) = self.input.split_at(len);
+        self.input = rest;
+        Ok(bytes)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+
+    fn peek_byte(&self) -> Result<u8, Error> {
+        self.input.first().copied().ok_or_else(|| Error("Unexpected end of the binary IR".into()))
+    }
+
+    fn take_byte(&mut self) -> Result<u8, Error> {
+        let tag = self.peek_byte()?;
+        self.input = &self.input[1..];
+        Ok(tag)
+    }
+
+    fn take_varint(&mut self) -> Result<u64, Error> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take_byte()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(Error("Invalid varint in the binary IR".to_string()))
+    }
+
+    fn take_len(&mut self) -> Result<usize, Error> {
+        usize::try_from(self.take_varint()?)
+            .map_err(|_| Error("Invalid length in the binary IR".to_string()))
+    }
+
+    fn take_str(&mut self) -> Result<&'de str, Error> {
+        let len = self.take_len()?;
+        std::str::from_utf8(self.take(len)?)
+            .map_err(|err| Error(format!("Invalid string in the binary IR: {err}")))
+    }
+
+    /// Skips a value without deserializing it, which is much faster than
+    /// visiting it for the fields which are unknown to this crate.
+    fn skip_value(&mut self) -> Result<(), Error> {
+        match self.take_byte()? {
+            NULL | FALSE | TRUE => {}
+            INT | UINT => {
+                self.take_varint()?;
+            }
+            FLOAT => {
+                self.take(8)?;
+            }
+            STRING => {
+                let len = self.take_len()?;
+                self.take(len)?;
+            }
+            ARRAY => {
+                for _ in 0..self.take_len()? {
+                    self.skip_value()?;
+                }
+            }
+            OBJECT => {
+                for _ in 0..self.take_len()? {
+                    let len = self.take_len()?;
+                    self.take(len)?;
+                    self.skip_value()?;
+                }
+            }
+            tag => return Err(Error(format!("Invalid tag in the binary IR: {tag}"))),
+        }
+        Ok(())
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.take_byte()? {
+            NULL => visitor.visit_unit(),
+            FALSE => visitor.visit_bool(false),
+            TRUE => visitor.visit_bool(true),
+            INT => {
+                let zigzag = self.take_varint()?;
+                visitor.visit_i64((zigzag >> 1) as i64 ^ -((zigzag & 1) as i64))
+            }
+            UINT => visitor.visit_u64(self.take_varint()?),
+            FLOAT => visitor.visit_f64(f64::from_le_bytes(self.take_array()?)),
+            STRING => visitor.visit_borrowed_str(self.take_str()?),
+            ARRAY => {
+                let remaining = self.take_len()?;
+                visitor.visit_seq(Elements { deserializer: self, remaining })
+            }
+            OBJECT => {
+                let remaining = self.take_len()?;
+                visitor.visit_map(Elements { deserializer: self, remaining })
+            }
+            tag => Err(Error(format!("Invalid tag in the binary IR: {tag}"))),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.peek_byte()? == NULL {
+            self.take_byte()?;
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// Enums are encoded like in JSON: unit variants as a string, and the
+    /// other variants as an object with a single member.
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.take_byte()? {
+            STRING => visitor.visit_enum(self.take_str()?.into_deserializer()),
+            OBJECT if self.take_len()? == 1 => visitor.visit_enum(Variant { deserializer: self }),
+            _ => Err(Error("Expected an enum in the binary IR".to_string())),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.skip_value()?;
+        visitor.visit_unit()
+    }
+}
+
+/// The elements of an array, or the members of an object.
+struct Elements<'a, 'de> {
+    deserializer: &'a mut Deserializer<'de>,
+    remaining: usize,
+}
+
+impl<'de, 'a> de::SeqAccess<'de> for Elements<'a, 'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.deserializer).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de, 'a> de::MapAccess<'de> for Elements<'a, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        let key = self.deserializer.take_str()?;
+        seed.deserialize(BorrowedStrDeserializer::new(key)).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        seed.deserialize(&mut *self.deserializer)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+/// A variant of an enum, encoded as an object with a single member.
+struct Variant<'a, 'de> {
+    deserializer: &'a mut Deserializer<'de>,
+}
+
+impl<'de, 'a> de::EnumAccess<'de> for Variant<'a, 'de> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self), Error> {
+        let key = self.deserializer.take_str()?;
+        Ok((seed.deserialize(BorrowedStrDeserializer::new(key))?, self))
+    }
+}
+
+impl<'de, 'a> de::VariantAccess<'de> for Variant<'a, 'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        <()>::deserialize(self.deserializer)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(self.deserializer)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        self.deserializer.deserialize_seq(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserializer.deserialize_map(visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::BTreeMap;
+    use std::rc::Rc;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Rect { width: u32, height: u32 },
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Drawing {
+        name: Rc<str>,
+        layer: i32,
+        hidden: bool,
+        parent: Option<u64>,
+        shapes: Vec<Shape>,
+        #[serde(default)]
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn test_encoding() {
+        assert_eq!(from_json(&json!(null)), [NULL]);
+        assert_eq!(from_json(&json!(true)), [TRUE]);
+        assert_eq!(from_json(&json!(-2)), [INT, 3]);
+        assert_eq!(from_json(&json!(300)), [INT, 0xd8, 0x04]);
+        assert_eq!(
+            from_json(&json!(u64::MAX)),
+            [UINT, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 1]
+        );
+        assert_eq!(from_json(&json!(1.5)), [FLOAT, 0, 0, 0, 0, 0, 0, 0xf8, 0x3f]);
+        assert_eq!(from_json(&json!("ab")), [STRING, 2, b'a', b'b']);
+        assert_eq!(from_json(&json!([false])), [ARRAY, 1, FALSE]);
+        assert_eq!(
+            from_json(&json!({"b": null, "a": true})),
+            [OBJECT, 2, 1, b'a', TRUE, 1, b'b', NULL]
+        );
+    }
+
+    #[test]
+    fn test_integers() {
+        for value in [0, 1, -1, 63, -64, 64, i64::MIN, i64::MAX] {
+            assert_eq!(from_slice::<i64>(&from_json(&json!(value))).unwrap(), value);
+        }
+        assert_eq!(from_slice::<u64>(&from_json(&json!(u64::MAX))).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn test_deserialize_like_json() {
+        let value = json!({
+            "name": "drawing",
+            "layer": -1,
+            "hidden": false,
+            "parent": null,
+            "shapes": ["Point", {"Circle": 1.5}, {"Rect": {"width": 2, "height": 3}}],
+            "unknown_field": [1, {"nested": "value"}],
+        });
+        let expected = Drawing {
+            name: "drawing".into(),
+            layer: -1,
+            hidden: false,
+            parent: None,
+            shapes: vec![Shape::Point, Shape::Circle(1.5), Shape::Rect { width: 2, height: 3 }],
+            tags: vec![],
+        };
+        assert_eq!(serde_json::from_value::<Drawing>(value.clone()).unwrap(), expected);
+        assert_eq!(from_slice::<Drawing>(&from_json(&value)).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_deserialize_untagged() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        #[serde(untagged)]
+        enum IndexOrName {
+            Index(usize),
+            Name(String),
+        }
+        let value = json!({"x": 3, "y": "name"});
+        assert_eq!(
+            from_slice::<BTreeMap<String, IndexOrName>>(&from_json(&value)).unwrap(),
+            BTreeMap::from([
+                ("x".to_string(), IndexOrName::Index(3)),
+                ("y".to_string(), IndexOrName::Name("name".to_string()))
+            ])
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = from_slice::<String>(&[STRING, 1]).unwrap_err();
+        assert_eq!(err.to_string(), "Unexpected end of the binary IR");
+        let err =
+            from_slice::<u64>(&[UINT, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80])
+                .unwrap_err();
+        assert_eq!(err.to_string(), "Invalid varint in the binary IR");
+        let err = from_slice::<u32>(&[42]).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid tag in the binary IR: 42");
+        let err = from_slice::<bool>(&[TRUE, TRUE]).unwrap_err();
+        assert_eq!(err.to_string(), "1 trailing bytes after the binary IR");
+        let err = from_slice::<Shape>(&from_json(&json!({"Hexagon": null}))).unwrap_err();
+        assert!(err.to_string().contains("unknown variant `Hexagon`"), "{err}");
+    }
+}
@@ -0,0 +1,139 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#include "rs_bindings_from_cc/cc_build_manifest.h"
+
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "absl/strings/ascii.h"
+#include "absl/strings/match.h"
+#include "absl/strings/str_cat.h"
+#include "absl/strings/str_join.h"
+#include "absl/strings/string_view.h"
+#include "absl/types/span.h"
+#include "rs_bindings_from_cc/bazel_types.h"
+#include "llvm/ADT/SmallString.h"
+#include "llvm/Support/FileSystem.h"
+#include "llvm/Support/JSON.h"
+#include "llvm/Support/Path.h"
+
+namespace crubit {
+namespace {
+
+std::string MakeAbsolute(absl::string_view path) {
+  llvm::SmallString<128> result(path);
+  llvm::sys::fs::make_absolute(result);
+  llvm::sys::path::remove_dots(result, /*remove_dot_dot=*/true);
+  return std::string(result);
+}
+
+// Returns the name of the CMake library compiling the generated C++ source
+// file of `target`, e.g. `foo_rs_api_impl` for `//some/package:foo`.
+std::string CMakeLibraryName(const BazelLabel& target) {
+  absl::string_view name = target.value();
+  if (size_t pos = name.find_last_of(":/"); pos != absl::string_view::npos) {
+    name.remove_prefix(pos + 1);
+  }
+  std::string result;
+  for (char c : name) {
+    result += absl::ascii_isalnum(c) || c == '-' ? c : '_';
+  }
+  return absl::StrCat(result, "_rs_api_impl");
+}
+
+// Returns `s` as a quoted CMake argument.
+std::string CMakeQuote(absl::string_view s) {
+  std::string result = "\"";
+  for (char c : s) {
+    if (c == '"' || c == '\\' || c == '$' || c == ';') {
+      result += '\\';
+    }
+    result += c;
+  }
+  result += '"';
+  return result;
+}
+
+std::string CMakeArguments(const std::vector<std::string>& args) {
+  return absl::StrJoin(args, " ", [](std::string* out, const std::string& arg) {
+    absl::StrAppend(out, CMakeQuote(arg));
+  });
+}
+
+}  // namespace
+
+llvm::json::Value CcBuildManifest::ToJson() const {
+  return llvm::json::Object{
+      {"target", target.value()},
+      {"cc_file", cc_file},
+      {"include_dirs", include_dirs},
+      {"system_include_dirs", system_include_dirs},
+      {"defines", defines},
+      {"compile_options", compile_options},
+  };
+}
+
+std::string CcBuildManifest::ToCMake() const {
+  std::string library = CMakeLibraryName(target);
+  std::string result = absl::StrCat(
+      "# Compiles the C++ implementation of the Rust bindings of ",
+      target.value(), ".\n# Generated by Crubit, do not edit.\n\n",
+      "add_library(", library, " STATIC ", CMakeQuote(cc_file), ")\n",
+      "target_include_directories(", library, " PRIVATE \"${CRUBIT_ROOT}\"");
+  if (!include_dirs.empty()) {
+    absl::StrAppend(&result, " ", CMakeArguments(include_dirs));
+  }
+  absl::StrAppend(&result, ")\n");
+  if (!system_include_dirs.empty()) {
+    absl::StrAppend(&result, "target_include_directories(", library,
+                    " SYSTEM PRIVATE ", CMakeArguments(system_include_dirs),
+                    ")\n");
+  }
+  if (!defines.empty()) {
+    absl::StrAppend(&result, "target_compile_definitions(", library,
+                    " PRIVATE ", CMakeArguments(defines), ")\n");
+  }
+  if (!compile_options.empty()) {
+    absl::StrAppend(&result, "target_compile_options(", library, " PRIVATE ",
+                    CMakeArguments(compile_options), ")\n");
+  }
+  return result;
+}
+
+CcBuildManifest MakeCcBuildManifest(BazelLabel target,
+                                    absl::string_view cc_file,
+                                    absl::Span<const std::string> clang_args) {
+  CcBuildManifest manifest;
+  manifest.target = std::move(target);
+  manifest.cc_file = MakeAbsolute(cc_file);
+  for (size_t i = 0; i < clang_args.size(); ++i) {
+    absl::string_view arg = clang_args[i];
+    // Returns the value of the flag `flag` if `arg` is that flag, either
+    // joined (`-Idir`) or separate (`-I dir`).
+    auto value_of = [&](absl::string_view flag) -> const char* {
+      if (!absl::StartsWith(arg, flag)) return nullptr;
+      if (arg.size() > flag.size()) return arg.data() + flag.size();
+      if (i + 1 == clang_args.size()) return nullptr;
+      return clang_args[++i].c_str();
+    };
+    if (const char* dir = value_of("-isystem")) {
+      manifest.system_include_dirs.push_back(MakeAbsolute(dir));
+    } else if (const char* dir = value_of("-iquote")) {
+      manifest.include_dirs.push_back(MakeAbsolute(dir));
+    } else if (const char* dir = value_of("-I")) {
+      manifest.include_dirs.push_back(MakeAbsolute(dir));
+    } else if (const char* define = value_of("-D")) {
+      manifest.defines.push_back(define);
+    } else if (const char* undefine = value_of("-U")) {
+      manifest.compile_options.push_back(absl::StrCat("-U", undefine));
+    } else if (absl::StartsWith(arg, "-std=")) {
+      manifest.compile_options.push_back(std::string(arg));
+    }
+  }
+  return manifest;
+}
+
+}  // namespace crubit
@@ -0,0 +1,58 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#ifndef CRUBIT_RS_BINDINGS_FROM_CC_CC_BUILD_MANIFEST_H_
+#define CRUBIT_RS_BINDINGS_FROM_CC_CC_BUILD_MANIFEST_H_
+
+#include <string>
+#include <vector>
+
+#include "absl/strings/string_view.h"
+#include "absl/types/span.h"
+#include "rs_bindings_from_cc/bazel_types.h"
+#include "llvm/Support/FormatVariadic.h"
+#include "llvm/Support/JSON.h"
+
+namespace crubit {
+
+// Describes how to compile the generated C++ source file (`--cc_out`), so that
+// builds other than Bazel can consume the bindings.
+//
+// The paths are absolute. The generated file also includes the support headers
+// of Crubit (relative to the root of the Crubit sources), whose include
+// directory is up to the build.
+struct CcBuildManifest {
+  llvm::json::Value ToJson() const;
+
+  // Returns a CMake snippet which defines a static library compiling
+  // `cc_file`. The including `CMakeLists.txt` sets `CRUBIT_ROOT` to the
+  // directory of the Crubit sources.
+  std::string ToCMake() const;
+
+  BazelLabel target;
+  std::string cc_file;
+  // `-I<dir>` and `-iquote <dir>`.
+  std::vector<std::string> include_dirs;
+  // `-isystem <dir>`.
+  std::vector<std::string> system_include_dirs;
+  // `-D<name>` and `-D<name>=<value>`.
+  std::vector<std::string> defines;
+  // The other Clang arguments which affect the compilation of `cc_file`
+  // (`-std=<standard>` and `-U<name>`).
+  std::vector<std::string> compile_options;
+};
+
+// Returns the manifest of `cc_file`, generated for `target` from the headers
+// parsed with `clang_args` (without the program name).
+CcBuildManifest MakeCcBuildManifest(BazelLabel target,
+                                    absl::string_view cc_file,
+                                    absl::Span<const std::string> clang_args);
+
+inline std::string CcBuildManifestAsJson(const CcBuildManifest& manifest) {
+  return llvm::formatv("{0:2}", manifest.ToJson());
+}
+
+}  // namespace crubit
+
+#endif  // CRUBIT_RS_BINDINGS_FROM_CC_CC_BUILD_MANIFEST_H_
@@ -0,0 +1,89 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#include "rs_bindings_from_cc/cc_build_manifest.h"
+
+#include <string>
+#include <vector>
+
+#include "gmock/gmock.h"
+#include "gtest/gtest.h"
+#include "rs_bindings_from_cc/bazel_types.h"
+
+namespace crubit {
+namespace {
+
+using ::testing::ElementsAre;
+using ::testing::HasSubstr;
+using ::testing::IsEmpty;
+using ::testing::Not;
+using ::testing::StrEq;
+
+TEST(CcBuildManifestTest, ClangArgs) {
+  std::vector<std::string> clang_args = {
+      "-I/src/include", "-I",       "/src/other/../gen", "-iquote",
+      "/src/quote",     "-isystem", "/usr/include/foo",  "-DNDEBUG",
+      "-D",             "FOO=1",    "-UBAR",             "-std=c++17",
+      "-Wall"};
+  CcBuildManifest manifest = MakeCcBuildManifest(
+      BazelLabel("//some/package:foo"), "/out/foo_rs_api_impl.cc", clang_args);
+
+  EXPECT_THAT(manifest.cc_file, StrEq("/out/foo_rs_api_impl.cc"));
+  EXPECT_THAT(manifest.include_dirs,
+              ElementsAre("/src/include", "/src/gen", "/src/quote"));
+  EXPECT_THAT(manifest.system_include_dirs, ElementsAre("/usr/include/foo"));
+  EXPECT_THAT(manifest.defines, ElementsAre("NDEBUG", "FOO=1"));
+  EXPECT_THAT(manifest.compile_options, ElementsAre("-UBAR", "-std=c++17"));
+}
+
+TEST(CcBuildManifestTest, MissingFlagValue) {
+  CcBuildManifest manifest =
+      MakeCcBuildManifest(BazelLabel("//:foo"), "/out/impl.cc", {"-I"});
+  EXPECT_THAT(manifest.include_dirs, IsEmpty());
+}
+
+TEST(CcBuildManifestTest, Json) {
+  CcBuildManifest manifest = MakeCcBuildManifest(
+      BazelLabel("//:foo"), "/out/impl.cc", {"-I/src", "-DNDEBUG"});
+  EXPECT_THAT(CcBuildManifestAsJson(manifest), StrEq(R"({
+  "cc_file": "/out/impl.cc",
+  "compile_options": [],
+  "defines": [
+    "NDEBUG"
+  ],
+  "include_dirs": [
+    "/src"
+  ],
+  "system_include_dirs": [],
+  "target": "//:foo"
+})"));
+}
+
+TEST(CcBuildManifestTest, CMake) {
+  CcBuildManifest manifest = MakeCcBuildManifest(
+      BazelLabel("//some/package:foo"), "/out/impl.cc",
+      {"-I/src", "-isystem/usr/include/foo", "-DMSG=\"$HOME\"", "-std=c++17"});
+  EXPECT_THAT(manifest.ToCMake(), HasSubstr(R"(
+add_library(foo_rs_api_impl STATIC "/out/impl.cc")
+target_include_directories(foo_rs_api_impl PRIVATE "${CRUBIT_ROOT}" "/src")
+target_include_directories(foo_rs_api_impl SYSTEM PRIVATE "/usr/include/foo")
+target_compile_definitions(foo_rs_api_impl PRIVATE "MSG=\"\$HOME\"")
+target_compile_options(foo_rs_api_impl PRIVATE "-std=c++17")
+)"));
+}
+
+TEST(CcBuildManifestTest, CMakeWithoutOptionalCommands) {
+  CcBuildManifest manifest =
+      MakeCcBuildManifest(BazelLabel("//:foo"), "/out/impl.cc", {});
+  std::string cmake = manifest.ToCMake();
+  EXPECT_THAT(cmake, HasSubstr(R"(
+target_include_directories(foo_rs_api_impl PRIVATE "${CRUBIT_ROOT}")
+)"));
+  EXPECT_THAT(cmake, Not(HasSubstr("SYSTEM")));
+  EXPECT_THAT(cmake, Not(HasSubstr("target_compile_definitions")));
+  EXPECT_THAT(cmake, Not(HasSubstr("target_compile_options")));
+}
+
+}  // namespace
+}  // namespace crubit
@@ -12,8 +12,14 @@
 
 #include "absl/flags/flag.h"
 #include "absl/log/log.h"
+#include "absl/strings/ascii.h"
+#include "absl/strings/match.h"
 #include "absl/strings/str_cat.h"
+#include "absl/strings/str_split.h"
+#include "absl/strings/string_view.h"
+#include "absl/strings/strip.h"
 #include "absl/strings/substitute.h"
+#include "common/file_io.h"
 #include "common/status_macros.h"
 #include "llvm/Support/JSON.h"
 
@@ -27,6 +33,10 @@ ABSL_FLAG(std::string, cc_out, "",
 ABSL_FLAG(std::string, ir_out, "",
           "(optional) output path for the JSON IR. If not present, the JSON IR "
           "will not be dumped.");
+ABSL_FLAG(bool, binary_ir_out, false,
+          "if set to true, `--ir_out` is written in the binary format, which "
+          "is faster to deserialize than JSON for large translation units "
+          "(also when passed back in `--dependency_irs`).");
 ABSL_FLAG(std::string, crubit_support_path, "",
           "path to the crubit/support directory in a format that "
           "should be used in the #include directives inside the generated .cc "
@@ -40,10 +50,27 @@ ABSL_FLAG(std::string, rustfmt_exe_path, "",
 ABSL_FLAG(std::string, rustfmt_config_path, "",
           "(optional) path to a rustfmt.toml file that should replace the "
           "default formatting of the .rs files generated by the tool.");
+ABSL_FLAG(std::string, rs_formatter, "rustfmt",
+          "how the generated .rs files are formatted: `rustfmt` (by the "
+          "rustfmt executable), `builtin` (in-process by prettyplease, which "
+          "is much faster, though less polished), or `none` (not at all)");
 ABSL_FLAG(std::vector<std::string>, public_headers, std::vector<std::string>(),
           "public headers of the cc_library this tool should generate bindings "
           "for, in a format suitable for usage in google3-relative quote "
           "include (#include \"\").");
+ABSL_FLAG(std::vector<std::string>, module_interfaces,
+          std::vector<std::string>(),
+          "C++20 module interface units of the cc_library this tool should "
+          "generate bindings for, as `module_name=path/to/interface.cppm`. "
+          "They are precompiled and imported in addition to the public "
+          "headers.");
+ABSL_FLAG(std::vector<std::string>, preprocessor_overrides,
+          std::vector<std::string>(),
+          "(optional) `-DNAME`, `-DNAME=VALUE`, or `-UNAME` flags which "
+          "override the preprocessor definitions of the target when its "
+          "headers are imported. The overrides, and the conditional "
+          "directives of the headers of the target, are recorded in the IR "
+          "to explain why a declaration is (or isn't) in the bindings.");
 ABSL_FLAG(std::string, target, "", "The target to generate bindings for.");
 ABSL_FLAG(std::string, targets_and_headers, std::string(),
           "Information about which headers belong to which targets, encoded as "
@@ -70,6 +97,178 @@ ABSL_FLAG(std::string, namespaces_out, "",
           "namespace hierarchy.");
 ABSL_FLAG(std::string, error_report_out, "",
           "(optional) output path for the JSON error report");
+ABSL_FLAG(std::string, bindings_report_out, "",
+          "(optional) output path for the JSON bindings report, which lists "
+          "every C++ item of the target and whether it was bound, renamed, "
+          "or skipped (with the reason and the source location)");
+ABSL_FLAG(std::string, cc_build_manifest_out, "",
+          "(optional) output path for the JSON manifest describing how to "
+          "compile the C++ source file (see `--cc_out`) outside of Bazel: its "
+          "path, and the include directories, defines, and other options "
+          "taken from the Clang arguments.");
+ABSL_FLAG(std::string, cmake_out, "",
+          "(optional) output path for a CMake snippet which defines a static "
+          "library compiling the C++ source file (see `--cc_out`). The "
+          "including `CMakeLists.txt` sets `CRUBIT_ROOT` to the directory of "
+          "the Crubit sources.");
+ABSL_FLAG(std::string, generation_manifest, "",
+          "(optional) path of the generation manifest, which stores the "
+          "code generated for each item of the target keyed by the hash of "
+          "the item. If the file exists, the code of the unchanged items is "
+          "reused instead of being regenerated, and the file is then "
+          "overwritten with the manifest of the new bindings.");
+ABSL_FLAG(std::string, bindings_changelog_out, "",
+          "(optional) output path for a human-readable changelog of the "
+          "items which were added to, removed from, or changed in the "
+          "bindings since the previous run. Requires --generation_manifest, "
+          "in which the API of the target is recorded for the next run.");
+ABSL_FLAG(std::string, safe_pointer_params_allowlist, "",
+          "(optional) path of a file listing the qualified names of "
+          "functions (one per line) whose pointer parameters are non-null "
+          "and non-escaping. Such functions get a safe wrapper taking "
+          "references, as if they were annotated with "
+          "`CRUBIT_SAFE_POINTER_PARAMS`. Empty lines and lines starting with "
+          "`#` are ignored.");
+ABSL_FLAG(std::string, bindings_cache, "",
+          "(optional) path of the bindings cache, which stores the generated "
+          "bindings keyed by the hash of the IR, of the generator options, "
+          "and of the generator itself. If the key of the cache matches, the "
+          "cached bindings are written out instead of being regenerated, and "
+          "otherwise the cache is overwritten with the new bindings.");
+ABSL_FLAG(int, codegen_threads, 1,
+          "the number of threads on which the items of the target are "
+          "generated (the generated bindings don't depend on it)");
+ABSL_FLAG(bool, check, false,
+          "if set to true the bindings are regenerated and compared with the "
+          "output files on disk instead of being written, and the generator "
+          "fails with a diff of the files that are out of date (like "
+          "`rustfmt --check`)");
+ABSL_FLAG(bool, stream_output, false,
+          "if set to true the generated bindings are formatted and written "
+          "into `--rs_out` and `--cc_out` item by item, instead of being built "
+          "in memory as a whole (which takes a lot of memory for targets with "
+          "many thousands of items)");
+ABSL_FLAG(bool, deny_warnings, false,
+          "if set to true the generated Rust bindings will contain "
+          "`#![deny(warnings)]` (by default only the lints that are known to "
+          "fire on generated code are allowed)");
+ABSL_FLAG(std::vector<std::string>, allowed_lints, std::vector<std::string>(),
+          "(optional) additional lints to `#![allow(...)]` in the generated "
+          "Rust bindings (e.g. `dead_code` or `clippy::all`).");
+ABSL_FLAG(std::string, exception_handling, "none",
+          "how the generated bindings handle C++ exceptions: `none` (the "
+          "wrapped code must not throw), `panic` (exceptions are translated "
+          "into Rust panics), `result` (functions return "
+          "`Result<T, CcException>`), or `unwind` (exceptions unwind through "
+          "the Rust frames)");
+ABSL_FLAG(std::string, thunk_linkage, "external",
+          "the linkage of the C++ thunks of the functions: `external` "
+          "(ordinary `extern \"C\"` functions) or `inline` (`inline` "
+          "functions named after the target, whose copies are merged by the "
+          "linker)");
+ABSL_FLAG(bool, inline_trivial_accessors, false,
+          "if set to true, trivial inline functions (getters and setters of "
+          "fields, and functions returning a constant) are reimplemented in "
+          "the generated Rust bindings instead of being called through a "
+          "C++ thunk");
+ABSL_FLAG(bool, generate_smoke_tests, false,
+          "if set to true, the generated Rust bindings include a "
+          "`#[cfg(test)]` module with smoke tests which construct, copy, and "
+          "destroy the records of the target");
+ABSL_FLAG(bool, rename_overloads, false,
+          "if set to true, overloaded functions get bindings under names "
+          "suffixed with the types of their parameters (e.g. `Foo_i32` and "
+          "`Foo_f64`), instead of not getting bindings at all");
+ABSL_FLAG(bool, trace_calls, false,
+          "if set to true, the functions of the generated Rust bindings report "
+          "their name and the duration of each call to the tracer installed "
+          "with `cc_call_trace::set_tracer`");
+ABSL_FLAG(std::string, module_path, "",
+          "(optional) path of the module (e.g. `ffi` or `sys::ffi`) of a "
+          "handwritten crate into which the generated Rust bindings are "
+          "`include!`d. If set, the generated Rust bindings consist of a "
+          "single `mod` item named after the last segment of the path, "
+          "instead of being a crate of their own.");
+ABSL_FLAG(std::string, module_visibility, "pub",
+          "the visibility of the module of `--module_path` (e.g. `pub` or "
+          "`pub(crate)`)");
+ABSL_FLAG(bool, friend_crate, false,
+          "if set to true, the private and protected member functions of the "
+          "records of the target get `pub(crate)` bindings in the `internal` "
+          "module of the generated Rust bindings (e.g. so that tests included "
+          "into the crate can call them)");
+ABSL_FLAG(bool, field_accessors, false,
+          "if set to true, the fields of structs are `pub(crate)` in the "
+          "generated Rust bindings, and are exposed through `field()` and "
+          "`set_field()` accessor methods instead");
+ABSL_FLAG(bool, snake_case_function_names, false,
+          "if set to true, functions and methods get bindings under snake_case "
+          "names (e.g. `member_function` for `MemberFunction`), unless the "
+          "snake_case name collides with the name of another function");
+ABSL_FLAG(bool, canonical_item_order, false,
+          "if set to true, the bindings of the items are generated in a "
+          "canonical order (by source location, then by mangled name) rather "
+          "than in the order in which the items were imported");
+ABSL_FLAG(bool, line_directives, false,
+          "if set to true, the C++ thunks and layout assertions are preceded "
+          "by `#line` directives pointing at the declarations they were "
+          "generated from, so that compile errors and debuggers refer to the "
+          "headers");
+ABSL_FLAG(bool, readable_thunk_names, false,
+          "if set to true, the names of the C++ thunks start with the "
+          "readable names of the functions they call, and the bindings report "
+          "(see --bindings_report_out) has a table mapping the names of the "
+          "thunks to the C++ signatures of the functions");
+ABSL_FLAG(std::string, windows_import_lib, "",
+          "(optional) name of the Windows DLL import library (e.g. `foo` for "
+          "`foo.lib`) that the C++ library of the target is linked through. "
+          "If set, the `extern` blocks of the generated Rust bindings are "
+          "annotated with `#[link(name = ..., kind = \"dylib\")]` when "
+          "targeting Windows.");
+ABSL_FLAG(std::vector<std::string>, crubit_features,
+          std::vector<std::string>({"supported", "experimental"}),
+          "the codegen features whose bindings are generated: `templates`, "
+          "`operators`, `bridging`, `member_pointers`, and "
+          "`value_semantics`, or `supported` and `experimental` for all the "
+          "features of that maturity (`value_semantics` has no maturity, and "
+          "is only enabled by its name). The bindings which require other "
+          "features are reported as unsupported.");
+ABSL_FLAG(std::string, license_header_file, "",
+          "(optional) path of a file with the license text to put at the top "
+          "of the generated files. Its lines become `//` comments, unless "
+          "they already are.");
+ABSL_FLAG(bool, do_not_edit_banner, false,
+          "if set to true, the generated files start with a banner saying "
+          "that they are generated and must not be edited by hand");
+ABSL_FLAG(std::string, generator_version, "",
+          "(optional) version of the generator (e.g. a release number or a "
+          "commit hash) to record at the top of the generated files");
+ABSL_FLAG(bool, record_input_hashes, false,
+          "if set to true, the SHA-256 hashes of the IR of the target and of "
+          "the IRs of its dependencies are recorded at the top of the "
+          "generated files");
+ABSL_FLAG(int, min_bindings_coverage, 0,
+          "(optional) the minimum percentage of the C++ items of the target "
+          "which get bindings. The generator fails if the coverage is lower, "
+          "e.g. because a change to the headers made items unsupported.");
+ABSL_FLAG(std::vector<std::string>, fail_on_unsupported,
+          std::vector<std::string>(),
+          "(optional) qualified C++ names of items (e.g. `ns::Foo`) which "
+          "must get bindings. The generator fails if any of them is "
+          "unsupported.");
+ABSL_FLAG(bool, print_bindings_coverage, false,
+          "if set to true, a summary table of the numbers of bound, renamed, "
+          "and skipped items of each kind is printed to stderr");
+ABSL_FLAG(std::vector<std::string>, dependency_irs, std::vector<std::string>(),
+          "(optional) paths of the IR files (see `--ir_out`) of the "
+          "dependencies of the target. The types owned by these dependencies "
+          "are referred to through the crate paths recorded in their IR.");
+ABSL_FLAG(std::vector<std::string>, shared_instantiations_targets,
+          std::vector<std::string>(),
+          "(optional) labels of the dependencies which are shared crates of "
+          "common template instantiations (their IRs have to be passed in "
+          "`--dependency_irs`). The template instantiations bound by these "
+          "crates are referred to instead of being generated again.");
 
 namespace crubit {
 
@@ -86,9 +285,79 @@ bool fromJSON(const llvm::json::Value& json, TargetAndHeaders& out,
   return mapper && mapper.map("t", out.target) && mapper.map("h", out.headers);
 }
 
+// Returns whether `name` is a valid name of a macro.
+bool IsValidMacroName(absl::string_view name) {
+  return !name.empty() && !absl::ascii_isdigit(name[0]) &&
+         std::all_of(name.begin(), name.end(), [](char c) {
+           return absl::ascii_isalnum(c) || c == '_';
+         });
+}
+
+// Returns whether `preprocessor_override` is `-DNAME`, `-DNAME=VALUE`, or
+// `-UNAME`.
+bool IsValidPreprocessorOverride(absl::string_view preprocessor_override) {
+  if (absl::ConsumePrefix(&preprocessor_override, "-D")) {
+    return IsValidMacroName(preprocessor_override.substr(
+        0, preprocessor_override.find('=')));
+  }
+  return absl::ConsumePrefix(&preprocessor_override, "-U") &&
+         IsValidMacroName(preprocessor_override);
+}
+
 }  // namespace
 
 absl::StatusOr<Cmdline> Cmdline::Create() {
+  std::string exception_handling_str = absl::GetFlag(FLAGS_exception_handling);
+  ExceptionHandling exception_handling;
+  if (exception_handling_str == "none") {
+    exception_handling = ExceptionHandling::kNone;
+  } else if (exception_handling_str == "panic") {
+    exception_handling = ExceptionHandling::kPanic;
+  } else if (exception_handling_str == "result") {
+    exception_handling = ExceptionHandling::kResult;
+  } else if (exception_handling_str == "unwind") {
+    exception_handling = ExceptionHandling::kUnwind;
+  } else {
+    return absl::InvalidArgumentError(
+        absl::StrCat("Invalid value of `--exception_handling`: '",
+                     exception_handling_str,
+                     "' (expected `none`, `panic`, `result`, or `unwind`)"));
+  }
+  std::string thunk_linkage_str = absl::GetFlag(FLAGS_thunk_linkage);
+  ThunkLinkage thunk_linkage;
+  if (thunk_linkage_str == "external") {
+    thunk_linkage = ThunkLinkage::kExternal;
+  } else if (thunk_linkage_str == "inline") {
+    thunk_linkage = ThunkLinkage::kInline;
+  } else {
+    return absl::InvalidArgumentError(
+        absl::StrCat("Invalid value of `--thunk_linkage`: '",
+                     thunk_linkage_str, "' (expected `external` or `inline`)"));
+  }
+  std::string rs_formatter_str = absl::GetFlag(FLAGS_rs_formatter);
+  RsFormatter rs_formatter;
+  if (rs_formatter_str == "rustfmt") {
+    rs_formatter = RsFormatter::kRustfmt;
+  } else if (rs_formatter_str == "builtin") {
+    rs_formatter = RsFormatter::kBuiltin;
+  } else if (rs_formatter_str == "none") {
+    rs_formatter = RsFormatter::kNone;
+  } else {
+    return absl::InvalidArgumentError(absl::StrCat(
+        "Invalid value of `--rs_formatter`: '", rs_formatter_str,
+        "' (expected `rustfmt`, `builtin`, or `none`)"));
+  }
+  std::string license_header;
+  if (std::string path = absl::GetFlag(FLAGS_license_header_file);
+      !path.empty()) {
+    absl::StatusOr<std::string> contents = GetFileContents(path);
+    if (!contents.ok()) {
+      return absl::InvalidArgumentError(
+          absl::StrCat("Failed to read `--license_header_file` '", path,
+                       "': ", contents.status().message()));
+    }
+    license_header = *std::move(contents);
+  }
   return CreateFromArgs(
       absl::GetFlag(FLAGS_target), absl::GetFlag(FLAGS_cc_out),
       absl::GetFlag(FLAGS_rs_out), absl::GetFlag(FLAGS_ir_out),
@@ -102,7 +371,53 @@ absl::StatusOr<Cmdline> Cmdline::Create() {
       absl::GetFlag(FLAGS_extra_rs_srcs),
       absl::GetFlag(FLAGS_srcs_to_scan_for_instantiations),
       absl::GetFlag(FLAGS_instantiations_out),
-      absl::GetFlag(FLAGS_error_report_out));
+      absl::GetFlag(FLAGS_error_report_out),
+      GeneratorOptions{
+          .deny_warnings = absl::GetFlag(FLAGS_deny_warnings),
+          .allowed_lints = absl::GetFlag(FLAGS_allowed_lints),
+          .exception_handling = exception_handling,
+          .thunk_linkage = thunk_linkage,
+          .inline_trivial_accessors =
+              absl::GetFlag(FLAGS_inline_trivial_accessors),
+          .generate_smoke_tests = absl::GetFlag(FLAGS_generate_smoke_tests),
+          .rename_overloads = absl::GetFlag(FLAGS_rename_overloads),
+          .trace_calls = absl::GetFlag(FLAGS_trace_calls),
+          .module_path = absl::GetFlag(FLAGS_module_path),
+          .module_visibility = absl::GetFlag(FLAGS_module_visibility),
+          .friend_crate = absl::GetFlag(FLAGS_friend_crate),
+          .field_accessors = absl::GetFlag(FLAGS_field_accessors),
+          .snake_case_function_names =
+              absl::GetFlag(FLAGS_snake_case_function_names),
+          .canonical_item_order = absl::GetFlag(FLAGS_canonical_item_order),
+          .line_directives = absl::GetFlag(FLAGS_line_directives),
+          .readable_thunk_names = absl::GetFlag(FLAGS_readable_thunk_names),
+          .windows_import_lib = absl::GetFlag(FLAGS_windows_import_lib),
+          .dependency_irs = absl::GetFlag(FLAGS_dependency_irs),
+          .shared_instantiations_targets =
+              absl::GetFlag(FLAGS_shared_instantiations_targets),
+          .crubit_features = absl::GetFlag(FLAGS_crubit_features),
+          .license_header = std::move(license_header),
+          .do_not_edit_banner = absl::GetFlag(FLAGS_do_not_edit_banner),
+          .generator_version = absl::GetFlag(FLAGS_generator_version),
+          .record_input_hashes = absl::GetFlag(FLAGS_record_input_hashes),
+      },
+      absl::GetFlag(FLAGS_module_interfaces),
+      absl::GetFlag(FLAGS_generation_manifest),
+      absl::GetFlag(FLAGS_safe_pointer_params_allowlist),
+      absl::GetFlag(FLAGS_bindings_cache),
+      absl::GetFlag(FLAGS_codegen_threads), absl::GetFlag(FLAGS_check),
+      absl::GetFlag(FLAGS_preprocessor_overrides),
+      absl::GetFlag(FLAGS_stream_output),
+      absl::GetFlag(FLAGS_bindings_report_out),
+      CoverageOptions{
+          .min_coverage_percent = absl::GetFlag(FLAGS_min_bindings_coverage),
+          .fail_on_unsupported = absl::GetFlag(FLAGS_fail_on_unsupported),
+          .print_summary = absl::GetFlag(FLAGS_print_bindings_coverage),
+      },
+      absl::GetFlag(FLAGS_bindings_changelog_out),
+      absl::GetFlag(FLAGS_cc_build_manifest_out),
+      absl::GetFlag(FLAGS_cmake_out), absl::GetFlag(FLAGS_binary_ir_out),
+      rs_formatter);
 }
 
 absl::StatusOr<Cmdline> Cmdline::CreateFromArgs(
@@ -113,7 +428,16 @@ absl::StatusOr<Cmdline> Cmdline::CreateFromArgs(
     bool do_nothing, std::vector<std::string> public_headers,
     std::string targets_and_headers_str, std::vector<std::string> extra_rs_srcs,
     std::vector<std::string> srcs_to_scan_for_instantiations,
-    std::string instantiations_out, std::string error_report_out) {
+    std::string instantiations_out, std::string error_report_out,
+    GeneratorOptions generator_options,
+    std::vector<std::string> module_interfaces,
+    std::string generation_manifest,
+    std::string safe_pointer_params_allowlist, std::string bindings_cache,
+    int codegen_threads, bool check,
+    std::vector<std::string> preprocessor_overrides, bool stream_output,
+    std::string bindings_report_out, CoverageOptions coverage_options,
+    std::string bindings_changelog_out, std::string cc_build_manifest_out,
+    std::string cmake_out, bool binary_ir_out, RsFormatter rs_formatter) {
   Cmdline cmdline;
   if (current_target.empty()) {
     return absl::InvalidArgumentError("please specify --target");
@@ -131,6 +455,7 @@ absl::StatusOr<Cmdline> Cmdline::CreateFromArgs(
   cmdline.cc_out_ = std::move(cc_out);
 
   cmdline.ir_out_ = std::move(ir_out);
+  cmdline.binary_ir_out_ = binary_ir_out;
 
   cmdline.namespaces_out_ = std::move(namespaces_out);
 
@@ -150,17 +475,45 @@ absl::StatusOr<Cmdline> Cmdline::CreateFromArgs(
   cmdline.rustfmt_exe_path_ = std::move(rustfmt_exe_path);
 
   cmdline.rustfmt_config_path_ = std::move(rustfmt_config_path);
+  cmdline.rs_formatter_ = rs_formatter;
   cmdline.do_nothing_ = do_nothing;
 
-  if (public_headers.empty()) {
-    return absl::InvalidArgumentError("please specify --public_headers");
+  if (public_headers.empty() && module_interfaces.empty()) {
+    return absl::InvalidArgumentError(
+        "please specify --public_headers or --module_interfaces");
   }
   std::transform(public_headers.begin(), public_headers.end(),
                  std::back_inserter(cmdline.public_headers_),
                  [](const std::string& s) { return HeaderName(s); });
 
+  for (const std::string& module_interface : module_interfaces) {
+    std::vector<std::string> name_and_path =
+        absl::StrSplit(module_interface, absl::MaxSplits('=', 1));
+    if (name_and_path.size() != 2 || name_and_path[0].empty() ||
+        name_and_path[1].empty()) {
+      return absl::InvalidArgumentError(absl::StrCat(
+          "Expected `--module_interfaces` entries of the form "
+          "`module_name=path`, got: '",
+          module_interface, "'"));
+    }
+    cmdline.module_interfaces_.push_back(ModuleInterface{
+        .module_name = std::move(name_and_path[0]),
+        .path = HeaderName(std::move(name_and_path[1])),
+    });
+  }
+
   cmdline.extra_rs_srcs_ = std::move(extra_rs_srcs);
 
+  for (const std::string& preprocessor_override : preprocessor_overrides) {
+    if (!IsValidPreprocessorOverride(preprocessor_override)) {
+      return absl::InvalidArgumentError(absl::StrCat(
+          "Invalid entry in `--preprocessor_overrides`: '",
+          preprocessor_override,
+          "' (expected `-DNAME`, `-DNAME=VALUE`, or `-UNAME`)"));
+    }
+  }
+  cmdline.preprocessor_overrides_ = std::move(preprocessor_overrides);
+
   if (srcs_to_scan_for_instantiations.empty() != instantiations_out.empty()) {
     return absl::InvalidArgumentError(
         "please specify both --rust_sources and --instantiations_out when "
@@ -170,6 +523,133 @@ absl::StatusOr<Cmdline> Cmdline::CreateFromArgs(
   cmdline.srcs_to_scan_for_instantiations_ =
       std::move(srcs_to_scan_for_instantiations);
   cmdline.error_report_out_ = std::move(error_report_out);
+  cmdline.bindings_report_out_ = std::move(bindings_report_out);
+  cmdline.cc_build_manifest_out_ = std::move(cc_build_manifest_out);
+  cmdline.cmake_out_ = std::move(cmake_out);
+
+  // The errors of the items whose code is reused from the manifest aren't
+  // reported again, so the error report would be incomplete.
+  if (!generation_manifest.empty() && !cmdline.error_report_out_.empty()) {
+    return absl::InvalidArgumentError(
+        "--generation_manifest can't be used together with "
+        "--error_report_out");
+  }
+  // The changelog compares the API of the target with the API recorded in the
+  // manifest of the previous run.
+  if (!bindings_changelog_out.empty() && generation_manifest.empty()) {
+    return absl::InvalidArgumentError(
+        "--bindings_changelog_out requires --generation_manifest");
+  }
+  cmdline.generation_manifest_ = std::move(generation_manifest);
+  cmdline.bindings_changelog_out_ = std::move(bindings_changelog_out);
+  cmdline.safe_pointer_params_allowlist_ =
+      std::move(safe_pointer_params_allowlist);
+  cmdline.bindings_cache_ = std::move(bindings_cache);
+
+  if (codegen_threads < 1) {
+    return absl::InvalidArgumentError(
+        absl::StrCat("Invalid value of `--codegen_threads`: ", codegen_threads,
+                     " (expected at least 1)"));
+  }
+  cmdline.codegen_threads_ = codegen_threads;
+
+  if (check && do_nothing) {
+    return absl::InvalidArgumentError(
+        "--check can't be used together with --do_nothing");
+  }
+  cmdline.check_ = check;
+
+  // The bindings that are written out directly can't be compared or cached.
+  if (stream_output && check) {
+    return absl::InvalidArgumentError(
+        "--stream_output can't be used together with --check");
+  }
+  if (stream_output && !cmdline.bindings_cache_.empty()) {
+    return absl::InvalidArgumentError(
+        "--stream_output can't be used together with --bindings_cache");
+  }
+  cmdline.stream_output_ = stream_output;
+
+  if (coverage_options.min_coverage_percent < 0 ||
+      coverage_options.min_coverage_percent > 100) {
+    return absl::InvalidArgumentError(absl::StrCat(
+        "Invalid value of `--min_bindings_coverage`: ",
+        coverage_options.min_coverage_percent, " (expected a percentage)"));
+  }
+  // The names are passed to the code generator as a comma-separated list.
+  for (const std::string& name : coverage_options.fail_on_unsupported) {
+    if (name.empty() || absl::StrContains(name, ',')) {
+      return absl::InvalidArgumentError(absl::StrCat(
+          "Invalid name in `--fail_on_unsupported`: '", name, "'"));
+    }
+  }
+  cmdline.coverage_options_ = std::move(coverage_options);
+
+  for (const std::string& lint : generator_options.allowed_lints) {
+    if (lint.empty() ||
+        !std::all_of(lint.begin(), lint.end(), [](char c) {
+          return absl::ascii_isalnum(c) || c == '_' || c == ':';
+        })) {
+      return absl::InvalidArgumentError(
+          absl::StrCat("Invalid lint name in `--allowed_lints`: '", lint, "'"));
+    }
+  }
+  // The paths are passed to the code generator as a comma-separated list.
+  for (const std::string& path : generator_options.dependency_irs) {
+    if (path.empty() || absl::StrContains(path, ',')) {
+      return absl::InvalidArgumentError(
+          absl::StrCat("Invalid path in `--dependency_irs`: '", path, "'"));
+    }
+  }
+  // The IRs of the shared instantiations targets provide the instantiations.
+  if (!generator_options.shared_instantiations_targets.empty() &&
+      generator_options.dependency_irs.empty()) {
+    return absl::InvalidArgumentError(
+        "--shared_instantiations_targets requires --dependency_irs");
+  }
+  for (const std::string& target :
+       generator_options.shared_instantiations_targets) {
+    if (target.empty() || absl::StrContains(target, ',')) {
+      return absl::InvalidArgumentError(absl::StrCat(
+          "Invalid label in `--shared_instantiations_targets`: '", target,
+          "'"));
+    }
+  }
+  // See `CrubitFeature` in `src_code_gen.rs`.
+  for (const std::string& feature : generator_options.crubit_features) {
+    if (feature != "supported" && feature != "experimental" &&
+        feature != "templates" && feature != "operators" &&
+        feature != "bridging" && feature != "member_pointers" &&
+        feature != "value_semantics") {
+      return absl::InvalidArgumentError(absl::StrCat(
+          "Invalid entry in `--crubit_features`: '", feature, "'"));
+    }
+  }
+  // The version is recorded in a single-line comment.
+  if (absl::StrContains(generator_options.generator_version, '\n')) {
+    return absl::InvalidArgumentError(
+        "Invalid value of `--generator_version`: expected a single line");
+  }
+  if (!generator_options.module_path.empty()) {
+    for (absl::string_view segment :
+         absl::StrSplit(generator_options.module_path, "::")) {
+      if (segment.empty() || absl::ascii_isdigit(segment[0]) ||
+          !std::all_of(segment.begin(), segment.end(), [](char c) {
+            return absl::ascii_isalnum(c) || c == '_';
+          })) {
+        return absl::InvalidArgumentError(
+            absl::StrCat("Invalid module path in `--module_path`: '",
+                         generator_options.module_path, "'"));
+      }
+    }
+    // Template instantiations are generated into a module of their own (see
+    // `IR::crate_root_path`).
+    if (!cmdline.instantiations_out_.empty()) {
+      return absl::InvalidArgumentError(
+          "--module_path can't be used together with --instantiations_out");
+    }
+  }
+  cmdline.generator_options_ = std::move(generator_options);
 
   if (targets_and_headers_str.empty()) {
     return absl::InvalidArgumentError("please specify --targets_and_headers");
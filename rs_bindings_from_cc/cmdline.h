@@ -12,7 +12,9 @@
 #include "absl/status/statusor.h"
 #include "absl/strings/string_view.h"
 #include "rs_bindings_from_cc/bazel_types.h"
+#include "rs_bindings_from_cc/generator_options.h"
 #include "rs_bindings_from_cc/ir.h"
+#include "rs_bindings_from_cc/ir_from_cc.h"
 
 namespace crubit {
 
@@ -33,7 +35,20 @@ class Cmdline {
       std::string targets_and_headers_str,
       std::vector<std::string> extra_rs_sources,
       std::vector<std::string> srcs_to_scan_for_instantiations,
-      std::string instantiations_out, std::string error_report_out) {
+      std::string instantiations_out, std::string error_report_out,
+      GeneratorOptions generator_options = {},
+      std::vector<std::string> module_interfaces = {},
+      std::string generation_manifest = "",
+      std::string safe_pointer_params_allowlist = "",
+      std::string bindings_cache = "", int codegen_threads = 1,
+      bool check = false,
+      std::vector<std::string> preprocessor_overrides = {},
+      bool stream_output = false, std::string bindings_report_out = "",
+      CoverageOptions coverage_options = {},
+      std::string bindings_changelog_out = "",
+      std::string cc_build_manifest_out = "", std::string cmake_out = "",
+      bool binary_ir_out = false,
+      RsFormatter rs_formatter = RsFormatter::kRustfmt) {
     return CreateFromArgs(
         std::move(current_target), std::move(cc_out), std::move(rs_out),
         std::move(ir_out), std::move(namespaces_out),
@@ -41,7 +56,15 @@ class Cmdline {
         std::move(rustfmt_exe_path), std::move(rustfmt_config_path), do_nothing,
         std::move(public_headers), std::move(targets_and_headers_str),
         std::move(extra_rs_sources), std::move(srcs_to_scan_for_instantiations),
-        std::move(instantiations_out), std::move(error_report_out));
+        std::move(instantiations_out), std::move(error_report_out),
+        std::move(generator_options), std::move(module_interfaces),
+        std::move(generation_manifest),
+        std::move(safe_pointer_params_allowlist), std::move(bindings_cache),
+        codegen_threads, check, std::move(preprocessor_overrides),
+        stream_output, std::move(bindings_report_out),
+        std::move(coverage_options), std::move(bindings_changelog_out),
+        std::move(cc_build_manifest_out), std::move(cmake_out), binary_ir_out,
+        rs_formatter);
   }
 
   Cmdline(const Cmdline&) = delete;
@@ -52,6 +75,7 @@ class Cmdline {
   absl::string_view cc_out() const { return cc_out_; }
   absl::string_view rs_out() const { return rs_out_; }
   absl::string_view ir_out() const { return ir_out_; }
+  bool binary_ir_out() const { return binary_ir_out_; }
   absl::string_view namespaces_out() const { return namespaces_out_; }
   absl::string_view crubit_support_path() const { return crubit_support_path_; }
   absl::string_view clang_format_exe_path() const {
@@ -59,14 +83,49 @@ class Cmdline {
   }
   absl::string_view rustfmt_exe_path() const { return rustfmt_exe_path_; }
   absl::string_view rustfmt_config_path() const { return rustfmt_config_path_; }
+  RsFormatter rs_formatter() const { return rs_formatter_; }
   absl::string_view instantiations_out() const { return instantiations_out_; }
   absl::string_view error_report_out() const { return error_report_out_; }
+  absl::string_view bindings_report_out() const {
+    return bindings_report_out_;
+  }
+  absl::string_view bindings_changelog_out() const {
+    return bindings_changelog_out_;
+  }
+  absl::string_view cc_build_manifest_out() const {
+    return cc_build_manifest_out_;
+  }
+  absl::string_view cmake_out() const { return cmake_out_; }
+  absl::string_view generation_manifest() const {
+    return generation_manifest_;
+  }
+  absl::string_view safe_pointer_params_allowlist() const {
+    return safe_pointer_params_allowlist_;
+  }
+  absl::string_view bindings_cache() const { return bindings_cache_; }
+  int codegen_threads() const { return codegen_threads_; }
+  bool check() const { return check_; }
+  bool stream_output() const { return stream_output_; }
   bool do_nothing() const { return do_nothing_; }
 
+  const GeneratorOptions& generator_options() const {
+    return generator_options_;
+  }
+
+  const CoverageOptions& coverage_options() const { return coverage_options_; }
+
   const std::vector<HeaderName>& public_headers() const {
     return public_headers_;
   }
 
+  const std::vector<ModuleInterface>& module_interfaces() const {
+    return module_interfaces_;
+  }
+
+  const std::vector<std::string>& preprocessor_overrides() const {
+    return preprocessor_overrides_;
+  }
+
   const std::vector<std::string>& extra_rs_srcs() const {
     return extra_rs_srcs_;
   }
@@ -94,30 +153,57 @@ class Cmdline {
       std::string targets_and_headers_str,
       std::vector<std::string> extra_rs_sources,
       std::vector<std::string> srcs_to_scan_for_instantiations,
-      std::string instantiations_out, std::string error_report_out);
+      std::string instantiations_out, std::string error_report_out,
+      GeneratorOptions generator_options,
+      std::vector<std::string> module_interfaces,
+      std::string generation_manifest,
+      std::string safe_pointer_params_allowlist, std::string bindings_cache,
+      int codegen_threads, bool check,
+      std::vector<std::string> preprocessor_overrides, bool stream_output,
+      std::string bindings_report_out, CoverageOptions coverage_options,
+      std::string bindings_changelog_out, std::string cc_build_manifest_out,
+      std::string cmake_out, bool binary_ir_out, RsFormatter rs_formatter);
 
   absl::StatusOr<BazelLabel> FindHeader(const HeaderName& header) const;
 
   std::string cc_out_;
   std::string rs_out_;
   std::string ir_out_;
+  bool binary_ir_out_ = false;
   std::string crubit_support_path_;
   std::string clang_format_exe_path_;
   std::string rustfmt_exe_path_;
   std::string rustfmt_config_path_;
+  RsFormatter rs_formatter_ = RsFormatter::kRustfmt;
   std::string error_report_out_;
+  std::string bindings_report_out_;
+  std::string bindings_changelog_out_;
+  std::string cc_build_manifest_out_;
+  std::string cmake_out_;
+  std::string generation_manifest_;
+  std::string safe_pointer_params_allowlist_;
+  std::string bindings_cache_;
+  int codegen_threads_ = 1;
+  bool check_ = false;
+  bool stream_output_ = false;
   bool do_nothing_ = true;
 
   BazelLabel current_target_;
   std::vector<HeaderName> public_headers_;
+  std::vector<ModuleInterface> module_interfaces_;
   absl::flat_hash_map<HeaderName, BazelLabel> headers_to_targets_;
 
   std::vector<std::string> extra_rs_srcs_;
 
+  std::vector<std::string> preprocessor_overrides_;
+
   std::vector<std::string> srcs_to_scan_for_instantiations_;
   std::string instantiations_out_;
 
   std::string namespaces_out_;
+
+  GeneratorOptions generator_options_;
+  CoverageOptions coverage_options_;
 };
 
 }  // namespace crubit
@@ -9,8 +9,10 @@
 
 #include "gmock/gmock.h"
 #include "gtest/gtest.h"
+#include "absl/strings/str_cat.h"
 #include "common/status_test_matchers.h"
 #include "rs_bindings_from_cc/bazel_types.h"
+#include "rs_bindings_from_cc/generator_options.h"
 
 namespace crubit {
 namespace {
@@ -18,6 +20,7 @@ namespace {
 using ::testing::AllOf;
 using ::testing::ElementsAre;
 using ::testing::HasSubstr;
+using ::testing::IsEmpty;
 using ::testing::Pair;
 using ::testing::UnorderedElementsAre;
 
@@ -61,11 +64,13 @@ TEST(CmdlineTest, BasicCorrectInput) {
   EXPECT_EQ(cmdline.cc_out(), "cc_out");
   EXPECT_EQ(cmdline.rs_out(), "rs_out");
   EXPECT_EQ(cmdline.ir_out(), "ir_out");
+  EXPECT_EQ(cmdline.binary_ir_out(), false);
   EXPECT_EQ(cmdline.namespaces_out(), "namespaces_out");
   EXPECT_EQ(cmdline.crubit_support_path(), "crubit_support_path");
   EXPECT_EQ(cmdline.clang_format_exe_path(), "clang_format_exe_path");
   EXPECT_EQ(cmdline.rustfmt_exe_path(), "rustfmt_exe_path");
   EXPECT_EQ(cmdline.rustfmt_config_path(), "rustfmt_config_path");
+  EXPECT_EQ(cmdline.rs_formatter(), RsFormatter::kRustfmt);
   EXPECT_EQ(cmdline.instantiations_out(), "instantiations_out");
   EXPECT_EQ(cmdline.error_report_out(), "error_report_out");
   EXPECT_EQ(cmdline.do_nothing(), false);
@@ -78,6 +83,195 @@ TEST(CmdlineTest, BasicCorrectInput) {
       cmdline.headers_to_targets(),
       UnorderedElementsAre(Pair(HeaderName("h1"), BazelLabel("//:t1")),
                            Pair(HeaderName("h2"), BazelLabel("//:t1"))));
+  EXPECT_EQ(cmdline.generator_options().deny_warnings, false);
+  EXPECT_THAT(cmdline.generator_options().allowed_lints, IsEmpty());
+  EXPECT_EQ(cmdline.generator_options().exception_handling,
+            ExceptionHandling::kNone);
+  EXPECT_EQ(cmdline.generator_options().thunk_linkage,
+            ThunkLinkage::kExternal);
+  EXPECT_EQ(cmdline.generator_options().inline_trivial_accessors, false);
+  EXPECT_EQ(cmdline.generator_options().generate_smoke_tests, false);
+  EXPECT_EQ(cmdline.generator_options().rename_overloads, false);
+  EXPECT_EQ(cmdline.generator_options().trace_calls, false);
+  EXPECT_EQ(cmdline.generator_options().module_path, "");
+  EXPECT_EQ(cmdline.generator_options().module_visibility, "pub");
+  EXPECT_EQ(cmdline.generator_options().friend_crate, false);
+  EXPECT_EQ(cmdline.generator_options().field_accessors, false);
+  EXPECT_EQ(cmdline.generator_options().snake_case_function_names, false);
+  EXPECT_EQ(cmdline.generator_options().canonical_item_order, false);
+  EXPECT_EQ(cmdline.generator_options().line_directives, false);
+  EXPECT_EQ(cmdline.generator_options().readable_thunk_names, false);
+  EXPECT_EQ(cmdline.generator_options().windows_import_lib, "");
+  EXPECT_THAT(cmdline.generator_options().dependency_irs, IsEmpty());
+  EXPECT_THAT(cmdline.generator_options().shared_instantiations_targets,
+              IsEmpty());
+}
+
+TEST(CmdlineTest, GeneratorOptions) {
+  ASSERT_OK_AND_ASSIGN(
+      Cmdline cmdline,
+      Cmdline::CreateForTesting(
+          "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+          "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+          "rustfmt_config_path",
+          /* do_nothing= */ false, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+          /* extra_rs_srcs= */ {},
+          /* srcs_to_scan_for_instantiations= */ {},
+          /* instantiations_out= */ "", "error_report_out",
+          GeneratorOptions{
+              .deny_warnings = true,
+              .allowed_lints = {"dead_code", "clippy::all"},
+              .exception_handling = ExceptionHandling::kResult,
+              .thunk_linkage = ThunkLinkage::kInline,
+              .inline_trivial_accessors = true,
+              .generate_smoke_tests = true,
+              .rename_overloads = true,
+              .trace_calls = true,
+              .module_path = "sys::ffi",
+              .module_visibility = "pub(crate)",
+              .friend_crate = true,
+              .field_accessors = true,
+              .snake_case_function_names = true,
+              .canonical_item_order = true,
+              .line_directives = true,
+              .readable_thunk_names = true,
+              .windows_import_lib = "foo",
+              .dependency_irs = {"dep1.json", "dep2.json"},
+              .shared_instantiations_targets = {"//:instantiations"}}));
+  EXPECT_EQ(cmdline.generator_options().deny_warnings, true);
+  EXPECT_THAT(cmdline.generator_options().allowed_lints,
+              ElementsAre("dead_code", "clippy::all"));
+  EXPECT_EQ(cmdline.generator_options().exception_handling,
+            ExceptionHandling::kResult);
+  EXPECT_EQ(cmdline.generator_options().thunk_linkage, ThunkLinkage::kInline);
+  EXPECT_EQ(cmdline.generator_options().inline_trivial_accessors, true);
+  EXPECT_EQ(cmdline.generator_options().generate_smoke_tests, true);
+  EXPECT_EQ(cmdline.generator_options().rename_overloads, true);
+  EXPECT_EQ(cmdline.generator_options().trace_calls, true);
+  EXPECT_EQ(cmdline.generator_options().module_path, "sys::ffi");
+  EXPECT_EQ(cmdline.generator_options().module_visibility, "pub(crate)");
+  EXPECT_EQ(cmdline.generator_options().friend_crate, true);
+  EXPECT_EQ(cmdline.generator_options().field_accessors, true);
+  EXPECT_EQ(cmdline.generator_options().snake_case_function_names, true);
+  EXPECT_EQ(cmdline.generator_options().canonical_item_order, true);
+  EXPECT_EQ(cmdline.generator_options().line_directives, true);
+  EXPECT_EQ(cmdline.generator_options().readable_thunk_names, true);
+  EXPECT_EQ(cmdline.generator_options().windows_import_lib, "foo");
+  EXPECT_THAT(cmdline.generator_options().dependency_irs,
+              ElementsAre("dep1.json", "dep2.json"));
+  EXPECT_THAT(cmdline.generator_options().shared_instantiations_targets,
+              ElementsAre("//:instantiations"));
+}
+
+TEST(CmdlineTest, AllowedLintsInvalid) {
+  ASSERT_THAT(
+      Cmdline::CreateForTesting(
+          "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+          "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+          "rustfmt_config_path",
+          /* do_nothing= */ false, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+          /* extra_rs_srcs= */ {},
+          /* srcs_to_scan_for_instantiations= */ {},
+          /* instantiations_out= */ "", "error_report_out",
+          GeneratorOptions{.allowed_lints = {"dead_code)] #![foo"}}),
+      StatusIs(absl::StatusCode::kInvalidArgument,
+               HasSubstr("Invalid lint name in `--allowed_lints`")));
+}
+
+TEST(CmdlineTest, DependencyIrsInvalid) {
+  for (const char* path : {"", "a.json,b.json"}) {
+    ASSERT_THAT(
+        Cmdline::CreateForTesting(
+            "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+            "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+            "rustfmt_config_path",
+            /* do_nothing= */ false, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+            /* extra_rs_srcs= */ {},
+            /* srcs_to_scan_for_instantiations= */ {},
+            /* instantiations_out= */ "", "error_report_out",
+            GeneratorOptions{.dependency_irs = {path}}),
+        StatusIs(absl::StatusCode::kInvalidArgument,
+                 HasSubstr("Invalid path in `--dependency_irs`")));
+  }
+}
+
+TEST(CmdlineTest, SharedInstantiationsTargetsWithoutDependencyIrs) {
+  ASSERT_THAT(
+      Cmdline::CreateForTesting(
+          "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+          "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+          "rustfmt_config_path",
+          /* do_nothing= */ false, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+          /* extra_rs_srcs= */ {},
+          /* srcs_to_scan_for_instantiations= */ {},
+          /* instantiations_out= */ "", "error_report_out",
+          GeneratorOptions{.shared_instantiations_targets = {"//:inst"}}),
+      StatusIs(absl::StatusCode::kInvalidArgument,
+               HasSubstr("--shared_instantiations_targets requires "
+                         "--dependency_irs")));
+}
+
+TEST(CmdlineTest, CrubitFeaturesInvalid) {
+  ASSERT_THAT(
+      Cmdline::CreateForTesting(
+          "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+          "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+          "rustfmt_config_path",
+          /* do_nothing= */ false, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+          /* extra_rs_srcs= */ {},
+          /* srcs_to_scan_for_instantiations= */ {},
+          /* instantiations_out= */ "", "error_report_out",
+          GeneratorOptions{.crubit_features = {"supported", "coroutines"}}),
+      StatusIs(absl::StatusCode::kInvalidArgument,
+               HasSubstr("Invalid entry in `--crubit_features`: "
+                         "'coroutines'")));
+}
+
+TEST(CmdlineTest, GeneratorVersionInvalid) {
+  ASSERT_THAT(
+      Cmdline::CreateForTesting(
+          "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+          "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+          "rustfmt_config_path",
+          /* do_nothing= */ false, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+          /* extra_rs_srcs= */ {},
+          /* srcs_to_scan_for_instantiations= */ {},
+          /* instantiations_out= */ "", "error_report_out",
+          GeneratorOptions{.generator_version = "1.0\npub fn injected() {}"}),
+      StatusIs(absl::StatusCode::kInvalidArgument,
+               HasSubstr("Invalid value of `--generator_version`")));
+}
+
+TEST(CmdlineTest, ModulePathInvalid) {
+  for (const char* module_path : {"ffi::", "::ffi", "sys::1ffi", "ffi)] x"}) {
+    ASSERT_THAT(
+        Cmdline::CreateForTesting(
+            "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+            "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+            "rustfmt_config_path",
+            /* do_nothing= */ false, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+            /* extra_rs_srcs= */ {},
+            /* srcs_to_scan_for_instantiations= */ {},
+            /* instantiations_out= */ "", "error_report_out",
+            GeneratorOptions{.module_path = module_path}),
+        StatusIs(absl::StatusCode::kInvalidArgument,
+                 HasSubstr("Invalid module path in `--module_path`")));
+  }
+}
+
+TEST(CmdlineTest, ModulePathWithInstantiationsOut) {
+  ASSERT_THAT(
+      Cmdline::CreateForTesting(
+          "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+          "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+          "rustfmt_config_path",
+          /* do_nothing= */ false, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+          /* extra_rs_srcs= */ {}, {"scan_for_instantiations.rs"},
+          "instantiations_out", "error_report_out",
+          GeneratorOptions{.module_path = "ffi"}),
+      StatusIs(absl::StatusCode::kInvalidArgument,
+               HasSubstr("--module_path can't be used together with "
+                         "--instantiations_out")));
 }
 
 TEST(CmdlineTest, TargetsAndHeadersEmpty) {
@@ -223,6 +417,228 @@ TEST(CmdlineTest, PublicHeadersCoveringMultipleTargets) {
                            Pair(HeaderName("d.h"), BazelLabel("//:target2"))));
 }
 
+TEST(CmdlineTest, ModuleInterfaces) {
+  ASSERT_OK_AND_ASSIGN(
+      Cmdline cmdline,
+      Cmdline::CreateForTesting(
+          "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+          "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+          "rustfmt_config_path",
+          /* do_nothing= */ false, /* public_headers= */ {},
+          R"([{"t": "//:t1", "h": ["h1"]}])",
+          /* extra_rs_srcs= */ {},
+          /* srcs_to_scan_for_instantiations= */ {},
+          /* instantiations_out= */ "", "error_report_out",
+          /* generator_options= */ {},
+          /* module_interfaces= */ {"foo.bar=foo/bar.cppm"}));
+  EXPECT_THAT(cmdline.public_headers(), IsEmpty());
+  ASSERT_EQ(cmdline.module_interfaces().size(), 1);
+  EXPECT_EQ(cmdline.module_interfaces()[0].module_name, "foo.bar");
+  EXPECT_EQ(cmdline.module_interfaces()[0].path, HeaderName("foo/bar.cppm"));
+}
+
+TEST(CmdlineTest, ModuleInterfacesInvalid) {
+  ASSERT_THAT(
+      Cmdline::CreateForTesting(
+          "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+          "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+          "rustfmt_config_path",
+          /* do_nothing= */ false, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+          /* extra_rs_srcs= */ {},
+          /* srcs_to_scan_for_instantiations= */ {},
+          /* instantiations_out= */ "", "error_report_out",
+          /* generator_options= */ {},
+          /* module_interfaces= */ {"foo/bar.cppm"}),
+      StatusIs(absl::StatusCode::kInvalidArgument,
+               HasSubstr("Expected `--module_interfaces` entries")));
+}
+
+TEST(CmdlineTest, GenerationManifest) {
+  ASSERT_OK_AND_ASSIGN(
+      Cmdline cmdline,
+      Cmdline::CreateForTesting(
+          "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+          "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+          "rustfmt_config_path",
+          /* do_nothing= */ false, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+          /* extra_rs_srcs= */ {},
+          /* srcs_to_scan_for_instantiations= */ {},
+          /* instantiations_out= */ "", /* error_report_out= */ "",
+          /* generator_options= */ {}, /* module_interfaces= */ {},
+          "generation_manifest.json"));
+  EXPECT_EQ(cmdline.generation_manifest(), "generation_manifest.json");
+}
+
+TEST(CmdlineTest, GenerationManifestWithErrorReport) {
+  ASSERT_THAT(
+      Cmdline::CreateForTesting(
+          "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+          "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+          "rustfmt_config_path",
+          /* do_nothing= */ false, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+          /* extra_rs_srcs= */ {},
+          /* srcs_to_scan_for_instantiations= */ {},
+          /* instantiations_out= */ "", "error_report_out",
+          /* generator_options= */ {}, /* module_interfaces= */ {},
+          "generation_manifest.json"),
+      StatusIs(absl::StatusCode::kInvalidArgument,
+               HasSubstr("--generation_manifest can't be used together with "
+                         "--error_report_out")));
+}
+
+TEST(CmdlineTest, BindingsCache) {
+  ASSERT_OK_AND_ASSIGN(
+      Cmdline cmdline,
+      Cmdline::CreateForTesting(
+          "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+          "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+          "rustfmt_config_path",
+          /* do_nothing= */ false, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+          /* extra_rs_srcs= */ {},
+          /* srcs_to_scan_for_instantiations= */ {},
+          /* instantiations_out= */ "", /* error_report_out= */ "",
+          /* generator_options= */ {}, /* module_interfaces= */ {},
+          /* generation_manifest= */ "",
+          /* safe_pointer_params_allowlist= */ "", "bindings_cache.json"));
+  EXPECT_EQ(cmdline.bindings_cache(), "bindings_cache.json");
+}
+
+TEST(CmdlineTest, CodegenThreadsInvalid) {
+  ASSERT_THAT(
+      Cmdline::CreateForTesting(
+          "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+          "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+          "rustfmt_config_path",
+          /* do_nothing= */ false, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+          /* extra_rs_srcs= */ {},
+          /* srcs_to_scan_for_instantiations= */ {},
+          /* instantiations_out= */ "", /* error_report_out= */ "",
+          /* generator_options= */ {}, /* module_interfaces= */ {},
+          /* generation_manifest= */ "",
+          /* safe_pointer_params_allowlist= */ "", /* bindings_cache= */ "",
+          /* codegen_threads= */ 0),
+      StatusIs(absl::StatusCode::kInvalidArgument,
+               HasSubstr("Invalid value of `--codegen_threads`: 0")));
+}
+
+TEST(CmdlineTest, CheckWithDoNothing) {
+  ASSERT_THAT(
+      Cmdline::CreateForTesting(
+          "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+          "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+          "rustfmt_config_path",
+          /* do_nothing= */ true, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+          /* extra_rs_srcs= */ {},
+          /* srcs_to_scan_for_instantiations= */ {},
+          /* instantiations_out= */ "", /* error_report_out= */ "",
+          /* generator_options= */ {}, /* module_interfaces= */ {},
+          /* generation_manifest= */ "",
+          /* safe_pointer_params_allowlist= */ "", /* bindings_cache= */ "",
+          /* codegen_threads= */ 1, /* check= */ true),
+      StatusIs(absl::StatusCode::kInvalidArgument,
+               HasSubstr("--check can't be used together with --do_nothing")));
+}
+
+TEST(CmdlineTest, StreamOutputWithCheck) {
+  ASSERT_THAT(
+      Cmdline::CreateForTesting(
+          "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+          "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+          "rustfmt_config_path",
+          /* do_nothing= */ false, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+          /* extra_rs_srcs= */ {},
+          /* srcs_to_scan_for_instantiations= */ {},
+          /* instantiations_out= */ "", /* error_report_out= */ "",
+          /* generator_options= */ {}, /* module_interfaces= */ {},
+          /* generation_manifest= */ "",
+          /* safe_pointer_params_allowlist= */ "", /* bindings_cache= */ "",
+          /* codegen_threads= */ 1, /* check= */ true,
+          /* preprocessor_overrides= */ {}, /* stream_output= */ true),
+      StatusIs(absl::StatusCode::kInvalidArgument,
+               HasSubstr("--stream_output can't be used together with --check")));
+}
+
+TEST(CmdlineTest, CoverageOptionsInvalid) {
+  auto create = [](CoverageOptions coverage_options) {
+    return Cmdline::CreateForTesting(
+        "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+        "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+        "rustfmt_config_path",
+        /* do_nothing= */ false, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+        /* extra_rs_srcs= */ {},
+        /* srcs_to_scan_for_instantiations= */ {},
+        /* instantiations_out= */ "", /* error_report_out= */ "",
+        /* generator_options= */ {}, /* module_interfaces= */ {},
+        /* generation_manifest= */ "",
+        /* safe_pointer_params_allowlist= */ "", /* bindings_cache= */ "",
+        /* codegen_threads= */ 1, /* check= */ false,
+        /* preprocessor_overrides= */ {}, /* stream_output= */ false,
+        /* bindings_report_out= */ "", std::move(coverage_options));
+  };
+  ASSERT_OK(create({.min_coverage_percent = 80,
+                    .fail_on_unsupported = {"ns::Foo"},
+                    .print_summary = true}));
+  ASSERT_THAT(create({.min_coverage_percent = 101}),
+              StatusIs(absl::StatusCode::kInvalidArgument,
+                       HasSubstr("Invalid value of `--min_bindings_coverage`: "
+                                 "101")));
+  ASSERT_THAT(create({.fail_on_unsupported = {""}}),
+              StatusIs(absl::StatusCode::kInvalidArgument,
+                       HasSubstr("Invalid name in `--fail_on_unsupported`")));
+}
+
+TEST(CmdlineTest, BindingsChangelogWithoutGenerationManifest) {
+  ASSERT_THAT(
+      Cmdline::CreateForTesting(
+          "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+          "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+          "rustfmt_config_path",
+          /* do_nothing= */ false, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+          /* extra_rs_srcs= */ {},
+          /* srcs_to_scan_for_instantiations= */ {},
+          /* instantiations_out= */ "", /* error_report_out= */ "",
+          /* generator_options= */ {}, /* module_interfaces= */ {},
+          /* generation_manifest= */ "",
+          /* safe_pointer_params_allowlist= */ "", /* bindings_cache= */ "",
+          /* codegen_threads= */ 1, /* check= */ false,
+          /* preprocessor_overrides= */ {}, /* stream_output= */ false,
+          /* bindings_report_out= */ "", /* coverage_options= */ {},
+          "bindings_changelog_out"),
+      StatusIs(absl::StatusCode::kInvalidArgument,
+               HasSubstr("--bindings_changelog_out requires "
+                         "--generation_manifest")));
+}
+
+TEST(CmdlineTest, PreprocessorOverrides) {
+  auto create = [](std::vector<std::string> preprocessor_overrides) {
+    return Cmdline::CreateForTesting(
+        "//:t1", "cc_out", "rs_out", "ir_out", "namespaces_out",
+        "crubit_support_path", "clang_format_exe_path", "rustfmt_exe_path",
+        "rustfmt_config_path",
+        /* do_nothing= */ false, {"h1"}, R"([{"t": "//:t1", "h": ["h1"]}])",
+        /* extra_rs_srcs= */ {},
+        /* srcs_to_scan_for_instantiations= */ {},
+        /* instantiations_out= */ "", /* error_report_out= */ "",
+        /* generator_options= */ {}, /* module_interfaces= */ {},
+        /* generation_manifest= */ "",
+        /* safe_pointer_params_allowlist= */ "", /* bindings_cache= */ "",
+        /* codegen_threads= */ 1, /* check= */ false,
+        std::move(preprocessor_overrides));
+  };
+  ASSERT_OK_AND_ASSIGN(Cmdline cmdline,
+                       create({"-DFOO", "-DBAR=1", "-DEMPTY=", "-UNDEBUG"}));
+  EXPECT_THAT(cmdline.preprocessor_overrides(),
+              ElementsAre("-DFOO", "-DBAR=1", "-DEMPTY=", "-UNDEBUG"));
+
+  for (const char* invalid : {"FOO", "-D", "-D1FOO", "-UFOO=1", "-I."}) {
+    EXPECT_THAT(create({invalid}),
+                StatusIs(absl::StatusCode::kInvalidArgument,
+                         HasSubstr(absl::StrCat(
+                             "Invalid entry in `--preprocessor_overrides`: '",
+                             invalid, "'"))));
+  }
+}
+
 TEST(CmdlineTest, InstantiationsOutEmpty) {
   constexpr absl::string_view kTargetsAndHeaders = R"([
     {"t": "//:target1", "h": ["a.h", "b.h"]}
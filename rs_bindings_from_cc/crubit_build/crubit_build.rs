@@ -0,0 +1,412 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+//! # Generation of Rust bindings for C++ headers from a Cargo build script.
+//!
+//! `crubit_build` generates bindings from the `build.rs` of a crate: it
+//! imports the given headers (parsed with the given Clang arguments) with the
+//! importer of `rs_bindings_from_cc`, and generates the Rust bindings and the
+//! C++ source file implementing them from the IR, in-process. They are
+//! written into `OUT_DIR`.
+//!
+//! The crate links the importer, so it is built with Bazel, and its build
+//! scripts are `cargo_build_script` targets depending on `:crubit_build`:
+//!
+//! ```ignore
+//! let bindings = crubit_build::Builder::new()
+//!     .header("mylib/mylib.h")
+//!     .clang_arg("-Iinclude")
+//!     .generate()
+//!     .expect("Failed to generate the bindings of mylib");
+//! // The C++ source file is compiled with the `cc` crate.
+//! cc::Build::new()
+//!     .cpp(true)
+//!     .std("c++17")
+//!     .include("include")
+//!     .includes(bindings.include_dirs())
+//!     .file(bindings.cc_path())
+//!     .compile("mylib_bindings");
+//! ```
+//!
+//! The Rust bindings are a module (`bindings` by default, see
+//! `Builder::module_path`) which the crate `include!`s, e.g. into its root:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+//! ```
+//!
+//! The bindings depend on the support crates of Crubit (e.g. `ctor` and
+//! `forward_declare`), and the features they use have to be enabled by the
+//! crate (they are listed in the documentation of the module).
+//!
+//! The formatters are found through environment variables, or in the `PATH`:
+//! `RUSTFMT` (`rustfmt`), and `CLANG_FORMAT` (`clang-format`). `CRUBIT_ROOT`
+//! is the directory of the Crubit sources, whose `support` headers are
+//! included by the C++ source file.
+
+use ffi_types::{FfiU8Slice, FfiU8SliceBox};
+use serde::{Deserialize, Serialize};
+use src_code_gen_impl::{FfiBindings, GenerateBindingsImpl};
+use std::env;
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The name of the generated Rust source file, in `OUT_DIR`.
+pub const RS_FILE_NAME: &str = "bindings.rs";
+
+/// The name of the generated C++ source file, in `OUT_DIR`.
+pub const CC_FILE_NAME: &str = "bindings_impl.cc";
+
+extern "C" {
+    /// Defined in `ir_from_headers.cc`.
+    fn crubit_build_json_ir_from_headers(request: FfiU8Slice, ok: *mut bool) -> FfiU8SliceBox;
+}
+
+/// The headers imported by `crubit_build_json_ir_from_headers`.
+#[derive(Debug, Serialize)]
+struct ImportRequest<'a> {
+    target: &'a str,
+    headers: &'a [String],
+    clang_args: &'a [String],
+    module_path: &'a str,
+}
+
+/// A `Diagnostic` of a failed generation (see `FfiBindings::error`).
+#[derive(Debug, Deserialize)]
+struct Diagnostic {
+    message: String,
+    item: Option<String>,
+    source_loc: Option<String>,
+}
+
+/// Configures and runs the generation of the bindings.
+#[derive(Clone, Debug, Default)]
+pub struct Builder {
+    headers: Vec<String>,
+    clang_args: Vec<String>,
+    target: Option<String>,
+    module_path: Option<String>,
+    crubit_features: Option<String>,
+    rename_overloads: bool,
+    out_dir: Option<PathBuf>,
+}
+
+impl Builder {
+    /// Returns a builder without headers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a header to generate bindings for, spelled like in an `#include`
+    /// (e.g. `mylib/mylib.h`, relative to an include directory).
+    pub fn header(mut self, header: impl Into<String>) -> Self {
+        self.headers.push(header.into());
+        self
+    }
+
+    /// Adds an argument of Clang (e.g. `-Iinclude` or `-DNDEBUG`), which is
+    /// used to parse the headers.
+    pub fn clang_arg(mut self, arg: impl Into<String>) -> Self {
+        self.clang_args.push(arg.into());
+        self
+    }
+
+    /// Adds arguments of Clang (see `clang_arg`).
+    pub fn clang_args<I: IntoIterator>(mut self, args: I) -> Self
+    where
+        I::Item: Into<String>,
+    {
+        self.clang_args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets the label of the target that owns the headers, which appears in the
+    /// generated code (`//:<package name>` by default).
+    pub fn target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    /// Sets the path of the module into which the bindings are `include!`d
+    /// (`bindings` by default), e.g. `sys::ffi` for bindings included into the
+    /// `sys` module (see `--module_path`).
+    pub fn module_path(mut self, module_path: impl Into<String>) -> Self {
+        self.module_path = Some(module_path.into());
+        self
+    }
+
+    /// Sets the comma-separated Crubit features and maturities enabled for the
+    /// headers (`supported,experimental` by default, see `--crubit_features`).
+    pub fn crubit_features(mut self, crubit_features: impl Into<String>) -> Self {
+        self.crubit_features = Some(crubit_features.into());
+        self
+    }
+
+    /// Gives distinct names to the bindings of overloaded functions (see
+    /// `--rename_overloads`).
+    pub fn rename_overloads(mut self, rename_overloads: bool) -> Self {
+        self.rename_overloads = rename_overloads;
+        self
+    }
+
+    /// Sets the directory into which the bindings are written (`OUT_DIR` by
+    /// default).
+    pub fn out_dir(mut self, out_dir: impl Into<PathBuf>) -> Self {
+        self.out_dir = Some(out_dir.into());
+        self
+    }
+
+    /// Generates the bindings, and tells Cargo to rerun the build script when
+    /// the headers (or the formatters) change.
+    pub fn generate(self) -> io::Result<Bindings> {
+        if self.headers.is_empty() {
+            return Err(invalid_input("No headers to generate bindings for"));
+        }
+        let out_dir = match &self.out_dir {
+            Some(out_dir) => out_dir.clone(),
+            None => PathBuf::from(
+                env::var_os("OUT_DIR").ok_or_else(|| invalid_input("OUT_DIR is not set"))?,
+            ),
+        };
+        let crubit_root = env::var_os("CRUBIT_ROOT").map(PathBuf::from);
+        let bindings = Bindings {
+            rs_path: out_dir.join(RS_FILE_NAME),
+            cc_path: out_dir.join(CC_FILE_NAME),
+            include_dirs: crubit_root.into_iter().collect(),
+        };
+
+        let ir = import_headers(&self.import_request_json()?)?;
+        self.generate_from_ir(&ir, &bindings)?;
+
+        for var in ["RUSTFMT", "CLANG_FORMAT", "CRUBIT_ROOT"] {
+            println!("cargo:rerun-if-env-changed={var}");
+        }
+        for header in self.header_paths() {
+            println!("cargo:rerun-if-changed={}", header.display());
+        }
+        Ok(bindings)
+    }
+
+    fn target_label(&self) -> String {
+        match &self.target {
+            Some(target) => target.clone(),
+            None => format!("//:{}", env::var("CARGO_PKG_NAME").unwrap_or_default()),
+        }
+    }
+
+    fn module_path_or_default(&self) -> &str {
+        self.module_path.as_deref().unwrap_or("bindings")
+    }
+
+    /// Returns the JSON request of `crubit_build_json_ir_from_headers`.
+    fn import_request_json(&self) -> io::Result<Vec<u8>> {
+        let request = ImportRequest {
+            target: &self.target_label(),
+            headers: &self.headers,
+            clang_args: &self.clang_args,
+            module_path: self.module_path_or_default(),
+        };
+        Ok(serde_json::to_vec(&request)?)
+    }
+
+    /// Generates the Rust bindings and the C++ source file implementing them
+    /// from the JSON `ir`, like `rs_bindings_from_cc` does, and writes them
+    /// into the files of `bindings`.
+    fn generate_from_ir(&self, ir: &[u8], bindings: &Bindings) -> io::Result<()> {
+        let rustfmt = tool("RUSTFMT", "rustfmt");
+        let clang_format = tool("CLANG_FORMAT", "clang-format");
+        let (Some(rustfmt), Some(clang_format)) = (rustfmt.to_str(), clang_format.to_str()) else {
+            return Err(invalid_input("The paths of the formatters must be valid UTF-8"));
+        };
+        let crubit_features = match &self.crubit_features {
+            Some(crubit_features) => {
+                crubit_features.split(',').filter(|name| !name.is_empty()).collect()
+            }
+            None => vec!["supported", "experimental"],
+        };
+        // See `BindingsOptions` in `src_code_gen.rs`. The other options keep
+        // their default values.
+        let options = serde_json::to_vec(&serde_json::json!({
+            "crubit_support_path": "support",
+            "clang_format_exe_path": clang_format,
+            "rustfmt_exe_path": rustfmt,
+            "generator_options": {
+                "rename_overloads": self.rename_overloads,
+                "module_path": self.module_path_or_default(),
+                "crubit_features": crubit_features,
+            },
+        }))?;
+
+        // SAFETY: The slices are alive during the call.
+        let FfiBindings {
+            rs_api,
+            rs_api_impl,
+            error_report,
+            generation_manifest,
+            bindings_report,
+            coverage_summary,
+            bindings_changelog,
+            error,
+        } = unsafe {
+            GenerateBindingsImpl(FfiU8Slice::from_slice(ir), FfiU8Slice::from_slice(&options))
+        };
+        // The unused outputs are still owned by the caller.
+        for unused in [
+            error_report,
+            generation_manifest,
+            bindings_report,
+            coverage_summary,
+            bindings_changelog,
+        ] {
+            drop(unused.into_boxed_slice());
+        }
+        let rs_api = rs_api.into_boxed_slice();
+        let rs_api_impl = rs_api_impl.into_boxed_slice();
+        let error = error.into_boxed_slice();
+        if !error.is_empty() {
+            return Err(io::Error::other(format!(
+                "Failed to generate the bindings:\n{}",
+                format_diagnostics(&error)?
+            )));
+        }
+        std::fs::write(&bindings.rs_path, rs_api)?;
+        std::fs::write(&bindings.cc_path, rs_api_impl)
+    }
+
+    /// Returns the paths of the headers which can be found in the include
+    /// directories of the Clang arguments (`-I<dir>`).
+    fn header_paths(&self) -> Vec<PathBuf> {
+        let include_dirs =
+            self.clang_args.iter().filter_map(|arg| arg.strip_prefix("-I")).collect::<Vec<_>>();
+        self.headers
+            .iter()
+            .filter_map(|header| {
+                include_dirs
+                    .iter()
+                    .map(|dir| Path::new(dir).join(header))
+                    .find(|path| path.is_file())
+            })
+            .collect()
+    }
+}
+
+/// The generated bindings.
+#[derive(Clone, Debug)]
+pub struct Bindings {
+    rs_path: PathBuf,
+    cc_path: PathBuf,
+    include_dirs: Vec<PathBuf>,
+}
+
+impl Bindings {
+    /// The path of the Rust bindings, which the crate `include!`s.
+    pub fn rs_path(&self) -> &Path {
+        &self.rs_path
+    }
+
+    /// The path of the C++ source file implementing the bindings, which has to
+    /// be compiled and linked into the crate (e.g. with the `cc` crate).
+    pub fn cc_path(&self) -> &Path {
+        &self.cc_path
+    }
+
+    /// The include directories needed to compile `cc_path`, in addition to the
+    /// ones of the headers: the Crubit sources (`CRUBIT_ROOT`), if set.
+    pub fn include_dirs(&self) -> &[PathBuf] {
+        &self.include_dirs
+    }
+}
+
+/// Runs the importer on the headers of the JSON `request` (see
+/// `ImportRequest`), and returns the JSON IR.
+fn import_headers(request: &[u8]) -> io::Result<Box<[u8]>> {
+    let mut ok = false;
+    // SAFETY: `request` is alive during the call, and `ok` is a valid pointer.
+    let result =
+        unsafe { crubit_build_json_ir_from_headers(FfiU8Slice::from_slice(request), &mut ok) }
+            .into_boxed_slice();
+    if !ok {
+        return Err(io::Error::other(format!(
+            "Failed to import the headers: {}",
+            String::from_utf8_lossy(&result)
+        )));
+    }
+    Ok(result)
+}
+
+/// Formats the JSON list of `Diagnostic`s of a failed generation, one per line.
+fn format_diagnostics(json: &[u8]) -> io::Result<String> {
+    let diagnostics: Vec<Diagnostic> = serde_json::from_slice(json)?;
+    Ok(diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let mut line = String::new();
+            if let Some(source_loc) = &diagnostic.source_loc {
+                line += &format!("{source_loc}: ");
+            }
+            if let Some(item) = &diagnostic.item {
+                line += &format!("Failed to generate bindings for `{item}`: ");
+            }
+            line + &diagnostic.message
+        })
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+/// Returns the path of a tool: the value of the environment variable `var`,
+/// or `name` (to be found in the `PATH`).
+fn tool(var: &str, name: &str) -> OsString {
+    env::var_os(var).unwrap_or_else(|| name.into())
+}
+
+fn invalid_input(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_request_json() {
+        let builder = Builder::new()
+            .header("mylib/a.h")
+            .header("mylib/\"b\".h")
+            .clang_args(["-Iinclude", "-DNDEBUG"])
+            .target("//mylib:mylib")
+            .module_path("sys::ffi");
+        let json = builder.import_request_json().unwrap();
+        assert_eq!(
+            std::str::from_utf8(&json).unwrap(),
+            r#"{"target":"//mylib:mylib","headers":["mylib/a.h","mylib/\"b\".h"],"clang_args":["-Iinclude","-DNDEBUG"],"module_path":"sys::ffi"}"#
+        );
+    }
+
+    #[test]
+    fn test_default_module_path() {
+        let json = Builder::new().header("mylib/a.h").import_request_json().unwrap();
+        let request: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(request["module_path"], "bindings");
+    }
+
+    #[test]
+    fn test_generate_without_headers() {
+        let error = Builder::new().out_dir("out").generate().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_format_diagnostics() {
+        let json = br#"[
+            {"message": "Unsupported type", "item": "Foo", "source_loc": "foo.h;l=3"},
+            {"message": "Some error", "item": null, "source_loc": null}
+        ]"#;
+        assert_eq!(
+            format_diagnostics(json).unwrap(),
+            "foo.h;l=3: Failed to generate bindings for `Foo`: Unsupported type\nSome error"
+        );
+        assert!(format_diagnostics(b"{").is_err());
+    }
+}
@@ -0,0 +1,90 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "absl/container/flat_hash_map.h"
+#include "absl/status/statusor.h"
+#include "absl/strings/str_cat.h"
+#include "absl/strings/string_view.h"
+#include "common/ffi_types.h"
+#include "rs_bindings_from_cc/bazel_types.h"
+#include "rs_bindings_from_cc/ir.h"
+#include "rs_bindings_from_cc/ir_from_cc.h"
+#include "llvm/Support/Error.h"
+#include "llvm/Support/FormatVariadic.h"
+#include "llvm/Support/JSON.h"
+
+namespace crubit {
+
+namespace {
+
+// The headers to import (see `ImportRequest` in `crubit_build.rs`).
+struct ImportRequest {
+  std::string target;
+  std::vector<std::string> headers;
+  std::vector<std::string> clang_args;
+  std::string module_path;
+};
+
+bool fromJSON(const llvm::json::Value& json, ImportRequest& out,
+              llvm::json::Path path) {
+  llvm::json::ObjectMapper mapper(json, path);
+  return mapper && mapper.map("target", out.target) &&
+         mapper.map("headers", out.headers) &&
+         mapper.map("clang_args", out.clang_args) &&
+         mapper.map("module_path", out.module_path);
+}
+
+absl::StatusOr<std::string> JsonIrFromHeaders(absl::string_view request_json) {
+  auto request = llvm::json::parse<ImportRequest>(
+      llvm::StringRef(request_json.data(), request_json.size()));
+  if (auto err = request.takeError()) {
+    return absl::InvalidArgumentError(absl::StrCat(
+        "Malformed import request: ", toString(std::move(err))));
+  }
+  if (request->headers.empty()) {
+    return absl::InvalidArgumentError("No headers to import");
+  }
+  BazelLabel target{request->target};
+  std::vector<HeaderName> public_headers;
+  absl::flat_hash_map<HeaderName, BazelLabel> headers_to_targets;
+  for (const std::string& header : request->headers) {
+    public_headers.push_back(HeaderName(header));
+    headers_to_targets.insert({HeaderName(header), target});
+  }
+  std::vector<absl::string_view> clang_args(request->clang_args.begin(),
+                                            request->clang_args.end());
+  absl::StatusOr<IR> ir = IrFromCc(
+      /* extra_source_code_for_testing= */ "", target, public_headers,
+      /* virtual_headers_contents_for_testing= */ {}, headers_to_targets,
+      /* extra_rs_srcs= */ {}, clang_args);
+  if (!ir.ok()) {
+    return ir.status();
+  }
+  if (!request->module_path.empty()) {
+    ir->crate_root_path = request->module_path;
+  }
+  return std::string(llvm::formatv("{0}", ir->ToJson()));
+}
+
+}  // namespace
+
+// Imports the headers of the JSON `request`, like the `rs_bindings_from_cc`
+// driver does, so that `crubit_build` generates the bindings in-process.
+//
+// Returns the JSON IR, or the error message if `*ok` is set to false. The
+// caller takes ownership of the returned value.
+extern "C" FfiU8SliceBox crubit_build_json_ir_from_headers(FfiU8Slice request,
+                                                           bool* ok) {
+  absl::StatusOr<std::string> json =
+      JsonIrFromHeaders(StringViewFromFfiU8Slice(request));
+  *ok = json.ok();
+  return AllocFfiU8SliceBox(
+      MakeFfiU8Slice(json.ok() ? *json : json.status().message()));
+}
+
+}  // namespace crubit
@@ -0,0 +1,450 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+//! Generates the Rust bindings, and the C++ source file implementing them, from
+//! the IR of a target (see `--ir_out` and `--binary_ir_out` of
+//! `rs_bindings_from_cc`), without the C++ driver which imports the headers:
+//!
+//! ```sh
+//! crubit_gen --ir=foo_ir.json --rs-out=foo_rs_api.rs --cc-out=foo_rs_api_impl.cc \
+//!   --crubit-support-path=support --clang-format-exe-path=clang-format \
+//!   --rustfmt-exe-path=rustfmt --rename-overloads
+//! ```
+//!
+//! The flags are the generator flags of `rs_bindings_from_cc` (see
+//! `cmdline.cc`) spelled in kebab-case. Their values follow a `=`, and lists
+//! are comma-separated.
+
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use ffi_types::FfiU8Slice;
+use src_code_gen_impl::{FfiBindings, GenerateBindingsImpl};
+use std::path::{Path, PathBuf};
+
+/// The command line of `crubit_gen`. The lists are kept comma-separated, and
+/// the enums are the snake_case names of their values, as on the command line.
+#[derive(Debug, Default)]
+struct Cmdline {
+    ir: PathBuf,
+    rs_out: PathBuf,
+    cc_out: PathBuf,
+    crubit_support_path: String,
+    clang_format_exe_path: String,
+    rustfmt_exe_path: String,
+    rustfmt_config_path: String,
+    /// The value of the C++ `RsFormatter` enum.
+    rs_formatter: String,
+    error_report_out: Option<PathBuf>,
+    bindings_report_out: Option<PathBuf>,
+    /// Read (if it exists), and then overwritten with the new manifest.
+    generation_manifest: Option<PathBuf>,
+    bindings_changelog_out: Option<PathBuf>,
+    dependency_irs: String,
+    shared_instantiations_targets: String,
+    deny_warnings: bool,
+    allowed_lints: String,
+    /// The value of the C++ `ExceptionHandling` enum.
+    exception_handling: String,
+    /// The value of the C++ `ThunkLinkage` enum.
+    thunk_linkage: String,
+    inline_trivial_accessors: bool,
+    generate_smoke_tests: bool,
+    rename_overloads: bool,
+    trace_calls: bool,
+    module_path: String,
+    module_visibility: String,
+    field_accessors: bool,
+    snake_case_function_names: bool,
+    canonical_item_order: bool,
+    line_directives: bool,
+    readable_thunk_names: bool,
+    windows_import_lib: String,
+    crubit_features: String,
+    codegen_threads: u32,
+    min_bindings_coverage: u32,
+    fail_on_unsupported: String,
+    print_bindings_coverage: bool,
+    license_header_file: Option<PathBuf>,
+    do_not_edit_banner: bool,
+    generator_version: String,
+}
+
+impl Cmdline {
+    /// Parses the arguments of `crubit_gen` (without the name of the
+    /// executable).
+    fn new<'a>(args: impl IntoIterator<Item = &'a str>) -> Result<Self> {
+        // The defaults of `cmdline.cc`.
+        let mut cmdline = Cmdline {
+            rs_formatter: "rustfmt".to_string(),
+            exception_handling: "none".to_string(),
+            thunk_linkage: "external".to_string(),
+            module_visibility: "pub".to_string(),
+            crubit_features: "supported,experimental".to_string(),
+            codegen_threads: 1,
+            ..Default::default()
+        };
+        for arg in args {
+            let (name, value) = match arg.strip_prefix("--") {
+                Some(flag) => flag.split_once('=').unwrap_or((flag, "")),
+                None => bail!("Unexpected positional argument `{arg}`"),
+            };
+            let path = || Some(PathBuf::from(value));
+            let flag = || -> Result<bool> {
+                match value {
+                    "" | "true" => Ok(true),
+                    "false" => Ok(false),
+                    _ => bail!("Invalid value of `--{name}`: `{value}` (expected a bool)"),
+                }
+            };
+            match name {
+                "ir" => cmdline.ir = value.into(),
+                "rs-out" => cmdline.rs_out = value.into(),
+                "cc-out" => cmdline.cc_out = value.into(),
+                "crubit-support-path" => cmdline.crubit_support_path = value.into(),
+                "clang-format-exe-path" => cmdline.clang_format_exe_path = value.into(),
+                "rustfmt-exe-path" => cmdline.rustfmt_exe_path = value.into(),
+                "rustfmt-config-path" => cmdline.rustfmt_config_path = value.into(),
+                "rs-formatter" => {
+                    ensure!(
+                        matches!(value, "rustfmt" | "builtin" | "none"),
+                        "Invalid value of `--rs-formatter`: `{value}` (expected `rustfmt`, \
+                         `builtin`, or `none`)"
+                    );
+                    cmdline.rs_formatter = value.into();
+                }
+                "error-report-out" => cmdline.error_report_out = path(),
+                "bindings-report-out" => cmdline.bindings_report_out = path(),
+                "generation-manifest" => cmdline.generation_manifest = path(),
+                "bindings-changelog-out" => cmdline.bindings_changelog_out = path(),
+                "dependency-irs" => cmdline.dependency_irs = value.into(),
+                "shared-instantiations-targets" => {
+                    cmdline.shared_instantiations_targets = value.into()
+                }
+                "deny-warnings" => cmdline.deny_warnings = flag()?,
+                "allowed-lints" => cmdline.allowed_lints = value.into(),
+                "exception-handling" => {
+                    ensure!(
+                        matches!(value, "none" | "panic" | "result" | "unwind"),
+                        "Invalid value of `--exception-handling`: `{value}` (expected `none`, \
+                         `panic`, `result`, or `unwind`)"
+                    );
+                    cmdline.exception_handling = value.into();
+                }
+                "thunk-linkage" => {
+                    ensure!(
+                        matches!(value, "external" | "inline"),
+                        "Invalid value of `--thunk-linkage`: `{value}` (expected `external` or \
+                         `inline`)"
+                    );
+                    cmdline.thunk_linkage = value.into();
+                }
+                "inline-trivial-accessors" => cmdline.inline_trivial_accessors = flag()?,
+                "generate-smoke-tests" => cmdline.generate_smoke_tests = flag()?,
+                "rename-overloads" => cmdline.rename_overloads = flag()?,
+                "trace-calls" => cmdline.trace_calls = flag()?,
+                "module-path" => cmdline.module_path = value.into(),
+                "module-visibility" => cmdline.module_visibility = value.into(),
+                "field-accessors" => cmdline.field_accessors = flag()?,
+                "snake-case-function-names" => cmdline.snake_case_function_names = flag()?,
+                "canonical-item-order" => cmdline.canonical_item_order = flag()?,
+                "line-directives" => cmdline.line_directives = flag()?,
+                "readable-thunk-names" => cmdline.readable_thunk_names = flag()?,
+                "windows-import-lib" => cmdline.windows_import_lib = value.into(),
+                "crubit-features" => cmdline.crubit_features = value.into(),
+                "codegen-threads" => {
+                    cmdline.codegen_threads =
+                        value.parse().ok().filter(|threads| *threads >= 1).ok_or_else(|| {
+                            anyhow!(
+                                "Invalid value of `--codegen-threads`: `{value}` (expected at \
+                                 least 1)"
+                            )
+                        })?
+                }
+                "min-bindings-coverage" => {
+                    cmdline.min_bindings_coverage =
+                        value.parse().ok().filter(|percent| *percent <= 100).ok_or_else(|| {
+                            anyhow!(
+                                "Invalid value of `--min-bindings-coverage`: `{value}` \
+                                 (expected a percentage)"
+                            )
+                        })?
+                }
+                "fail-on-unsupported" => cmdline.fail_on_unsupported = value.into(),
+                "print-bindings-coverage" => cmdline.print_bindings_coverage = flag()?,
+                "license-header-file" => cmdline.license_header_file = path(),
+                "do-not-edit-banner" => cmdline.do_not_edit_banner = flag()?,
+                "generator-version" => cmdline.generator_version = value.into(),
+                _ => bail!("Unknown flag `--{name}`"),
+            }
+        }
+
+        for (name, is_empty) in [
+            ("ir", cmdline.ir.as_os_str().is_empty()),
+            ("rs-out", cmdline.rs_out.as_os_str().is_empty()),
+            ("cc-out", cmdline.cc_out.as_os_str().is_empty()),
+            ("crubit-support-path", cmdline.crubit_support_path.is_empty()),
+            ("clang-format-exe-path", cmdline.clang_format_exe_path.is_empty()),
+            ("rustfmt-exe-path", cmdline.rustfmt_exe_path.is_empty()),
+        ] {
+            ensure!(!is_empty, "please specify --{name}");
+        }
+        ensure!(
+            cmdline.bindings_changelog_out.is_none() || cmdline.generation_manifest.is_some(),
+            "--bindings-changelog-out requires --generation-manifest"
+        );
+        ensure!(
+            cmdline.shared_instantiations_targets.is_empty() || !cmdline.dependency_irs.is_empty(),
+            "--shared-instantiations-targets requires --dependency-irs"
+        );
+        Ok(cmdline)
+    }
+}
+
+/// Formats the JSON list of `Diagnostic`s of a failed generation like
+/// `FormatDiagnostics` in `src_code_gen.cc`.
+fn format_diagnostics(json: &[u8]) -> String {
+    let diagnostics: Vec<serde_json::Value> = match serde_json::from_slice(json) {
+        Ok(diagnostics) => diagnostics,
+        Err(err) => {
+            return format!("Invalid diagnostics ({err}): {}", String::from_utf8_lossy(json));
+        }
+    };
+    diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let field = |name| diagnostic.get(name).and_then(serde_json::Value::as_str);
+            let mut line = String::new();
+            if let Some(source_loc) = field("source_loc") {
+                line += &format!("{source_loc}: ");
+            }
+            if let Some(item) = field("item") {
+                line += &format!("Failed to generate bindings for `{item}`: ");
+            }
+            line += field("message").unwrap_or_default();
+            if let Some(snippet) = field("snippet") {
+                line += &format!("\n{snippet}");
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn write_file(path: &Path, contents: &[u8]) -> Result<()> {
+    std::fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn run(cmdline: &Cmdline) -> Result<()> {
+    let ir = std::fs::read(&cmdline.ir)
+        .with_context(|| format!("Failed to read the IR {}", cmdline.ir.display()))?;
+    // A missing manifest is treated like an empty one, as in the C++ driver.
+    let previous_generation_manifest = cmdline
+        .generation_manifest
+        .as_ref()
+        .map(|path| std::fs::read_to_string(path).unwrap_or_default());
+    let license_header = match &cmdline.license_header_file {
+        Some(path) => std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read the license header {}", path.display()))?,
+        None => String::new(),
+    };
+    let list = |s: &str| {
+        s.split(',').filter(|item| !item.is_empty()).map(str::to_string).collect::<Vec<_>>()
+    };
+    let non_empty = |s: &str| (!s.is_empty()).then(|| s.to_string());
+    // See `BindingsOptions` in `src_code_gen.rs`.
+    let options = serde_json::to_vec(&serde_json::json!({
+        "crubit_support_path": cmdline.crubit_support_path,
+        "clang_format_exe_path": cmdline.clang_format_exe_path,
+        "rustfmt_exe_path": cmdline.rustfmt_exe_path,
+        "rustfmt_config_path": non_empty(&cmdline.rustfmt_config_path),
+        "rs_formatter": cmdline.rs_formatter,
+        "generate_error_report": cmdline.error_report_out.is_some(),
+        "generator_options": {
+            "deny_warnings": cmdline.deny_warnings,
+            "allowed_lints": list(&cmdline.allowed_lints),
+            "exception_handling": cmdline.exception_handling,
+            "thunk_linkage": cmdline.thunk_linkage,
+            "inline_trivial_accessors": cmdline.inline_trivial_accessors,
+            "generate_smoke_tests": cmdline.generate_smoke_tests,
+            "rename_overloads": cmdline.rename_overloads,
+            "trace_calls": cmdline.trace_calls,
+            "module_path": non_empty(&cmdline.module_path),
+            "module_visibility": non_empty(&cmdline.module_visibility),
+            "field_accessors": cmdline.field_accessors,
+            "snake_case_function_names": cmdline.snake_case_function_names,
+            "canonical_item_order": cmdline.canonical_item_order,
+            "line_directives": cmdline.line_directives,
+            "readable_thunk_names": cmdline.readable_thunk_names,
+            "windows_import_lib": non_empty(&cmdline.windows_import_lib),
+            "crubit_features": list(&cmdline.crubit_features),
+        },
+        "dependency_irs": list(&cmdline.dependency_irs),
+        "shared_instantiations_targets": list(&cmdline.shared_instantiations_targets),
+        "previous_generation_manifest": previous_generation_manifest,
+        "codegen_threads": cmdline.codegen_threads,
+        // The bindings are returned rather than written out item by item, so
+        // `rs_api_out_path` and `rs_api_impl_out_path` are left out.
+        "generate_bindings_report": cmdline.bindings_report_out.is_some(),
+        "coverage_options": {
+            "min_coverage_percent": cmdline.min_bindings_coverage,
+            "fail_on_unsupported": list(&cmdline.fail_on_unsupported),
+            "print_summary": cmdline.print_bindings_coverage,
+        },
+        "generate_bindings_changelog": cmdline.bindings_changelog_out.is_some(),
+        // The hashes of the inputs are only recorded by the C++ driver.
+        "preamble_options": {
+            "license_header": non_empty(&license_header),
+            "do_not_edit_banner": cmdline.do_not_edit_banner,
+            "generator_version": non_empty(&cmdline.generator_version),
+        },
+    }))?;
+
+    // SAFETY: The slices are alive during the call.
+    let FfiBindings {
+        rs_api,
+        rs_api_impl,
+        error_report,
+        generation_manifest,
+        bindings_report,
+        coverage_summary,
+        bindings_changelog,
+        error,
+    } = unsafe {
+        GenerateBindingsImpl(FfiU8Slice::from_slice(&ir), FfiU8Slice::from_slice(&options))
+    };
+    let rs_api = rs_api.into_boxed_slice();
+    let rs_api_impl = rs_api_impl.into_boxed_slice();
+    let error_report = error_report.into_boxed_slice();
+    let generation_manifest = generation_manifest.into_boxed_slice();
+    let bindings_report = bindings_report.into_boxed_slice();
+    let coverage_summary = coverage_summary.into_boxed_slice();
+    let bindings_changelog = bindings_changelog.into_boxed_slice();
+    let error = error.into_boxed_slice();
+    if !error.is_empty() {
+        bail!("{}", format_diagnostics(&error));
+    }
+
+    eprint!("{}", String::from_utf8_lossy(&coverage_summary));
+    write_file(&cmdline.rs_out, &rs_api)?;
+    write_file(&cmdline.cc_out, &rs_api_impl)?;
+    for (path, contents) in [
+        (&cmdline.error_report_out, error_report),
+        (&cmdline.bindings_report_out, bindings_report),
+        (&cmdline.generation_manifest, generation_manifest),
+        (&cmdline.bindings_changelog_out, bindings_changelog),
+    ] {
+        if let Some(path) = path {
+            write_file(path, &contents)?;
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    // `std::env::args()` panics if any of the arguments isn't valid Unicode.
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let result = Cmdline::new(args.iter().map(String::as_str)).and_then(|cmdline| run(&cmdline));
+    if let Err(err) = result {
+        eprintln!("{err:#}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_cmdline<'a>(args: impl IntoIterator<Item = &'a str>) -> Result<Cmdline> {
+        Cmdline::new(
+            [
+                "--ir=foo_ir.json",
+                "--rs-out=foo_rs_api.rs",
+                "--cc-out=foo_rs_api_impl.cc",
+                "--crubit-support-path=support",
+                "--clang-format-exe-path=clang-format",
+                "--rustfmt-exe-path=rustfmt",
+            ]
+            .into_iter()
+            .chain(args),
+        )
+    }
+
+    #[test]
+    fn test_defaults() {
+        let cmdline = new_cmdline([]).unwrap();
+        assert_eq!(cmdline.ir, Path::new("foo_ir.json"));
+        assert_eq!(cmdline.rs_out, Path::new("foo_rs_api.rs"));
+        assert_eq!(cmdline.cc_out, Path::new("foo_rs_api_impl.cc"));
+        assert_eq!(cmdline.crubit_support_path, "support");
+        assert_eq!(cmdline.exception_handling, "none");
+        assert_eq!(cmdline.thunk_linkage, "external");
+        assert_eq!(cmdline.rs_formatter, "rustfmt");
+        assert_eq!(cmdline.module_visibility, "pub");
+        assert_eq!(cmdline.crubit_features, "supported,experimental");
+        assert_eq!(cmdline.codegen_threads, 1);
+        assert_eq!(cmdline.error_report_out, None);
+        assert!(!cmdline.rename_overloads);
+    }
+
+    #[test]
+    fn test_generator_flags() {
+        let cmdline = new_cmdline([
+            "--exception-handling=unwind",
+            "--thunk-linkage=inline",
+            "--allowed-lints=dead_code,clippy::all",
+            "--rename-overloads",
+            "--trace-calls=false",
+            "--canonical-item-order",
+            "--line-directives",
+            "--readable-thunk-names",
+            "--rs-formatter=builtin",
+            "--module-path=sys::ffi",
+            "--codegen-threads=4",
+            "--error-report-out=errors.json",
+        ])
+        .unwrap();
+        assert_eq!(cmdline.exception_handling, "unwind");
+        assert_eq!(cmdline.thunk_linkage, "inline");
+        assert_eq!(cmdline.allowed_lints, "dead_code,clippy::all");
+        assert!(cmdline.rename_overloads);
+        assert!(!cmdline.trace_calls);
+        assert!(cmdline.canonical_item_order);
+        assert!(cmdline.line_directives);
+        assert!(cmdline.readable_thunk_names);
+        assert_eq!(cmdline.rs_formatter, "builtin");
+        assert_eq!(cmdline.module_path, "sys::ffi");
+        assert_eq!(cmdline.codegen_threads, 4);
+        assert_eq!(cmdline.error_report_out.as_deref(), Some(Path::new("errors.json")));
+    }
+
+    #[test]
+    fn test_invalid_cmdline() {
+        let err = |args: &[&str]| new_cmdline(args.iter().copied()).unwrap_err().to_string();
+        assert_eq!(err(&["--foo"]), "Unknown flag `--foo`");
+        assert_eq!(err(&["foo.h"]), "Unexpected positional argument `foo.h`");
+        assert!(err(&["--exception-handling=throw"]).contains("`throw`"));
+        assert!(err(&["--codegen-threads=0"]).contains("expected at least 1"));
+        assert!(err(&["--rename-overloads=yes"]).contains("expected a bool"));
+        assert_eq!(
+            err(&["--bindings-changelog-out=changelog.txt"]),
+            "--bindings-changelog-out requires --generation-manifest"
+        );
+        assert_eq!(
+            Cmdline::new(["--ir=foo_ir.json"]).unwrap_err().to_string(),
+            "please specify --rs-out"
+        );
+    }
+
+    #[test]
+    fn test_format_diagnostics() {
+        let json = br#"[
+            {"message": "Unsupported type", "item": "Foo", "source_loc": "foo.h;l=3"},
+            {"message": "Some error", "item": null, "source_loc": null}
+        ]"#;
+        assert_eq!(
+            format_diagnostics(json),
+            "foo.h;l=3: Failed to generate bindings for `Foo`: Unsupported type\nSome error"
+        );
+        assert!(format_diagnostics(b"{").starts_with("Invalid diagnostics"));
+    }
+}
@@ -8,6 +8,7 @@
 #include <optional>
 
 #include "absl/container/flat_hash_map.h"
+#include "absl/container/flat_hash_set.h"
 #include "absl/log/check.h"
 #include "absl/status/statusor.h"
 #include "lifetime_annotations/lifetime_annotations.h"
@@ -26,8 +27,8 @@ class Invocation {
         lifetime_context_(std::make_shared<
                           clang::tidy::lifetimes::LifetimeAnnotationContext>()),
         header_targets_(header_targets) {
-    // Caller should verify that the inputs are non-empty.
-    CHECK(!public_headers_.empty());
+    // Caller should verify that the inputs are non-empty. (`public_headers` may
+    // be empty if the target only has C++20 module interface units.)
     CHECK(!header_targets_.empty());
 
     ir_.public_headers.insert(ir_.public_headers.end(), public_headers_.begin(),
@@ -52,6 +53,15 @@ class Invocation {
   const std::shared_ptr<clang::tidy::lifetimes::LifetimeAnnotationContext>
       lifetime_context_;
 
+  // Qualified names of the functions whose pointer parameters are known to be
+  // non-null and non-escaping, even though they are not annotated with
+  // `CRUBIT_SAFE_POINTER_PARAMS`.
+  absl::flat_hash_set<std::string> safe_pointer_params_allowlist_;
+
+  // Whether the private and protected member functions of the records of the
+  // current target are imported (see `GeneratorOptions::friend_crate`).
+  bool friend_crate_ = false;
+
   // The main output of the import process
   IR ir_;
 
@@ -129,6 +139,13 @@ class ImportContext {
   virtual std::optional<std::string> GetComment(
       const clang::Decl* decl) const = 0;
 
+  // Gets the bindings annotations of the declaration (see `CRUBIT_RUST_NAME`,
+  // `CRUBIT_SKIP` and `CRUBIT_DOC_HIDDEN` in `support/annotations.h`).
+  // Returns an error if the annotations are invalid (e.g. if the Rust name
+  // isn't an identifier).
+  virtual absl::StatusOr<ItemAnnotations> GetItemAnnotations(
+      const clang::Decl* decl) const = 0;
+
   // Converts a Clang source location to IR.
   virtual std::string ConvertSourceLocation(
       clang::SourceLocation loc) const = 0;
@@ -136,13 +153,13 @@ class ImportContext {
   // Converts the Clang type `qual_type` into an equivalent `MappedType`.
   // Lifetimes for the type can optionally be specified using `lifetimes`.
   // If `qual_type` is a pointer type, `nullable` specifies whether the
-  // pointer can be null.
+  // pointer can be null, unless the pointer is explicitly annotated with
+  // `_Nonnull` / `_Nullable` (or `absl::Nonnull` / `absl::Nullable`). Nested
+  // pointers are nullable unless annotated otherwise.
   // TODO(b/209390498): Currently, we're able to specify nullability only for
   // top-level pointers. Extend this so that we can specify nullability for
   // all pointers contained in `qual_type`, in the same way that `lifetimes`
-  // specifies lifetimes for all these pointers. Once this is done, make sure
-  // that all callers pass in the appropriate information, derived from
-  // nullability annotations.
+  // specifies lifetimes for all these pointers.
   virtual absl::StatusOr<MappedType> ConvertQualType(
       clang::QualType qual_type,
       std::optional<clang::tidy::lifetimes::ValueLifetimes>& lifetimes,
@@ -5,17 +5,124 @@
 #include "rs_bindings_from_cc/frontend_action.h"
 
 #include <memory>
+#include <string>
+#include <utility>
+#include <vector>
 
+#include "absl/strings/str_cat.h"
+#include "absl/strings/string_view.h"
+#include "absl/strings/strip.h"
 #include "rs_bindings_from_cc/ast_consumer.h"
+#include "rs_bindings_from_cc/bazel_types.h"
+#include "rs_bindings_from_cc/ir.h"
 #include "clang/AST/ASTConsumer.h"
+#include "clang/Basic/SourceLocation.h"
+#include "clang/Basic/SourceManager.h"
 #include "clang/Frontend/CompilerInstance.h"
+#include "clang/Lex/Lexer.h"
+#include "clang/Lex/MacroInfo.h"
+#include "clang/Lex/PPCallbacks.h"
+#include "clang/Lex/Token.h"
 
 namespace crubit {
 
+namespace {
+
+// Records the conditional directives of the headers of the current target in
+// `IR::preprocessor_conditionals`.
+class ConditionalRecorder : public clang::PPCallbacks {
+ public:
+  ConditionalRecorder(Invocation& invocation, clang::CompilerInstance& instance)
+      : invocation_(invocation), instance_(instance) {}
+
+  void If(clang::SourceLocation loc, clang::SourceRange condition_range,
+          ConditionValueKind condition_value) override {
+    bool taken = condition_value == CVK_True;
+    taken_branches_.push_back(taken);
+    Record(loc, "if", SourceText(condition_range), taken);
+  }
+
+  void Ifdef(clang::SourceLocation loc, const clang::Token& macro_name_tok,
+             const clang::MacroDefinition& macro_definition) override {
+    bool taken = static_cast<bool>(macro_definition);
+    taken_branches_.push_back(taken);
+    Record(loc, "ifdef", MacroName(macro_name_tok), taken);
+  }
+
+  void Ifndef(clang::SourceLocation loc, const clang::Token& macro_name_tok,
+              const clang::MacroDefinition& macro_definition) override {
+    bool taken = !macro_definition;
+    taken_branches_.push_back(taken);
+    Record(loc, "ifndef", MacroName(macro_name_tok), taken);
+  }
+
+  void Elif(clang::SourceLocation loc, clang::SourceRange condition_range,
+            ConditionValueKind condition_value, clang::SourceLocation) override {
+    bool taken = condition_value == CVK_True;
+    if (!taken_branches_.empty()) {
+      taken_branches_.back() = taken_branches_.back() || taken;
+    }
+    Record(loc, "elif", SourceText(condition_range), taken);
+  }
+
+  void Else(clang::SourceLocation loc, clang::SourceLocation) override {
+    bool taken = !taken_branches_.empty() && !taken_branches_.back();
+    Record(loc, "else", "", taken);
+  }
+
+  void Endif(clang::SourceLocation, clang::SourceLocation) override {
+    if (!taken_branches_.empty()) {
+      taken_branches_.pop_back();
+    }
+  }
+
+ private:
+  std::string SourceText(clang::SourceRange range) const {
+    return clang::Lexer::getSourceText(
+               clang::CharSourceRange::getTokenRange(range),
+               instance_.getSourceManager(), instance_.getLangOpts())
+        .str();
+  }
+
+  static std::string MacroName(const clang::Token& macro_name_tok) {
+    const clang::IdentifierInfo* identifier =
+        macro_name_tok.getIdentifierInfo();
+    return identifier != nullptr ? identifier->getName().str() : "";
+  }
+
+  void Record(clang::SourceLocation loc, absl::string_view directive,
+              std::string condition, bool taken) {
+    clang::SourceManager& sm = instance_.getSourceManager();
+    absl::string_view filename = sm.getFilename(loc);
+    absl::ConsumePrefix(&filename, "./");
+    if (invocation_.header_target(HeaderName(std::string(filename))) !=
+        invocation_.target_) {
+      return;
+    }
+    invocation_.ir_.preprocessor_conditionals.push_back(PreprocessorConditional{
+        .directive = std::string(directive),
+        .condition = std::move(condition),
+        .taken = taken,
+        .source_loc =
+            absl::StrCat(filename, ":", sm.getSpellingLineNumber(loc)),
+    });
+  }
+
+  Invocation& invocation_;
+  clang::CompilerInstance& instance_;
+  // For each enclosing conditional, whether any of its branches seen so far
+  // was taken.
+  std::vector<bool> taken_branches_;
+};
+
+}  // namespace
+
 std::unique_ptr<clang::ASTConsumer> FrontendAction::CreateASTConsumer(
     clang::CompilerInstance& instance, llvm::StringRef) {
   AddLifetimeAnnotationHandlers(instance.getPreprocessor(),
                                 invocation_.lifetime_context_);
+  instance.getPreprocessor().addPPCallbacks(
+      std::make_unique<ConditionalRecorder>(invocation_, instance));
   return std::make_unique<AstConsumer>(instance, invocation_);
 }
 
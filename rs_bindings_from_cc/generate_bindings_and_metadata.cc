@@ -10,13 +10,26 @@
 
 #include "absl/container/flat_hash_map.h"
 #include "absl/container/flat_hash_set.h"
+#include "absl/status/status.h"
+#include "absl/status/statusor.h"
+#include "absl/strings/ascii.h"
+#include "absl/strings/match.h"
+#include "absl/strings/str_cat.h"
+#include "absl/strings/str_join.h"
+#include "absl/strings/str_split.h"
 #include "absl/strings/string_view.h"
+#include "common/file_io.h"
 #include "common/status_macros.h"
 #include "rs_bindings_from_cc/collect_instantiations.h"
 #include "rs_bindings_from_cc/collect_namespaces.h"
 #include "rs_bindings_from_cc/ir.h"
 #include "rs_bindings_from_cc/ir_from_cc.h"
 #include "rs_bindings_from_cc/src_code_gen.h"
+#include "llvm/ADT/StringExtras.h"
+#include "llvm/Support/FileSystem.h"
+#include "llvm/Support/FormatVariadic.h"
+#include "llvm/Support/JSON.h"
+#include "llvm/Support/SHA256.h"
 
 namespace crubit {
 
@@ -54,10 +67,181 @@ std::vector<const Record*> FindInstantiationsInNamespace(const IR& ir,
   return result;
 }
 
+// Parses the file passed to `--safe_pointer_params_allowlist`: one qualified
+// function name per line, ignoring empty lines and `#` comments.
+absl::StatusOr<std::vector<std::string>> ReadSafePointerParamsAllowlist(
+    absl::string_view path) {
+  std::vector<std::string> function_names;
+  if (path.empty()) {
+    return function_names;
+  }
+  CRUBIT_ASSIGN_OR_RETURN(std::string contents, GetFileContents(path));
+  for (absl::string_view line : absl::StrSplit(contents, '\n')) {
+    line = absl::StripAsciiWhitespace(line);
+    if (line.empty() || absl::StartsWith(line, "#")) {
+      continue;
+    }
+    function_names.push_back(std::string(line));
+  }
+  return function_names;
+}
+
+// Returns the key of the bindings cache (see `--bindings_cache`): a hash of
+// everything that the generated bindings depend on. This includes the
+// generator binary itself, so that a new version of the generator doesn't reuse
+// bindings generated by an old one.
+absl::StatusOr<std::string> BindingsCacheKey(const Cmdline& cmdline,
+                                             const IR& ir,
+                                             bool generate_error_report,
+                                             bool generate_bindings_report,
+                                             bool generate_bindings_changelog) {
+  llvm::SHA256 hasher;
+  // Each part is prefixed with its size, so that the boundaries between the
+  // parts are part of the hash.
+  auto add = [&hasher](absl::string_view part) {
+    hasher.update(absl::StrCat(part.size(), ":"));
+    hasher.update(llvm::StringRef(part.data(), part.size()));
+  };
+
+  std::string generator_path = llvm::sys::fs::getMainExecutable(
+      nullptr, reinterpret_cast<void*>(&BindingsCacheKey));
+  CRUBIT_ASSIGN_OR_RETURN(std::string generator,
+                          GetFileContents(generator_path));
+  add(generator);
+
+  add(IrToJson(ir));
+  add(cmdline.crubit_support_path());
+  add(cmdline.clang_format_exe_path());
+  add(cmdline.rustfmt_exe_path());
+  add(cmdline.rustfmt_config_path());
+  add(absl::StrCat(static_cast<int>(cmdline.rs_formatter())));
+  if (!cmdline.rustfmt_config_path().empty()) {
+    // The generated bindings are formatted with the default config if the
+    // config file doesn't exist.
+    absl::StatusOr<std::string> rustfmt_config =
+        GetFileContents(cmdline.rustfmt_config_path());
+    add(rustfmt_config.ok() ? *rustfmt_config : "");
+  }
+  add(generate_error_report ? "error_report" : "");
+  add(generate_bindings_report ? "bindings_report" : "");
+  add(generate_bindings_changelog ? "bindings_changelog" : "");
+
+  const GeneratorOptions& options = cmdline.generator_options();
+  add(options.deny_warnings ? "deny_warnings" : "");
+  add(absl::StrJoin(options.allowed_lints, ","));
+  add(absl::StrCat(static_cast<int>(options.exception_handling)));
+  add(absl::StrCat(static_cast<int>(options.thunk_linkage)));
+  add(options.inline_trivial_accessors ? "inline_trivial_accessors" : "");
+  add(options.generate_smoke_tests ? "generate_smoke_tests" : "");
+  add(options.rename_overloads ? "rename_overloads" : "");
+  add(options.trace_calls ? "trace_calls" : "");
+  add(options.module_path);
+  add(options.module_visibility);
+  add(options.friend_crate ? "friend_crate" : "");
+  add(options.field_accessors ? "field_accessors" : "");
+  add(options.snake_case_function_names ? "snake_case_function_names" : "");
+  add(options.canonical_item_order ? "canonical_item_order" : "");
+  add(options.line_directives ? "line_directives" : "");
+  add(options.readable_thunk_names ? "readable_thunk_names" : "");
+  add(options.windows_import_lib);
+  // The bindings depend on the contents of the dependency IRs, not just on
+  // their paths.
+  for (const std::string& path : options.dependency_irs) {
+    CRUBIT_ASSIGN_OR_RETURN(std::string dependency_ir, GetFileContents(path));
+    add(path);
+    add(dependency_ir);
+  }
+  add(absl::StrJoin(options.shared_instantiations_targets, ","));
+  add(absl::StrJoin(options.crubit_features, ","));
+  add(options.license_header);
+  add(options.do_not_edit_banner ? "do_not_edit_banner" : "");
+  add(options.generator_version);
+  add(options.record_input_hashes ? "record_input_hashes" : "");
+
+  // The coverage options don't affect the bindings, but bindings cached with
+  // other options haven't been checked against these ones.
+  const CoverageOptions& coverage_options = cmdline.coverage_options();
+  add(absl::StrCat(coverage_options.min_coverage_percent));
+  add(absl::StrJoin(coverage_options.fail_on_unsupported, ","));
+  add(coverage_options.print_summary ? "print_summary" : "");
+
+  return llvm::toHex(hasher.final(), /*LowerCase=*/true);
+}
+
+// Returns the bindings stored in the bindings cache at `path`, if the cache
+// exists and its key is `key`.
+std::optional<Bindings> ReadCachedBindings(absl::string_view path,
+                                           absl::string_view key) {
+  absl::StatusOr<std::string> contents = GetFileContents(path);
+  if (!contents.ok()) {
+    // There is no cache yet in the first build.
+    return std::nullopt;
+  }
+  llvm::Expected<llvm::json::Value> json = llvm::json::parse(*contents);
+  if (!json) {
+    llvm::consumeError(json.takeError());
+    return std::nullopt;
+  }
+  const llvm::json::Object* cache = json->getAsObject();
+  if (cache == nullptr) {
+    return std::nullopt;
+  }
+  std::optional<llvm::StringRef> cached_key = cache->getString("key");
+  std::optional<llvm::StringRef> rs_api = cache->getString("rs_api");
+  std::optional<llvm::StringRef> rs_api_impl = cache->getString("rs_api_impl");
+  std::optional<llvm::StringRef> error_report =
+      cache->getString("error_report");
+  std::optional<llvm::StringRef> generation_manifest =
+      cache->getString("generation_manifest");
+  std::optional<llvm::StringRef> bindings_report =
+      cache->getString("bindings_report");
+  std::optional<llvm::StringRef> coverage_summary =
+      cache->getString("coverage_summary");
+  std::optional<llvm::StringRef> bindings_changelog =
+      cache->getString("bindings_changelog");
+  if (!cached_key.has_value() || *cached_key != key || !rs_api.has_value() ||
+      !rs_api_impl.has_value() || !error_report.has_value() ||
+      !generation_manifest.has_value() || !bindings_report.has_value() ||
+      !coverage_summary.has_value() || !bindings_changelog.has_value()) {
+    return std::nullopt;
+  }
+  return Bindings{
+      .rs_api = rs_api->str(),
+      .rs_api_impl = rs_api_impl->str(),
+      .error_report = error_report->str(),
+      .generation_manifest = generation_manifest->str(),
+      .bindings_report = bindings_report->str(),
+      .coverage_summary = coverage_summary->str(),
+      .bindings_changelog = bindings_changelog->str(),
+  };
+}
+
+// Overwrites the bindings cache at `path` with `bindings`, keyed by `key`.
+absl::Status WriteCachedBindings(absl::string_view path, absl::string_view key,
+                                 const Bindings& bindings) {
+  llvm::json::Object cache{
+      {"key", key},
+      {"rs_api", bindings.rs_api},
+      {"rs_api_impl", bindings.rs_api_impl},
+      {"error_report", bindings.error_report},
+      {"generation_manifest", bindings.generation_manifest},
+      {"bindings_report", bindings.bindings_report},
+      {"coverage_summary", bindings.coverage_summary},
+      {"bindings_changelog", bindings.bindings_changelog},
+  };
+  return SetFileContents(
+      path, std::string(llvm::formatv(
+                "{0}", llvm::json::Value(std::move(cache)))));
+}
+
 absl::StatusOr<BindingsAndMetadata> GenerateBindingsAndMetadata(
     Cmdline& cmdline, std::vector<std::string> clang_args,
     absl::flat_hash_map<const HeaderName, const std::string>
         virtual_headers_contents_for_testing) {
+  // The overrides follow the flags of the target, so that they take
+  // precedence.
+  clang_args.insert(clang_args.end(), cmdline.preprocessor_overrides().begin(),
+                    cmdline.preprocessor_overrides().end());
   std::vector<absl::string_view> clang_args_view;
   clang_args_view.insert(clang_args_view.end(), clang_args.begin(),
                          clang_args.end());
@@ -66,25 +250,73 @@ absl::StatusOr<BindingsAndMetadata> GenerateBindingsAndMetadata(
       std::vector<std::string> requested_instantiations,
       CollectInstantiations(cmdline.srcs_to_scan_for_instantiations()));
 
+  CRUBIT_ASSIGN_OR_RETURN(
+      std::vector<std::string> safe_pointer_params_allowlist,
+      ReadSafePointerParamsAllowlist(cmdline.safe_pointer_params_allowlist()));
+
   CRUBIT_ASSIGN_OR_RETURN(
       IR ir,
       IrFromCc(
           /* extra_source_code_for_testing= */ "", cmdline.current_target(),
           cmdline.public_headers(), virtual_headers_contents_for_testing,
           cmdline.headers_to_targets(), cmdline.extra_rs_srcs(),
-          clang_args_view, requested_instantiations));
+          clang_args_view, requested_instantiations,
+          cmdline.module_interfaces(), safe_pointer_params_allowlist,
+          cmdline.generator_options().friend_crate));
+  ir.preprocessor_overrides = cmdline.preprocessor_overrides();
 
   if (!cmdline.instantiations_out().empty()) {
     ir.crate_root_path = "__cc_template_instantiations_rs_api";
   }
+  if (!cmdline.generator_options().module_path.empty()) {
+    ir.crate_root_path = cmdline.generator_options().module_path;
+  }
 
   bool generate_error_report = !cmdline.error_report_out().empty();
-  CRUBIT_ASSIGN_OR_RETURN(
-      Bindings bindings,
-      GenerateBindings(ir, cmdline.crubit_support_path(),
-                       cmdline.clang_format_exe_path(),
-                       cmdline.rustfmt_exe_path(),
-                       cmdline.rustfmt_config_path(), generate_error_report));
+  bool generate_bindings_report = !cmdline.bindings_report_out().empty();
+  bool generate_bindings_changelog =
+      !cmdline.bindings_changelog_out().empty();
+  std::optional<std::string> previous_generation_manifest;
+  if (!cmdline.generation_manifest().empty()) {
+    // There is no manifest yet in the first build, in which case all the items
+    // are generated from scratch.
+    absl::StatusOr<std::string> contents =
+        GetFileContents(cmdline.generation_manifest());
+    previous_generation_manifest = contents.ok() ? *std::move(contents) : "";
+  }
+  std::string bindings_cache_key;
+  std::optional<Bindings> cached_bindings;
+  if (!cmdline.bindings_cache().empty()) {
+    CRUBIT_ASSIGN_OR_RETURN(
+        bindings_cache_key,
+        BindingsCacheKey(cmdline, ir, generate_error_report,
+                         generate_bindings_report,
+                         generate_bindings_changelog));
+    cached_bindings =
+        ReadCachedBindings(cmdline.bindings_cache(), bindings_cache_key);
+  }
+  Bindings bindings;
+  if (cached_bindings.has_value()) {
+    bindings = *std::move(cached_bindings);
+  } else {
+    CRUBIT_ASSIGN_OR_RETURN(
+        bindings,
+        GenerateBindings(ir, cmdline.crubit_support_path(),
+                         cmdline.clang_format_exe_path(),
+                         cmdline.rustfmt_exe_path(),
+                         cmdline.rustfmt_config_path(), generate_error_report,
+                         cmdline.generator_options(),
+                         previous_generation_manifest,
+                         cmdline.codegen_threads(),
+                         cmdline.stream_output() ? cmdline.rs_out() : "",
+                         cmdline.stream_output() ? cmdline.cc_out() : "",
+                         generate_bindings_report, cmdline.coverage_options(),
+                         generate_bindings_changelog, cmdline.rs_formatter()));
+    if (!cmdline.bindings_cache().empty()) {
+      CRUBIT_RETURN_IF_ERROR(WriteCachedBindings(
+          cmdline.bindings_cache(), bindings_cache_key, bindings));
+    }
+  }
 
   absl::flat_hash_map<std::string, std::string> instantiations;
   std::optional<const Namespace*> ns =
@@ -106,6 +338,10 @@ absl::StatusOr<BindingsAndMetadata> GenerateBindingsAndMetadata(
       .namespaces = std::move(top_level_namespaces),
       .instantiations = std::move(instantiations),
       .error_report = bindings.error_report,
+      .generation_manifest = bindings.generation_manifest,
+      .bindings_report = bindings.bindings_report,
+      .coverage_summary = bindings.coverage_summary,
+      .bindings_changelog = bindings.bindings_changelog,
   };
 }
 
@@ -30,6 +30,14 @@ struct BindingsAndMetadata {
   absl::flat_hash_map<std::string, std::string> instantiations;
   // A JSON error report, if requested.
   std::string error_report;
+  // A JSON generation manifest, if requested.
+  std::string generation_manifest;
+  // A JSON bindings report, if requested.
+  std::string bindings_report;
+  // A summary table of the binding coverage, if requested.
+  std::string coverage_summary;
+  // A changelog of the bindings, if requested.
+  std::string bindings_changelog;
 };
 
 // Returns `BindingsAndMetadata` as requested by the user on the command line.
@@ -5,21 +5,27 @@
 #include "rs_bindings_from_cc/generate_bindings_and_metadata.h"
 
 #include <string>
+#include <vector>
 
 #include "gmock/gmock.h"
 #include "gtest/gtest.h"
 #include "absl/status/status.h"
 #include "absl/status/statusor.h"
+#include "absl/strings/str_cat.h"
 #include "absl/strings/string_view.h"
+#include "common/file_io.h"
 #include "common/status_macros.h"
 #include "common/test_utils.h"
 #include "rs_bindings_from_cc/cmdline.h"
 #include "rs_bindings_from_cc/collect_namespaces.h"
 #include "rs_bindings_from_cc/ir.h"
+#include "llvm/Support/FormatVariadic.h"
+#include "llvm/Support/JSON.h"
 
 namespace crubit {
 namespace {
 
+using ::testing::Contains;
 using ::testing::ElementsAre;
 using ::testing::IsEmpty;
 using ::testing::Pair;
@@ -213,6 +219,110 @@ TEST(GenerateBindingsAndMetadataTest,
                                "__CcTemplateInst16ExpectedTemplateIbE")));
 }
 
+TEST(GenerateBindingsAndMetadataTest, BindingsCache) {
+  constexpr absl::string_view kTargetsAndHeaders = R"([
+    {"t": "target1", "h": ["a.h"]}
+  ])";
+  std::string bindings_cache =
+      WriteFileForCurrentTest("bindings_cache.json", "");
+  auto generate = [&](absl::string_view header_content)
+      -> absl::StatusOr<BindingsAndMetadata> {
+    CRUBIT_ASSIGN_OR_RETURN(
+        Cmdline cmdline,
+        Cmdline::CreateForTesting(
+            "//:target", "cc_out", "rs_out", "ir_out", "namespaces_out",
+            "crubit_support_path", std::string(kDefaultClangFormatExePath),
+            std::string(kDefaultRustfmtExePath), "nowhere/rustfmt.toml",
+            /* do_nothing= */ false,
+            /* public_headers= */ {"a.h"}, std::string(kTargetsAndHeaders),
+            /* extra_rs_srcs= */ {},
+            /* srcs_to_scan_for_instantiations= */ {},
+            /* instantiations_out= */ "",
+            /* error_report_out= */ "", /* generator_options= */ {},
+            /* module_interfaces= */ {}, /* generation_manifest= */ "",
+            /* safe_pointer_params_allowlist= */ "", bindings_cache));
+    return GenerateBindingsAndMetadata(
+        cmdline, DefaultClangArgs(),
+        /* virtual_headers_contents= */
+        {{HeaderName("a.h"), std::string(header_content)}});
+  };
+
+  ASSERT_OK_AND_ASSIGN(BindingsAndMetadata first, generate("struct S {};"));
+
+  // Replace the cached Rust bindings, to check that they are reused.
+  ASSERT_OK_AND_ASSIGN(std::string contents, GetFileContents(bindings_cache));
+  llvm::Expected<llvm::json::Value> cache = llvm::json::parse(contents);
+  ASSERT_TRUE(static_cast<bool>(cache));
+  (*cache->getAsObject())["rs_api"] = "// cached";
+  ASSERT_OK(SetFileContents(bindings_cache,
+                            std::string(llvm::formatv("{0}", *cache))));
+  ASSERT_OK_AND_ASSIGN(BindingsAndMetadata second, generate("struct S {};"));
+  EXPECT_EQ(second.rs_api, "// cached");
+  EXPECT_EQ(second.rs_api_impl, first.rs_api_impl);
+
+  // Changing the headers invalidates the cache.
+  ASSERT_OK_AND_ASSIGN(BindingsAndMetadata third, generate("struct T {};"));
+  EXPECT_NE(third.rs_api, "// cached");
+}
+
+TEST(GenerateBindingsAndMetadataTest, PreprocessorOverrides) {
+  constexpr absl::string_view kTargetsAndHeaders = R"([
+    {"t": "//:target", "h": ["a.h"]}
+  ])";
+  ASSERT_OK_AND_ASSIGN(
+      Cmdline cmdline,
+      Cmdline::CreateForTesting(
+          "//:target", "cc_out", "rs_out", "ir_out", "namespaces_out",
+          "crubit_support_path", std::string(kDefaultClangFormatExePath),
+          std::string(kDefaultRustfmtExePath), "nowhere/rustfmt.toml",
+          /* do_nothing= */ false,
+          /* public_headers= */ {"a.h"}, std::string(kTargetsAndHeaders),
+          /* extra_rs_srcs= */ {},
+          /* srcs_to_scan_for_instantiations= */ {},
+          /* instantiations_out= */ "",
+          /* error_report_out= */ "", /* generator_options= */ {},
+          /* module_interfaces= */ {}, /* generation_manifest= */ "",
+          /* safe_pointer_params_allowlist= */ "", /* bindings_cache= */ "",
+          /* codegen_threads= */ 1, /* check= */ false,
+          /* preprocessor_overrides= */ {"-DFOO", "-DBAR=2"}));
+
+  ASSERT_OK_AND_ASSIGN(
+      BindingsAndMetadata result,
+      GenerateBindingsAndMetadata(cmdline, DefaultClangArgs(),
+                                  /* virtual_headers_contents= */
+                                  {{HeaderName("a.h"), R"(
+#ifdef FOO
+struct Foo {};
+#else
+struct NoFoo {};
+#endif
+#if BAR > 1
+struct Bar {};
+#elif BAR
+struct SmallBar {};
+#endif
+)"}}));
+
+  EXPECT_THAT(result.ir.preprocessor_overrides,
+              ElementsAre("-DFOO", "-DBAR=2"));
+  std::vector<std::string> conditionals;
+  for (const PreprocessorConditional& conditional :
+       result.ir.preprocessor_conditionals) {
+    conditionals.push_back(absl::StrCat(
+        conditional.source_loc, " #", conditional.directive, " ",
+        conditional.condition, ": ", conditional.taken ? "taken" : "skipped"));
+  }
+  EXPECT_THAT(conditionals,
+              ElementsAre("a.h:2 #ifdef FOO: taken", "a.h:4 #else : skipped",
+                          "a.h:7 #if BAR > 1: taken",
+                          "a.h:9 #elif BAR: skipped"));
+  std::vector<std::string> records;
+  for (const Record* record : result.ir.get_items_if<Record>()) {
+    records.push_back(record->cc_name);
+  }
+  EXPECT_THAT(records, ElementsAre("Foo", "Bar"));
+}
+
 TEST(GenerateBindingsAndMetadataTest, NamespacesJsonGenerated) {
   constexpr absl::string_view kTargetsAndHeaders = R"([
     {"t": "//:target1", "h": ["a.h"]}
@@ -286,5 +396,48 @@ TEST(GenerateBindingsAndMetadataTest, NamespacesJsonGenerated) {
   ASSERT_THAT(NamespacesAsJson(result.namespaces), StrEq(kExpected));
 }
 
+TEST(GenerateBindingsAndMetadataTest, BindingsReport) {
+  constexpr absl::string_view kTargetsAndHeaders = R"([
+    {"t": "//:target", "h": ["a.h"]}
+  ])";
+  ASSERT_OK_AND_ASSIGN(
+      Cmdline cmdline,
+      Cmdline::CreateForTesting(
+          "//:target", "cc_out", "rs_out", "ir_out", "namespaces_out",
+          "crubit_support_path", std::string(kDefaultClangFormatExePath),
+          std::string(kDefaultRustfmtExePath), "nowhere/rustfmt.toml",
+          /* do_nothing= */ false,
+          /* public_headers= */ {"a.h"}, std::string(kTargetsAndHeaders),
+          /* extra_rs_srcs= */ {},
+          /* srcs_to_scan_for_instantiations= */ {},
+          /* instantiations_out= */ "",
+          /* error_report_out= */ "", /* generator_options= */ {},
+          /* module_interfaces= */ {}, /* generation_manifest= */ "",
+          /* safe_pointer_params_allowlist= */ "", /* bindings_cache= */ "",
+          /* codegen_threads= */ 1, /* check= */ false,
+          /* preprocessor_overrides= */ {}, /* stream_output= */ false,
+          /* bindings_report_out= */ "bindings_report_out"));
+
+  ASSERT_OK_AND_ASSIGN(
+      BindingsAndMetadata result,
+      GenerateBindingsAndMetadata(cmdline, DefaultClangArgs(),
+                                  /* virtual_headers_contents= */
+                                  {{HeaderName("a.h"), "struct S final {};"}}));
+
+  llvm::Expected<llvm::json::Value> report =
+      llvm::json::parse(result.bindings_report);
+  ASSERT_TRUE(static_cast<bool>(report));
+  const llvm::json::Array* items = report->getAsObject()->getArray("items");
+  ASSERT_NE(items, nullptr);
+  std::vector<std::string> statuses;
+  for (const llvm::json::Value& item : *items) {
+    const llvm::json::Object* object = item.getAsObject();
+    statuses.push_back(
+        absl::StrCat(object->getString("name").value_or("").str(), ": ",
+                     object->getString("status").value_or("").str()));
+  }
+  EXPECT_THAT(statuses, Contains("S: bound"));
+}
+
 }  // namespace
 }  // namespace crubit
@@ -0,0 +1,258 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#ifndef CRUBIT_RS_BINDINGS_FROM_CC_GENERATOR_OPTIONS_H_
+#define CRUBIT_RS_BINDINGS_FROM_CC_GENERATOR_OPTIONS_H_
+
+#include <cstdint>
+#include <string>
+#include <vector>
+
+namespace crubit {
+
+// How the generated bindings handle C++ exceptions thrown by the wrapped
+// functions.
+//
+// Note: the values are passed to the Rust side of the generator by name
+// (see `ExceptionHandling` in `src_code_gen.rs`).
+enum class ExceptionHandling : uint8_t {
+  // Exceptions are not caught. Unwinding a C++ exception into Rust is
+  // undefined behavior, so this is only appropriate for code that doesn't
+  // throw (e.g. code compiled with `-fno-exceptions`).
+  kNone = 0,
+  // The C++ thunks catch all exceptions, and the Rust bindings panic with the
+  // exception message.
+  kPanic = 1,
+  // Like `kPanic`, but functions and methods return
+  // `Result<T, ::cc_exception::CcException>` instead of panicking. (Trait
+  // implementations, whose signatures are fixed, still panic.)
+  kResult = 2,
+  // Exceptions are not caught, but are allowed to unwind through the Rust
+  // frames: the thunks are declared as `extern "C-unwind"` (and so are the
+  // trampolines of Rust callbacks, which lets Rust panics unwind through C++).
+  // This requires building the Rust code with `panic=unwind`.
+  kUnwind = 3,
+};
+
+// The linkage of the C++ thunks of the functions (in the `rs_api_impl`
+// translation unit), through which the Rust bindings call the functions.
+//
+// The thunks can't have internal linkage (e.g. be `static`), because they are
+// called from the Rust bindings, which are a separate translation unit.
+//
+// Note: the values are passed to the Rust side of the generator by name
+// (see `ThunkLinkage` in `src_code_gen.rs`).
+enum class ThunkLinkage : uint8_t {
+  // The thunks are ordinary `extern "C"` functions.
+  kExternal = 0,
+  // The thunks are `inline` functions, which are always emitted (they are
+  // `[[gnu::used]]`), so that the linker merges their copies instead of
+  // reporting duplicate symbols (e.g. when `rs_api_impl` is linked into
+  // several libraries of an LTO build). The names of the thunks include the
+  // target, so that only copies of the same thunk are merged.
+  kInline = 1,
+};
+
+// How the generated Rust bindings are formatted. (This doesn't affect the
+// contents of the bindings, so it isn't one of the `GeneratorOptions`.)
+//
+// Note: the values are passed to the Rust side of the generator by name
+// (see `RsFormatter` in `src_code_gen.rs`).
+enum class RsFormatter : uint8_t {
+  // The bindings are formatted by the `rustfmt` executable.
+  kRustfmt = 0,
+  // The bindings are formatted in-process by `prettyplease`, which is much
+  // faster than `rustfmt`, though the output is less polished (e.g. the
+  // arguments of macros aren't formatted).
+  kBuiltin = 1,
+  // The bindings aren't formatted at all, which is the fastest.
+  kNone = 2,
+};
+
+// Options that control the contents of the generated bindings (as opposed to
+// where the bindings are written to, or how they are formatted).
+struct GeneratorOptions {
+  // Whether the generated Rust bindings should `#![deny(warnings)]`.
+  //
+  // This is off by default, because a new rustc lint firing on the generated
+  // code would otherwise break the build of every crate using the bindings.
+  // Instead, the bindings only allow the specific lints that are known to fire
+  // on generated code (e.g. `non_snake_case`), and all other lints follow the
+  // settings of the build.
+  bool deny_warnings = false;
+
+  // Additional lints to `#![allow(...)]` in the generated Rust bindings (e.g.
+  // `dead_code` or `clippy::all`).
+  std::vector<std::string> allowed_lints;
+
+  // How C++ exceptions thrown by the wrapped functions are handled.
+  ExceptionHandling exception_handling = ExceptionHandling::kNone;
+
+  // The linkage of the C++ thunks of the functions.
+  ThunkLinkage thunk_linkage = ThunkLinkage::kExternal;
+
+  // Whether trivial inline functions (getters and setters of fields, and
+  // functions returning a constant) are reimplemented in Rust, instead of
+  // being called through a thunk. This avoids the cost of the FFI call when
+  // the bindings are built without cross-language inlining.
+  bool inline_trivial_accessors = false;
+
+  // Whether the generated Rust bindings include a `#[cfg(test)]` module with
+  // smoke tests which construct, copy, and destroy the records of the target.
+  // This checks linking and basic ABI correctness of the bindings with
+  // `cargo test`, without any hand-written tests.
+  bool generate_smoke_tests = false;
+
+  // Whether overloaded functions get bindings under names suffixed with the
+  // types of their parameters (e.g. `Foo(int)` and `Foo(double)` become
+  // `Foo_i32` and `Foo_f64`; an overload without parameters keeps its name).
+  // Otherwise, no bindings are generated for overloaded functions.
+  bool rename_overloads = false;
+
+  // Whether the functions of the generated Rust bindings report their calls
+  // (the name of the C++ function and the duration of the call) to the tracer
+  // installed with `cc_call_trace::set_tracer`. This measures the frequency
+  // and latency of the calls into C++ without manual instrumentation; the
+  // overhead is a relaxed atomic load per call while no tracer is installed.
+  bool trace_calls = false;
+
+  // The path of the module (e.g. `ffi` or `sys::ffi`) of a handwritten crate
+  // into which the generated Rust bindings are `include!`d. If empty, the
+  // generated Rust bindings are a crate of their own.
+  //
+  // In this "augmentation mode", the generated Rust bindings consist of a
+  // single `mod` item named after the last segment of the path (so the
+  // `include!` goes into the parent module), without any crate-level
+  // attributes, and the bindings refer to their own items through
+  // `crate::<module_path>::`. The handwritten crate builds its curated API on
+  // top of the raw bindings: because the generated types belong to the same
+  // crate, it can add inherent `impl` blocks and trait implementations for
+  // them, while the C++ thunks stay private to the generated module.
+  std::string module_path;
+
+  // The visibility of the generated module in augmentation mode, e.g. `pub` or
+  // `pub(crate)` (to only expose the curated API of the handwritten crate).
+  std::string module_visibility = "pub";
+
+  // Whether the private and protected member functions of the records of the
+  // target get bindings, e.g. so that tests can call them. The bindings of
+  // such functions are `pub(crate)` items of the `internal` module, and their
+  // thunks access the members through explicit template instantiations (see
+  // `support/internal/private_member_access.h`). This is meant to be enabled
+  // only for the targets in an allowlist (see
+  // `//rs_bindings_from_cc/bazel_support:friend_crate_targets`).
+  bool friend_crate = false;
+
+  // Whether the fields of structs are exposed through accessor methods (e.g.
+  // `fn x(&self) -> &i32` and `fn set_x(&mut self, value: i32)`) instead of
+  // `pub` fields. This hides the layout of the generated structs (e.g. padding
+  // and `__non_field_data`), so that it can change without breaking users.
+  // This is meant to be enabled per target (see
+  // `//rs_bindings_from_cc/bazel_support:field_accessors_targets`).
+  bool field_accessors = false;
+
+  // Whether functions and methods get bindings under snake_case names (e.g.
+  // `MemberFunction` becomes `member_function`), which is idiomatic in Rust and
+  // avoids the `non_snake_case` lint. A function keeps its C++ name if the
+  // snake_case name collides with the name of another function of the same
+  // class or namespace. `CRUBIT_RUST_NAME` takes precedence over the renaming
+  // (e.g. to keep the C++ name of a function). This is meant to be enabled per
+  // target (see
+  // `//rs_bindings_from_cc/bazel_support:snake_case_function_names_targets`).
+  bool snake_case_function_names = false;
+
+  // Whether the bindings of the items are generated in a canonical order (by
+  // source location, then by mangled name) rather than in the order of the IR,
+  // which can change whenever the importer traverses the AST differently.
+  bool canonical_item_order = false;
+
+  // Whether the C++ thunks and layout assertions are preceded by `#line`
+  // directives pointing at the declarations they were generated from, so that
+  // compile errors and debuggers refer to the locations in the headers.
+  bool line_directives = false;
+
+  // Whether the names of the C++ thunks start with the readable names of the
+  // functions they call (e.g. `__rust_thunk__ns_Foo_ctor___ZN2ns3FooC1Ev`),
+  // and the bindings report has a table mapping them to the C++ signatures.
+  bool readable_thunk_names = false;
+
+  // The name of the import library (e.g. `foo` for `foo.lib`) through which
+  // the C++ library of the target is linked when it is built as a Windows DLL.
+  // If not empty, the `extern` blocks of the generated Rust bindings are
+  // annotated with `#[cfg_attr(windows, link(name = ..., kind = "dylib"))]`,
+  // so that the thunks and the `link_name` symbols get resolved through the
+  // import library without a handwritten build script. The DLL has to export
+  // these symbols. This is meant to be enabled per target (see
+  // `//rs_bindings_from_cc/bazel_support:windows_dll_targets`).
+  std::string windows_import_lib;
+
+  // The paths of the IR files (see `--ir_out`) of the dependencies of the
+  // target. The records which are owned by these dependencies are referred
+  // to through the crate root path recorded in their IR, and the records for
+  // which a dependency has no bindings are reported as unsupported instead of
+  // being referred to through a path which doesn't exist.
+  std::vector<std::string> dependency_irs;
+
+  // The labels of the dependencies (whose IRs have to be passed in
+  // `dependency_irs`) which are shared crates of common template
+  // instantiations, e.g. `std::vector<int>`. The instantiations which are
+  // bound by such a crate are referred to through it, instead of being
+  // generated again by every target which uses them, which would result in
+  // duplicate symbols and in distinct Rust types for the same C++ type.
+  std::vector<std::string> shared_instantiations_targets;
+
+  // The codegen features (e.g. `templates`, `operators`, or `bridging`) whose
+  // bindings are generated for the target, so that large codebases can adopt
+  // new features incrementally. Entries can also be maturities (`supported`
+  // or `experimental`), which enable all the features of that maturity (see
+  // `CrubitFeature` in `src_code_gen.rs`). The features which aren't enabled
+  // are reported as unsupported. This is meant to be set per target (see
+  // `//rs_bindings_from_cc/bazel_support:default_crubit_features`).
+  std::vector<std::string> crubit_features = {"supported", "experimental"};
+
+  // The following options configure the preamble of the generated files, whose
+  // requirements differ between organizations (e.g. for checked-in bindings).
+
+  // The license text at the top of the generated files (e.g. the license of
+  // the C++ library). Its lines become `//` comments, unless they already are.
+  // Empty for no license text.
+  std::string license_header;
+
+  // Whether the preamble warns that the generated files must not be edited by
+  // hand, because they are overwritten when the bindings are regenerated.
+  bool do_not_edit_banner = false;
+
+  // The version of the generator (e.g. a release number or a commit hash)
+  // recorded in the preamble. Empty to leave it out.
+  std::string generator_version;
+
+  // Whether the preamble records the SHA-256 hashes of the inputs of the
+  // generator (the IR of the target, and the IRs of its dependencies), so that
+  // the provenance of checked-in bindings can be verified.
+  bool record_input_hashes = false;
+};
+
+// Options of the check of the binding coverage of the target: the fraction of
+// the C++ items of the target which get bindings (see `BindingsReport` in
+// `src_code_gen.rs`). They don't affect the generated bindings, but the
+// generation fails if the check fails.
+struct CoverageOptions {
+  // The minimum coverage, in percent. The generation fails if fewer items get
+  // bindings, so that checking in the current coverage of a target prevents
+  // it from regressing. 0 disables the check.
+  int min_coverage_percent = 0;
+
+  // The qualified C++ names of items (e.g. `ns::Foo` or `ns::Foo::Bar`) which
+  // must get bindings. The generation fails if any of them is unsupported, or
+  // isn't declared by the target.
+  std::vector<std::string> fail_on_unsupported;
+
+  // Whether a summary table of the coverage (the numbers of bound, renamed,
+  // and skipped items of each kind) is printed to stderr.
+  bool print_summary = false;
+};
+
+}  // namespace crubit
+
+#endif  // CRUBIT_RS_BINDINGS_FROM_CC_GENERATOR_OPTIONS_H_
@@ -356,7 +356,8 @@ std::vector<ItemId> Importer::GetItemIdsInSourceOrder(
   }
 
   for (auto& [_, comment] : ordered_comments) {
-    items.push_back({GetSourceOrderKey(comment), GenerateItemId(comment)});
+    items.push_back(
+        {GetSourceOrderKey(comment), GenerateItemId(comment, sm)});
   }
   llvm::sort(items, compare_locations);
 
@@ -412,7 +413,7 @@ void Importer::Import(clang::TranslationUnitDecl* translation_unit_decl) {
     ordered_items.push_back(
         {GetSourceOrderKey(comment),
          Comment{.text = comment->getFormattedText(sm, sm.getDiagnostics()),
-                 .id = GenerateItemId(comment)}});
+                 .id = GenerateItemId(comment, sm)}});
   }
 
   ImportDeclsFromDeclContext(translation_unit_decl);
@@ -435,8 +436,9 @@ void Importer::Import(clang::TranslationUnitDecl* translation_unit_decl) {
   invocation_.ir_.top_level_item_ids =
       GetItemIdsInSourceOrder(translation_unit_decl);
 
-  // TODO(b/257302656): Consider placing the generated template instantiations
-  // into a separate namespace (maybe `crubit::instantiated_templates` ?).
+  // Template instantiations go after the items in source order. The code
+  // generator places them into the `__instantiations` module (see
+  // `Record::is_template_instantiation`).
   llvm::copy(GetOrderedItemIdsOfTemplateInstantiations(),
              std::back_inserter(invocation_.ir_.top_level_item_ids));
 }
@@ -549,7 +551,15 @@ bool IsTransitivelyInPrivate(clang::Decl* decl_to_check) {
 }
 
 std::optional<IR::Item> Importer::ImportDecl(clang::Decl* decl) {
-  if (IsTransitivelyInPrivate(decl)) return std::nullopt;
+  if (IsTransitivelyInPrivate(decl)) {
+    // Friend crates also get bindings for the non-public member functions of
+    // (accessible) records, see `GeneratorOptions::friend_crate`.
+    auto* method_decl = clang::dyn_cast<clang::CXXMethodDecl>(decl);
+    if (!invocation_.friend_crate_ || method_decl == nullptr ||
+        IsTransitivelyInPrivate(method_decl->getParent())) {
+      return std::nullopt;
+    }
+  }
   std::optional<IR::Item> result;
   for (auto& importer : decl_importers_) {
     if (importer->CanImport(decl)) {
@@ -656,6 +666,22 @@ std::optional<std::string> Importer::GetComment(const clang::Decl* decl) const {
   return cleaned_comment_text;
 }
 
+absl::StatusOr<ItemAnnotations> Importer::GetItemAnnotations(
+    const clang::Decl* decl) const {
+  std::optional<std::string> rust_name =
+      GetAnnotationArg(*decl, "crubit_rust_name");
+  if (rust_name.has_value() &&
+      !llvm::Regex("^[A-Za-z_][A-Za-z0-9_]*$").match(*rust_name)) {
+    return absl::InvalidArgumentError(absl::Substitute(
+        "Invalid `CRUBIT_RUST_NAME`: '$0' is not an identifier", *rust_name));
+  }
+  return ItemAnnotations{
+      .rust_name = std::move(rust_name),
+      .skip = HasAnnotation(*decl, "crubit_skip"),
+      .doc_hidden = HasAnnotation(*decl, "crubit_doc_hidden"),
+  };
+}
+
 std::string Importer::ConvertSourceLocation(clang::SourceLocation loc) const {
   auto& sm = ctx_.getSourceManager();
   // For macros: https://clang.llvm.org/doxygen/SourceManager_8h.html:
@@ -744,6 +770,14 @@ absl::StatusOr<MappedType> Importer::ConvertTemplateSpecializationType(
 }
 
 absl::StatusOr<MappedType> Importer::ConvertTypeDecl(clang::TypeDecl* decl) {
+  // The C++ bindings of a Rust type (generated by `cc_bindings_from_rs`) are
+  // mapped back to the Rust type, rather than getting bindings of their own.
+  if (std::optional<std::string> rust_type =
+          GetAnnotationArg(*decl, "crubit_internal_rust_type");
+      rust_type.has_value()) {
+    return MappedType::Simple(*std::move(rust_type),
+                              decl->getQualifiedNameAsString());
+  }
   if (!EnsureSuccessfullyImported(decl)) {
     return absl::NotFoundError(absl::Substitute(
         "No generated bindings found for '$0'", decl->getNameAsString()));
@@ -753,6 +787,61 @@ absl::StatusOr<MappedType> Importer::ConvertTypeDecl(clang::TypeDecl* decl) {
   return MappedType::WithDeclId(decl_id);
 }
 
+// Returns the function type `R(Args...)` if `type` is (possibly a typedef of)
+// `std::function<R(Args...)>`, and nullptr otherwise.
+static const clang::FunctionProtoType* GetStdFunctionSignature(
+    const clang::Type* type) {
+  const auto* specialization_decl =
+      clang::dyn_cast_or_null<clang::ClassTemplateSpecializationDecl>(
+          type->getAsCXXRecordDecl());
+  if (!specialization_decl || !specialization_decl->isInStdNamespace() ||
+      specialization_decl->getName() != "function") {
+    return nullptr;
+  }
+  const clang::TemplateArgumentList& args =
+      specialization_decl->getTemplateArgs();
+  if (args.size() != 1 ||
+      args[0].getKind() != clang::TemplateArgument::Type) {
+    return nullptr;
+  }
+  return args[0].getAsType()->getAs<clang::FunctionProtoType>();
+}
+
+// If `type` is `std::vector<T>` (with the default allocator) or
+// `std::optional<T>`, returns the specialization, whose first template argument
+// is `T`. `std::vector<bool>` is excluded, because it is not a container of
+// `bool`s.
+static const clang::ClassTemplateSpecializationDecl* GetStdContainer(
+    const clang::Type* type) {
+  const auto* specialization_decl =
+      clang::dyn_cast_or_null<clang::ClassTemplateSpecializationDecl>(
+          type->getAsCXXRecordDecl());
+  if (!specialization_decl || !specialization_decl->isInStdNamespace()) {
+    return nullptr;
+  }
+  const clang::TemplateArgumentList& args =
+      specialization_decl->getTemplateArgs();
+  if (args.size() == 0 || args[0].getKind() != clang::TemplateArgument::Type) {
+    return nullptr;
+  }
+  if (specialization_decl->getName() == "optional") {
+    return args.size() == 1 ? specialization_decl : nullptr;
+  }
+  if (specialization_decl->getName() != "vector" || args.size() != 2 ||
+      args[0].getAsType()->isBooleanType() ||
+      args[1].getKind() != clang::TemplateArgument::Type) {
+    return nullptr;
+  }
+  const auto* allocator_decl =
+      clang::dyn_cast_or_null<clang::ClassTemplateSpecializationDecl>(
+          args[1].getAsType()->getAsCXXRecordDecl());
+  if (!allocator_decl || !allocator_decl->isInStdNamespace() ||
+      allocator_decl->getName() != "allocator") {
+    return nullptr;
+  }
+  return specialization_decl;
+}
+
 absl::StatusOr<MappedType> Importer::ConvertType(
     const clang::Type* type,
     std::optional<clang::tidy::lifetimes::ValueLifetimes>& lifetimes,
@@ -763,6 +852,84 @@ absl::StatusOr<MappedType> Importer::ConvertType(
   if (auto maybe_mapped_type = MapKnownCcTypeToRsType(type_string);
       maybe_mapped_type.has_value()) {
     return MappedType::Simple(std::string(*maybe_mapped_type), type_string);
+  } else if (const auto* func_type = GetStdFunctionSignature(type)) {
+    // Lifetimes of the callable's own parameters are not tracked - the
+    // callable is invoked through a trampoline which only forwards them.
+    std::optional<clang::tidy::lifetimes::ValueLifetimes> no_lifetimes;
+    CRUBIT_ASSIGN_OR_RETURN(
+        MappedType mapped_return_type,
+        ConvertQualType(func_type->getReturnType(), no_lifetimes));
+    std::vector<MappedType> mapped_param_types;
+    for (const clang::QualType& param_type : func_type->getParamTypes()) {
+      CRUBIT_ASSIGN_OR_RETURN(MappedType mapped_param_type,
+                              ConvertQualType(param_type, no_lifetimes));
+      mapped_param_types.push_back(std::move(mapped_param_type));
+    }
+    return MappedType::Callable(std::move(mapped_return_type),
+                                std::move(mapped_param_types));
+  } else if (const auto* container_decl = GetStdContainer(type)) {
+    // Lifetimes of the elements are not tracked.
+    std::optional<clang::tidy::lifetimes::ValueLifetimes> no_lifetimes;
+    CRUBIT_ASSIGN_OR_RETURN(
+        MappedType mapped_element_type,
+        ConvertQualType(container_decl->getTemplateArgs()[0].getAsType(),
+                        no_lifetimes));
+    if (container_decl->getName() == "vector") {
+      return MappedType::StdVector(std::move(mapped_element_type));
+    }
+    return MappedType::StdOptional(std::move(mapped_element_type));
+  } else if (const auto* member_ptr_type =
+                 type->getAs<clang::MemberPointerType>()) {
+    // Pointers to members are opaque values in Rust, so lifetimes of the
+    // member types are not tracked.
+    std::optional<clang::tidy::lifetimes::ValueLifetimes> no_lifetimes;
+    CRUBIT_ASSIGN_OR_RETURN(
+        MappedType mapped_class_type,
+        ConvertQualType(clang::QualType(member_ptr_type->getClass(), 0),
+                        no_lifetimes));
+    clang::QualType pointee_type = member_ptr_type->getPointeeType();
+    const auto* func_type = pointee_type->getAs<clang::FunctionProtoType>();
+    if (func_type == nullptr) {
+      CRUBIT_ASSIGN_OR_RETURN(MappedType mapped_member_type,
+                              ConvertQualType(pointee_type, no_lifetimes));
+      return MappedType::DataMemberPtr(std::move(mapped_class_type),
+                                       std::move(mapped_member_type));
+    }
+    if (func_type->isVariadic() ||
+        func_type->getRefQualifier() != clang::RQ_None ||
+        func_type->getMethodQuals().hasVolatile()) {
+      return absl::UnimplementedError(absl::StrCat(
+          "Pointers to variadic, ref-qualified or volatile member functions "
+          "are not supported: ",
+          type_string));
+    }
+    if (func_type->getCallConv() != clang::CC_C) {
+      return absl::UnimplementedError(absl::StrCat(
+          "Pointers to member functions with a non-default calling convention "
+          "are not supported: ",
+          type_string));
+    }
+    CRUBIT_ASSIGN_OR_RETURN(
+        MappedType mapped_return_type,
+        ConvertQualType(func_type->getReturnType(), no_lifetimes));
+    std::vector<MappedType> mapped_param_types;
+    for (const clang::QualType& param_type : func_type->getParamTypes()) {
+      CRUBIT_ASSIGN_OR_RETURN(MappedType mapped_param_type,
+                              ConvertQualType(param_type, no_lifetimes));
+      mapped_param_types.push_back(std::move(mapped_param_type));
+    }
+    return MappedType::MemberFuncPtr(
+        std::move(mapped_class_type), func_type->getMethodQuals().hasConst(),
+        std::move(mapped_return_type), std::move(mapped_param_types));
+  } else if (const auto* array_type =
+                 llvm::dyn_cast<clang::ConstantArrayType>(type)) {
+    // Lifetimes of the elements are not tracked.
+    std::optional<clang::tidy::lifetimes::ValueLifetimes> no_lifetimes;
+    CRUBIT_ASSIGN_OR_RETURN(
+        MappedType mapped_element_type,
+        ConvertQualType(array_type->getElementType(), no_lifetimes));
+    return MappedType::ArrayOf(std::move(mapped_element_type),
+                               array_type->getSize().getZExtValue());
   } else if (type->isPointerType() || type->isLValueReferenceType() ||
              type->isRValueReferenceType()) {
     clang::QualType pointee_type = type->getPointeeType();
@@ -788,14 +955,19 @@ absl::StatusOr<MappedType> Importer::ConvertType(
       CRUBIT_ASSIGN_OR_RETURN(
           absl::string_view rs_abi,
           ConvertCcCallConvIntoRsAbi(func_type->getCallConv()));
+      // The lifetimes of the function's own parameters and return type are not
+      // tracked (e.g. a returned function pointer which itself returns a
+      // pointer is mapped to `extern "C" fn(...) -> *mut T`), because they
+      // would be late-bound lifetimes of the function pointer type.
+      std::optional<clang::tidy::lifetimes::ValueLifetimes> no_lifetimes;
       CRUBIT_ASSIGN_OR_RETURN(
           MappedType mapped_return_type,
-          ConvertQualType(func_type->getReturnType(), lifetimes));
+          ConvertQualType(func_type->getReturnType(), no_lifetimes));
 
       std::vector<MappedType> mapped_param_types;
       for (const clang::QualType& param_type : func_type->getParamTypes()) {
         CRUBIT_ASSIGN_OR_RETURN(MappedType mapped_param_type,
-                                ConvertQualType(param_type, lifetimes));
+                                ConvertQualType(param_type, no_lifetimes));
         mapped_param_types.push_back(std::move(mapped_param_type));
       }
 
@@ -814,6 +986,16 @@ absl::StatusOr<MappedType> Importer::ConvertType(
     CRUBIT_ASSIGN_OR_RETURN(MappedType mapped_pointee_type,
                             ConvertQualType(pointee_type, lifetimes));
     if (type->isPointerType()) {
+      // Explicit nullability annotations take precedence over the nullability
+      // assumed by the caller. Note that the nullability only affects pointers
+      // with lifetimes, which are mapped to `&T` or `Option<&T>` - pointers
+      // without lifetimes are always mapped to raw pointers.
+      std::optional<clang::NullabilityKind> nullability =
+          GetPointerNullability(type);
+      if (nullability.has_value() &&
+          *nullability != clang::NullabilityKind::Unspecified) {
+        nullable = *nullability != clang::NullabilityKind::NonNull;
+      }
       return MappedType::PointerTo(std::move(mapped_pointee_type), lifetime,
                                    nullable);
     } else if (type->isLValueReferenceType()) {
@@ -861,7 +1043,26 @@ absl::StatusOr<MappedType> Importer::ConvertType(
         }
     }
   } else if (const auto* tag_type = type->getAsAdjusted<clang::TagType>()) {
-    return ConvertTypeDecl(tag_type->getDecl());
+    CRUBIT_ASSIGN_OR_RETURN(MappedType mapped_type,
+                            ConvertTypeDecl(tag_type->getDecl()));
+    // Records with lifetime parameters (see `CRUBIT_LIFETIME_PARAMS`) are
+    // generic over their lifetimes in Rust, so their lifetime arguments must
+    // be known.
+    llvm::SmallVector<std::string> lifetime_params =
+        clang::tidy::lifetimes::GetLifetimeParameters(clang::QualType(type, 0));
+    if (!lifetime_params.empty()) {
+      if (!lifetimes.has_value()) {
+        return absl::UnimplementedError(absl::StrCat(
+            "Types with lifetime parameters are only supported where the "
+            "lifetimes are known: ",
+            type_string));
+      }
+      for (const std::string& param : lifetime_params) {
+        mapped_type.rs_type.lifetime_args.push_back(
+            LifetimeId(lifetimes->GetLifetimeParameter(param).Id()));
+      }
+    }
+    return mapped_type;
   } else if (const auto* typedef_type =
                  type->getAsAdjusted<clang::TypedefType>()) {
     return ConvertTypeDecl(typedef_type->getDecl());
@@ -985,6 +1186,13 @@ std::string Importer::GetMangledName(const clang::NamedDecl* named_decl) const {
   llvm::raw_string_ostream stream(name);
   mangler_->mangleName(decl, stream);
   stream.flush();
+  // The mangler prefixes the names given with an asm label (e.g.
+  // `void f() __asm__("g");`) with '\01', which tells LLVM not to apply the
+  // platform's global prefix. On targets without a global prefix (e.g. Linux),
+  // the symbol is the name itself.
+  if (!name.empty() && name[0] == '\01') {
+    name.erase(0, 1);
+  }
   return name;
 }
 
@@ -23,6 +23,8 @@
 #include "rs_bindings_from_cc/importers/function_template.h"
 #include "rs_bindings_from_cc/importers/namespace.h"
 #include "rs_bindings_from_cc/importers/typedef_name.h"
+#include "rs_bindings_from_cc/importers/using_shadow.h"
+#include "rs_bindings_from_cc/importers/var.h"
 #include "rs_bindings_from_cc/ir.h"
 #include "clang/AST/Mangle.h"
 #include "clang/AST/RawCommentList.h"
@@ -48,6 +50,8 @@ class Importer final : public ImportContext {
         std::make_unique<FunctionTemplateDeclImporter>(*this));
     decl_importers_.push_back(std::make_unique<NamespaceDeclImporter>(*this));
     decl_importers_.push_back(std::make_unique<TypedefNameDeclImporter>(*this));
+    decl_importers_.push_back(std::make_unique<UsingShadowDeclImporter>(*this));
+    decl_importers_.push_back(std::make_unique<VarDeclImporter>(*this));
   }
 
   // Import all visible declarations from a translation unit.
@@ -78,6 +82,8 @@ class Importer final : public ImportContext {
     return *identifier;
   }
   std::optional<std::string> GetComment(const clang::Decl* decl) const override;
+  absl::StatusOr<ItemAnnotations> GetItemAnnotations(
+      const clang::Decl* decl) const override;
   std::string ConvertSourceLocation(clang::SourceLocation loc) const override;
   absl::StatusOr<MappedType> ConvertQualType(
       clang::QualType qual_type,
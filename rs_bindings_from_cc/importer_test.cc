@@ -842,10 +842,23 @@ TEST(ImporterTest, RecordItemIds) {
   ASSERT_OK_AND_ASSIGN(IR ir, IrFromCc(file));
 
   std::vector<const Record*> records = ir.get_items_if<Record>();
-  EXPECT_THAT(records, SizeIs(1));
+  EXPECT_THAT(records, SizeIs(2));
+  std::optional<ItemId> record_id = DeclIdForRecord(ir, "TopLevelStruct");
+  ASSERT_TRUE(record_id.has_value());
+  const Record* record = nullptr;
+  const Record* nested_record = nullptr;
+  for (const Record* r : records) {
+    if (r->id == *record_id) {
+      record = r;
+    } else {
+      nested_record = r;
+    }
+  }
+  ASSERT_NE(nested_record, nullptr);
+  EXPECT_EQ(nested_record->enclosing_record_id, record_id);
 
   std::vector<IR::Item> items;
-  for (const auto& id : records[0]->child_item_ids) {
+  for (const auto& id : record->child_item_ids) {
     auto item = FindItemById(ir, id);
     ASSERT_TRUE(item.has_value());
     items.push_back(*item);
@@ -854,8 +867,7 @@ TEST(ImporterTest, RecordItemIds) {
   EXPECT_THAT(items,
               AllOf(Contains(VariantWith<Comment>(TextIs("A free comment"))),
                     Contains(VariantWith<Func>(IdentifierIs("bar"))),
-                    Contains(VariantWith<UnsupportedItem>(
-                        NameIs("TopLevelStruct::Nested"))),
+                    Contains(VariantWith<Record>(RsNameIs("Nested"))),
                     Contains(VariantWith<Func>(IdentifierIs("baz")))));
 }
 
@@ -11,10 +11,21 @@
 #include "absl/log/check.h"
 #include "absl/log/die_if_null.h"
 #include "absl/log/log.h"
+#include "absl/status/status.h"
+#include "absl/status/statusor.h"
+#include "absl/strings/string_view.h"
+#include "absl/strings/substitute.h"
+#include "lifetime_annotations/lifetime.h"
+#include "lifetime_annotations/pointee_type.h"
+#include "lifetime_annotations/type_lifetimes.h"
 #include "rs_bindings_from_cc/ast_convert.h"
+#include "rs_bindings_from_cc/ast_util.h"
 #include "clang/AST/ASTContext.h"
+#include "clang/AST/Attr.h"
 #include "clang/AST/CXXInheritance.h"
 #include "clang/AST/Decl.h"
+#include "clang/AST/DeclFriend.h"
+#include "clang/AST/DeclTemplate.h"
 #include "clang/AST/PrettyPrinter.h"
 #include "clang/AST/RecordLayout.h"
 #include "clang/AST/Type.h"
@@ -104,6 +115,192 @@ absl::StatusOr<RecordType> TranslateRecordType(
   llvm::report_fatal_error("Unrecognized clang::TagKind");
 }
 
+// Returns true if `func` is `std::istream& operator>>(std::istream&, T&)`,
+// where `T` is `record_type`.
+bool IsStreamExtractionOperator(const clang::ASTContext& ast_context,
+                                const clang::FunctionDecl* func,
+                                clang::QualType record_type) {
+  if (func == nullptr ||
+      func->getOverloadedOperator() != clang::OO_GreaterGreater ||
+      func->getNumParams() != 2 || func->isDeleted()) {
+    return false;
+  }
+  clang::QualType stream_type = func->getParamDecl(0)->getType();
+  clang::QualType value_type = func->getParamDecl(1)->getType();
+  if (!stream_type->isLValueReferenceType() ||
+      !value_type->isLValueReferenceType() ||
+      value_type.getNonReferenceType().isConstQualified() ||
+      !ast_context.hasSameUnqualifiedType(value_type.getNonReferenceType(),
+                                          record_type)) {
+    return false;
+  }
+  const auto* stream_decl =
+      clang::dyn_cast_or_null<clang::ClassTemplateSpecializationDecl>(
+          stream_type.getNonReferenceType()->getAsCXXRecordDecl());
+  return stream_decl != nullptr && stream_decl->isInStdNamespace() &&
+         stream_decl->getName() == "basic_istream" &&
+         stream_decl->getTemplateArgs()[0].getAsType()->isCharType();
+}
+
+// Returns true if `record_decl` can be parsed from a string: it has a public
+// default constructor, and a stream extraction operator is declared either in
+// its enclosing namespace or as its friend.
+bool IsStreamExtractable(clang::Sema& sema, clang::CXXRecordDecl* record_decl) {
+  const clang::CXXConstructorDecl* default_ctor =
+      sema.LookupDefaultConstructor(record_decl);
+  if (default_ctor == nullptr || default_ctor->isDeleted() ||
+      default_ctor->getAccess() != clang::AS_public) {
+    return false;
+  }
+  const clang::ASTContext& ast_context = sema.getASTContext();
+  clang::QualType record_type = ast_context.getRecordType(record_decl);
+  clang::DeclarationName name =
+      ast_context.DeclarationNames.getCXXOperatorName(clang::OO_GreaterGreater);
+  for (const clang::NamedDecl* decl :
+       record_decl->getDeclContext()->getRedeclContext()->lookup(name)) {
+    if (IsStreamExtractionOperator(
+            ast_context, clang::dyn_cast<clang::FunctionDecl>(decl),
+            record_type)) {
+      return true;
+    }
+  }
+  for (const clang::FriendDecl* friend_decl : record_decl->friends()) {
+    if (IsStreamExtractionOperator(
+            ast_context,
+            clang::dyn_cast_or_null<clang::FunctionDecl>(
+                friend_decl->getFriendDecl()),
+            record_type)) {
+      return true;
+    }
+  }
+  return false;
+}
+
+// Adds `offset` (in bits) to the offsets of `fields`, and of the members of the
+// anonymous structs and unions among them.
+void ShiftFieldOffsets(std::vector<Field>& fields, uint64_t offset) {
+  for (Field& field : fields) {
+    field.offset += offset;
+    ShiftFieldOffsets(field.anonymous_members, offset);
+  }
+}
+
+// Returns the number of lifetime arguments of a value of type `type`: one for
+// each level of pointers or references, and the lifetime arguments of records
+// with lifetime parameters. Returns `std::nullopt` if the lifetimes of values
+// of `type` can't be given by `CRUBIT_MEMBER_LIFETIMES`.
+std::optional<size_t> GetNumLifetimeArgs(clang::QualType type) {
+  if (type->isFunctionType() ||
+      type->getAs<clang::SubstTemplateTypeParmType>() != nullptr) {
+    return std::nullopt;
+  }
+  if (clang::QualType pointee_type = clang::tidy::lifetimes::PointeeType(type);
+      !pointee_type.isNull()) {
+    std::optional<size_t> num_pointee_lifetime_args =
+        GetNumLifetimeArgs(pointee_type);
+    if (!num_pointee_lifetime_args.has_value()) return std::nullopt;
+    return *num_pointee_lifetime_args + 1;
+  }
+  if (type->isStructureOrClassType()) {
+    if (!clang::tidy::lifetimes::GetTemplateArgs(type).empty()) {
+      return std::nullopt;
+    }
+    return clang::tidy::lifetimes::GetLifetimeParameters(type).size();
+  }
+  return 0;
+}
+
+// Returns the lifetimes of the value of `field_decl`, a field of a record with
+// lifetime parameters `lifetime_params`, an object of which has lifetimes
+// `record_lifetimes`. The lifetime arguments of the field are given by its
+// `CRUBIT_MEMBER_LIFETIMES` annotation, and fields without lifetime arguments
+// have no lifetimes.
+absl::StatusOr<std::optional<clang::tidy::lifetimes::ValueLifetimes>>
+GetFieldLifetimes(
+    const clang::FieldDecl& field_decl,
+    const clang::tidy::lifetimes::LifetimeSymbolTable& lifetime_params,
+    const clang::tidy::lifetimes::ObjectLifetimes& record_lifetimes) {
+  std::optional<size_t> num_lifetime_args =
+      GetNumLifetimeArgs(field_decl.getType());
+  if (!num_lifetime_args.has_value()) {
+    return absl::UnimplementedError(
+        "Lifetimes of fields of this type are not supported");
+  }
+  std::vector<std::string> lifetime_args =
+      GetAnnotationArgs(field_decl, "member_lifetimes");
+  if (lifetime_args.size() != *num_lifetime_args) {
+    return absl::InvalidArgumentError(absl::Substitute(
+        "Expected $0 lifetime arguments in `CRUBIT_MEMBER_LIFETIMES`, found $1",
+        *num_lifetime_args, lifetime_args.size()));
+  }
+  if (lifetime_args.empty()) return std::nullopt;
+  for (const std::string& lifetime_arg : lifetime_args) {
+    if (!lifetime_params.LookupName(lifetime_arg).has_value()) {
+      return absl::InvalidArgumentError(absl::Substitute(
+          "Unknown lifetime '$0 in `CRUBIT_MEMBER_LIFETIMES`", lifetime_arg));
+    }
+  }
+  return record_lifetimes
+      .GetFieldOrBaseLifetimes(
+          field_decl.getType(),
+          llvm::SmallVector<std::string>(lifetime_args.begin(),
+                                         lifetime_args.end()))
+      .GetValueLifetimes();
+}
+
+// Returns whether the layout of the fields of `record_decl` can't be reproduced
+// by a `#[repr(C)]` Rust struct (see `Record::needs_opaque_layout`). Fields
+// which are laid out as opaque blobs of bytes anyway (`[[no_unique_address]]`
+// fields) only constrain the fields after them by their offset.
+bool NeedsOpaqueLayout(clang::ASTContext& ctx,
+                       const clang::CXXRecordDecl& record_decl) {
+  if (record_decl.isUnion()) return false;
+  const clang::ASTRecordLayout& layout = ctx.getASTRecordLayout(&record_decl);
+  uint64_t prev_end = 0;
+  for (const clang::FieldDecl* field_decl : record_decl.fields()) {
+    uint64_t offset = layout.getFieldOffset(field_decl->getFieldIndex());
+    if (offset < prev_end) return true;
+    clang::QualType type = field_decl->getType();
+    if (field_decl->isBitField()) {
+      prev_end = offset + field_decl->getBitWidthValue(ctx);
+    } else if (type->isIncompleteType() ||
+               field_decl->hasAttr<clang::NoUniqueAddressAttr>()) {
+      prev_end = offset;
+    } else {
+      if (offset % ctx.getTypeAlign(type) != 0) return true;
+      prev_end = offset + ctx.getTypeSize(type);
+    }
+  }
+  return false;
+}
+
+// Declares the constructors inherited by `record_decl` through
+// `using Base::Base;`. Clang only declares them lazily, on first use, so
+// without this they wouldn't be imported as constructors of `record_decl`.
+void DeclareInheritedConstructors(clang::Sema& sema,
+                                  clang::CXXRecordDecl* record_decl) {
+  // `findInheritingConstructor` adds the new constructors to `record_decl`, so
+  // the shadow decls are collected before declaring any of them.
+  std::vector<clang::ConstructorUsingShadowDecl*> shadow_decls;
+  for (clang::Decl* decl : record_decl->decls()) {
+    if (auto* shadow_decl =
+            clang::dyn_cast<clang::ConstructorUsingShadowDecl>(decl)) {
+      shadow_decls.push_back(shadow_decl);
+    }
+  }
+  for (clang::ConstructorUsingShadowDecl* shadow_decl : shadow_decls) {
+    auto* base_ctor = clang::dyn_cast<clang::CXXConstructorDecl>(
+        shadow_decl->getTargetDecl());
+    // Copy and move constructors are never inherited.
+    if (!base_ctor || base_ctor->isDeleted() ||
+        base_ctor->isCopyOrMoveConstructor()) {
+      continue;
+    }
+    sema.findInheritingConstructor(record_decl->getLocation(), base_ctor,
+                                   shadow_decl);
+  }
+}
+
 }  // namespace
 
 std::optional<Identifier> CXXRecordDeclImporter::GetTranslatedFieldName(
@@ -148,9 +345,10 @@ std::optional<IR::Item> CXXRecordDeclImporter::Import(
   if (record_decl->isImplicit()) {
     return std::nullopt;
   }
-  if (decl_context->isRecord()) {
-    return ictx_.ImportUnsupportedItem(record_decl,
-                                       "Nested classes are not supported yet");
+  // The C++ bindings of a Rust type (generated by `cc_bindings_from_rs`) are
+  // mapped back to the Rust type (see `Importer::ConvertTypeDecl`).
+  if (HasAnnotation(*record_decl, "crubit_internal_rust_type")) {
+    return std::nullopt;
   }
   if (clang::isa<clang::ClassTemplatePartialSpecializationDecl>(record_decl)) {
     return ictx_.ImportUnsupportedItem(
@@ -165,6 +363,21 @@ std::optional<IR::Item> CXXRecordDeclImporter::Import(
     return ictx_.ImportUnsupportedItem(record_decl,
                                        "Dependent records are not supported");
   }
+  std::optional<ItemId> enclosing_record_id;
+  if (auto* parent =
+          clang::dyn_cast<clang::CXXRecordDecl>(record_decl->getDeclContext())) {
+    if (clang::isa<clang::ClassTemplateSpecializationDecl>(parent)) {
+      return ictx_.ImportUnsupportedItem(
+          record_decl,
+          "Classes nested in class template instantiations are not supported "
+          "yet");
+    }
+    if (!ictx_.EnsureSuccessfullyImported(parent)) {
+      return ictx_.ImportUnsupportedItem(record_decl,
+                                         "Couldn't import the parent");
+    }
+    enclosing_record_id = GenerateItemId(parent);
+  }
   if (record_decl->isInvalidDecl()) {
     return std::nullopt;
   }
@@ -199,10 +412,12 @@ std::optional<IR::Item> CXXRecordDeclImporter::Import(
   std::string rs_name, cc_name, preferred_cc_name;
   clang::SourceLocation source_loc;
   std::optional<std::string> doc_comment;
+  bool is_template_instantiation = false;
   bool is_explicit_class_template_instantiation_definition = false;
   if (auto* specialization_decl =
           clang::dyn_cast<clang::ClassTemplateSpecializationDecl>(
               record_decl)) {
+    is_template_instantiation = true;
     is_explicit_class_template_instantiation_definition =
         specialization_decl->getSpecializationKind() ==
         clang::TSK_ExplicitInstantiationDefinition;
@@ -256,7 +471,19 @@ std::optional<IR::Item> CXXRecordDeclImporter::Import(
         .id = GenerateItemId(record_decl),
         .owning_target = ictx_.GetOwningTarget(record_decl),
         .record_type = *record_type,
-        .enclosing_namespace_id = GetEnclosingNamespaceId(record_decl)};
+        .is_template_instantiation = is_template_instantiation,
+        .enclosing_namespace_id = GetEnclosingNamespaceId(record_decl),
+        .enclosing_record_id = enclosing_record_id};
+  }
+
+  absl::StatusOr<ItemAnnotations> annotations =
+      ictx_.GetItemAnnotations(record_decl);
+  if (!annotations.ok()) {
+    return ictx_.ImportUnsupportedItem(
+        record_decl, std::string(annotations.status().message()));
+  }
+  if (annotations->rust_name.has_value()) {
+    rs_name = *annotations->rust_name;
   }
 
   // At this point we know that the import of `record_decl` will succeed /
@@ -264,6 +491,7 @@ std::optional<IR::Item> CXXRecordDeclImporter::Import(
   ictx_.MarkAsSuccessfullyImported(record_decl);
 
   ictx_.sema_.ForceDeclarationOfImplicitMembers(record_decl);
+  DeclareInheritedConstructors(ictx_.sema_, record_decl);
 
   const clang::ASTRecordLayout& layout =
       ictx_.ctx_.getASTRecordLayout(record_decl);
@@ -272,13 +500,24 @@ std::optional<IR::Item> CXXRecordDeclImporter::Import(
   bool override_alignment = record_decl->hasAttr<clang::AlignedAttr>() ||
                             is_derived_class || layout.hasOwnVFPtr();
 
-  std::vector<Field> fields = ImportFields(record_decl);
+  std::vector<LifetimeName> lifetime_params;
+  clang::tidy::lifetimes::LifetimeSymbolTable lifetime_symbol_table;
+  for (const std::string& name : clang::tidy::lifetimes::GetLifetimeParameters(
+           ictx_.ctx_.getRecordType(record_decl))) {
+    clang::tidy::lifetimes::Lifetime lifetime =
+        lifetime_symbol_table.LookupNameAndMaybeDeclare(name);
+    lifetime_params.push_back({.name = name, .id = LifetimeId(lifetime.Id())});
+  }
+
+  std::vector<Field> fields = ImportFields(record_decl, lifetime_symbol_table);
   for (const Field& field : fields) {
     if (field.is_no_unique_address || !field.type.ok()) {
       override_alignment = true;
       break;
     }
   }
+  bool needs_opaque_layout = NeedsOpaqueLayout(ictx_.ctx_, *record_decl);
+  override_alignment |= needs_opaque_layout;
 
   bool is_effectively_final = record_decl->isEffectivelyFinal() ||
                               record_decl->isUnion() ||
@@ -293,14 +532,17 @@ std::optional<IR::Item> CXXRecordDeclImporter::Import(
       .id = GenerateItemId(record_decl),
       .owning_target = ictx_.GetOwningTarget(record_decl),
       .doc_comment = std::move(doc_comment),
+      .deprecated = GetDeprecationMessage(*record_decl),
       .source_loc = ictx_.ConvertSourceLocation(source_loc),
       .unambiguous_public_bases = GetUnambiguousPublicBases(*record_decl),
       .fields = std::move(fields),
+      .lifetime_params = std::move(lifetime_params),
       .size = layout.getSize().getQuantity(),
       .original_cc_size = layout.getSize().getQuantity(),
       .alignment = layout.getAlignment().getQuantity(),
       .is_derived_class = is_derived_class,
       .override_alignment = override_alignment,
+      .needs_opaque_layout = needs_opaque_layout,
       .copy_constructor = GetCopyCtorSpecialMemberFunc(*record_decl),
       .move_constructor = GetMoveCtorSpecialMemberFunc(*record_decl),
       .destructor = GetDestructorSpecialMemberFunc(*record_decl),
@@ -310,10 +552,21 @@ std::optional<IR::Item> CXXRecordDeclImporter::Import(
       .record_type = *record_type,
       .is_aggregate = record_decl->isAggregate(),
       .is_anon_record_with_typedef = anon_typedef != nullptr,
+      .is_template_instantiation = is_template_instantiation,
       .is_explicit_class_template_instantiation_definition =
           is_explicit_class_template_instantiation_definition,
+      .has_throwing_assignment =
+          HasAnnotation(*record_decl, "crubit_throwing_assignment"),
+      .is_bytes_serializable =
+          HasAnnotation(*record_decl, "crubit_bytes_serializable"),
+      .is_stream_extractable = IsStreamExtractable(ictx_.sema_, record_decl),
+      .is_log_sink = HasAnnotation(*record_decl, "crubit_log_sink"),
+      .is_extern_c = record_decl->isCLike() &&
+                     record_decl->getDeclContext()->isExternCContext(),
       .child_item_ids = std::move(item_ids),
       .enclosing_namespace_id = GetEnclosingNamespaceId(record_decl),
+      .enclosing_record_id = enclosing_record_id,
+      .annotations = *std::move(annotations),
   };
 
   // If the align attribute was attached to the typedef decl, we should
@@ -351,23 +604,47 @@ std::optional<IR::Item> CXXRecordDeclImporter::Import(
 }
 
 std::vector<Field> CXXRecordDeclImporter::ImportFields(
-    clang::CXXRecordDecl* record_decl) {
+    clang::CXXRecordDecl* record_decl,
+    const clang::tidy::lifetimes::LifetimeSymbolTable& lifetime_params) {
   clang::AccessSpecifier default_access =
       record_decl->isClass() ? clang::AS_private : clang::AS_public;
   std::vector<Field> fields;
   const clang::ASTRecordLayout& layout =
       ictx_.ctx_.getASTRecordLayout(record_decl);
+  // The lifetimes of an object of a record with lifetime parameters, which the
+  // lifetimes of its fields are derived from.
+  std::optional<clang::tidy::lifetimes::ObjectLifetimes> record_lifetimes;
+  if (!lifetime_params.GetMapping().empty()) {
+    record_lifetimes = clang::tidy::lifetimes::ObjectLifetimes(
+        clang::tidy::lifetimes::Lifetime::CreateVariable(),
+        clang::tidy::lifetimes::ValueLifetimes::ForRecord(
+            ictx_.ctx_.getRecordType(record_decl),
+            /*template_argument_lifetimes=*/{}, lifetime_params));
+  }
   for (const clang::FieldDecl* field_decl : record_decl->fields()) {
     clang::AccessSpecifier access = field_decl->getAccess();
     if (access == clang::AS_none) {
       access = default_access;
     }
 
-    std::optional<clang::tidy::lifetimes::ValueLifetimes> no_lifetimes;
+    absl::StatusOr<std::optional<clang::tidy::lifetimes::ValueLifetimes>>
+        lifetimes = std::optional<clang::tidy::lifetimes::ValueLifetimes>();
+    if (record_lifetimes.has_value()) {
+      lifetimes =
+          GetFieldLifetimes(*field_decl, lifetime_params, *record_lifetimes);
+    }
     absl::StatusOr<MappedType> type;
     switch (access) {
       case clang::AS_public:
-        type = ictx_.ConvertQualType(field_decl->getType(), no_lifetimes);
+        if (!lifetimes.ok()) {
+          type = lifetimes.status();
+          break;
+        }
+        type = ictx_.ConvertQualType(field_decl->getType(), *lifetimes);
+        if (type.ok() && type->IsCallable()) {
+          type = absl::UnimplementedError(
+              "Fields of type `std::function` are not supported");
+        }
         break;
       case clang::AS_protected:
       case clang::AS_private:
@@ -395,19 +672,35 @@ std::vector<Field> CXXRecordDeclImporter::ImportFields(
       }
     }
 
+    // The members of anonymous structs and unions are imported as well, so
+    // that they can be accessed from Rust.
+    uint64_t offset = layout.getFieldOffset(field_decl->getFieldIndex());
+    std::vector<Field> anonymous_members;
+    if (field_decl->isAnonymousStructOrUnion()) {
+      // TODO(mboehme): Support lifetimes of anonymous members, which are
+      // derived from the lifetimes of the enclosing record.
+      anonymous_members = ImportFields(
+          field_record, clang::tidy::lifetimes::LifetimeSymbolTable());
+      ShiftFieldOffsets(anonymous_members, offset);
+    }
+
     fields.push_back(
         {.identifier = GetTranslatedFieldName(field_decl),
          .doc_comment = ictx_.GetComment(field_decl),
+         .deprecated = GetDeprecationMessage(*field_decl),
          .type = std::move(type),
          .access = TranslateAccessSpecifier(access),
-         .offset = layout.getFieldOffset(field_decl->getFieldIndex()),
+         .offset = offset,
          .size = field_decl->isBitField()
                      ? field_decl->getBitWidthValue(ictx_.ctx_)
                      : ictx_.ctx_.getTypeSize(field_decl->getType()),
          .is_no_unique_address =
              field_decl->hasAttr<clang::NoUniqueAddressAttr>(),
          .is_bitfield = field_decl->isBitField(),
-         .is_inheritable = is_inheritable});
+         .is_inheritable = is_inheritable,
+         .anonymous_members = std::move(anonymous_members),
+         .is_anonymous_union = field_decl->isAnonymousStructOrUnion() &&
+                               field_record->isUnion()});
   }
   return fields;
 }
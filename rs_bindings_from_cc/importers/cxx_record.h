@@ -5,6 +5,7 @@
 #ifndef CRUBIT_RS_BINDINGS_FROM_CC_IMPORTERS_CXX_RECORD_H_
 #define CRUBIT_RS_BINDINGS_FROM_CC_IMPORTERS_CXX_RECORD_H_
 
+#include "lifetime_annotations/lifetime_symbol_table.h"
 #include "rs_bindings_from_cc/decl_importer.h"
 namespace crubit {
 
@@ -15,7 +16,11 @@ class CXXRecordDeclImporter : public DeclImporterBase<clang::CXXRecordDecl> {
   std::optional<IR::Item> Import(clang::CXXRecordDecl*);
 
  private:
-  std::vector<Field> ImportFields(clang::CXXRecordDecl*);
+  // `lifetime_params` are the lifetime parameters of the record (see
+  // `CRUBIT_LIFETIME_PARAMS`), which the lifetimes of its fields refer to.
+  std::vector<Field> ImportFields(
+      clang::CXXRecordDecl*,
+      const clang::tidy::lifetimes::LifetimeSymbolTable& lifetime_params);
   std::vector<BaseClass> GetUnambiguousPublicBases(
       const clang::CXXRecordDecl& record_decl) const;
   std::optional<Identifier> GetTranslatedFieldName(
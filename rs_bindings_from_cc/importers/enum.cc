@@ -4,6 +4,8 @@
 
 #include "rs_bindings_from_cc/importers/enum.h"
 
+#include "rs_bindings_from_cc/ast_util.h"
+
 namespace crubit {
 
 std::optional<IR::Item> EnumDeclImporter::Import(clang::EnumDecl* enum_decl) {
@@ -42,6 +44,7 @@ std::optional<IR::Item> EnumDeclImporter::Import(clang::EnumDecl* enum_decl) {
   }
 
   std::vector<Enumerator> enumerators;
+  bool has_zero_enumerator = false;
   enumerators.reserve(std::distance(enum_decl->enumerators().begin(),
                                     enum_decl->enumerators().end()));
   for (clang::EnumConstantDecl* enumerator : enum_decl->enumerators()) {
@@ -58,6 +61,22 @@ std::optional<IR::Item> EnumDeclImporter::Import(clang::EnumDecl* enum_decl) {
         .identifier = *enumerator_name,
         .value = IntegerConstant(enumerator->getInitVal()),
     });
+    if (enumerator->getInitVal() == 0) has_zero_enumerator = true;
+  }
+
+  bool is_error_code = HasAnnotation(*enum_decl, "crubit_error_code");
+  if (is_error_code && !has_zero_enumerator) {
+    return ictx_.ImportUnsupportedItem(
+        enum_decl,
+        "Error code enums must have an enumerator with the value 0, which "
+        "denotes success");
+  }
+
+  absl::StatusOr<ItemAnnotations> annotations =
+      ictx_.GetItemAnnotations(enum_decl);
+  if (!annotations.ok()) {
+    return ictx_.ImportUnsupportedItem(
+        enum_decl, std::string(annotations.status().message()));
   }
 
   return Enum{
@@ -65,9 +84,12 @@ std::optional<IR::Item> EnumDeclImporter::Import(clang::EnumDecl* enum_decl) {
       .id = GenerateItemId(enum_decl),
       .owning_target = ictx_.GetOwningTarget(enum_decl),
       .source_loc = ictx_.ConvertSourceLocation(enum_decl->getBeginLoc()),
+      .deprecated = GetDeprecationMessage(*enum_decl),
       .underlying_type = *std::move(type),
       .enumerators = enumerators,
+      .is_error_code = is_error_code,
       .enclosing_namespace_id = GetEnclosingNamespaceId(enum_decl),
+      .annotations = *std::move(annotations),
   };
 }
 
@@ -6,13 +6,40 @@
 
 #include <optional>
 
+#include "absl/status/status.h"
+#include "absl/strings/numbers.h"
+#include "absl/strings/str_cat.h"
 #include "absl/strings/substitute.h"
+#include "lifetime_annotations/lifetime.h"
+#include "lifetime_annotations/type_lifetimes.h"
 #include "rs_bindings_from_cc/ast_util.h"
+#include "clang/AST/Expr.h"
+#include "clang/AST/ExprCXX.h"
+#include "clang/AST/Stmt.h"
+#include "clang/Basic/Visibility.h"
 #include "clang/Sema/Sema.h"
+#include "llvm/ADT/APSInt.h"
+#include "llvm/ADT/STLExtras.h"
 #include "llvm/ADT/StringRef.h"
+#include "llvm/Support/Error.h"
+#include "llvm/Support/MathExtras.h"
 
 namespace crubit {
 
+// Returns the type of `this` in `method_decl`, when called on an object of type
+// `record_decl` (a class derived from the class of `method_decl`, for a member
+// function made a member of the derived class by a using-declaration).
+static clang::QualType GetThisType(const clang::CXXMethodDecl* method_decl,
+                                   const clang::CXXRecordDecl* record_decl) {
+  if (record_decl == method_decl->getParent()) {
+    return method_decl->getThisType();
+  }
+  clang::ASTContext& ast_context = method_decl->getASTContext();
+  return ast_context.getPointerType(ast_context.getQualifiedType(
+      ast_context.getRecordType(record_decl),
+      method_decl->getMethodQualifiers()));
+}
+
 static bool IsInStdNamespace(const clang::FunctionDecl* decl) {
   const clang::DeclContext* context = decl->getDeclContext();
   while (context) {
@@ -40,9 +67,259 @@ Identifier FunctionDeclImporter::GetTranslatedParamName(
   return *name;
 }
 
+std::optional<TrivialBody> FunctionDeclImporter::GetTrivialBody(
+    const clang::FunctionDecl* function_decl) {
+  if (!function_decl->isInlined()) return std::nullopt;
+  const auto* body =
+      llvm::dyn_cast_or_null<clang::CompoundStmt>(function_decl->getBody());
+  if (body == nullptr || body->size() != 1) return std::nullopt;
+  const clang::Stmt* stmt = body->body_front();
+
+  // Returns the field of `*this` that `expr` refers to, if any, and if the
+  // type of the field is `type` (ignoring references and qualifiers).
+  const auto* method_decl =
+      clang::dyn_cast<clang::CXXMethodDecl>(function_decl);
+  auto get_this_field = [&](const clang::Expr* expr,
+                            clang::QualType type) -> std::optional<Identifier> {
+    if (method_decl == nullptr || !method_decl->isInstance()) {
+      return std::nullopt;
+    }
+    const auto* member_expr =
+        clang::dyn_cast<clang::MemberExpr>(expr->IgnoreParenImpCasts());
+    if (member_expr == nullptr ||
+        !clang::isa<clang::CXXThisExpr>(
+            member_expr->getBase()->IgnoreParenImpCasts())) {
+      return std::nullopt;
+    }
+    const auto* field_decl =
+        clang::dyn_cast<clang::FieldDecl>(member_expr->getMemberDecl());
+    if (field_decl == nullptr || field_decl->isBitField() ||
+        field_decl->getParent() != method_decl->getParent() ||
+        !ictx_.ctx_.hasSameUnqualifiedType(field_decl->getType(),
+                                           type.getNonReferenceType())) {
+      return std::nullopt;
+    }
+    absl::StatusOr<Identifier> field_name =
+        ictx_.GetTranslatedIdentifier(field_decl);
+    if (!field_name.ok()) return std::nullopt;
+    return *field_name;
+  };
+
+  // Returns the function called by `expr`, if it is a call which passes all
+  // the parameters of `function_decl` through unchanged, in order.
+  auto get_forwarding_callee =
+      [&](const clang::Expr* expr) -> const clang::FunctionDecl* {
+    const auto* call_expr =
+        clang::dyn_cast<clang::CallExpr>(expr->IgnoreParenImpCasts());
+    if (call_expr == nullptr ||
+        clang::isa<clang::CXXMemberCallExpr, clang::CXXOperatorCallExpr>(
+            call_expr) ||
+        call_expr->getNumArgs() != function_decl->getNumParams()) {
+      return nullptr;
+    }
+    for (unsigned i = 0; i < call_expr->getNumArgs(); ++i) {
+      const auto* decl_ref = clang::dyn_cast<clang::DeclRefExpr>(
+          call_expr->getArg(i)->IgnoreParenImpCasts());
+      if (decl_ref == nullptr ||
+          decl_ref->getDecl() != function_decl->getParamDecl(i)) {
+        return nullptr;
+      }
+    }
+    return call_expr->getDirectCallee();
+  };
+
+  if (const auto* return_stmt = clang::dyn_cast<clang::ReturnStmt>(stmt)) {
+    const clang::Expr* value = return_stmt->getRetValue();
+    if (value == nullptr) return std::nullopt;
+    if (std::optional<Identifier> field =
+            get_this_field(value, function_decl->getReturnType())) {
+      return TrivialBody{.kind = TrivialBody::kReturnsField,
+                         .field = std::move(field)};
+    }
+    if (const auto* builtin_type =
+            function_decl->getReturnType()->getAs<clang::BuiltinType>();
+        builtin_type != nullptr && builtin_type->isInteger() &&
+        !value->HasSideEffects(ictx_.ctx_)) {
+      if (std::optional<llvm::APSInt> constant =
+              value->getIntegerConstantExpr(ictx_.ctx_)) {
+        std::string constant_str;
+        if (builtin_type->getKind() == clang::BuiltinType::Bool) {
+          constant_str = constant->getBoolValue() ? "true" : "false";
+        } else {
+          constant_str = llvm::toString(*constant, 10);
+        }
+        return TrivialBody{.kind = TrivialBody::kReturnsConstant,
+                           .constant = std::move(constant_str)};
+      }
+    }
+    if (const clang::FunctionDecl* callee = get_forwarding_callee(value)) {
+      return TrivialBody{.kind = TrivialBody::kForwardsCall,
+                         .callee = GenerateItemId(callee)};
+    }
+    return std::nullopt;
+  }
+
+  const auto* expr = clang::dyn_cast<clang::Expr>(stmt);
+  if (expr == nullptr) return std::nullopt;
+  if (const auto* assignment = clang::dyn_cast<clang::BinaryOperator>(
+          expr->IgnoreParenImpCasts());
+      assignment != nullptr && assignment->getOpcode() == clang::BO_Assign &&
+      function_decl->getNumParams() == 1) {
+    const auto* decl_ref = clang::dyn_cast<clang::DeclRefExpr>(
+        assignment->getRHS()->IgnoreParenImpCasts());
+    if (decl_ref == nullptr ||
+        decl_ref->getDecl() != function_decl->getParamDecl(0)) {
+      return std::nullopt;
+    }
+    if (std::optional<Identifier> field =
+            get_this_field(assignment->getLHS(),
+                           function_decl->getParamDecl(0)->getType())) {
+      return TrivialBody{.kind = TrivialBody::kSetsField,
+                         .field = std::move(field)};
+    }
+    return std::nullopt;
+  }
+  if (function_decl->getReturnType()->isVoidType()) {
+    if (const clang::FunctionDecl* callee = get_forwarding_callee(expr)) {
+      return TrivialBody{.kind = TrivialBody::kForwardsCall,
+                         .callee = GenerateItemId(callee)};
+    }
+  }
+  return std::nullopt;
+}
+
+bool FunctionDeclImporter::ReturnsStaticLocal(
+    const clang::FunctionDecl* function_decl) {
+  clang::QualType return_type = function_decl->getReturnType();
+  if (!return_type->isLValueReferenceType() && !return_type->isPointerType()) {
+    return false;
+  }
+  const auto* body =
+      llvm::dyn_cast_or_null<clang::CompoundStmt>(function_decl->getBody());
+  if (body == nullptr || body->body_empty()) return false;
+  const auto* return_stmt =
+      clang::dyn_cast<clang::ReturnStmt>(body->body_back());
+  if (return_stmt == nullptr || return_stmt->getRetValue() == nullptr) {
+    return false;
+  }
+  const clang::Expr* value = return_stmt->getRetValue()->IgnoreParenImpCasts();
+  if (return_type->isPointerType()) {
+    const auto* address_of = clang::dyn_cast<clang::UnaryOperator>(value);
+    if (address_of == nullptr || address_of->getOpcode() != clang::UO_AddrOf) {
+      return false;
+    }
+    value = address_of->getSubExpr()->IgnoreParenImpCasts();
+  }
+  const auto* decl_ref = clang::dyn_cast<clang::DeclRefExpr>(value);
+  if (decl_ref == nullptr) return false;
+  const auto* var_decl = clang::dyn_cast<clang::VarDecl>(decl_ref->getDecl());
+  return var_decl != nullptr && var_decl->isStaticLocal() &&
+         !var_decl->getType()->isReferenceType();
+}
+
+absl::Status FunctionDeclImporter::ImportParamContracts(
+    const clang::FunctionDecl* function_decl, const clang::ParmVarDecl* param,
+    FuncParam& func_param) {
+  std::optional<std::string> sized_by =
+      GetAnnotationArg(*param, "crubit_sized_by");
+  std::optional<std::string> alignment =
+      GetAnnotationArg(*param, "crubit_aligned_to");
+  if (!param->getType()->isPointerType()) {
+    if (sized_by.has_value() || alignment.has_value()) {
+      return absl::InvalidArgumentError(
+          "CRUBIT_SIZED_BY and CRUBIT_ALIGNED_TO only apply to pointers");
+    }
+    return absl::OkStatus();
+  }
+
+  func_param.is_nonnull =
+      GetPointerNullability(param->getType().getTypePtr()) ==
+      clang::NullabilityKind::NonNull;
+  if (sized_by.has_value()) {
+    const clang::ParmVarDecl* size_param = nullptr;
+    for (const clang::ParmVarDecl* other_param : function_decl->parameters()) {
+      if (other_param->getName() == *sized_by) size_param = other_param;
+    }
+    if (size_param == nullptr || !size_param->getType()->isBuiltinType() ||
+        !size_param->getType()->isIntegerType() ||
+        size_param->getType()->isBooleanType()) {
+      return absl::InvalidArgumentError(absl::StrCat(
+          "CRUBIT_SIZED_BY(", *sized_by, ") must name an integer parameter"));
+    }
+    func_param.sized_by = GetTranslatedParamName(size_param);
+  }
+  if (alignment.has_value()) {
+    uint64_t value;
+    if (!absl::SimpleAtoi(*alignment, &value) || !llvm::isPowerOf2_64(value)) {
+      return absl::InvalidArgumentError(absl::StrCat(
+          "CRUBIT_ALIGNED_TO(", *alignment, ") must be a power of two"));
+    }
+    func_param.alignment = value;
+  }
+  return absl::OkStatus();
+}
+
+absl::Status FunctionDeclImporter::ImportCallbackContext(
+    const clang::FunctionDecl* function_decl, const clang::ParmVarDecl* param,
+    FuncParam& func_param) {
+  std::optional<std::string> context =
+      GetAnnotationArg(*param, "crubit_callback");
+  std::optional<std::string> retained_context =
+      GetAnnotationArg(*param, "crubit_retained_callback");
+  if (!context.has_value() && !retained_context.has_value()) {
+    return absl::OkStatus();
+  }
+  if (context.has_value() && retained_context.has_value()) {
+    return absl::InvalidArgumentError(
+        "CRUBIT_CALLBACK and CRUBIT_RETAINED_CALLBACK are mutually exclusive");
+  }
+  func_param.is_callback_retained = retained_context.has_value();
+  if (!context.has_value()) context = std::move(retained_context);
+
+  const clang::FunctionProtoType* callback_type = nullptr;
+  if (param->getType()->isFunctionPointerType()) {
+    callback_type = param->getType()
+                        ->getPointeeType()
+                        ->getAs<clang::FunctionProtoType>();
+  }
+  if (callback_type == nullptr) {
+    return absl::InvalidArgumentError(
+        "CRUBIT_CALLBACK only applies to function pointers");
+  }
+  if (llvm::count_if(callback_type->param_types(), [](clang::QualType type) {
+        return type->isVoidPointerType() &&
+               !type->getPointeeType().isConstQualified();
+      }) != 1) {
+    return absl::InvalidArgumentError(
+        "The callback must take exactly one `void*` context parameter");
+  }
+  const clang::ParmVarDecl* context_param = nullptr;
+  for (const clang::ParmVarDecl* other_param : function_decl->parameters()) {
+    if (other_param->getName() == *context) context_param = other_param;
+  }
+  if (context_param == nullptr ||
+      !context_param->getType()->isVoidPointerType() ||
+      context_param->getType()->getPointeeType().isConstQualified()) {
+    return absl::InvalidArgumentError(absl::StrCat(
+        "CRUBIT_CALLBACK(", *context, ") must name a `void*` parameter"));
+  }
+  func_param.callback_context = GetTranslatedParamName(context_param);
+  return absl::OkStatus();
+}
+
 std::optional<IR::Item> FunctionDeclImporter::Import(
     clang::FunctionDecl* function_decl) {
-  if (!ictx_.IsFromCurrentTarget(function_decl)) return std::nullopt;
+  return ImportFunction(function_decl, /*using_shadow_decl=*/nullptr);
+}
+
+std::optional<IR::Item> FunctionDeclImporter::ImportFunction(
+    clang::FunctionDecl* function_decl,
+    const clang::UsingShadowDecl* using_shadow_decl) {
+  // The decl which declares the function in its scope: the function itself,
+  // or the using-declaration which makes it a member of a derived class.
+  const clang::NamedDecl* declaring_decl = function_decl;
+  if (using_shadow_decl != nullptr) declaring_decl = using_shadow_decl;
+  if (!ictx_.IsFromCurrentTarget(declaring_decl)) return std::nullopt;
   if (function_decl->isDeleted()) return std::nullopt;
 
   if (IsInStdNamespace(function_decl)) {
@@ -68,18 +345,24 @@ std::optional<IR::Item> FunctionDeclImporter::Import(
           "cannot be instantiated in user crates");
     }
   }
-  // Method is private, we don't need to import it.
+  // Method is private, we don't need to import it (unless the current target
+  // is a friend crate, see `GeneratorOptions::friend_crate`).
   if (auto* method_decl =
           clang::dyn_cast<clang::CXXMethodDecl>(function_decl)) {
-    switch (method_decl->getAccess()) {
+    switch (declaring_decl->getAccess()) {
       case clang::AS_public:
         break;
       case clang::AS_protected:
       case clang::AS_private:
       case clang::AS_none:
-        // No need for IR to include Func representing private methods.
-        // TODO(lukasza): Revisit this for protected methods.
-        return std::nullopt;
+        // Constructors, destructors and operators of friend crates are still
+        // skipped, because they are bound as trait implementations, which
+        // can't be restricted to the crate.
+        if (!ictx_.invocation_.friend_crate_ ||
+            !method_decl->getDeclName().isIdentifier()) {
+          return std::nullopt;
+        }
+        break;
     }
   }
 
@@ -107,9 +390,17 @@ std::optional<IR::Item> FunctionDeclImporter::Import(
     auto result = errors.insert(std::move(msg));
     CHECK(result.second) << "Duplicated error message";
   };
+  // The record of a member function (the derived class, for a member function
+  // of a base class made a member by a using-declaration).
+  const clang::CXXRecordDecl* record_decl = nullptr;
   if (auto* method_decl =
           clang::dyn_cast<clang::CXXMethodDecl>(function_decl)) {
-    if (!ictx_.HasBeenAlreadySuccessfullyImported(method_decl->getParent())) {
+    record_decl = method_decl->getParent();
+    if (using_shadow_decl != nullptr) {
+      record_decl = clang::cast<clang::CXXRecordDecl>(
+          using_shadow_decl->getDeclContext());
+    }
+    if (!ictx_.HasBeenAlreadySuccessfullyImported(record_decl)) {
       return ictx_.ImportUnsupportedItem(function_decl,
                                          "Couldn't import the parent");
     }
@@ -121,7 +412,8 @@ std::optional<IR::Item> FunctionDeclImporter::Import(
         this_lifetimes = lifetimes->GetThisLifetimes();
       }
       auto param_type =
-          ictx_.ConvertQualType(method_decl->getThisType(), this_lifetimes,
+          ictx_.ConvertQualType(GetThisType(method_decl, record_decl),
+                                this_lifetimes,
                                 /*nullable=*/false);
       if (!param_type.ok()) {
         add_error(absl::StrCat("`this` parameter is not supported: ",
@@ -151,7 +443,45 @@ std::optional<IR::Item> FunctionDeclImporter::Import(
 
     std::optional<Identifier> param_name = GetTranslatedParamName(param);
     CHECK(param_name.has_value());  // No known failure cases.
-    params.push_back({*param_type, *std::move(param_name)});
+    FuncParam func_param{*param_type, *std::move(param_name)};
+    if (absl::Status status = ImportParamContracts(function_decl, param,
+                                                   func_param);
+        !status.ok()) {
+      add_error(absl::Substitute("Parameter #$0 has an invalid contract: $1",
+                                 i, status.message()));
+      continue;
+    }
+    if (absl::Status status =
+            ImportCallbackContext(function_decl, param, func_param);
+        !status.ok()) {
+      add_error(absl::Substitute("Parameter #$0 is an invalid callback: $1", i,
+                                 status.message()));
+      continue;
+    }
+    func_param.is_callable_borrowed =
+        HasAnnotation(*param, "crubit_borrowed_callable");
+    params.push_back(std::move(func_param));
+  }
+
+  // The symbol of a function with internal linkage or hidden visibility can't
+  // be referenced by the Rust bindings, so such a function is called through a
+  // thunk: the thunk's translation unit has its own copy of a function with
+  // internal linkage, and the thunk is linked into the same shared object as
+  // a function with hidden visibility.
+  bool has_internal_linkage = !function_decl->isExternallyVisible();
+  bool has_hidden_visibility =
+      !has_internal_linkage &&
+      function_decl->getVisibility() == clang::HiddenVisibility;
+  if (has_internal_linkage && !function_decl->isDefined()) {
+    add_error(
+        "Functions with internal linkage are only supported if they are "
+        "defined in the header");
+  }
+  if ((has_internal_linkage || has_hidden_visibility) &&
+      function_decl->isVariadic()) {
+    add_error(
+        "Variadic functions with internal linkage or hidden visibility are not "
+        "supported, because they can't be called through a thunk");
   }
 
   if (function_decl->getReturnType()->isUndeducedType()) {
@@ -167,8 +497,63 @@ std::optional<IR::Item> FunctionDeclImporter::Import(
     return_lifetimes = lifetimes->GetReturnLifetimes();
   }
 
+  // A returned pointer is either owned by the caller, who deletes it with
+  // `result_deleter`, or borrowed from the parameters. Owned pointers are
+  // imported without lifetimes, since they don't borrow from anything.
+  std::optional<std::string> result_deleter =
+      GetAnnotationArg(*function_decl, "crubit_owned_result");
+  bool has_borrowed_result =
+      HasAnnotation(*function_decl, "crubit_borrowed_result");
+  clang::QualType cc_return_type = function_decl->getReturnType();
+  if (result_deleter.has_value()) {
+    if (has_borrowed_result) {
+      add_error(
+          "`CRUBIT_OWNED_RESULT` and `CRUBIT_BORROWED_RESULT` are mutually "
+          "exclusive");
+    }
+    if (!cc_return_type->isPointerType() ||
+        cc_return_type->getPointeeType().isConstQualified()) {
+      add_error(
+          "`CRUBIT_OWNED_RESULT` requires the return type to be a pointer to "
+          "a non-const object");
+    }
+    return_lifetimes = std::nullopt;
+  } else if (has_borrowed_result) {
+    if (!cc_return_type->isPointerType() &&
+        !cc_return_type->isReferenceType()) {
+      add_error(
+          "`CRUBIT_BORROWED_RESULT` requires the return type to be a pointer "
+          "or a reference");
+    }
+    if (!return_lifetimes.has_value()) {
+      add_error(
+          "`CRUBIT_BORROWED_RESULT` requires the lifetimes of the function to "
+          "be known (e.g. with `#pragma clang lifetime_elision`)");
+    }
+  }
+
+  // A function-local static outlives all the calls, and is never null, no
+  // matter what the (elided) lifetimes of the function say.
+  bool returns_static_local = ReturnsStaticLocal(function_decl);
+  if (returns_static_local) {
+    auto static_lifetime = [](const clang::Expr*)
+        -> llvm::Expected<clang::tidy::lifetimes::Lifetime> {
+      return clang::tidy::lifetimes::Lifetime::Static();
+    };
+    llvm::Expected<clang::tidy::lifetimes::ValueLifetimes> static_lifetimes =
+        clang::tidy::lifetimes::ValueLifetimes::Create(
+            function_decl->getReturnType(), static_lifetime);
+    if (static_lifetimes) {
+      return_lifetimes = *std::move(static_lifetimes);
+    } else {
+      llvm::consumeError(static_lifetimes.takeError());
+      returns_static_local = false;
+    }
+  }
+
   auto return_type =
-      ictx_.ConvertQualType(function_decl->getReturnType(), return_lifetimes);
+      ictx_.ConvertQualType(function_decl->getReturnType(), return_lifetimes,
+                            /*nullable=*/!returns_static_local);
   if (!return_type.ok()) {
     add_error(absl::StrCat("Return type is not supported: ",
                            return_type.status().message()));
@@ -216,9 +601,30 @@ std::optional<IR::Item> FunctionDeclImporter::Import(
       };
     }
 
+    AccessSpecifier access = kPublic;
+    switch (declaring_decl->getAccess()) {
+      case clang::AS_public:
+        break;
+      case clang::AS_protected:
+        access = kProtected;
+        break;
+      case clang::AS_private:
+      case clang::AS_none:
+        access = kPrivate;
+        break;
+    }
+
     member_func_metadata = MemberFuncMetadata{
-        .record_id = GenerateItemId(method_decl->getParent()),
-        .instance_method_metadata = instance_metadata};
+        .record_id = GenerateItemId(record_decl),
+        .instance_method_metadata = instance_metadata,
+        .is_static = method_decl->isStatic(),
+        .access = access};
+  }
+
+  absl::StatusOr<ItemAnnotations> annotations =
+      ictx_.GetItemAnnotations(function_decl);
+  if (!annotations.ok()) {
+    add_error(std::string(annotations.status().message()));
   }
 
   if (!errors.empty()) {
@@ -228,6 +634,18 @@ std::optional<IR::Item> FunctionDeclImporter::Import(
   bool has_c_calling_convention =
       function_decl->getType()->getAs<clang::FunctionType>()->getCallConv() ==
       clang::CC_C;
+
+  bool is_noexcept = false;
+  if (const auto* proto_type =
+          function_decl->getType()->getAs<clang::FunctionProtoType>()) {
+    // The exception specification of implicitly declared special member
+    // functions (and of template instantiations) may not be computed yet.
+    if (clang::isUnresolvedExceptionSpec(proto_type->getExceptionSpecType())) {
+      proto_type = ictx_.sema_.ResolveExceptionSpec(
+          function_decl->getLocation(), proto_type);
+    }
+    is_noexcept = proto_type != nullptr && proto_type->isNothrow();
+  }
   bool is_member_or_descendant_of_class_template =
       IsFullClassTemplateSpecializationOrChild(function_decl);
 
@@ -242,6 +660,15 @@ std::optional<IR::Item> FunctionDeclImporter::Import(
       doc_comment = ictx_.GetComment(func_pattern);
     }
   }
+  if (!doc_comment.has_value()) {
+    // Inherited constructors are documented by the base class constructor.
+    if (auto* ctor_decl =
+            clang::dyn_cast<clang::CXXConstructorDecl>(function_decl);
+        ctor_decl != nullptr && ctor_decl->isInheritingConstructor()) {
+      doc_comment = ictx_.GetComment(
+          ctor_decl->getInheritedConstructor().getConstructor());
+    }
+  }
 
   std::string mangled_name = ictx_.GetMangledName(function_decl);
   if (is_member_or_descendant_of_class_template) {
@@ -251,27 +678,59 @@ std::optional<IR::Item> FunctionDeclImporter::Import(
     mangled_name += '_';
     mangled_name += ConvertToCcIdentifier(ictx_.GetOwningTarget(function_decl));
   }
+  std::optional<ItemId> inherited_from;
+  if (using_shadow_decl != nullptr) {
+    // The function is called through a thunk taking the derived class, which
+    // needs a name different from the thunk of the base class.
+    inherited_from = GenerateItemId(
+        clang::cast<clang::CXXMethodDecl>(function_decl)->getParent());
+    absl::StrAppend(&mangled_name, "_", ictx_.GetMangledName(record_decl));
+  }
 
   // Silence ClangTidy, checked above: calling `add_error` if
-  // `!return_type.ok()` and returning early if `!errors.empty()`.
+  // `!return_type.ok()` or `!annotations.ok()` and returning early if
+  // `!errors.empty()`.
   CHECK(return_type.ok());
+  CHECK(annotations.ok());
 
   return Func{
       .name = *translated_name,
       .owning_target = ictx_.GetOwningTarget(function_decl),
       .doc_comment = std::move(doc_comment),
+      .deprecated = GetDeprecationMessage(*function_decl),
       .mangled_name = std::move(mangled_name),
       .return_type = *return_type,
       .params = std::move(params),
       .lifetime_params = std::move(lifetime_params),
       .is_inline = function_decl->isInlined(),
+      .is_noexcept = is_noexcept,
       .member_func_metadata = std::move(member_func_metadata),
       .has_c_calling_convention = has_c_calling_convention,
       .is_member_or_descendant_of_class_template =
           is_member_or_descendant_of_class_template,
       .source_loc = ictx_.ConvertSourceLocation(function_decl->getBeginLoc()),
-      .id = GenerateItemId(function_decl),
+      .id = GenerateItemId(declaring_decl),
       .enclosing_namespace_id = GetEnclosingNamespaceId(function_decl),
+      // The trivial body of a member function of a base class refers to the
+      // fields of the base class.
+      .trivial_body = using_shadow_decl == nullptr
+                          ? GetTrivialBody(function_decl)
+                          : std::nullopt,
+      .returns_static_local = returns_static_local,
+      .has_safe_pointer_params =
+          HasAnnotation(*function_decl, "crubit_safe_pointer_params") ||
+          ictx_.invocation_.safe_pointer_params_allowlist_.contains(
+              function_decl->getQualifiedNameAsString()),
+      .is_variadic = function_decl->isVariadic(),
+      .has_internal_linkage = has_internal_linkage,
+      .has_hidden_visibility = has_hidden_visibility,
+      .is_extern_c = function_decl->isExternC(),
+      .precondition = GetAnnotationArg(*function_decl, "crubit_precondition"),
+      .inherited_from = inherited_from,
+      .result_deleter = std::move(result_deleter),
+      .required_capabilities =
+          GetAnnotationArgs(*function_decl, "crubit_requires_capability"),
+      .annotations = *std::move(annotations),
   };
 }
 
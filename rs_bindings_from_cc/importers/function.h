@@ -5,7 +5,9 @@
 #ifndef CRUBIT_RS_BINDINGS_FROM_CC_IMPORTERS_FUNCTION_H_
 #define CRUBIT_RS_BINDINGS_FROM_CC_IMPORTERS_FUNCTION_H_
 
+#include "absl/status/status.h"
 #include "rs_bindings_from_cc/decl_importer.h"
+#include "clang/AST/DeclCXX.h"
 
 namespace crubit {
 
@@ -15,8 +17,43 @@ class FunctionDeclImporter : public DeclImporterBase<clang::FunctionDecl> {
   FunctionDeclImporter(ImportContext& context) : DeclImporterBase(context) {}
   std::optional<IR::Item> Import(clang::FunctionDecl*);
 
+  // Imports `function_decl`, or if `using_shadow_decl` isn't null, imports it
+  // as a member function of the derived class containing the using-declaration
+  // (`using Base::Method;`) of `using_shadow_decl`.
+  std::optional<IR::Item> ImportFunction(
+      clang::FunctionDecl* function_decl,
+      const clang::UsingShadowDecl* using_shadow_decl);
+
  private:
   Identifier GetTranslatedParamName(const clang::ParmVarDecl* param_decl);
+
+  // Imports the memory-safety contracts of a pointer parameter into
+  // `func_param` (see `CRUBIT_SIZED_BY` and `CRUBIT_ALIGNED_TO` in
+  // `support/annotations.h`).
+  absl::Status ImportParamContracts(const clang::FunctionDecl* function_decl,
+                                    const clang::ParmVarDecl* param,
+                                    FuncParam& func_param);
+
+  // Imports the context parameter of a C-style callback into `func_param` (see
+  // `CRUBIT_CALLBACK` in `support/annotations.h`).
+  absl::Status ImportCallbackContext(const clang::FunctionDecl* function_decl,
+                                     const clang::ParmVarDecl* param,
+                                     FuncParam& func_param);
+
+  // Returns the `TrivialBody` of `function_decl`, if it is an inline function
+  // whose body only forwards to something else (a field, a constant, or
+  // another function).
+  std::optional<TrivialBody> GetTrivialBody(
+      const clang::FunctionDecl* function_decl);
+
+  // Returns true if `function_decl` returns a reference (or a pointer) to a
+  // function-local static variable, as in the "Meyers singleton" pattern:
+  //
+  //   Foo& GetInstance() {
+  //     static Foo instance;
+  //     return instance;
+  //   }
+  bool ReturnsStaticLocal(const clang::FunctionDecl* function_decl);
 };
 
 }  // namespace crubit
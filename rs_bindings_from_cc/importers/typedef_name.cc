@@ -49,9 +49,24 @@ std::optional<IR::Item> crubit::TypedefNameDeclImporter::Import(
                                         identifier.status().message()));
   }
 
+  absl::StatusOr<ItemAnnotations> annotations =
+      ictx_.GetItemAnnotations(typedef_name_decl);
+  if (!annotations.ok()) {
+    return ictx_.ImportUnsupportedItem(
+        typedef_name_decl, std::string(annotations.status().message()));
+  }
+
   std::optional<clang::tidy::lifetimes::ValueLifetimes> no_lifetimes;
   absl::StatusOr<MappedType> underlying_type = ictx_.ConvertQualType(
       typedef_name_decl->getUnderlyingType(), no_lifetimes);
+  if (underlying_type.ok() && underlying_type->IsCallable()) {
+    // Callables are only bound as function parameters (see
+    // `MappedType::Callable`), so there is no Rust type to alias. Uses of the
+    // alias are resolved to the underlying `std::function` instead.
+    return ictx_.ImportUnsupportedItem(
+        typedef_name_decl,
+        "Type aliases of `std::function` are not supported");
+  }
 
   if (underlying_type.ok()) {
     if (const auto* tag_decl = type->getAsTagDecl();
@@ -72,6 +87,7 @@ std::optional<IR::Item> crubit::TypedefNameDeclImporter::Import(
             ictx_.ConvertSourceLocation(typedef_name_decl->getBeginLoc()),
         .enclosing_record_id = enclosing_record_id,
         .enclosing_namespace_id = GetEnclosingNamespaceId(typedef_name_decl),
+        .annotations = *std::move(annotations),
     };
   }
   return ictx_.ImportUnsupportedItem(
@@ -0,0 +1,39 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#include "rs_bindings_from_cc/importers/using_shadow.h"
+
+#include <optional>
+
+#include "rs_bindings_from_cc/importers/function.h"
+#include "clang/AST/Decl.h"
+#include "clang/AST/DeclCXX.h"
+
+namespace crubit {
+
+std::optional<IR::Item> UsingShadowDeclImporter::Import(
+    clang::UsingShadowDecl* using_shadow_decl) {
+  // Inherited constructors are imported as regular constructors of the derived
+  // class.
+  if (clang::isa<clang::ConstructorUsingShadowDecl>(using_shadow_decl)) {
+    return std::nullopt;
+  }
+  if (!clang::isa<clang::CXXRecordDecl>(using_shadow_decl->getDeclContext())) {
+    return std::nullopt;
+  }
+  auto* method_decl =
+      clang::dyn_cast<clang::CXXMethodDecl>(using_shadow_decl->getTargetDecl());
+  if (!method_decl) return std::nullopt;
+
+  std::optional<IR::Item> item = FunctionDeclImporter(ictx_).ImportFunction(
+      method_decl, using_shadow_decl);
+  // Methods which can't be bound are still bound on the base class, which
+  // already reports why, so don't report them a second time.
+  if (!item.has_value() || std::holds_alternative<UnsupportedItem>(*item)) {
+    return std::nullopt;
+  }
+  return item;
+}
+
+}  // namespace crubit
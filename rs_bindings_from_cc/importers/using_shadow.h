@@ -0,0 +1,27 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#ifndef CRUBIT_RS_BINDINGS_FROM_CC_IMPORTERS_USING_SHADOW_H_
+#define CRUBIT_RS_BINDINGS_FROM_CC_IMPORTERS_USING_SHADOW_H_
+
+#include "rs_bindings_from_cc/decl_importer.h"
+#include "clang/AST/DeclCXX.h"
+
+namespace crubit {
+
+// A `DeclImporter` for `UsingShadowDecl`s of member functions, i.e. for
+// `using Base::Method;` in a derived class. Inherited constructors
+// (`using Base::Base;`) are instead declared and imported as constructors of
+// the derived class by `CXXRecordDeclImporter`.
+class UsingShadowDeclImporter
+    : public DeclImporterBase<clang::UsingShadowDecl> {
+ public:
+  UsingShadowDeclImporter(ImportContext& context)
+      : DeclImporterBase(context) {}
+  std::optional<IR::Item> Import(clang::UsingShadowDecl*);
+};
+
+}  // namespace crubit
+
+#endif  // CRUBIT_RS_BINDINGS_FROM_CC_IMPORTERS_USING_SHADOW_H_
@@ -0,0 +1,212 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#include "rs_bindings_from_cc/importers/var.h"
+
+#include <optional>
+#include <string>
+#include <utility>
+
+#include "absl/status/status.h"
+#include "absl/status/statusor.h"
+#include "absl/strings/str_cat.h"
+#include "common/status_macros.h"
+#include "clang/AST/APValue.h"
+#include "clang/AST/Decl.h"
+#include "clang/AST/DeclTemplate.h"
+#include "clang/AST/Expr.h"
+#include "llvm/ADT/StringRef.h"
+
+namespace crubit {
+namespace {
+
+// Returns true if `type` is `const char*` (ignoring top-level qualifiers).
+bool IsCStringType(clang::QualType type) {
+  const auto* pointer_type = type->getAs<clang::PointerType>();
+  if (pointer_type == nullptr) return false;
+  clang::QualType pointee_type = pointer_type->getPointeeType();
+  return pointee_type.isConstQualified() && pointee_type->isCharType();
+}
+
+// Returns true if `type` is `std::string_view`.
+bool IsStringViewType(clang::QualType type) {
+  const auto* specialization_decl =
+      clang::dyn_cast_or_null<clang::ClassTemplateSpecializationDecl>(
+          type->getAsCXXRecordDecl());
+  if (specialization_decl == nullptr ||
+      !specialization_decl->isInStdNamespace() ||
+      specialization_decl->getName() != "basic_string_view") {
+    return false;
+  }
+  const clang::TemplateArgumentList& args =
+      specialization_decl->getTemplateArgs();
+  return args.size() > 0 &&
+         args[0].getKind() == clang::TemplateArgument::Type &&
+         args[0].getAsType()->isCharType();
+}
+
+// Returns the characters that `pointer` (the value of a `const char*`) points
+// to, up to the end of the string literal, or an error if it doesn't point
+// into a string literal.
+absl::StatusOr<llvm::StringRef> GetPointedToChars(
+    const clang::APValue& pointer) {
+  if (!pointer.isLValue() || pointer.isNullPointer()) {
+    return absl::InvalidArgumentError("The string is null");
+  }
+  const auto* string_literal = clang::dyn_cast_or_null<clang::StringLiteral>(
+      pointer.getLValueBase().dyn_cast<const clang::Expr*>());
+  if (string_literal == nullptr || string_literal->getCharByteWidth() != 1) {
+    return absl::InvalidArgumentError(
+        "The string must point into a string literal");
+  }
+  llvm::StringRef bytes = string_literal->getBytes();
+  uint64_t offset = pointer.getLValueOffset().getQuantity();
+  if (offset > bytes.size()) {
+    return absl::InvalidArgumentError(
+        "The string must point into a string literal");
+  }
+  return bytes.substr(offset);
+}
+
+// Returns the bytes of the value of a string constant of type `type` (see
+// `IsCStringType` and `IsStringViewType`).
+absl::StatusOr<std::string> GetStringBytes(clang::QualType type,
+                                           const clang::APValue& value) {
+  if (IsCStringType(type)) {
+    CRUBIT_ASSIGN_OR_RETURN(llvm::StringRef chars, GetPointedToChars(value));
+    // The C string ends at the first NUL.
+    return chars.substr(0, chars.find('\0')).str();
+  }
+  // The layout of `std::string_view` depends on the standard library, but it
+  // always consists of a pointer to the characters and a size.
+  if (!value.isStruct()) {
+    return absl::InvalidArgumentError("Unexpected value of a string view");
+  }
+  const clang::APValue* pointer = nullptr;
+  const clang::APValue* size = nullptr;
+  for (unsigned i = 0; i < value.getStructNumFields(); ++i) {
+    const clang::APValue& field = value.getStructField(i);
+    if (field.isLValue()) {
+      pointer = &field;
+    } else if (field.isInt()) {
+      size = &field;
+    }
+  }
+  if (pointer == nullptr || size == nullptr) {
+    return absl::InvalidArgumentError("Unexpected value of a string view");
+  }
+  uint64_t num_chars = size->getInt().getZExtValue();
+  if (num_chars == 0) return std::string();
+  CRUBIT_ASSIGN_OR_RETURN(llvm::StringRef chars, GetPointedToChars(*pointer));
+  if (num_chars > chars.size()) {
+    return absl::InvalidArgumentError(
+        "The string view must not extend past the end of the string literal");
+  }
+  return chars.substr(0, num_chars).str();
+}
+
+}  // namespace
+
+std::optional<IR::Item> VarDeclImporter::Import(clang::VarDecl* var_decl) {
+  // TODO(b/208945197): Support global variables and static data members. For
+  // now only namespace-scope compile-time constants (and `const char* const`
+  // strings, which are constants in all but name) are imported.
+  bool is_const_c_string = var_decl->getType().isConstQualified() &&
+                           IsCStringType(var_decl->getType());
+  if (!var_decl->getDeclContext()->isFileContext() ||
+      !(var_decl->isConstexpr() || is_const_c_string) ||
+      clang::isa<clang::VarTemplateSpecializationDecl>(var_decl)) {
+    return std::nullopt;
+  }
+
+  absl::StatusOr<Identifier> identifier =
+      ictx_.GetTranslatedIdentifier(var_decl);
+  if (!identifier.ok()) {
+    return ictx_.ImportUnsupportedItem(
+        var_decl, absl::StrCat("Constant name is not supported: ",
+                               identifier.status().message()));
+  }
+
+  absl::StatusOr<ItemAnnotations> annotations =
+      ictx_.GetItemAnnotations(var_decl);
+  if (!annotations.ok()) {
+    return ictx_.ImportUnsupportedItem(
+        var_decl, std::string(annotations.status().message()));
+  }
+
+  clang::QualType cc_type = var_decl->getType().getUnqualifiedType();
+  if (IsCStringType(cc_type) || IsStringViewType(cc_type)) {
+    return ImportStringConstant(var_decl, *std::move(identifier),
+                                *std::move(annotations), cc_type);
+  }
+  if (!cc_type->isIntegerType()) {
+    return ictx_.ImportUnsupportedItem(
+        var_decl, "Only constants of integer types are supported");
+  }
+  std::optional<clang::tidy::lifetimes::ValueLifetimes> no_lifetimes;
+  absl::StatusOr<MappedType> type =
+      ictx_.ConvertQualType(cc_type, no_lifetimes);
+  if (!type.ok()) {
+    return ictx_.ImportUnsupportedItem(var_decl, type.status().ToString());
+  }
+
+  // The initializer is evaluated by Clang, using the layout of the target
+  // platform for expressions like `sizeof(T)` or `alignof(T)`.
+  const clang::APValue* value = var_decl->evaluateValue();
+  if (value == nullptr || !value->isInt()) {
+    return ictx_.ImportUnsupportedItem(
+        var_decl, "The value of the constant couldn't be evaluated");
+  }
+
+  return Constant{
+      .identifier = *identifier,
+      .id = GenerateItemId(var_decl),
+      .owning_target = ictx_.GetOwningTarget(var_decl),
+      .doc_comment = ictx_.GetComment(var_decl),
+      .type = *std::move(type),
+      .value = IntegerConstant(value->getInt()),
+      .source_loc = ictx_.ConvertSourceLocation(var_decl->getBeginLoc()),
+      .enclosing_namespace_id = GetEnclosingNamespaceId(var_decl),
+      .annotations = *std::move(annotations),
+  };
+}
+
+std::optional<IR::Item> VarDeclImporter::ImportStringConstant(
+    clang::VarDecl* var_decl, Identifier identifier,
+    ItemAnnotations annotations, clang::QualType cc_type) {
+  std::optional<clang::tidy::lifetimes::ValueLifetimes> no_lifetimes;
+  absl::StatusOr<MappedType> type = ictx_.ConvertQualType(
+      ictx_.ctx_.getPointerType(ictx_.ctx_.CharTy.withConst()), no_lifetimes);
+  if (!type.ok()) {
+    return ictx_.ImportUnsupportedItem(var_decl, type.status().ToString());
+  }
+
+  const clang::APValue* value = var_decl->evaluateValue();
+  if (value == nullptr) {
+    return ictx_.ImportUnsupportedItem(
+        var_decl, "The value of the constant couldn't be evaluated");
+  }
+  absl::StatusOr<std::string> bytes = GetStringBytes(cc_type, *value);
+  if (!bytes.ok()) {
+    return ictx_.ImportUnsupportedItem(
+        var_decl, absl::StrCat("The value of the string constant isn't "
+                               "supported: ",
+                               bytes.status().message()));
+  }
+
+  return Constant{
+      .identifier = std::move(identifier),
+      .id = GenerateItemId(var_decl),
+      .owning_target = ictx_.GetOwningTarget(var_decl),
+      .doc_comment = ictx_.GetComment(var_decl),
+      .type = *std::move(type),
+      .value = StringConstant{.is_c_string = IsCStringType(cc_type),
+                              .bytes = *std::move(bytes)},
+      .source_loc = ictx_.ConvertSourceLocation(var_decl->getBeginLoc()),
+      .enclosing_namespace_id = GetEnclosingNamespaceId(var_decl),
+      .annotations = std::move(annotations),
+  };
+}
+
+}  // namespace crubit
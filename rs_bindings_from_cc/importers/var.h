@@ -0,0 +1,32 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#ifndef CRUBIT_RS_BINDINGS_FROM_CC_IMPORTERS_VAR_H_
+#define CRUBIT_RS_BINDINGS_FROM_CC_IMPORTERS_VAR_H_
+
+#include <optional>
+
+#include "rs_bindings_from_cc/decl_importer.h"
+#include "clang/AST/Decl.h"
+
+namespace crubit {
+
+// A `DeclImporter` for `VarDecl`s.
+class VarDeclImporter : public DeclImporterBase<clang::VarDecl> {
+ public:
+  VarDeclImporter(ImportContext& context) : DeclImporterBase(context) {}
+  std::optional<IR::Item> Import(clang::VarDecl*);
+
+ private:
+  // Imports a constant of type `cc_type`, which is `const char*` or
+  // `std::string_view`.
+  std::optional<IR::Item> ImportStringConstant(clang::VarDecl* var_decl,
+                                               Identifier identifier,
+                                               ItemAnnotations annotations,
+                                               clang::QualType cc_type);
+};
+
+}  // namespace crubit
+
+#endif  // CRUBIT_RS_BINDINGS_FROM_CC_IMPORTERS_VAR_H_
@@ -6,6 +6,8 @@
 
 #include <stdint.h>
 
+#include <algorithm>
+#include <cstring>
 #include <optional>
 #include <ostream>
 #include <string>
@@ -13,14 +15,75 @@
 #include <variant>
 #include <vector>
 
+#include "absl/container/flat_hash_map.h"
 #include "absl/log/check.h"
+#include "absl/strings/str_cat.h"
 #include "absl/strings/string_view.h"
 #include "common/strong_int.h"
 #include "rs_bindings_from_cc/bazel_types.h"
+#include "clang/AST/ASTContext.h"
+#include "clang/AST/DeclBase.h"
+#include "clang/Basic/SourceLocation.h"
+#include "clang/Basic/SourceManager.h"
+#include "clang/Index/USRGeneration.h"
+#include "llvm/ADT/SmallString.h"
+#include "llvm/Support/FormatVariadic.h"
 #include "llvm/Support/JSON.h"
+#include "llvm/Support/xxhash.h"
 
 namespace crubit {
 
+namespace {
+
+std::string FormatLocation(const clang::SourceManager& sm,
+                           clang::SourceLocation loc) {
+  clang::PresumedLoc presumed_loc = sm.getPresumedLoc(loc);
+  if (presumed_loc.isInvalid()) return "<invalid>";
+  return absl::StrCat(presumed_loc.getFilename(), ":", presumed_loc.getLine(),
+                      ":", presumed_loc.getColumn());
+}
+
+// Returns a unique key of the location of a declaration or comment. The
+// spelling location tells apart the declarations expanded from the same macro.
+std::string LocationKey(const clang::SourceManager& sm,
+                        clang::SourceLocation loc) {
+  return absl::StrCat(FormatLocation(sm, sm.getExpansionLoc(loc)), "@",
+                      FormatLocation(sm, sm.getSpellingLoc(loc)));
+}
+
+ItemId ItemIdFromKey(absl::string_view key) {
+  // JSON integers are signed, so the top bit is dropped.
+  return ItemId(static_cast<uintptr_t>(
+      llvm::xxHash64(llvm::StringRef(key.data(), key.size())) >> 1));
+}
+
+}  // namespace
+
+ItemId GenerateItemId(const clang::Decl* decl) {
+  const clang::SourceManager& sm = decl->getASTContext().getSourceManager();
+  // Reopened namespaces are separate items, but share the USR.
+  bool is_namespace = clang::isa<clang::NamespaceDecl>(decl);
+  if (!is_namespace) decl = decl->getCanonicalDecl();
+
+  llvm::SmallString<128> usr;
+  // `generateUSRForDecl` returns true on failure.
+  if (clang::index::generateUSRForDecl(decl, usr)) {
+    return ItemIdFromKey(absl::StrCat("decl:", decl->getDeclKindName(), ":",
+                                      LocationKey(sm, decl->getLocation())));
+  }
+  if (is_namespace) {
+    return ItemIdFromKey(absl::StrCat("namespace:", usr.str(), ":",
+                                      LocationKey(sm, decl->getLocation())));
+  }
+  return ItemIdFromKey(absl::StrCat("usr:", usr.str()));
+}
+
+ItemId GenerateItemId(const clang::RawComment* comment,
+                      const clang::SourceManager& sm) {
+  return ItemIdFromKey(
+      absl::StrCat("comment:", LocationKey(sm, comment->getBeginLoc())));
+}
+
 template <class T>
 llvm::json::Value toJSON(const T& t) {
   return t.ToJson();
@@ -194,6 +257,88 @@ MappedType MappedType::FuncRef(absl::string_view cc_call_conv,
   };
 }
 
+MappedType MappedType::Callable(MappedType return_type,
+                                std::vector<MappedType> param_types) {
+  std::vector<MappedType> type_args = std::move(param_types);
+  type_args.push_back(std::move(return_type));
+
+  MappedType result = MappedType::Simple(std::string(internal::kRustCallable),
+                                         std::string(internal::kCcCallable));
+  result.rs_type.type_args.reserve(type_args.size());
+  result.cc_type.type_args.reserve(type_args.size());
+  for (MappedType& type_arg : type_args) {
+    result.cc_type.type_args.push_back(std::move(type_arg.cc_type));
+    result.rs_type.type_args.push_back(std::move(type_arg.rs_type));
+  }
+  return result;
+}
+
+static MappedType StdContainer(absl::string_view rs_name,
+                               absl::string_view cc_name,
+                               MappedType element_type) {
+  MappedType result =
+      MappedType::Simple(std::string(rs_name), std::string(cc_name));
+  result.rs_type.type_args.push_back(std::move(element_type.rs_type));
+  result.cc_type.type_args.push_back(std::move(element_type.cc_type));
+  return result;
+}
+
+MappedType MappedType::StdVector(MappedType element_type) {
+  return StdContainer(internal::kRustCcVector, internal::kCcStdVector,
+                      std::move(element_type));
+}
+
+MappedType MappedType::StdOptional(MappedType element_type) {
+  return StdContainer(internal::kRustCcOptional, internal::kCcStdOptional,
+                      std::move(element_type));
+}
+
+MappedType MappedType::MemberFuncPtr(MappedType class_type, bool is_const,
+                                     MappedType return_type,
+                                     std::vector<MappedType> param_types) {
+  std::vector<MappedType> type_args;
+  type_args.reserve(param_types.size() + 2);
+  type_args.push_back(std::move(class_type));
+  for (MappedType& param_type : param_types) {
+    type_args.push_back(std::move(param_type));
+  }
+  type_args.push_back(std::move(return_type));
+
+  MappedType result = MappedType::Simple(
+      std::string(is_const ? internal::kRustMemberFnPtrConst
+                           : internal::kRustMemberFnPtrMut),
+      std::string(is_const ? internal::kCcMemberFnPtrConst
+                           : internal::kCcMemberFnPtrMut));
+  result.rs_type.type_args.reserve(type_args.size());
+  result.cc_type.type_args.reserve(type_args.size());
+  for (MappedType& type_arg : type_args) {
+    result.cc_type.type_args.push_back(std::move(type_arg.cc_type));
+    result.rs_type.type_args.push_back(std::move(type_arg.rs_type));
+  }
+  return result;
+}
+
+MappedType MappedType::DataMemberPtr(MappedType class_type,
+                                     MappedType member_type) {
+  MappedType result =
+      MappedType::Simple(std::string(internal::kRustDataMemberPtr),
+                         std::string(internal::kCcDataMemberPtr));
+  result.rs_type.type_args = {std::move(class_type.rs_type),
+                              std::move(member_type.rs_type)};
+  result.cc_type.type_args = {std::move(class_type.cc_type),
+                              std::move(member_type.cc_type)};
+  return result;
+}
+
+MappedType MappedType::ArrayOf(MappedType element_type, uint64_t size) {
+  MappedType result =
+      MappedType::Simple(absl::StrCat(internal::kRustArray, " ", size),
+                         absl::StrCat(internal::kCcArray, " ", size));
+  result.rs_type.type_args.push_back(std::move(element_type.rs_type));
+  result.cc_type.type_args.push_back(std::move(element_type.cc_type));
+  return result;
+}
+
 llvm::json::Value MappedType::ToJson() const {
   return llvm::json::Object{
       {"rs_type", rs_type},
@@ -250,6 +395,12 @@ llvm::json::Value FuncParam::ToJson() const {
   return llvm::json::Object{
       {"type", type},
       {"identifier", identifier},
+      {"is_nonnull", is_nonnull},
+      {"sized_by", sized_by},
+      {"alignment", alignment},
+      {"callback_context", callback_context},
+      {"is_callback_retained", is_callback_retained},
+      {"is_callable_borrowed", is_callable_borrowed},
   };
 }
 
@@ -278,10 +429,27 @@ llvm::json::Value MemberFuncMetadata::InstanceMethodMetadata::ToJson() const {
   };
 }
 
+static std::string AccessToString(AccessSpecifier access) {
+  switch (access) {
+    case kPublic:
+      return "Public";
+    case kProtected:
+      return "Protected";
+    case kPrivate:
+      return "Private";
+  }
+}
+
+std::ostream& operator<<(std::ostream& o, const AccessSpecifier& access) {
+  return o << AccessToString(access);
+}
+
 llvm::json::Value MemberFuncMetadata::ToJson() const {
   return llvm::json::Object{
       {"record_id", record_id},
       {"instance_method_metadata", instance_method_metadata},
+      {"is_static", is_static},
+      {"access", AccessToString(access)},
   };
 }
 
@@ -297,16 +465,51 @@ llvm::json::Value UseMod::ToJson() const {
   };
 }
 
+llvm::json::Value TrivialBody::ToJson() const {
+  const char* kind_str = nullptr;
+  switch (kind) {
+    case kReturnsField:
+      kind_str = "ReturnsField";
+      break;
+    case kSetsField:
+      kind_str = "SetsField";
+      break;
+    case kReturnsConstant:
+      kind_str = "ReturnsConstant";
+      break;
+    case kForwardsCall:
+      kind_str = "ForwardsCall";
+      break;
+  }
+
+  return llvm::json::Object{
+      {"kind", kind_str},
+      {"field", field},
+      {"constant", constant},
+      {"callee", callee},
+  };
+}
+
+llvm::json::Value ItemAnnotations::ToJson() const {
+  return llvm::json::Object{
+      {"rust_name", rust_name},
+      {"skip", skip},
+      {"doc_hidden", doc_hidden},
+  };
+}
+
 llvm::json::Value Func::ToJson() const {
   llvm::json::Object func{
       {"name", name},
       {"owning_target", owning_target},
       {"doc_comment", doc_comment},
+      {"deprecated", deprecated},
       {"mangled_name", mangled_name},
       {"return_type", return_type},
       {"params", params},
       {"lifetime_params", lifetime_params},
       {"is_inline", is_inline},
+      {"is_noexcept", is_noexcept},
       {"member_func_metadata", member_func_metadata},
       {"has_c_calling_convention", has_c_calling_convention},
       {"is_member_or_descendant_of_class_template",
@@ -315,6 +518,18 @@ llvm::json::Value Func::ToJson() const {
       {"id", id},
       {"enclosing_namespace_id", enclosing_namespace_id},
       {"adl_enclosing_record", adl_enclosing_record},
+      {"trivial_body", trivial_body},
+      {"returns_static_local", returns_static_local},
+      {"has_safe_pointer_params", has_safe_pointer_params},
+      {"is_variadic", is_variadic},
+      {"has_internal_linkage", has_internal_linkage},
+      {"has_hidden_visibility", has_hidden_visibility},
+      {"is_extern_c", is_extern_c},
+      {"precondition", precondition},
+      {"inherited_from", inherited_from},
+      {"result_deleter", result_deleter},
+      {"required_capabilities", required_capabilities},
+      {"annotations", annotations},
   };
 
   return llvm::json::Object{
@@ -322,25 +537,11 @@ llvm::json::Value Func::ToJson() const {
   };
 }
 
-static std::string AccessToString(AccessSpecifier access) {
-  switch (access) {
-    case kPublic:
-      return "Public";
-    case kProtected:
-      return "Protected";
-    case kPrivate:
-      return "Private";
-  }
-}
-
-std::ostream& operator<<(std::ostream& o, const AccessSpecifier& access) {
-  return o << AccessToString(access);
-}
-
 llvm::json::Value Field::ToJson() const {
   return llvm::json::Object{
       {"identifier", identifier},
       {"doc_comment", doc_comment},
+      {"deprecated", deprecated},
       {"type", type},
       {"access", AccessToString(access)},
       {"offset", offset},
@@ -348,6 +549,8 @@ llvm::json::Value Field::ToJson() const {
       {"is_no_unique_address", is_no_unique_address},
       {"is_bitfield", is_bitfield},
       {"is_inheritable", is_inheritable},
+      {"anonymous_members", anonymous_members},
+      {"is_anonymous_union", is_anonymous_union},
   };
 }
 
@@ -393,7 +596,9 @@ llvm::json::Value IncompleteRecord::ToJson() const {
       {"id", id},
       {"owning_target", owning_target},
       {"record_type", RecordTypeToString(record_type)},
+      {"is_template_instantiation", is_template_instantiation},
       {"enclosing_namespace_id", enclosing_namespace_id},
+      {"enclosing_record_id", enclosing_record_id},
   };
 
   return llvm::json::Object{
@@ -415,6 +620,7 @@ llvm::json::Value Record::ToJson() const {
       {"id", id},
       {"owning_target", owning_target},
       {"doc_comment", doc_comment},
+      {"deprecated", deprecated},
       {"source_loc", source_loc},
       {"unambiguous_public_bases", unambiguous_public_bases},
       {"fields", fields},
@@ -424,6 +630,7 @@ llvm::json::Value Record::ToJson() const {
       {"alignment", alignment},
       {"is_derived_class", is_derived_class},
       {"override_alignment", override_alignment},
+      {"needs_opaque_layout", needs_opaque_layout},
       {"copy_constructor", copy_constructor},
       {"move_constructor", move_constructor},
       {"destructor", destructor},
@@ -433,8 +640,16 @@ llvm::json::Value Record::ToJson() const {
       {"record_type", RecordTypeToString(record_type)},
       {"is_aggregate", is_aggregate},
       {"is_anon_record_with_typedef", is_anon_record_with_typedef},
+      {"is_template_instantiation", is_template_instantiation},
+      {"has_throwing_assignment", has_throwing_assignment},
+      {"is_bytes_serializable", is_bytes_serializable},
+      {"is_stream_extractable", is_stream_extractable},
+      {"is_log_sink", is_log_sink},
+      {"is_extern_c", is_extern_c},
       {"child_item_ids", std::move(json_item_ids)},
       {"enclosing_namespace_id", enclosing_namespace_id},
+      {"enclosing_record_id", enclosing_record_id},
+      {"annotations", annotations},
   };
 
   return llvm::json::Object{
@@ -455,9 +670,12 @@ llvm::json::Value Enum::ToJson() const {
       {"id", id},
       {"owning_target", owning_target},
       {"source_loc", source_loc},
+      {"deprecated", deprecated},
       {"underlying_type", underlying_type},
       {"enumerators", enumerators},
+      {"is_error_code", is_error_code},
       {"enclosing_namespace_id", enclosing_namespace_id},
+      {"annotations", annotations},
   };
 
   return llvm::json::Object{
@@ -475,6 +693,7 @@ llvm::json::Value TypeAlias::ToJson() const {
       {"source_loc", source_loc},
       {"enclosing_record_id", enclosing_record_id},
       {"enclosing_namespace_id", enclosing_namespace_id},
+      {"annotations", annotations},
   };
 
   return llvm::json::Object{
@@ -482,6 +701,39 @@ llvm::json::Value TypeAlias::ToJson() const {
   };
 }
 
+llvm::json::Value StringConstant::ToJson() const {
+  llvm::json::Array json_bytes;
+  for (char byte : bytes) {
+    json_bytes.push_back(static_cast<uint8_t>(byte));
+  }
+  return llvm::json::Object{
+      {"is_c_string", is_c_string},
+      {"bytes", std::move(json_bytes)},
+  };
+}
+
+llvm::json::Value Constant::ToJson() const {
+  llvm::json::Value json_value =
+      std::holds_alternative<IntegerConstant>(value)
+          ? llvm::json::Object{{"Integer", std::get<IntegerConstant>(value)}}
+          : llvm::json::Object{{"String", std::get<StringConstant>(value)}};
+  llvm::json::Object constant{
+      {"identifier", identifier},
+      {"id", id},
+      {"owning_target", owning_target},
+      {"doc_comment", doc_comment},
+      {"type", type},
+      {"value", std::move(json_value)},
+      {"source_loc", source_loc},
+      {"enclosing_namespace_id", enclosing_namespace_id},
+      {"annotations", annotations},
+  };
+
+  return llvm::json::Object{
+      {"Constant", std::move(constant)},
+  };
+}
+
 llvm::json::Value UnsupportedItem::ToJson() const {
   llvm::json::Object unsupported{
       {"name", name},
@@ -506,6 +758,15 @@ llvm::json::Value Comment::ToJson() const {
   };
 }
 
+llvm::json::Value PreprocessorConditional::ToJson() const {
+  return llvm::json::Object{
+      {"directive", directive},
+      {"condition", condition},
+      {"taken", taken},
+      {"source_loc", source_loc},
+  };
+}
+
 llvm::json::Value Namespace::ToJson() const {
   std::vector<llvm::json::Value> json_item_ids;
   json_item_ids.reserve(child_item_ids.size());
@@ -528,6 +789,57 @@ llvm::json::Value Namespace::ToJson() const {
   };
 }
 
+namespace {
+
+// Replaces every `MappedType` component (i.e. each value stored under an
+// "rs_type" or "cc_type" key) with an index into a table of distinct types.
+// Identical type trees are only serialized once, which keeps large IRs small.
+class TypeInterner {
+ public:
+  void Intern(llvm::json::Value& value) {
+    if (auto* object = value.getAsObject()) {
+      for (auto& [key, field] : *object) {
+        if (key == "rs_type") {
+          field = Index(rs_type_indices_, rs_types_, std::move(field));
+        } else if (key == "cc_type") {
+          field = Index(cc_type_indices_, cc_types_, std::move(field));
+        } else {
+          Intern(field);
+        }
+      }
+    } else if (auto* array = value.getAsArray()) {
+      for (auto& element : *array) {
+        Intern(element);
+      }
+    }
+  }
+
+  llvm::json::Object TakeTables() && {
+    return llvm::json::Object{
+        {"rs_types", std::move(rs_types_)},
+        {"cc_types", std::move(cc_types_)},
+    };
+  }
+
+ private:
+  static int64_t Index(absl::flat_hash_map<std::string, int64_t>& indices,
+                       llvm::json::Array& table, llvm::json::Value type) {
+    auto [it, inserted] = indices.try_emplace(
+        std::string(llvm::formatv("{0}", type)), table.size());
+    if (inserted) {
+      table.push_back(std::move(type));
+    }
+    return it->second;
+  }
+
+  absl::flat_hash_map<std::string, int64_t> rs_type_indices_;
+  absl::flat_hash_map<std::string, int64_t> cc_type_indices_;
+  llvm::json::Array rs_types_;
+  llvm::json::Array cc_types_;
+};
+
+}  // namespace
+
 llvm::json::Value IR::ToJson() const {
   std::vector<llvm::json::Value> json_items;
   json_items.reserve(items.size());
@@ -536,6 +848,11 @@ llvm::json::Value IR::ToJson() const {
   }
   CHECK_EQ(json_items.size(), items.size());
 
+  TypeInterner interner;
+  for (auto& json_item : json_items) {
+    interner.Intern(json_item);
+  }
+
   std::vector<llvm::json::Value> top_level_ids;
   top_level_ids.reserve(top_level_item_ids.size());
   for (const auto& id : top_level_item_ids) {
@@ -543,17 +860,142 @@ llvm::json::Value IR::ToJson() const {
   }
 
   llvm::json::Object result{
+      {"schema_version", kIrSchemaVersion},
       {"public_headers", public_headers},
       {"current_target", current_target},
       {"items", std::move(json_items)},
       {"top_level_item_ids", std::move(top_level_ids)},
+      // llvm::json emits keys in sorted order, so the type tables precede
+      // "items" and can be resolved by ir.rs while the items are parsed.
+      {"interned_types", std::move(interner).TakeTables()},
   };
+  if (!imported_modules.empty()) {
+    result["imported_modules"] = imported_modules;
+  }
+  if (!preprocessor_overrides.empty()) {
+    result["preprocessor_overrides"] = preprocessor_overrides;
+  }
+  if (!preprocessor_conditionals.empty()) {
+    std::vector<llvm::json::Value> json_conditionals;
+    json_conditionals.reserve(preprocessor_conditionals.size());
+    for (const auto& conditional : preprocessor_conditionals) {
+      json_conditionals.push_back(conditional.ToJson());
+    }
+    result["preprocessor_conditionals"] = std::move(json_conditionals);
+  }
   if (!crate_root_path.empty()) {
     result["crate_root_path"] = crate_root_path;
   }
   return std::move(result);
 }
 
+namespace {
+
+// The tags of the values of the binary IR (see `binary_ir.rs`).
+enum BinaryIrTag : char {
+  kBinaryIrNull = 0,
+  kBinaryIrFalse = 1,
+  kBinaryIrTrue = 2,
+  kBinaryIrInt = 3,
+  kBinaryIrUint = 4,
+  kBinaryIrFloat = 5,
+  kBinaryIrString = 6,
+  kBinaryIrArray = 7,
+  kBinaryIrObject = 8,
+};
+
+// Appends `value` as a LEB128 varint.
+void AppendVarint(uint64_t value, std::string& out) {
+  while (value >= 0x80) {
+    out.push_back(static_cast<char>(value | 0x80));
+    value >>= 7;
+  }
+  out.push_back(static_cast<char>(value));
+}
+
+void AppendString(llvm::StringRef string, std::string& out) {
+  AppendVarint(string.size(), out);
+  out.append(string.data(), string.size());
+}
+
+void AppendBinaryIr(const llvm::json::Value& value, std::string& out) {
+  switch (value.kind()) {
+    case llvm::json::Value::Null:
+      out.push_back(kBinaryIrNull);
+      break;
+    case llvm::json::Value::Boolean:
+      out.push_back(*value.getAsBoolean() ? kBinaryIrTrue : kBinaryIrFalse);
+      break;
+    case llvm::json::Value::Number:
+      if (auto integer = value.getAsInteger()) {
+        // Zigzag encoding, so that small negative integers are short too.
+        out.push_back(kBinaryIrInt);
+        AppendVarint((static_cast<uint64_t>(*integer) << 1) ^
+                         static_cast<uint64_t>(*integer >> 63),
+                     out);
+      } else if (auto unsigned_integer = value.getAsUINT64()) {
+        out.push_back(kBinaryIrUint);
+        AppendVarint(*unsigned_integer, out);
+      } else {
+        double number = *value.getAsNumber();
+        uint64_t bits;
+        std::memcpy(&bits, &number, sizeof(bits));
+        out.push_back(kBinaryIrFloat);
+        for (int i = 0; i < 8; ++i) {
+          out.push_back(static_cast<char>(bits >> (8 * i)));
+        }
+      }
+      break;
+    case llvm::json::Value::String:
+      out.push_back(kBinaryIrString);
+      AppendString(*value.getAsString(), out);
+      break;
+    case llvm::json::Value::Array: {
+      const llvm::json::Array& array = *value.getAsArray();
+      out.push_back(kBinaryIrArray);
+      AppendVarint(array.size(), out);
+      for (const llvm::json::Value& element : array) {
+        AppendBinaryIr(element, out);
+      }
+      break;
+    }
+    case llvm::json::Value::Object: {
+      // The members are sorted by key, like in the JSON IR (see `IR::ToJson`).
+      const llvm::json::Object& object = *value.getAsObject();
+      std::vector<const llvm::json::Object::value_type*> members;
+      members.reserve(object.size());
+      for (const auto& member : object) {
+        members.push_back(&member);
+      }
+      std::sort(members.begin(), members.end(),
+                [](const auto* a, const auto* b) {
+                  return a->first < b->first;
+                });
+      out.push_back(kBinaryIrObject);
+      AppendVarint(members.size(), out);
+      for (const auto* member : members) {
+        AppendString(member->first, out);
+        AppendBinaryIr(member->second, out);
+      }
+      break;
+    }
+  }
+}
+
+}  // namespace
+
+std::string JsonToBinaryIr(const llvm::json::Value& value) {
+  std::string out;
+  AppendBinaryIr(value, out);
+  return out;
+}
+
+std::string IrToBinary(const IR& ir) {
+  std::string out(1, kBinaryIrHeader);
+  AppendBinaryIr(ir.ToJson(), out);
+  return out;
+}
+
 std::string ItemToString(const IR::Item& item) {
   return std::visit(
       [&](auto&& item) { return llvm::formatv("{0}", item.ToJson()); }, item);
@@ -31,6 +31,7 @@
 #include "clang/AST/DeclBase.h"
 #include "clang/AST/DeclTemplate.h"
 #include "clang/AST/RawCommentList.h"
+#include "clang/Basic/SourceManager.h"
 #include "llvm/ADT/APSInt.h"
 #include "llvm/ADT/Optional.h"
 #include "llvm/Support/FormatVariadic.h"
@@ -53,11 +54,33 @@ inline constexpr absl::string_view kRustRvalueRefConst =
 // Function pointers.
 inline constexpr absl::string_view kRustFuncPtr = "#funcPtr";
 
+// Type-erased callables (e.g. `std::function<R(Args...)>`).
+inline constexpr absl::string_view kRustCallable = "#Callable";
+
+// Adapters for C++ standard library containers.
+inline constexpr absl::string_view kRustCcVector = "#CcVector";
+inline constexpr absl::string_view kRustCcOptional = "#CcOptional";
+
+// Pointers to members.
+inline constexpr absl::string_view kRustMemberFnPtrConst = "#MemberFnPtr const";
+inline constexpr absl::string_view kRustMemberFnPtrMut = "#MemberFnPtr mut";
+inline constexpr absl::string_view kRustDataMemberPtr = "#DataMemberPtr";
+
+// Constant-size arrays. The size follows the name (e.g. `#Array 32`).
+inline constexpr absl::string_view kRustArray = "#Array";
+
 // C++ types therein.
 inline constexpr absl::string_view kCcPtr = "*";
 inline constexpr absl::string_view kCcLValueRef = "&";
 inline constexpr absl::string_view kCcRValueRef = "&&";
 inline constexpr absl::string_view kCcFuncValue = "#funcValue";
+inline constexpr absl::string_view kCcCallable = "#Callable";
+inline constexpr absl::string_view kCcStdVector = "std::vector";
+inline constexpr absl::string_view kCcStdOptional = "std::optional";
+inline constexpr absl::string_view kCcMemberFnPtrConst = "#memberFuncPtr const";
+inline constexpr absl::string_view kCcMemberFnPtrMut = "#memberFuncPtr";
+inline constexpr absl::string_view kCcDataMemberPtr = "#dataMemberPtr";
+inline constexpr absl::string_view kCcArray = "#array";
 
 inline constexpr int kJsonIndent = 2;
 }  // namespace internal
@@ -95,18 +118,22 @@ inline std::ostream& operator<<(std::ostream& o, const HeaderName& h) {
 // edges that don't follow the JSON tree structure (for example between types
 // and records), as well as location of comments and items we don't yet support.
 //  We use ItemIds for this.
+//
+// ItemIds are hashes of the identity of the item (see `GenerateItemId`), so
+// they are stable: the same item gets the same ItemId in every invocation,
+// including in the IR of every target which imports it. This allows referring
+// to the items of other targets (see `--dependency_irs`).
 CRUBIT_DEFINE_STRONG_INT_TYPE(ItemId, uintptr_t);
 
-inline ItemId GenerateItemId(const clang::Decl* decl) {
-  if (auto namespace_decl = clang::dyn_cast<clang::NamespaceDecl>(decl)) {
-    return ItemId(reinterpret_cast<uintptr_t>(namespace_decl));
-  }
-  return ItemId(reinterpret_cast<uintptr_t>(decl->getCanonicalDecl()));
-}
+// Returns the ItemId of `decl`, which is derived from the Unified Symbol
+// Resolution (USR) of the declaration, i.e. from its mangled, fully qualified
+// name. Reopened namespaces, and the few declarations without a USR (e.g.
+// friend declarations), are additionally identified by their location.
+ItemId GenerateItemId(const clang::Decl* decl);
 
-inline ItemId GenerateItemId(const clang::RawComment* comment) {
-  return ItemId(reinterpret_cast<uintptr_t>(comment));
-}
+// Returns the ItemId of `comment`, which is derived from its location.
+ItemId GenerateItemId(const clang::RawComment* comment,
+                      const clang::SourceManager& sm);
 
 // Returns the ID of the parent namespace, if such exists, and `std::nullopt`
 // for top level decls. We use this function to assign a parent namespace to all
@@ -158,6 +185,8 @@ struct CcType {
   // - "#funcValue <callConv>" (compare with "#funcPtr <abi>" in RsType::name
   //   and note that Rust only supports function pointers; note that <callConv>
   //   in CcType doesn't map 1:1 to <abi> in RsType).
+  // - "#Callable" (`std::function<R(Args...)>`; the layout of `type_args` is
+  //   the same as for "#funcValue").
   // - An empty string when `decl_id` is non-empty.
   std::string name;
 
@@ -196,6 +225,8 @@ struct RsType {
   //   `type_args`; param types are stored in other `type_args`; <abi> would be
   //   replaced with "cdecl", "stdcall" or other Abi - see
   //   https://doc.rust-lang.org/reference/types/function-pointer.html);
+  // - "#Callable" (a type-erased callable like `std::function`, which is
+  //   bound as a Rust closure; `type_args` are laid out like for "#funcPtr").
   // - An empty string when `decl_id` is non-empty.
   std::string name;
 
@@ -270,7 +301,36 @@ struct MappedType {
                             MappedType return_type,
                             std::vector<MappedType> param_types);
 
+  // Creates a mapped type for a type-erased C++ callable, such as
+  // `std::function<R(Args...)>`. Such types have no Rust equivalent with the
+  // same layout, and can only be passed as function parameters, where they are
+  // bound as Rust closures invoked through a generated trampoline.
+  static MappedType Callable(MappedType return_type,
+                             std::vector<MappedType> param_types);
+
+  // Creates mapped types for `std::vector<T>` and `std::optional<T>`, which
+  // are bound to the generic `CcVector<T>` and `CcOptional<T>` adapters of the
+  // `cc_containers` crate, rather than to records generated for each template
+  // instantiation.
+  static MappedType StdVector(MappedType element_type);
+  static MappedType StdOptional(MappedType element_type);
+
+  // Creates mapped types for pointers to members of `class_type`, which are
+  // bound to the opaque `CcMemberFnPtr` and `CcDataMemberPtr` types of the
+  // `member_pointers` crate. `is_const` is true for pointers to const member
+  // functions.
+  static MappedType MemberFuncPtr(MappedType class_type, bool is_const,
+                                  MappedType return_type,
+                                  std::vector<MappedType> param_types);
+  static MappedType DataMemberPtr(MappedType class_type,
+                                  MappedType member_type);
+
+  // Creates a mapped type for a C++ array of `size` elements (e.g.
+  // `uint8_t[32]`), which is bound to a Rust array (e.g. `[u8; 32]`).
+  static MappedType ArrayOf(MappedType element_type, uint64_t size);
+
   bool IsVoid() const { return rs_type.name == "()"; }
+  bool IsCallable() const { return rs_type.name == internal::kRustCallable; }
 
   llvm::json::Value ToJson() const;
 
@@ -374,6 +434,27 @@ struct FuncParam {
 
   MappedType type;
   Identifier identifier;
+
+  // Memory-safety contracts of a pointer parameter, which are checked by debug
+  // assertions in the generated Rust wrapper (see `CRUBIT_SIZED_BY` and
+  // `CRUBIT_ALIGNED_TO` in `support/annotations.h`):
+  //
+  // * Whether the pointer is annotated as non-null (e.g. with `_Nonnull`).
+  bool is_nonnull = false;
+  // * The parameter holding the number of elements pointed to.
+  std::optional<Identifier> sized_by;
+  // * The alignment of the pointer, in bytes.
+  std::optional<uint64_t> alignment;
+
+  // For a C-style callback (a function pointer): the `void*` parameter passed
+  // back to the callback as its context (see `CRUBIT_CALLBACK`), and whether
+  // the callback may be called after the function returns.
+  std::optional<Identifier> callback_context;
+  bool is_callback_retained = false;
+
+  // For a `std::function` parameter: whether the function only calls it
+  // during the call, without retaining it (see `CRUBIT_BORROWED_CALLABLE`).
+  bool is_callable_borrowed = false;
 };
 
 inline std::ostream& operator<<(std::ostream& o, const FuncParam& param) {
@@ -396,6 +477,15 @@ std::ostream& operator<<(std::ostream& o, const SpecialName& special_name);
 using UnqualifiedIdentifier = std::variant<Identifier, Operator, SpecialName>;
 llvm::json::Value toJSON(const UnqualifiedIdentifier& unqualified_identifier);
 
+// Access specifier for a member or base class.
+enum AccessSpecifier {
+  kPublic,
+  kProtected,
+  kPrivate,
+};
+
+std::ostream& operator<<(std::ostream& o, const AccessSpecifier& access);
+
 struct MemberFuncMetadata {
   enum ReferenceQualification : char {
     kLValue,       // void Foo() &;
@@ -423,6 +513,58 @@ struct MemberFuncMetadata {
   //
   // If null, this is a static method.
   std::optional<InstanceMethodMetadata> instance_method_metadata;
+
+  // Whether this is a static member function. Static member functions don't
+  // have a `__this` parameter, and their bindings are associated functions of
+  // the record (e.g. `impl SomeStruct { pub fn StaticMethod() }`).
+  bool is_static = false;
+
+  // Non-public member functions are only imported for friend crates (see
+  // `--friend_crate`).
+  AccessSpecifier access = kPublic;
+};
+
+// The body of an inline function which only forwards to something else (a
+// field, a constant, or another function). The generated bindings may
+// reimplement such functions directly in Rust, instead of calling a thunk.
+struct TrivialBody {
+  enum Kind : char {
+    kReturnsField,     // int x() const { return x_; }
+    kSetsField,        // void set_x(int x) { x_ = x; }
+    kReturnsConstant,  // int answer() { return 42; }
+    kForwardsCall,     // int Foo(int x, int y) { return Bar(x, y); }
+  };
+
+  llvm::json::Value ToJson() const;
+
+  Kind kind;
+
+  // For `kReturnsField` and `kSetsField`: the name of the field of `*this`.
+  // The field is a non-bitfield field declared directly in the record of the
+  // method, and its type is the return type (for `kReturnsField`) or the type
+  // of the parameter (for `kSetsField`), ignoring references and qualifiers.
+  std::optional<Identifier> field;
+
+  // For `kReturnsConstant`: the returned integer or boolean value, spelled as
+  // a literal (e.g. `-1` or `true`).
+  std::optional<std::string> constant;
+
+  // For `kForwardsCall`: the function that is called with all the parameters,
+  // in order.
+  std::optional<ItemId> callee;
+};
+
+// The bindings annotations of an item (see `CRUBIT_RUST_NAME`, `CRUBIT_SKIP`
+// and `CRUBIT_DOC_HIDDEN` in `support/annotations.h`).
+struct ItemAnnotations {
+  llvm::json::Value ToJson() const;
+
+  // The name of the generated Rust item, if it differs from the C++ name.
+  std::optional<std::string> rust_name;
+  // True if no bindings should be generated for the item.
+  bool skip = false;
+  // True if the generated Rust item should be `#[doc(hidden)]`.
+  bool doc_hidden = false;
 };
 
 // A function involved in the bindings.
@@ -432,11 +574,17 @@ struct Func {
   UnqualifiedIdentifier name;
   BazelLabel owning_target;
   std::optional<std::string> doc_comment;
+  // The message of the `[[deprecated]]` attribute, if any (see also
+  // `GetDeprecationMessage`).
+  std::optional<std::string> deprecated;
   std::string mangled_name;
   MappedType return_type;
   std::vector<FuncParam> params;
   std::vector<LifetimeName> lifetime_params;
   bool is_inline;
+  // True if the function can't throw (e.g. because it is declared `noexcept`,
+  // or because it is a destructor).
+  bool is_noexcept = false;
   // If null, this is not a member function.
   std::optional<MemberFuncMetadata> member_func_metadata;
   bool has_c_calling_convention = true;
@@ -451,21 +599,51 @@ struct Func {
   // Rust type modeling in src_code_gen makes it much easier to do on the
   // consuming end.
   std::optional<ItemId> adl_enclosing_record;
+  // If present, the body of this (inline) function trivially forwards to
+  // something else.
+  std::optional<TrivialBody> trivial_body;
+  // True if the function returns a reference (or a pointer) to a
+  // function-local static variable, which is lazily initialized on the first
+  // call (see `FunctionDeclImporter::ReturnsStaticLocal`).
+  bool returns_static_local = false;
+  // True if the pointer parameters of the function are known to be non-null
+  // and not retained after the call (see `CRUBIT_SAFE_POINTER_PARAMS`).
+  bool has_safe_pointer_params = false;
+  // True for C-style variadic functions (e.g. `printf(const char*, ...)`).
+  bool is_variadic = false;
+  // True if the function has internal linkage (e.g. a `static` function, or a
+  // function in an anonymous namespace), so its symbol is local to each
+  // translation unit that defines it.
+  bool has_internal_linkage = false;
+  // True if the function has hidden visibility (e.g.
+  // `__attribute__((visibility("hidden")))`), so its symbol isn't exported
+  // from the shared object that defines it.
+  bool has_hidden_visibility = false;
+  // True if the function has C language linkage (e.g. it is declared in an
+  // `extern "C"` block of a C header), so its symbol is its unmangled name.
+  bool is_extern_c = false;
+  // The precondition of the function (a C++ expression over its parameters),
+  // which the function CHECK-fails on (see `CRUBIT_PRECONDITION`).
+  std::optional<std::string> precondition;
+  // The base class which declares the function, if it is a member function of
+  // a derived class (`member_func_metadata->record_id`) by virtue of a
+  // using-declaration (`using Base::Method;`). Such functions are always
+  // called through a thunk, which converts `this` to the base class.
+  std::optional<ItemId> inherited_from;
+  // The function which deletes the pointer returned by the function, if the
+  // caller takes ownership of it (see `CRUBIT_OWNED_RESULT`). This is either
+  // the name of a function taking the pointer, or `delete`.
+  std::optional<std::string> result_deleter;
+  // The paths of the Rust token types of the capabilities which have to be
+  // held to call the function (see `CRUBIT_REQUIRES_CAPABILITY`).
+  std::vector<std::string> required_capabilities;
+  ItemAnnotations annotations;
 };
 
 inline std::ostream& operator<<(std::ostream& o, const Func& f) {
   return o << std::string(llvm::formatv("{0:2}", f.ToJson()));
 }
 
-// Access specifier for a member or base class.
-enum AccessSpecifier {
-  kPublic,
-  kProtected,
-  kPrivate,
-};
-
-std::ostream& operator<<(std::ostream& o, const AccessSpecifier& access);
-
 // A field (non-static member variable) of a record.
 struct Field {
   llvm::json::Value ToJson() const;
@@ -476,6 +654,7 @@ struct Field {
   std::optional<Identifier> identifier;
 
   std::optional<std::string> doc_comment;
+  std::optional<std::string> deprecated;
   absl::StatusOr<MappedType> type;
   AccessSpecifier access;
   uint64_t offset;            // Field offset in bits.
@@ -483,6 +662,13 @@ struct Field {
   bool is_no_unique_address;  // True if the field is [[no_unique_address]].
   bool is_bitfield;           // True if the field is a bitfield.
   bool is_inheritable;        // True if the field is inheritable.
+
+  // The members of an anonymous struct or union (e.g. `union { int i; float
+  // f; };`), which are accessible as if they were members of the enclosing
+  // record. Their offsets are relative to the enclosing record.
+  std::vector<Field> anonymous_members;
+  // True if the field is an anonymous union.
+  bool is_anonymous_union = false;
 };
 
 inline std::ostream& operator<<(std::ostream& o, const Field& f) {
@@ -550,7 +736,8 @@ struct Record {
 
   // `rs_name` and `cc_name` are typically equal, but they may be different for
   // template instantiations (when `cc_name` is similar to `MyStruct<int>` and
-  // `rs_name` is similar to "__CcTemplateInst8MyStructIiE").
+  // `rs_name` is similar to "__CcTemplateInst8MyStructIiE"), or for
+  // records annotated with `CRUBIT_RUST_NAME` (see `annotations`).
   std::string rs_name;
   std::string cc_name;
   std::string mangled_cc_name;
@@ -558,6 +745,7 @@ struct Record {
   ItemId id;
   BazelLabel owning_target;
   std::optional<std::string> doc_comment;
+  std::optional<std::string> deprecated;
   std::string source_loc;
   std::vector<BaseClass> unambiguous_public_bases;
   std::vector<Field> fields;
@@ -584,6 +772,16 @@ struct Record {
   // More information: docs/struct_layout
   bool override_alignment = false;
 
+  // True if the layout of the fields can't be reproduced by a `#[repr(C)]`
+  // Rust struct: a field overlaps the previous one (e.g. an empty
+  // [[no_unique_address]] field placed at offset 0), or isn't aligned to the
+  // alignment of its type (e.g. in a `__attribute__((packed))` struct). Such a
+  // record is laid out as an opaque blob of bytes, and its fields are accessed
+  // through accessors instead.
+  //
+  // More information: docs/struct_layout
+  bool needs_opaque_layout = false;
+
   // Special member functions.
   SpecialMemberFunc copy_constructor = SpecialMemberFunc::kUnavailable;
   SpecialMemberFunc move_constructor = SpecialMemberFunc::kUnavailable;
@@ -623,13 +821,50 @@ struct Record {
   // It is an anoymous record with a typedef name.
   bool is_anon_record_with_typedef = false;
 
+  // True when this record is an instantiation of a class template. Such
+  // records are generated into the `__instantiations` module, under a name
+  // derived from the mangled name of the instantiated type.
+  bool is_template_instantiation = false;
+
   // True when this record is created from an explicit class template
   // instantiation definition (which is also what cc_template!{} macro results
   // in).
   bool is_explicit_class_template_instantiation_definition = false;
 
+  // True if the assignment operators of this record may throw, and may leave
+  // the object in an invalid state when they do (see
+  // `CRUBIT_THROWING_ASSIGNMENT` in `support/annotations.h`).
+  bool has_throwing_assignment = false;
+
+  // True if bindings should include helpers to reinterpret this record as
+  // bytes (see `CRUBIT_BYTES_SERIALIZABLE` in `support/annotations.h`).
+  bool is_bytes_serializable = false;
+
+  // True if this record is default-constructible and has a stream extraction
+  // operator (`std::istream& operator>>(std::istream&, T&)`), which bindings
+  // use to implement `FromStr`.
+  bool is_stream_extractable = false;
+
+  // True if bindings should include a Rust implementation of this logging
+  // interface (see `CRUBIT_LOG_SINK` in `support/annotations.h`).
+  bool is_log_sink = false;
+
+  // True if this record is a plain C struct or union with C language linkage
+  // (e.g. declared in an `extern "C"` block of a C header), without any C++
+  // features (see `clang::CXXRecordDecl::isCLike`). The bindings of such
+  // records are plain `Copy` data, without bindings for the special member
+  // functions.
+  bool is_extern_c = false;
+
   std::vector<ItemId> child_item_ids;
   std::optional<ItemId> enclosing_namespace_id;
+
+  // The record in which this record is nested (e.g. `Outer` for
+  // `Outer::Inner`), if any. The bindings of nested records are generated into
+  // a module named after the enclosing record (e.g. `outer::Inner`).
+  std::optional<ItemId> enclosing_record_id;
+
+  ItemAnnotations annotations;
 };
 
 // A forward-declared record (e.g. `struct Foo;`)
@@ -640,7 +875,10 @@ struct IncompleteRecord {
   ItemId id;
   BazelLabel owning_target;
   RecordType record_type;
+  bool is_template_instantiation = false;
   std::optional<ItemId> enclosing_namespace_id;
+  // See `Record::enclosing_record_id`.
+  std::optional<ItemId> enclosing_record_id;
 };
 
 struct Enumerator {
@@ -657,9 +895,14 @@ struct Enum {
   ItemId id;
   BazelLabel owning_target;
   std::string source_loc;
+  std::optional<std::string> deprecated;
   MappedType underlying_type;
   std::vector<Enumerator> enumerators;
+  // True if the enum is annotated with `CRUBIT_ERROR_CODE` (see
+  // `support/annotations.h`).
+  bool is_error_code = false;
   std::optional<ItemId> enclosing_namespace_id;
+  ItemAnnotations annotations;
 };
 
 inline std::ostream& operator<<(std::ostream& o, const Record& r) {
@@ -678,12 +921,49 @@ struct TypeAlias {
   std::string source_loc;
   std::optional<ItemId> enclosing_record_id;
   std::optional<ItemId> enclosing_namespace_id;
+  ItemAnnotations annotations;
 };
 
 inline std::ostream& operator<<(std::ostream& o, const TypeAlias& t) {
   return o << std::string(llvm::formatv("{0:2}", t.ToJson()));
 }
 
+// The value of a string constant: a C string (`const char*`), which is bound as
+// a `&'static CStr`, or a `std::string_view`, which is bound as a
+// `&'static str`.
+struct StringConstant {
+  llvm::json::Value ToJson() const;
+
+  bool is_c_string;
+  // The bytes of the string. For C strings, this excludes the terminating NUL
+  // (and anything after it).
+  std::string bytes;
+};
+
+// A compile-time constant (e.g. a `constexpr` variable at namespace scope).
+//
+// The value is evaluated during import, so initializers like `sizeof(T)` or
+// `alignof(T)` are evaluated using the layout of the target platform.
+struct Constant {
+  llvm::json::Value ToJson() const;
+
+  Identifier identifier;
+  ItemId id;
+  BazelLabel owning_target;
+  std::optional<std::string> doc_comment;
+  // The type of the constant. For string constants, this is `const char*`
+  // (the type of a pointer to the characters), regardless of the C++ type.
+  MappedType type;
+  std::variant<IntegerConstant, StringConstant> value;
+  std::string source_loc;
+  std::optional<ItemId> enclosing_namespace_id;
+  ItemAnnotations annotations;
+};
+
+inline std::ostream& operator<<(std::ostream& o, const Constant& c) {
+  return o << std::string(llvm::formatv("{0:2}", c.ToJson()));
+}
+
 // A placeholder for an item that we can't generate bindings for (yet)
 struct UnsupportedItem {
   llvm::json::Value ToJson() const;
@@ -747,6 +1027,34 @@ inline std::ostream& operator<<(std::ostream& o, const UseMod& use_mod) {
   return o << std::string(llvm::formatv("{0:2}", use_mod.ToJson()));
 }
 
+// A preprocessor conditional directive (`#if`, `#ifdef`, `#ifndef`, `#elif`,
+// or `#else`) in a header of the current target.
+//
+// These are recorded only to explain why a declaration is (or isn't) in the
+// bindings, and are not used by the code generator.
+struct PreprocessorConditional {
+  llvm::json::Value ToJson() const;
+
+  // The name of the directive, e.g. `ifdef`.
+  std::string directive;
+  // The condition of the directive, as spelled in the header (empty for
+  // `#else`).
+  std::string condition;
+  // Whether the lines following the directive were compiled. An `#elif`
+  // following a taken branch is not evaluated, and is not taken.
+  bool taken;
+  std::string source_loc;
+};
+
+// The version of the schema of the JSON IR, which is recorded in the IR as
+// `schema_version` (see `IR_SCHEMA_VERSION` in `ir.rs`).
+//
+// Adding a field doesn't change the schema version if `ir.rs` gives it a
+// default value (`#[serde(default)]`) for the IRs of older importers, because
+// the fields which are unknown to `ir.rs` are ignored. Renaming or removing a
+// field, changing its type, or adding a kind of item does.
+inline constexpr int kIrSchemaVersion = 1;
+
 // A complete intermediate representation of bindings for publicly accessible
 // declarations of a single C++ library.
 struct IR {
@@ -770,10 +1078,28 @@ struct IR {
   // preserved.
   std::vector<HeaderName> public_headers;
 
+  // Names of the C++20 modules that were imported (in addition to
+  // `public_headers`) to construct the AST of this `IR`.
+  //
+  // In production, these come from the `--module_interfaces` cmdline flag.
+  std::vector<std::string> imported_modules;
+
+  // The `-D` and `-U` flags which override the preprocessor definitions of the
+  // current target.
+  //
+  // In production, these come from the `--preprocessor_overrides` cmdline
+  // flag.
+  std::vector<std::string> preprocessor_overrides;
+
+  // The conditional directives of the headers of the current target that were
+  // seen by the preprocessor, in the order in which they were seen.
+  std::vector<PreprocessorConditional> preprocessor_conditionals;
+
   BazelLabel current_target;
 
-  using Item = std::variant<Func, Record, IncompleteRecord, Enum, TypeAlias,
-                            UnsupportedItem, Comment, Namespace, UseMod>;
+  using Item =
+      std::variant<Func, Record, IncompleteRecord, Enum, TypeAlias, Constant,
+                   UnsupportedItem, Comment, Namespace, UseMod>;
   std::vector<Item> items;
   std::vector<ItemId> top_level_item_ids;
   // Empty string signals that the bindings should be generated in the crate
@@ -799,6 +1125,19 @@ inline std::ostream& operator<<(std::ostream& o, const IR& ir) {
   return o << IrToJson(ir);
 }
 
+// The first byte of an IR in the binary format, which can't start a JSON IR
+// (see `BINARY_IR_HEADER` in `ir.rs`).
+inline constexpr char kBinaryIrHeader = '\0';
+
+// Returns the IR in the binary format, which is faster to deserialize than JSON
+// for large translation units. The format encodes the JSON IR, so that both
+// deserialize the same (see `binary_ir.rs`).
+std::string IrToBinary(const IR& ir);
+
+// Returns the binary encoding of `value`, which `IrToBinary` prefixes with
+// `kBinaryIrHeader`.
+std::string JsonToBinaryIr(const llvm::json::Value& value);
+
 // Utility function to convert items to string.
 std::string ItemToString(const IR::Item& item);
 inline std::string ItemToString(const std::optional<IR::Item>& item) {
@@ -7,20 +7,117 @@
 //! information.
 
 use arc_anyhow::{anyhow, bail, Context, Error, Result};
-use once_cell::unsync::OnceCell;
+use once_cell::sync::OnceCell;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use serde::Deserialize;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer};
+use std::cell::RefCell;
 use std::collections::hash_map::{Entry, HashMap};
+use std::collections::HashSet;
 use std::convert::TryFrom;
 use std::fmt::{self, Debug, Formatter};
 use std::hash::{Hash, Hasher};
 use std::io::Read;
-use std::rc::Rc;
+use std::sync::Arc;
 
-/// Deserialize `IR` from JSON given as a reader.
-pub fn deserialize_ir<R: Read>(reader: R) -> Result<IR> {
-    let flat_ir = serde_json::from_reader(reader)?;
+mod binary_ir;
+mod ir_validation;
+
+pub use ir_validation::{validate, Diagnostic};
+
+/// The version of the schema of the JSON IR which this crate deserializes. See
+/// `kIrSchemaVersion` in `ir.h`, which has to be the same.
+pub const IR_SCHEMA_VERSION: u32 = 1;
+
+/// The oldest version of the schema of the JSON IR which this crate still
+/// deserializes.
+pub const OLDEST_SUPPORTED_IR_SCHEMA_VERSION: u32 = 1;
+
+/// The schema version of the IRs which don't record it, which were produced
+/// before the schema was versioned.
+fn unversioned_ir_schema_version() -> u32 {
+    1
+}
+
+/// Returns an error if IRs of the schema `version` can't be deserialized.
+fn check_ir_schema_version(version: u32) -> Result<()> {
+    if version > IR_SCHEMA_VERSION {
+        bail!(
+            "The IR was produced by a newer importer (IR schema version {version}, but the \
+             bindings generator only supports versions {OLDEST_SUPPORTED_IR_SCHEMA_VERSION} to \
+             {IR_SCHEMA_VERSION}). Update the bindings generator, or use an older importer."
+        );
+    }
+    if version < OLDEST_SUPPORTED_IR_SCHEMA_VERSION {
+        bail!(
+            "The IR was produced by an older importer (IR schema version {version}, but the \
+             bindings generator only supports versions {OLDEST_SUPPORTED_IR_SCHEMA_VERSION} to \
+             {IR_SCHEMA_VERSION}). Regenerate the IR with a newer importer."
+        );
+    }
+    Ok(())
+}
+
+/// The first byte of an IR in the binary format (see `IrToBinary` in `ir.h`),
+/// which can't start a JSON IR.
+pub const BINARY_IR_HEADER: u8 = 0;
+
+/// Deserialize `IR` given as a reader, either from JSON or from the binary
+/// format (which starts with `BINARY_IR_HEADER`).
+pub fn deserialize_ir<R: Read>(mut reader: R) -> Result<IR> {
+    let mut bytes = vec![];
+    reader.read_to_end(&mut bytes).context("Failed to read the IR")?;
+    if bytes.first() == Some(&BINARY_IR_HEADER) {
+        return deserialize_ir_binary(&bytes);
+    }
+    deserialize_versioned_ir(serde_json::from_slice::<FlatIR>(&bytes), || {
+        serde_json::from_slice::<SchemaVersion>(&bytes).ok()
+    })
+}
+
+/// Deserialize `IR` from the binary format, which is faster to parse than JSON
+/// for large translation units (see `ir_benchmark.rs`).
+pub fn deserialize_ir_binary(bytes: &[u8]) -> Result<IR> {
+    let Some((&BINARY_IR_HEADER, binary)) = bytes.split_first() else {
+        bail!("The IR doesn't start with the header of the binary format");
+    };
+    deserialize_versioned_ir(binary_ir::from_slice::<FlatIR>(binary), || {
+        binary_ir::from_slice::<SchemaVersion>(binary).ok()
+    })
+}
+
+/// Converts a JSON IR to the binary format.
+pub fn json_ir_to_binary(json: &[u8]) -> Result<Vec<u8>> {
+    let value = serde_json::from_slice::<serde_json::Value>(json)?;
+    let mut bytes = vec![BINARY_IR_HEADER];
+    bytes.extend(binary_ir::from_json(&value));
+    Ok(bytes)
+}
+
+#[derive(Deserialize)]
+struct SchemaVersion {
+    #[serde(default = "unversioned_ir_schema_version")]
+    schema_version: u32,
+}
+
+fn deserialize_versioned_ir<E: std::error::Error + Send + Sync + 'static>(
+    flat_ir: std::result::Result<FlatIR, E>,
+    schema_version: impl FnOnce() -> Option<SchemaVersion>,
+) -> Result<IR> {
+    INTERNED_TYPES.with(|types| types.take());
+    let flat_ir = match flat_ir {
+        Ok(flat_ir) => flat_ir,
+        Err(err) => {
+            // An IR of another schema version likely fails to deserialize, in
+            // which case the version is a better explanation than the error.
+            if let Some(SchemaVersion { schema_version }) = schema_version() {
+                check_ir_schema_version(schema_version)?;
+            }
+            return Err(err.into());
+        }
+    };
+    check_ir_schema_version(flat_ir.schema_version)?;
     make_ir(flat_ir)
 }
 
@@ -31,9 +128,18 @@ pub fn make_ir_from_parts(
     public_headers: Vec<HeaderName>,
     current_target: BazelLabel,
     top_level_item_ids: Vec<ItemId>,
-    crate_root_path: Option<Rc<str>>,
+    crate_root_path: Option<Arc<str>>,
 ) -> Result<IR> {
-    make_ir(FlatIR { public_headers, current_target, items, top_level_item_ids, crate_root_path })
+    make_ir(FlatIR {
+        schema_version: IR_SCHEMA_VERSION,
+        public_headers,
+        imported_modules: vec![],
+        interned_types: (),
+        current_target,
+        items,
+        top_level_item_ids,
+        crate_root_path,
+    })
 }
 
 fn make_ir(flat_ir: FlatIR) -> Result<IR> {
@@ -51,6 +157,13 @@ fn make_ir(flat_ir: FlatIR) -> Result<IR> {
         .collect::<HashMap<_, _>>();
 
     let mut lifetimes: HashMap<LifetimeId, LifetimeName> = HashMap::new();
+    // `'static` isn't a lifetime parameter of any item, but types can still
+    // refer to it (e.g. the return type of a function annotated with
+    // `[[clang::annotate("lifetimes", "a -> static")]]`).
+    lifetimes.insert(
+        LifetimeId::STATIC,
+        LifetimeName { name: "static".into(), id: LifetimeId::STATIC },
+    );
     for item in &flat_ir.items {
         let lifetime_params = match item {
             Item::Record(record) => &record.lifetime_params,
@@ -98,29 +211,36 @@ fn make_ir(flat_ir: FlatIR) -> Result<IR> {
         lifetimes,
         namespace_id_to_number_of_reopened_namespaces,
         reopened_namespace_id_to_idx,
+        dependency_irs: HashMap::new(),
     })
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
 pub struct HeaderName {
-    pub name: Rc<str>,
+    pub name: Arc<str>,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Deserialize)]
 #[serde(transparent)]
 pub struct LifetimeId(pub i32);
 
+impl LifetimeId {
+    /// The ID of the `'static` lifetime (see `Lifetime::Static()` in
+    /// `lifetime_annotations/lifetime.cc`).
+    pub const STATIC: LifetimeId = LifetimeId(-1);
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
 pub struct LifetimeName {
-    pub name: Rc<str>,
+    pub name: Arc<str>,
     pub id: LifetimeId,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
 pub struct RsType {
-    pub name: Option<Rc<str>>,
-    pub lifetime_args: Rc<[LifetimeId]>,
-    pub type_args: Rc<[RsType]>,
+    pub name: Option<Arc<str>>,
+    pub lifetime_args: Arc<[LifetimeId]>,
+    pub type_args: Arc<[RsType]>,
     pub decl_id: Option<ItemId>,
 }
 
@@ -132,7 +252,7 @@ impl RsType {
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
 pub struct CcType {
-    pub name: Option<Rc<str>>,
+    pub name: Option<Arc<str>>,
     pub is_const: bool,
     pub type_args: Vec<CcType>,
     pub decl_id: Option<ItemId>,
@@ -156,13 +276,66 @@ impl TypeWithDeclId for CcType {
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
 pub struct MappedType {
+    #[serde(deserialize_with = "deserialize_interned_rs_type")]
     pub rs_type: RsType,
+    #[serde(deserialize_with = "deserialize_interned_cc_type")]
     pub cc_type: CcType,
 }
 
+/// The deduplicated types of an IR. `MappedType`s refer to them by their index,
+/// so that identical type trees are only serialized (and parsed) once.
+#[derive(Debug, Default, Deserialize)]
+struct InternedTypes {
+    rs_types: Vec<RsType>,
+    cc_types: Vec<CcType>,
+}
+
+thread_local! {
+    /// The `InternedTypes` of the IR which is being deserialized. They precede
+    /// the `items` of the IR in its JSON (whose keys are sorted), so they are
+    /// available by the time the `MappedType`s are deserialized.
+    static INTERNED_TYPES: RefCell<InternedTypes> = RefCell::default();
+}
+
+fn deserialize_interned_types<'de, D: Deserializer<'de>>(deserializer: D) -> Result<(), D::Error> {
+    let types = InternedTypes::deserialize(deserializer)?;
+    INTERNED_TYPES.with(|interned_types| interned_types.replace(types));
+    Ok(())
+}
+
+/// A type, or its index in the `InternedTypes`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MaybeInterned<T> {
+    Index(usize),
+    Type(T),
+}
+
+fn deserialize_interned_rs_type<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<RsType, D::Error> {
+    match MaybeInterned::deserialize(deserializer)? {
+        MaybeInterned::Type(rs_type) => Ok(rs_type),
+        MaybeInterned::Index(index) => INTERNED_TYPES
+            .with(|types| types.borrow().rs_types.get(index).cloned())
+            .ok_or_else(|| D::Error::custom(format!("Invalid interned RsType index: {index}"))),
+    }
+}
+
+fn deserialize_interned_cc_type<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<CcType, D::Error> {
+    match MaybeInterned::deserialize(deserializer)? {
+        MaybeInterned::Type(cc_type) => Ok(cc_type),
+        MaybeInterned::Index(index) => INTERNED_TYPES
+            .with(|types| types.borrow().cc_types.get(index).cloned())
+            .ok_or_else(|| D::Error::custom(format!("Invalid interned CcType index: {index}"))),
+    }
+}
+
 #[derive(PartialEq, Eq, Hash, Clone, Deserialize)]
 pub struct Identifier {
-    pub identifier: Rc<str>,
+    pub identifier: Arc<str>,
 }
 
 impl fmt::Debug for Identifier {
@@ -179,7 +352,7 @@ pub struct IntegerConstant {
 
 #[derive(PartialEq, Eq, Hash, Clone, Deserialize)]
 pub struct Operator {
-    pub name: Rc<str>,
+    pub name: Arc<str>,
 }
 
 impl Operator {
@@ -198,6 +371,8 @@ impl fmt::Debug for Operator {
     }
 }
 
+/// A stable identifier of an item: the same item has the same `ItemId` in the IR
+/// of every target which imports it (see `GenerateItemId` in `ir.h`).
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Deserialize)]
 #[serde(transparent)]
 pub struct ItemId(usize);
@@ -216,7 +391,7 @@ impl ToTokens for ItemId {
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
 #[serde(transparent)]
-pub struct BazelLabel(pub Rc<str>);
+pub struct BazelLabel(pub Arc<str>);
 
 impl BazelLabel {
     pub fn target_name(&self) -> &str {
@@ -279,6 +454,8 @@ pub struct InstanceMethodMetadata {
 pub struct MemberFuncMetadata {
     pub record_id: ItemId,
     pub instance_method_metadata: Option<InstanceMethodMetadata>,
+    pub is_static: bool,
+    pub access: AccessSpecifier,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
@@ -286,14 +463,63 @@ pub struct FuncParam {
     #[serde(rename(deserialize = "type"))]
     pub type_: MappedType,
     pub identifier: Identifier,
+    pub is_nonnull: bool,
+    pub sized_by: Option<Identifier>,
+    pub alignment: Option<u64>,
+    pub callback_context: Option<Identifier>,
+    pub is_callback_retained: bool,
+    pub is_callable_borrowed: bool,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize)]
+pub enum TrivialBodyKind {
+    ReturnsField,
+    SetsField,
+    ReturnsConstant,
+    ForwardsCall,
+}
+
+/// The body of an inline function which only forwards to something else (a
+/// field, a constant, or another function).
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
+pub struct TrivialBody {
+    pub kind: TrivialBodyKind,
+    /// For `ReturnsField` and `SetsField`: the name of the field of `*this`.
+    pub field: Option<Identifier>,
+    /// For `ReturnsConstant`: the returned integer or boolean literal.
+    pub constant: Option<Arc<str>>,
+    /// For `ForwardsCall`: the function called with all the parameters.
+    pub callee: Option<ItemId>,
+}
+
+/// The bindings annotations of an item (see `CRUBIT_RUST_NAME`, `CRUBIT_SKIP`
+/// and `CRUBIT_DOC_HIDDEN` in `support/annotations.h`).
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Deserialize)]
+pub struct ItemAnnotations {
+    /// The name of the generated Rust item, if it differs from the C++ name.
+    pub rust_name: Option<Arc<str>>,
+    /// True if no bindings are generated for the item.
+    pub skip: bool,
+    /// True if the generated Rust item is `#[doc(hidden)]`.
+    pub doc_hidden: bool,
+}
+
+impl ItemAnnotations {
+    /// Returns the name of the generated Rust item of the C++ item named
+    /// `cc_name`.
+    pub fn rs_name<'a>(&'a self, cc_name: &'a str) -> &'a str {
+        self.rust_name.as_deref().unwrap_or(cc_name)
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
 pub struct Func {
     pub name: UnqualifiedIdentifier,
     pub owning_target: BazelLabel,
-    pub mangled_name: Rc<str>,
-    pub doc_comment: Option<Rc<str>>,
+    pub mangled_name: Arc<str>,
+    pub doc_comment: Option<Arc<str>>,
+    /// The message of the `[[deprecated]]` attribute, if any.
+    pub deprecated: Option<Arc<str>>,
     pub return_type: MappedType,
     pub params: Vec<FuncParam>,
     /// For tests and internal use only.
@@ -303,21 +529,32 @@ pub struct Func {
     /// not originally part of the IR.
     pub lifetime_params: Vec<LifetimeName>,
     pub is_inline: bool,
+    pub is_noexcept: bool,
     pub member_func_metadata: Option<MemberFuncMetadata>,
     pub has_c_calling_convention: bool,
     pub is_member_or_descendant_of_class_template: bool,
-    pub source_loc: Rc<str>,
+    pub source_loc: Arc<str>,
     pub id: ItemId,
     pub enclosing_namespace_id: Option<ItemId>,
     pub adl_enclosing_record: Option<ItemId>,
+    pub trivial_body: Option<TrivialBody>,
+    pub returns_static_local: bool,
+    pub has_safe_pointer_params: bool,
+    pub is_variadic: bool,
+    pub has_internal_linkage: bool,
+    pub has_hidden_visibility: bool,
+    pub is_extern_c: bool,
+    pub precondition: Option<Arc<str>>,
+    pub inherited_from: Option<ItemId>,
+    pub result_deleter: Option<Arc<str>>,
+    pub required_capabilities: Vec<Arc<str>>,
+    #[serde(default)]
+    pub annotations: ItemAnnotations,
 }
 
 impl Func {
     pub fn is_instance_method(&self) -> bool {
-        self.member_func_metadata
-            .as_ref()
-            .filter(|meta| meta.instance_method_metadata.is_some())
-            .is_some()
+        self.member_func_metadata.as_ref().is_some_and(|meta| !meta.is_static)
     }
 }
 
@@ -331,7 +568,8 @@ pub enum AccessSpecifier {
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
 pub struct Field {
     pub identifier: Option<Identifier>,
-    pub doc_comment: Option<Rc<str>>,
+    pub doc_comment: Option<Arc<str>>,
+    pub deprecated: Option<Arc<str>>,
     #[serde(rename(deserialize = "type"))]
     pub type_: Result<MappedType, String>,
     pub access: AccessSpecifier,
@@ -342,6 +580,8 @@ pub struct Field {
     // TODO(kinuko): Consider removing this, it is a duplicate of the same information
     // in `Record`.
     pub is_inheritable: bool,
+    pub anonymous_members: Vec<Field>,
+    pub is_anonymous_union: bool,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
@@ -360,12 +600,14 @@ pub struct BaseClass {
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
 pub struct IncompleteRecord {
-    pub cc_name: Rc<str>,
-    pub rs_name: Rc<str>,
+    pub cc_name: Arc<str>,
+    pub rs_name: Arc<str>,
     pub id: ItemId,
     pub owning_target: BazelLabel,
     pub record_type: RecordType,
+    pub is_template_instantiation: bool,
     pub enclosing_namespace_id: Option<ItemId>,
+    pub enclosing_record_id: Option<ItemId>,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Deserialize)]
@@ -388,13 +630,14 @@ impl ToTokens for RecordType {
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
 pub struct Record {
-    pub rs_name: Rc<str>,
-    pub cc_name: Rc<str>,
-    pub mangled_cc_name: Rc<str>,
+    pub rs_name: Arc<str>,
+    pub cc_name: Arc<str>,
+    pub mangled_cc_name: Arc<str>,
     pub id: ItemId,
     pub owning_target: BazelLabel,
-    pub doc_comment: Option<Rc<str>>,
-    pub source_loc: Rc<str>,
+    pub doc_comment: Option<Arc<str>>,
+    pub deprecated: Option<Arc<str>>,
+    pub source_loc: Arc<str>,
     pub unambiguous_public_bases: Vec<BaseClass>,
     pub fields: Vec<Field>,
     pub lifetime_params: Vec<LifetimeName>,
@@ -403,6 +646,7 @@ pub struct Record {
     pub alignment: usize,
     pub is_derived_class: bool,
     pub override_alignment: bool,
+    pub needs_opaque_layout: bool,
     pub copy_constructor: SpecialMemberFunc,
     pub move_constructor: SpecialMemberFunc,
     pub destructor: SpecialMemberFunc,
@@ -412,8 +656,17 @@ pub struct Record {
     pub record_type: RecordType,
     pub is_aggregate: bool,
     pub is_anon_record_with_typedef: bool,
+    pub is_template_instantiation: bool,
+    pub has_throwing_assignment: bool,
+    pub is_bytes_serializable: bool,
+    pub is_stream_extractable: bool,
+    pub is_log_sink: bool,
+    pub is_extern_c: bool,
     pub child_item_ids: Vec<ItemId>,
     pub enclosing_namespace_id: Option<ItemId>,
+    pub enclosing_record_id: Option<ItemId>,
+    #[serde(default)]
+    pub annotations: ItemAnnotations,
 }
 
 impl Record {
@@ -435,7 +688,9 @@ impl Record {
     ///
     ///    (In future versions, we could also include types which are POD for
     ///    the purpose of layout, but this is less predictable to C++ users,
-    ///    and ABI-specific.)
+    ///    and ABI-specific.) Plain C records (see `is_extern_c`) are the
+    ///    exception: they are meant to be used as plain data, and, being POD
+    ///    for the purpose of layout, their tail padding is never reused.
     ///
     ///    We are assuming, for the moment, that no object is stored in a
     ///    `[[no_unique_address]]` variable. Much like packed structs and
@@ -444,7 +699,9 @@ impl Record {
     ///
     /// Described in more detail at: docs/unpin
     pub fn is_unpin(&self) -> bool {
-        self.is_trivial_abi && !self.is_inheritable && self.fields.iter().all(|f| !f.is_inheritable)
+        self.is_trivial_abi
+            && (self.is_extern_c
+                || (!self.is_inheritable && self.fields.iter().all(|f| !f.is_inheritable)))
     }
 
     pub fn is_union(&self) -> bool {
@@ -460,10 +717,14 @@ pub struct Enum {
     pub identifier: Identifier,
     pub id: ItemId,
     pub owning_target: BazelLabel,
-    pub source_loc: Rc<str>,
+    pub source_loc: Arc<str>,
+    pub deprecated: Option<Arc<str>>,
     pub underlying_type: MappedType,
     pub enumerators: Vec<Enumerator>,
+    pub is_error_code: bool,
     pub enclosing_namespace_id: Option<ItemId>,
+    #[serde(default)]
+    pub annotations: ItemAnnotations,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
@@ -477,11 +738,44 @@ pub struct TypeAlias {
     pub identifier: Identifier,
     pub id: ItemId,
     pub owning_target: BazelLabel,
-    pub doc_comment: Option<Rc<str>>,
+    pub doc_comment: Option<Arc<str>>,
     pub underlying_type: MappedType,
-    pub source_loc: Rc<str>,
+    pub source_loc: Arc<str>,
     pub enclosing_record_id: Option<ItemId>,
     pub enclosing_namespace_id: Option<ItemId>,
+    #[serde(default)]
+    pub annotations: ItemAnnotations,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
+pub struct Constant {
+    pub identifier: Identifier,
+    pub id: ItemId,
+    pub owning_target: BazelLabel,
+    pub doc_comment: Option<Arc<str>>,
+    #[serde(rename(deserialize = "type"))]
+    pub type_: MappedType,
+    pub value: ConstantValue,
+    pub source_loc: Arc<str>,
+    pub enclosing_namespace_id: Option<ItemId>,
+    #[serde(default)]
+    pub annotations: ItemAnnotations,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
+pub enum ConstantValue {
+    Integer(IntegerConstant),
+    String(StringConstant),
+}
+
+/// The value of a string constant: a C string (bound as a `&'static CStr`), or
+/// a `std::string_view` (bound as a `&'static str`).
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
+pub struct StringConstant {
+    pub is_c_string: bool,
+    /// The bytes of the string. For C strings, this excludes the terminating
+    /// NUL.
+    pub bytes: Vec<u8>,
 }
 
 /// A wrapper type that does not contribute to equality or hashing. All
@@ -509,16 +803,16 @@ impl<T> Hash for IgnoredField<T> {
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
 pub struct UnsupportedItem {
-    pub name: Rc<str>,
-    message: Rc<str>,
-    pub source_loc: Rc<str>,
+    pub name: Arc<str>,
+    message: Arc<str>,
+    pub source_loc: Arc<str>,
     pub id: ItemId,
     #[serde(skip)]
     cause: IgnoredField<OnceCell<Error>>,
 }
 
 impl UnsupportedItem {
-    pub fn new_with_message(name: &str, message: &str, source_loc: Rc<str>, id: ItemId) -> Self {
+    pub fn new_with_message(name: &str, message: &str, source_loc: Arc<str>, id: ItemId) -> Self {
         Self {
             name: name.into(),
             message: message.into(),
@@ -527,7 +821,7 @@ impl UnsupportedItem {
             cause: Default::default(),
         }
     }
-    pub fn new_with_cause(name: String, cause: Error, source_loc: Rc<str>, id: ItemId) -> Self {
+    pub fn new_with_cause(name: String, cause: Error, source_loc: Arc<str>, id: ItemId) -> Self {
         Self {
             name: name.into(),
             message: cause.to_string().into(),
@@ -547,7 +841,7 @@ impl UnsupportedItem {
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
 pub struct Comment {
-    pub text: Rc<str>,
+    pub text: Arc<str>,
     pub id: ItemId,
 }
 
@@ -565,32 +859,34 @@ pub struct Namespace {
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
 pub struct UseMod {
-    pub path: Rc<str>,
+    pub path: Arc<str>,
     pub mod_name: Identifier,
     pub id: ItemId,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
 pub enum Item {
-    Func(Rc<Func>),
-    IncompleteRecord(Rc<IncompleteRecord>),
-    Record(Rc<Record>),
-    Enum(Rc<Enum>),
-    TypeAlias(Rc<TypeAlias>),
-    UnsupportedItem(Rc<UnsupportedItem>),
-    Comment(Rc<Comment>),
-    Namespace(Rc<Namespace>),
-    UseMod(Rc<UseMod>),
+    Func(Arc<Func>),
+    IncompleteRecord(Arc<IncompleteRecord>),
+    Record(Arc<Record>),
+    Enum(Arc<Enum>),
+    TypeAlias(Arc<TypeAlias>),
+    Constant(Arc<Constant>),
+    UnsupportedItem(Arc<UnsupportedItem>),
+    Comment(Arc<Comment>),
+    Namespace(Arc<Namespace>),
+    UseMod(Arc<UseMod>),
 }
 
 impl Item {
-    fn id(&self) -> ItemId {
+    pub fn id(&self) -> ItemId {
         match self {
             Item::Func(func) => func.id,
             Item::IncompleteRecord(record) => record.id,
             Item::Record(record) => record.id,
             Item::Enum(enum_) => enum_.id,
             Item::TypeAlias(type_alias) => type_alias.id,
+            Item::Constant(constant) => constant.id,
             Item::UnsupportedItem(unsupported) => unsupported.id,
             Item::Comment(comment) => comment.id,
             Item::Namespace(namespace) => namespace.id,
@@ -605,12 +901,22 @@ impl Item {
             Item::Func(func) => func.enclosing_namespace_id,
             Item::Namespace(namespace) => namespace.enclosing_namespace_id,
             Item::TypeAlias(type_alias) => type_alias.enclosing_namespace_id,
+            Item::Constant(constant) => constant.enclosing_namespace_id,
             Item::Comment(..) => None,
             Item::UnsupportedItem(..) => None,
             Item::UseMod(..) => None,
         }
     }
 
+    /// Returns the record in which this item is nested, for nested records.
+    pub fn enclosing_record_id(&self) -> Option<ItemId> {
+        match self {
+            Item::Record(record) => record.enclosing_record_id,
+            Item::IncompleteRecord(record) => record.enclosing_record_id,
+            _ => None,
+        }
+    }
+
     /// Returns the target that this should generate source code in.
     pub fn owning_target(&self) -> Option<&BazelLabel> {
         match self {
@@ -619,21 +925,39 @@ impl Item {
             Item::Record(record) => Some(&record.owning_target),
             Item::Enum(e) => Some(&e.owning_target),
             Item::TypeAlias(type_alias) => Some(&type_alias.owning_target),
+            Item::Constant(constant) => Some(&constant.owning_target),
             Item::UnsupportedItem(..) => None,
             Item::Comment(..) => None,
             Item::Namespace(..) => None,
             Item::UseMod(..) => None,
         }
     }
+
+    /// Returns the bindings annotations of the item, for the kinds of items
+    /// which can be annotated.
+    pub fn annotations(&self) -> Option<&ItemAnnotations> {
+        match self {
+            Item::Func(func) => Some(&func.annotations),
+            Item::Record(record) => Some(&record.annotations),
+            Item::Enum(e) => Some(&e.annotations),
+            Item::TypeAlias(type_alias) => Some(&type_alias.annotations),
+            Item::Constant(constant) => Some(&constant.annotations),
+            Item::IncompleteRecord(..)
+            | Item::UnsupportedItem(..)
+            | Item::Comment(..)
+            | Item::Namespace(..)
+            | Item::UseMod(..) => None,
+        }
+    }
 }
 
 impl From<Func> for Item {
     fn from(func: Func) -> Item {
-        Item::Func(Rc::new(func))
+        Item::Func(Arc::new(func))
     }
 }
 
-impl<'a> TryFrom<&'a Item> for &'a Rc<Func> {
+impl<'a> TryFrom<&'a Item> for &'a Arc<Func> {
     type Error = Error;
     fn try_from(value: &'a Item) -> Result<Self, Self::Error> {
         if let Item::Func(f) = value { Ok(f) } else { bail!("Not a Func: {:#?}", value) }
@@ -642,11 +966,11 @@ impl<'a> TryFrom<&'a Item> for &'a Rc<Func> {
 
 impl From<Record> for Item {
     fn from(record: Record) -> Item {
-        Item::Record(Rc::new(record))
+        Item::Record(Arc::new(record))
     }
 }
 
-impl<'a> TryFrom<&'a Item> for &'a Rc<Record> {
+impl<'a> TryFrom<&'a Item> for &'a Arc<Record> {
     type Error = Error;
     fn try_from(value: &'a Item) -> Result<Self, Self::Error> {
         if let Item::Record(r) = value { Ok(r) } else { bail!("Not a Record: {:#?}", value) }
@@ -655,11 +979,11 @@ impl<'a> TryFrom<&'a Item> for &'a Rc<Record> {
 
 impl From<UnsupportedItem> for Item {
     fn from(unsupported: UnsupportedItem) -> Item {
-        Item::UnsupportedItem(Rc::new(unsupported))
+        Item::UnsupportedItem(Arc::new(unsupported))
     }
 }
 
-impl<'a> TryFrom<&'a Item> for &'a Rc<UnsupportedItem> {
+impl<'a> TryFrom<&'a Item> for &'a Arc<UnsupportedItem> {
     type Error = Error;
     fn try_from(value: &'a Item) -> Result<Self, Self::Error> {
         if let Item::UnsupportedItem(u) = value {
@@ -672,29 +996,44 @@ impl<'a> TryFrom<&'a Item> for &'a Rc<UnsupportedItem> {
 
 impl From<Comment> for Item {
     fn from(comment: Comment) -> Item {
-        Item::Comment(Rc::new(comment))
+        Item::Comment(Arc::new(comment))
     }
 }
 
-impl<'a> TryFrom<&'a Item> for &'a Rc<Comment> {
+impl<'a> TryFrom<&'a Item> for &'a Arc<Comment> {
     type Error = Error;
     fn try_from(value: &'a Item) -> Result<Self, Self::Error> {
         if let Item::Comment(c) = value { Ok(c) } else { bail!("Not a Comment: {:#?}", value) }
     }
 }
 
+impl<'a> TryFrom<&'a Item> for &'a Arc<Namespace> {
+    type Error = Error;
+    fn try_from(value: &'a Item) -> Result<Self, Self::Error> {
+        if let Item::Namespace(n) = value { Ok(n) } else { bail!("Not a Namespace: {:#?}", value) }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize)]
 #[serde(rename(deserialize = "IR"))]
 struct FlatIR {
+    /// See `IR_SCHEMA_VERSION`. The fields which are unknown to this version
+    /// are ignored.
+    #[serde(default = "unversioned_ir_schema_version")]
+    schema_version: u32,
     #[serde(default)]
     public_headers: Vec<HeaderName>,
+    #[serde(default)]
+    imported_modules: Vec<Arc<str>>,
+    #[serde(default, deserialize_with = "deserialize_interned_types")]
+    interned_types: (),
     current_target: BazelLabel,
     #[serde(default)]
     items: Vec<Item>,
     #[serde(default)]
     top_level_item_ids: Vec<ItemId>,
     #[serde(default)]
-    crate_root_path: Option<Rc<str>>,
+    crate_root_path: Option<Arc<str>>,
 }
 
 /// Struct providing the necessary information about the API of a C++ target to
@@ -708,6 +1047,9 @@ pub struct IR {
     lifetimes: HashMap<LifetimeId, LifetimeName>,
     namespace_id_to_number_of_reopened_namespaces: HashMap<ItemId, usize>,
     reopened_namespace_id_to_idx: HashMap<ItemId, usize>,
+    // The IRs of the dependencies of the current target (see `--dependency_irs`), keyed by their
+    // current target.
+    dependency_irs: HashMap<BazelLabel, Arc<IR>>,
 }
 
 impl IR {
@@ -727,35 +1069,46 @@ impl IR {
         self.flat_ir.public_headers.iter()
     }
 
-    pub fn functions(&self) -> impl Iterator<Item = &Rc<Func>> {
+    pub fn imported_modules(&self) -> impl Iterator<Item = &Arc<str>> {
+        self.flat_ir.imported_modules.iter()
+    }
+
+    pub fn functions(&self) -> impl Iterator<Item = &Arc<Func>> {
         self.items().filter_map(|item| match item {
             Item::Func(func) => Some(func),
             _ => None,
         })
     }
 
-    pub fn records(&self) -> impl Iterator<Item = &Rc<Record>> {
+    pub fn records(&self) -> impl Iterator<Item = &Arc<Record>> {
         self.items().filter_map(|item| match item {
             Item::Record(func) => Some(func),
             _ => None,
         })
     }
 
-    pub fn unsupported_items(&self) -> impl Iterator<Item = &Rc<UnsupportedItem>> {
+    pub fn enums(&self) -> impl Iterator<Item = &Arc<Enum>> {
+        self.items().filter_map(|item| match item {
+            Item::Enum(enum_) => Some(enum_),
+            _ => None,
+        })
+    }
+
+    pub fn unsupported_items(&self) -> impl Iterator<Item = &Arc<UnsupportedItem>> {
         self.items().filter_map(|item| match item {
             Item::UnsupportedItem(unsupported_item) => Some(unsupported_item),
             _ => None,
         })
     }
 
-    pub fn comments(&self) -> impl Iterator<Item = &Rc<Comment>> {
+    pub fn comments(&self) -> impl Iterator<Item = &Arc<Comment>> {
         self.items().filter_map(|item| match item {
             Item::Comment(comment) => Some(comment),
             _ => None,
         })
     }
 
-    pub fn namespaces(&self) -> impl Iterator<Item = &Rc<Namespace>> {
+    pub fn namespaces(&self) -> impl Iterator<Item = &Arc<Namespace>> {
         self.items().filter_map(|item| match item {
             Item::Namespace(ns) => Some(ns),
             _ => None,
@@ -785,7 +1138,7 @@ impl IR {
         })
     }
 
-    fn find_untyped_decl(&self, decl_id: ItemId) -> Result<&Item> {
+    pub fn find_untyped_decl(&self, decl_id: ItemId) -> Result<&Item> {
         let idx = *self
             .item_id_to_item_idx
             .get(&decl_id)
@@ -844,7 +1197,7 @@ impl IR {
     ///
     /// If `Func` is a member function, but its `Record` is somehow not in
     /// `self`, returns an error.
-    pub fn record_for_member_func<'a>(&self, func: &'a Func) -> Result<Option<&Rc<Record>>> {
+    pub fn record_for_member_func<'a>(&self, func: &'a Func) -> Result<Option<&Arc<Record>>> {
         if let Some(meta) = func.member_func_metadata.as_ref() {
             Ok(Some(self.find_decl(meta.record_id).with_context(|| {
                 format!("Failed to retrieve Record for MemberFuncMetadata of {:?}", func)
@@ -854,9 +1207,110 @@ impl IR {
         }
     }
 
-    pub fn crate_root_path(&self) -> Option<Rc<str>> {
+    pub fn crate_root_path(&self) -> Option<Arc<str>> {
         self.flat_ir.crate_root_path.clone()
     }
+
+    /// The version of the schema of the JSON IR this IR was deserialized from
+    /// (see `IR_SCHEMA_VERSION`).
+    pub fn schema_version(&self) -> u32 {
+        self.flat_ir.schema_version
+    }
+
+    /// Adds the IR of a dependency of the current target, which describes the
+    /// bindings of the items owned by the dependency.
+    pub fn add_dependency_ir(&mut self, dependency_ir: IR) -> Result<()> {
+        let target = dependency_ir.current_target().clone();
+        if self.is_current_target(&target) {
+            bail!("The IR of the current target {:?} can't be its own dependency", target.0);
+        }
+        match self.dependency_irs.entry(target) {
+            Entry::Occupied(occupied) => {
+                bail!("Duplicate IR of the dependency {:?}", occupied.key().0)
+            }
+            Entry::Vacant(vacant) => {
+                vacant.insert(Arc::new(dependency_ir));
+            }
+        }
+        Ok(())
+    }
+
+    /// Hands over the template instantiations of the current target which are
+    /// also bound by the dependency `target` (a shared crate of common
+    /// instantiations, see `--shared_instantiations_targets`) to that
+    /// dependency, along with their members and nested items. The bindings
+    /// then refer to the types of the shared crate, instead of generating
+    /// types of their own, which wouldn't unify with the instantiations in
+    /// other crates.
+    pub fn use_shared_instantiations(&mut self, target: &BazelLabel) -> Result<()> {
+        let dependency_ir = self.dependency_irs.get(target).with_context(|| {
+            format!("The IR of the shared instantiations target {:?} is missing", target.0)
+        })?;
+        // `ItemId`s are stable, so the instantiations have the same IDs in the IR of
+        // the dependency.
+        let mut shared_record_ids: HashSet<ItemId> = dependency_ir
+            .items()
+            .filter_map(|item| match item {
+                Item::Record(record) if record.is_template_instantiation => Some(record.id),
+                Item::IncompleteRecord(record) if record.is_template_instantiation => {
+                    Some(record.id)
+                }
+                _ => None,
+            })
+            .filter(|id| self.item_id_to_item_idx.contains_key(id))
+            .collect();
+        loop {
+            let nested_record_ids = self
+                .items()
+                .filter(|item| {
+                    item.enclosing_record_id().is_some_and(|id| shared_record_ids.contains(&id))
+                })
+                .map(|item| item.id())
+                .filter(|id| !shared_record_ids.contains(id))
+                .collect::<Vec<_>>();
+            if nested_record_ids.is_empty() {
+                break;
+            }
+            shared_record_ids.extend(nested_record_ids);
+        }
+
+        let current_target = self.current_target().clone();
+        let is_shared = |id: &ItemId| shared_record_ids.contains(id);
+        for item in self.flat_ir.items.iter_mut() {
+            let owning_target = match item {
+                Item::Record(record) if is_shared(&record.id) => {
+                    &mut Arc::make_mut(record).owning_target
+                }
+                Item::IncompleteRecord(record) if is_shared(&record.id) => {
+                    &mut Arc::make_mut(record).owning_target
+                }
+                Item::Func(func)
+                    if func
+                        .member_func_metadata
+                        .as_ref()
+                        .is_some_and(|meta| is_shared(&meta.record_id)) =>
+                {
+                    &mut Arc::make_mut(func).owning_target
+                }
+                Item::TypeAlias(type_alias)
+                    if type_alias.enclosing_record_id.as_ref().is_some_and(is_shared) =>
+                {
+                    &mut Arc::make_mut(type_alias).owning_target
+                }
+                _ => continue,
+            };
+            if *owning_target == current_target {
+                *owning_target = target.clone();
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the IR of the dependency `target`, if it was added with
+    /// `add_dependency_ir`.
+    pub fn dependency_ir(&self, target: &BazelLabel) -> Option<&IR> {
+        self.dependency_irs.get(target).map(|ir| &**ir)
+    }
 }
 
 #[cfg(test)]
@@ -891,7 +1345,10 @@ mod tests {
         "#;
         let ir = deserialize_ir(input.as_bytes()).unwrap();
         let expected = FlatIR {
+            schema_version: 1,
             public_headers: vec![HeaderName { name: "foo/bar.h".into() }],
+            imported_modules: vec![],
+            interned_types: (),
             current_target: "//foo:bar".into(),
             top_level_item_ids: vec![],
             items: vec![],
@@ -900,6 +1357,64 @@ mod tests {
         assert_eq!(ir.flat_ir, expected);
     }
 
+    #[test]
+    fn test_imported_modules() {
+        let input = r#"
+        {
+            "imported_modules": ["foo.bar"],
+            "current_target": "//foo:bar"
+        }
+        "#;
+        let ir = deserialize_ir(input.as_bytes()).unwrap();
+        assert_eq!(ir.imported_modules().map(|m| &**m).collect::<Vec<_>>(), vec!["foo.bar"]);
+    }
+
+    #[test]
+    fn test_interned_types() {
+        let input = r#"
+        {
+            "current_target": "//foo:bar",
+            "interned_types": {
+                "rs_types": [{ "name": "i32", "lifetime_args": [], "type_args": [] }],
+                "cc_types": [{ "name": "int", "is_const": false, "type_args": [] }]
+            },
+            "items": [{ "TypeAlias": {
+                "identifier": { "identifier": "MyInt" },
+                "id": 1,
+                "owning_target": "//foo:bar",
+                "underlying_type": { "rs_type": 0, "cc_type": 0 },
+                "source_loc": "foo/bar.h;l=1"
+            }}]
+        }
+        "#;
+        let ir = deserialize_ir(input.as_bytes()).unwrap();
+        let type_alias = match ir.items().next() {
+            Some(Item::TypeAlias(type_alias)) => type_alias,
+            item => panic!("Expected a TypeAlias, got {item:?}"),
+        };
+        assert_eq!(type_alias.underlying_type.rs_type.name.as_deref(), Some("i32"));
+        assert_eq!(type_alias.underlying_type.cc_type.name.as_deref(), Some("int"));
+    }
+
+    #[test]
+    fn test_invalid_interned_type_index() {
+        let input = r#"
+        {
+            "current_target": "//foo:bar",
+            "interned_types": { "rs_types": [], "cc_types": [] },
+            "items": [{ "TypeAlias": {
+                "identifier": { "identifier": "MyInt" },
+                "id": 1,
+                "owning_target": "//foo:bar",
+                "underlying_type": { "rs_type": 0, "cc_type": 0 },
+                "source_loc": "foo/bar.h;l=1"
+            }}]
+        }
+        "#;
+        let err = deserialize_ir(input.as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("Invalid interned RsType index: 0"), "{err}");
+    }
+
     #[test]
     fn test_empty_crate_root_path() {
         let input = "{ \"current_target\": \"//foo:bar\" }";
@@ -918,4 +1433,144 @@ mod tests {
         let ir = deserialize_ir(input.as_bytes()).unwrap();
         assert_eq!(ir.crate_root_path().as_deref(), Some("__cc_template_instantiations_rs_api"));
     }
+
+    #[test]
+    fn test_current_schema_version() {
+        let input = format!(
+            r#"{{ "schema_version": {IR_SCHEMA_VERSION}, "current_target": "//foo:bar" }}"#
+        );
+        let ir = deserialize_ir(input.as_bytes()).unwrap();
+        assert_eq!(ir.schema_version(), IR_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_unversioned_schema() {
+        let input = r#"{ "current_target": "//foo:bar" }"#;
+        let ir = deserialize_ir(input.as_bytes()).unwrap();
+        assert_eq!(ir.schema_version(), 1);
+    }
+
+    #[test]
+    fn test_unknown_fields_are_ignored() {
+        let input = r#"
+        {
+            "schema_version": 1,
+            "current_target": "//foo:bar",
+            "some_future_field": { "nested": [1, 2, 3] },
+            "interned_types": {
+                "rs_types": [{ "name": "i32", "lifetime_args": [], "type_args": [] }],
+                "cc_types": [{ "name": "int", "is_const": false, "type_args": [] }]
+            },
+            "items": [{ "TypeAlias": {
+                "identifier": { "identifier": "MyInt" },
+                "id": 1,
+                "owning_target": "//foo:bar",
+                "underlying_type": { "rs_type": 0, "cc_type": 0 },
+                "source_loc": "foo/bar.h;l=1",
+                "some_future_item_field": true
+            }}]
+        }
+        "#;
+        let ir = deserialize_ir(input.as_bytes()).unwrap();
+        assert_eq!(ir.items().count(), 1);
+    }
+
+    #[test]
+    fn test_newer_schema_version() {
+        let input = r#"{ "schema_version": 1000, "current_target": "//foo:bar" }"#;
+        let err = deserialize_ir(input.as_bytes()).unwrap_err();
+        assert!(
+            err.to_string().starts_with(
+                "The IR was produced by a newer importer (IR schema version 1000, but the \
+                 bindings generator only supports versions 1 to 1)"
+            ),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_newer_schema_version_with_incompatible_items() {
+        // The version is reported instead of the failure to deserialize the
+        // unknown kind of item.
+        let input = r#"
+        {
+            "schema_version": 1000,
+            "current_target": "//foo:bar",
+            "items": [{ "SomeFutureItem": { "id": 1 } }]
+        }
+        "#;
+        let err = deserialize_ir(input.as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("produced by a newer importer"), "{err}");
+    }
+
+    #[test]
+    fn test_older_schema_version() {
+        let input = r#"{ "schema_version": 0, "current_target": "//foo:bar" }"#;
+        let err = deserialize_ir(input.as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("produced by an older importer"), "{err}");
+        assert!(err.to_string().contains("Regenerate the IR"), "{err}");
+    }
+
+    #[test]
+    fn test_incompatible_items_of_current_schema_version() {
+        let input = r#"
+        {
+            "schema_version": 1,
+            "current_target": "//foo:bar",
+            "items": [{ "SomeFutureItem": { "id": 1 } }]
+        }
+        "#;
+        let err = deserialize_ir(input.as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("unknown variant `SomeFutureItem`"), "{err}");
+    }
+
+    #[test]
+    fn test_binary_ir() {
+        let input = r#"
+        {
+            "schema_version": 1,
+            "public_headers": [{ "name": "foo/bar.h" }],
+            "current_target": "//foo:bar",
+            "interned_types": {
+                "rs_types": [{ "name": "i32", "lifetime_args": [], "type_args": [] }],
+                "cc_types": [{ "name": "int", "is_const": false, "type_args": [] }]
+            },
+            "items": [{ "TypeAlias": {
+                "identifier": { "identifier": "MyInt" },
+                "id": 1,
+                "owning_target": "//foo:bar",
+                "doc_comment": null,
+                "underlying_type": { "rs_type": 0, "cc_type": 0 },
+                "source_loc": "foo/bar.h;l=1",
+                "enclosing_namespace_id": null
+            }}],
+            "top_level_item_ids": [1]
+        }
+        "#;
+        let binary = json_ir_to_binary(input.as_bytes()).unwrap();
+        assert_eq!(binary[0], BINARY_IR_HEADER);
+        let json_ir = deserialize_ir(input.as_bytes()).unwrap();
+        assert_eq!(deserialize_ir_binary(&binary).unwrap().flat_ir, json_ir.flat_ir);
+        assert_eq!(deserialize_ir(&binary[..]).unwrap().flat_ir, json_ir.flat_ir);
+    }
+
+    #[test]
+    fn test_binary_ir_newer_schema_version() {
+        let input = r#"
+        {
+            "schema_version": 1000,
+            "current_target": "//foo:bar",
+            "items": [{ "SomeFutureItem": { "id": 1 } }]
+        }
+        "#;
+        let binary = json_ir_to_binary(input.as_bytes()).unwrap();
+        let err = deserialize_ir(&binary[..]).unwrap_err();
+        assert!(err.to_string().contains("produced by a newer importer"), "{err}");
+    }
+
+    #[test]
+    fn test_binary_ir_without_header() {
+        let err = deserialize_ir_binary(br#"{ "current_target": "//foo:bar" }"#).unwrap_err();
+        assert_eq!(err.to_string(), "The IR doesn't start with the header of the binary format");
+    }
 }
@@ -0,0 +1,115 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+//! Compares the time it takes to deserialize IRs from JSON and from the binary
+//! format (see `deserialize_ir_binary`):
+//!
+//! ```sh
+//! bazel run -c opt //rs_bindings_from_cc:ir_benchmark -- \
+//!   --iterations=10 /tmp/foo_ir.json /tmp/bar_ir.json
+//! ```
+//!
+//! The IRs are JSON IRs written with `--ir_out`. Without them, the benchmark
+//! deserializes a synthetic IR with many items.
+
+use arc_anyhow::{anyhow, Context, Result};
+use ir::{deserialize_ir, json_ir_to_binary};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// The number of items of the synthetic IR.
+const SYNTHETIC_IR_ITEMS: usize = 100_000;
+
+/// Returns a JSON IR with `items` type aliases.
+fn synthetic_json_ir(items: usize) -> Vec<u8> {
+    let items = (0..items)
+        .map(|id| {
+            serde_json::json!({ "TypeAlias": {
+                "identifier": { "identifier": format!("Alias{id}") },
+                "id": id,
+                "owning_target": "//benchmark:synthetic",
+                "doc_comment": format!("The type alias number {id}."),
+                "underlying_type": { "rs_type": 0, "cc_type": 0 },
+                "source_loc": format!("benchmark/synthetic.h;l={id}"),
+                "enclosing_namespace_id": null,
+            }})
+        })
+        .collect::<Vec<_>>();
+    let top_level_item_ids = (0..items.len()).collect::<Vec<_>>();
+    serde_json::to_vec(&serde_json::json!({
+        "schema_version": ir::IR_SCHEMA_VERSION,
+        "public_headers": [{ "name": "benchmark/synthetic.h" }],
+        "current_target": "//benchmark:synthetic",
+        "interned_types": {
+            "rs_types": [{ "name": "i32", "lifetime_args": [], "type_args": [] }],
+            "cc_types": [{ "name": "int", "is_const": false, "type_args": [] }],
+        },
+        "items": items,
+        "top_level_item_ids": top_level_item_ids,
+    }))
+    .unwrap()
+}
+
+/// Returns the mean time it takes to deserialize `ir`.
+fn time_deserialization(ir: &[u8], iterations: u32) -> Result<Duration> {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(deserialize_ir(black_box(ir))?);
+    }
+    Ok(start.elapsed() / iterations)
+}
+
+fn benchmark(name: &str, json: &[u8], iterations: u32) -> Result<()> {
+    let binary = json_ir_to_binary(json)?;
+    let json_time = time_deserialization(json, iterations)?;
+    let binary_time = time_deserialization(&binary, iterations)?;
+    println!(
+        "{name}:\n  JSON:   {:>12} bytes, {json_time:>12.3?}\n  binary: {:>12} bytes, \
+         {binary_time:>12.3?} ({:.1}x faster)",
+        json.len(),
+        binary.len(),
+        json_time.as_secs_f64() / binary_time.as_secs_f64(),
+    );
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let mut iterations = 10;
+    let mut paths = vec![];
+    for arg in std::env::args().skip(1) {
+        match arg.strip_prefix("--iterations=") {
+            Some(value) => {
+                iterations = value
+                    .parse()
+                    .ok()
+                    .filter(|&iterations| iterations > 0)
+                    .ok_or_else(|| anyhow!("Invalid value of `--iterations`: {value:?}"))?;
+            }
+            None => paths.push(arg),
+        }
+    }
+    if paths.is_empty() {
+        let name = format!("synthetic IR ({SYNTHETIC_IR_ITEMS} items)");
+        return benchmark(&name, &synthetic_json_ir(SYNTHETIC_IR_ITEMS), iterations);
+    }
+    for path in &paths {
+        let json = std::fs::read(path).with_context(|| format!("Failed to read the IR {path}"))?;
+        benchmark(path, &json, iterations)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_synthetic_ir() {
+        let json = synthetic_json_ir(3);
+        let json_ir = deserialize_ir(&json[..]).unwrap();
+        let binary_ir = deserialize_ir(&json_ir_to_binary(&json).unwrap()[..]).unwrap();
+        assert_eq!(json_ir.items().count(), 3);
+        assert_eq!(binary_ir.items().count(), 3);
+    }
+}
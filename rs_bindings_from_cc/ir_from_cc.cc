@@ -5,6 +5,7 @@
 #include "rs_bindings_from_cc/ir_from_cc.h"
 
 #include <memory>
+#include <optional>
 #include <string>
 #include <utility>
 #include <vector>
@@ -17,11 +18,18 @@
 #include "absl/strings/string_view.h"
 #include "absl/strings/substitute.h"
 #include "absl/types/span.h"
+#include "common/file_io.h"
+#include "common/status_macros.h"
 #include "rs_bindings_from_cc/bazel_types.h"
 #include "rs_bindings_from_cc/frontend_action.h"
 #include "rs_bindings_from_cc/ir.h"
+#include "clang/Frontend/CompilerInstance.h"
 #include "clang/Frontend/FrontendAction.h"
+#include "clang/Frontend/FrontendActions.h"
 #include "clang/Tooling/Tooling.h"
+#include "llvm/ADT/SmallString.h"
+#include "llvm/Support/FileSystem.h"
+#include "llvm/Support/FileUtilities.h"
 
 namespace crubit {
 
@@ -30,6 +38,64 @@ static constexpr absl::string_view kVirtualHeaderPath =
 static constexpr absl::string_view kVirtualInputPath =
     "ir_from_cc_virtual_input.cc";
 
+namespace {
+
+// Precompiles a C++20 module interface unit into `output_path`.
+class BuildModuleInterfaceAction : public clang::GenerateModuleInterfaceAction {
+ public:
+  explicit BuildModuleInterfaceAction(std::string output_path)
+      : output_path_(std::move(output_path)) {}
+
+ protected:
+  bool BeginInvocation(clang::CompilerInstance& ci) override {
+    ci.getFrontendOpts().OutputFile = output_path_;
+    return clang::GenerateModuleInterfaceAction::BeginInvocation(ci);
+  }
+
+ private:
+  std::string output_path_;
+};
+
+// Builds the BMI (binary module interface) of `module_interface` and returns
+// its path.
+absl::StatusOr<std::string> BuildModuleInterface(
+    const ModuleInterface& module_interface,
+    const std::vector<std::string>& args,
+    const clang::tooling::FileContentMappings& file_contents) {
+  std::string path(module_interface.path.IncludePath());
+  std::optional<std::string> contents;
+  for (const auto& [name, content] : file_contents) {
+    if (name == path) {
+      contents = content;
+    }
+  }
+  if (!contents.has_value()) {
+    CRUBIT_ASSIGN_OR_RETURN(contents, GetFileContents(path));
+  }
+
+  llvm::SmallString<128> bmi_path;
+  if (std::error_code error = llvm::sys::fs::createTemporaryFile(
+          module_interface.module_name, "pcm", bmi_path)) {
+    return absl::InternalError(
+        absl::Substitute("Could not create a BMI file for module `$0`: $1",
+                         module_interface.module_name, error.message()));
+  }
+  std::vector<std::string> module_args = args;
+  module_args.push_back("-xc++-module");
+  if (!clang::tooling::runToolOnCodeWithArgs(
+          std::make_unique<BuildModuleInterfaceAction>(std::string(bmi_path)),
+          *contents, module_args, path, "rs_bindings_from_cc",
+          std::make_shared<clang::PCHContainerOperations>(), file_contents)) {
+    return absl::Status(
+        absl::StatusCode::kInvalidArgument,
+        absl::Substitute("Could not compile the interface of module `$0`",
+                         module_interface.module_name));
+  }
+  return std::string(bmi_path);
+}
+
+}  // namespace
+
 absl::StatusOr<IR> IrFromCc(
     const absl::string_view extra_source_code_for_testing,
     const BazelLabel current_target,
@@ -39,10 +105,13 @@ absl::StatusOr<IR> IrFromCc(
     absl::flat_hash_map<HeaderName, BazelLabel> headers_to_targets,
     absl::Span<const std::string> extra_rs_srcs,
     absl::Span<const absl::string_view> clang_args,
-    absl::Span<const std::string> extra_instantiations) {
+    absl::Span<const std::string> extra_instantiations,
+    absl::Span<const ModuleInterface> module_interfaces,
+    absl::Span<const std::string> safe_pointer_params_allowlist,
+    bool friend_crate) {
   // Caller should verify that the inputs are not empty.
   CHECK(!extra_source_code_for_testing.empty() || !public_headers.empty() ||
-        !extra_instantiations.empty());
+        !extra_instantiations.empty() || !module_interfaces.empty());
 
   clang::tooling::FileContentMappings file_contents;
 
@@ -68,6 +137,8 @@ absl::StatusOr<IR> IrFromCc(
     absl::SubstituteAndAppend(&virtual_input_file_content, "#include \"$0\"\n",
                               header_name.IncludePath());
   }
+  // Module imports have to follow the includes, but precede the instantiations.
+  const size_t imports_position = virtual_input_file_content.size();
   if (!extra_instantiations.empty()) {
     absl::SubstituteAndAppend(&virtual_input_file_content, "namespace $0 {\n",
                               kInstantiationsNamespaceName);
@@ -82,14 +153,39 @@ absl::StatusOr<IR> IrFromCc(
                               kInstantiationsNamespaceName);
   }
   std::vector<std::string> args_as_strings{
-      "-std=gnu++17",
+      // Importing modules requires C++20.
+      module_interfaces.empty() ? "-std=gnu++17" : "-std=gnu++20",
       // Parse non-doc comments that are used as documention
       "-fparse-all-comments"};
   args_as_strings.insert(args_as_strings.end(), clang_args.begin(),
                          clang_args.end());
 
+  // Interface units are owned by the current target, just like its public
+  // headers, and are imported after them.
+  std::vector<std::string> module_file_args;
+  std::vector<std::unique_ptr<llvm::FileRemover>> bmi_removers;
+  std::string module_imports;
+  for (const ModuleInterface& module_interface : module_interfaces) {
+    CRUBIT_ASSIGN_OR_RETURN(
+        std::string bmi_path,
+        BuildModuleInterface(module_interface, args_as_strings, file_contents));
+    bmi_removers.push_back(std::make_unique<llvm::FileRemover>(bmi_path));
+    module_file_args.push_back(absl::Substitute(
+        "-fmodule-file=$0=$1", module_interface.module_name, bmi_path));
+    absl::SubstituteAndAppend(&module_imports, "import $0;\n",
+                              module_interface.module_name);
+    headers_to_targets.insert({module_interface.path, current_target});
+  }
+  args_as_strings.insert(args_as_strings.end(), module_file_args.begin(),
+                         module_file_args.end());
+  virtual_input_file_content.insert(imports_position, module_imports);
+
   Invocation invocation(current_target, augmented_public_headers,
                         headers_to_targets);
+  invocation.safe_pointer_params_allowlist_.insert(
+      safe_pointer_params_allowlist.begin(),
+      safe_pointer_params_allowlist.end());
+  invocation.friend_crate_ = friend_crate;
   if (!clang::tooling::runToolOnCodeWithArgs(
           std::make_unique<FrontendAction>(invocation),
           virtual_input_file_content, args_as_strings, kVirtualInputPath,
@@ -98,6 +194,9 @@ absl::StatusOr<IR> IrFromCc(
     return absl::Status(absl::StatusCode::kInvalidArgument,
                         "Could not compile header contents");
   }
+  for (const ModuleInterface& module_interface : module_interfaces) {
+    invocation.ir_.imported_modules.push_back(module_interface.module_name);
+  }
 
   invocation.ir_.items.reserve(invocation.ir_.items.size() +
                                extra_rs_srcs.size());
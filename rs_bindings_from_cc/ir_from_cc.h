@@ -21,6 +21,15 @@ namespace crubit {
 static constexpr absl::string_view kInstantiationsNamespaceName =
     "__cc_template_instantiations";
 
+// A C++20 module interface unit (e.g. `foo.cppm` containing
+// `export module foo;`).
+struct ModuleInterface {
+  // The name of the module, e.g. `foo` or `foo.bar`.
+  std::string module_name;
+  // The path of the interface unit.
+  HeaderName path;
+};
+
 // Parses C++ source code into IR.
 //
 // Parameters:
@@ -44,6 +53,15 @@ static constexpr absl::string_view kInstantiationsNamespaceName =
 //   the crate. This is done via `#[path="..."] mod <...>; pub use <...>::*;`.
 // * `extra_instantiations`: names of full C++ class template specializations
 // to instantiate and generate bindings from.
+// * `module_interfaces`: C++20 module interface units of the current target.
+//   They are precompiled into temporary BMI files and imported in addition to
+//   `public_headers`. Contents of interface units listed in
+//   `virtual_headers_contents_for_testing` are taken from there.
+// * `safe_pointer_params_allowlist`: qualified names of functions (e.g.
+//   `ns::Foo`) which are treated as if they were annotated with
+//   `CRUBIT_SAFE_POINTER_PARAMS`.
+// * `friend_crate`: whether the private and protected member functions of the
+//   records of the current target are imported.
 //
 absl::StatusOr<IR> IrFromCc(
     absl::string_view extra_source_code_for_testing,
@@ -54,7 +72,10 @@ absl::StatusOr<IR> IrFromCc(
     absl::flat_hash_map<HeaderName, BazelLabel> headers_to_targets = {},
     absl::Span<const std::string> extra_rs_srcs = {},
     absl::Span<const absl::string_view> clang_args = {},
-    absl::Span<const std::string> extra_instantiations = {});
+    absl::Span<const std::string> extra_instantiations = {},
+    absl::Span<const ModuleInterface> module_interfaces = {},
+    absl::Span<const std::string> safe_pointer_params_allowlist = {},
+    bool friend_crate = false);
 
 }  // namespace crubit
 
@@ -23,6 +23,7 @@ fn test_function() {
                 owning_target: BazelLabel("//test:testing_target"),
                 mangled_name: "_Z1fii",
                 doc_comment: None,
+                deprecated: None,
                 return_type: MappedType {
                     rs_type: RsType {
                         name: Some("i32"),
@@ -54,6 +55,12 @@ fn test_function() {
                             },
                         },
                         identifier: "a",
+                        is_nonnull: false,
+                        sized_by: None,
+                        alignment: None,
+                        callback_context: None,
+                        is_callback_retained: false,
+                        is_callable_borrowed: false,
                     },
                     FuncParam {
                         type_: MappedType {
@@ -71,10 +78,17 @@ fn test_function() {
                             },
                         },
                         identifier: "b",
+                        is_nonnull: false,
+                        sized_by: None,
+                        alignment: None,
+                        callback_context: None,
+                        is_callback_retained: false,
+                        is_callable_borrowed: false,
                     },
                 ],
                 lifetime_params: [],
                 is_inline: false,
+                is_noexcept: false,
                 member_func_metadata: None,
                 has_c_calling_convention: true,
                 is_member_or_descendant_of_class_template: false,
@@ -82,11 +96,46 @@ fn test_function() {
                 id: ItemId(...),
                 enclosing_namespace_id: None,
                 adl_enclosing_record: None,
+                trivial_body: None,
+                returns_static_local: false,
+                has_safe_pointer_params: false,
+                is_variadic: false,
+                has_internal_linkage: false,
+                has_hidden_visibility: false,
+                is_extern_c: false,
+                precondition: None,
+                inherited_from: None,
+                result_deleter: None,
+                required_capabilities: [],
+                annotations: ItemAnnotations { rust_name: None, skip: false, doc_hidden: false },
             }
         }
     );
 }
 
+#[test]
+fn test_item_ids_are_stable() -> Result<()> {
+    let header = r#"
+        // A comment.
+        namespace ns {
+          struct S final {};
+          void f(S s);
+        }  // namespace ns
+        namespace ns {
+          inline void f(int i) {}
+        }  // namespace ns
+    "#;
+    let item_ids = |ir: &IR| ir.items().map(|item| item.id()).collect_vec();
+    let ir = ir_from_cc(header)?;
+    assert_eq!(item_ids(&ir), item_ids(&ir_from_cc(header)?));
+    assert_eq!(item_ids(&ir).into_iter().unique().count(), ir.items().count());
+
+    // The record has the same ID in the IR of a target which depends on it.
+    let dependent_ir = ir_from_cc_dependency("void g(ns::S s);", header)?;
+    assert_eq!(retrieve_record(&ir, "S").id, retrieve_record(&dependent_ir, "S").id);
+    Ok(())
+}
+
 #[test]
 fn test_function_with_unnamed_parameters() {
     let ir = ir_from_cc("int f(int, int);").unwrap();
@@ -509,6 +558,41 @@ fn test_struct_with_unnamed_struct_and_union_members() {
     );
 }
 
+#[test]
+fn test_anonymous_members() {
+    let ir = ir_from_cc(
+        r#"
+        struct S {
+          int first;
+          union {
+            int i;
+            struct {
+              short lo;
+              short hi;
+            };
+          };
+        }; "#,
+    )
+    .unwrap();
+    let record = retrieve_record(&ir, "S");
+    let names = |fields: &[Field]| {
+        fields
+            .iter()
+            .map(|f| (f.identifier.as_ref().map(|i| i.identifier.to_string()), f.offset))
+            .collect_vec()
+    };
+    let anonymous_union = &record.fields[1];
+    assert!(anonymous_union.is_anonymous_union);
+    assert_eq!(names(&anonymous_union.anonymous_members), [(Some("i".into()), 32), (None, 32)]);
+    let anonymous_struct = &anonymous_union.anonymous_members[1];
+    assert!(!anonymous_struct.is_anonymous_union);
+    assert_eq!(
+        names(&anonymous_struct.anonymous_members),
+        [(Some("lo".into()), 32), (Some("hi".into()), 48)]
+    );
+    assert!(record.fields[0].anonymous_members.is_empty());
+}
+
 #[test]
 fn test_record_private_member_functions_not_present() {
     let ir = ir_from_cc(
@@ -1438,6 +1522,12 @@ fn test_subst_template_type_parm_pack_type() -> Result<()> {
                             cc_type: CcType { name: Some("int"), ...  },
                         },
                         identifier: "__my_args_0",
+                        is_nonnull: false,
+                        sized_by: None,
+                        alignment: None,
+                        callback_context: None,
+                        is_callback_retained: false,
+                        is_callable_borrowed: false,
                     },
                     FuncParam {
                         type_: MappedType {
@@ -1445,6 +1535,12 @@ fn test_subst_template_type_parm_pack_type() -> Result<()> {
                             cc_type: CcType { name: Some("int"), ...  },
                         },
                         identifier: "__my_args_1",
+                        is_nonnull: false,
+                        sized_by: None,
+                        alignment: None,
+                        callback_context: None,
+                        is_callback_retained: false,
+                        is_callable_borrowed: false,
                     },
                 ], ...
             }
@@ -1627,6 +1723,8 @@ fn test_fully_instantiated_template_in_public_field() -> Result<()> {
                        is_no_unique_address: false,
                        is_bitfield: false,
                        is_inheritable: true,
+                       anonymous_members: [],
+                       is_anonymous_union: false,
                    }], ...
                }
         }
@@ -1667,6 +1765,8 @@ fn test_fully_instantiated_template_in_private_field() -> Result<()> {
                        is_no_unique_address: false,
                        is_bitfield: false,
                        is_inheritable: false,
+                       anonymous_members: [],
+                       is_anonymous_union: false,
                    }], ...
                }
         }
@@ -2217,26 +2317,50 @@ fn test_ignore_union_typedef_from_decl_context_redecl_from_multiple_targets() {
 }
 
 #[test]
-fn test_records_nested_in_records_not_supported_yet() {
-    let ir = ir_from_cc("struct SomeStruct { struct NestedStruct {}; };").unwrap();
+fn test_records_nested_in_records() -> Result<()> {
+    let ir = ir_from_cc(
+        "namespace ns {
+           struct SomeStruct {
+             struct NestedStruct {
+               struct DoublyNestedStruct {};
+             };
+             struct ForwardDeclaredStruct;
+           };
+         }",
+    )?;
+    let find_record = |rs_name: &str| ir.records().find(|r| r.rs_name.as_ref() == rs_name).unwrap();
+    let outer = find_record("SomeStruct");
+    let nested = find_record("NestedStruct");
+    let doubly_nested = find_record("DoublyNestedStruct");
+    assert_eq!(outer.enclosing_record_id, None);
+    assert_eq!(nested.cc_name.as_ref(), "NestedStruct");
+    assert_eq!(nested.enclosing_record_id, Some(outer.id));
+    assert_eq!(nested.enclosing_namespace_id, outer.enclosing_namespace_id);
+    assert_eq!(doubly_nested.enclosing_record_id, Some(nested.id));
+    assert!(outer.child_item_ids.contains(&nested.id));
     assert_ir_matches!(
         ir,
-        quote! { UnsupportedItem {
-          name: "SomeStruct::NestedStruct",
-          message: "Nested classes are not supported yet" ...
+        quote! { IncompleteRecord {
+          cc_name: "ForwardDeclaredStruct",
+          rs_name: "ForwardDeclaredStruct",
+          ...
+          enclosing_record_id: Some(ItemId(...)),
         }}
     );
+    Ok(())
 }
 
 #[test]
 fn test_record_with_unsupported_field_type() -> Result<()> {
-    // Using a nested struct because it's currently not supported.
+    // Using a private nested struct because no bindings are generated for it.
     // But... any other unsupported type would also work for this test.
     let ir = ir_from_cc(
         r#"
         struct StructWithUnsupportedField {
+         private:
           struct NestedStruct {};
 
+         public:
           // Doc comment for `my_field`.
           NestedStruct my_field;
         };
@@ -2252,6 +2376,7 @@ fn test_record_with_unsupported_field_type() -> Result<()> {
                fields: [Field {
                    identifier: Some("my_field"),
                    doc_comment: Some("Doc comment for `my_field`."),
+                   deprecated: None,
                    type_: Err(
                        "Unsupported type 'struct StructWithUnsupportedField::NestedStruct': No generated bindings found for 'NestedStruct'",
                    ),
@@ -2261,6 +2386,8 @@ fn test_record_with_unsupported_field_type() -> Result<()> {
                    is_no_unique_address: false,
                    is_bitfield: false,
                    is_inheritable: false,
+                   anonymous_members: [],
+                   is_anonymous_union: false,
                }],
                ...
                size: 1,
@@ -2270,40 +2397,31 @@ fn test_record_with_unsupported_field_type() -> Result<()> {
            }
         }
     );
-    assert_ir_matches!(
-        ir,
-        quote! {
-            UnsupportedItem {
-                name: "StructWithUnsupportedField::NestedStruct",
-                message: "Nested classes are not supported yet",
-                ...
-            }
-        }
-    );
+    assert_ir_not_matches!(ir, quote! { Record { rs_name: "NestedStruct" ... } });
     Ok(())
 }
 
 #[test]
 fn test_record_with_unsupported_base() -> Result<()> {
     let ir = ir_from_cc(
-        r#" struct OuterStruct {
-              struct NestedStruct {
+        r#" namespace rs_std {
+              struct rs_char {
                 // Having a field here avoids empty base class optimization
                 // and forces `derived_field` to be at a non-zero offset.
                 // See also: https://en.cppreference.com/w/cpp/language/ebo
-                char nested_field;
+                char value;
               };
-            };
+            }  // namespace rs_std
 
-            // Using a nested struct as a base class because nested structs are
-            // currently unsupported.  But... any other unsupported base class
-            // would also work for this test.
-            struct DerivedClass : public OuterStruct::NestedStruct {
+            // Using `rs_char` as a base class because it is currently
+            // unsupported.  But... any other unsupported base class would also
+            // work for this test.
+            struct DerivedClass : public rs_std::rs_char {
               int derived_field;
             }; "#,
     )?;
     // Verify that `unambiguous_public_bases` are empty (instead of containing a
-    // dangling `ItemId` of the `NestedStruct` (which got imported as
+    // dangling `ItemId` of the `rs_char` (which got imported as
     // `UnsupportedItem` rather than as a `Record`).
     assert_ir_matches!(
         ir,
@@ -2315,6 +2433,7 @@ fn test_record_with_unsupported_base() -> Result<()> {
                id: ItemId(...),
                owning_target: BazelLabel("//test:testing_target"),
                doc_comment: Some(...),
+               deprecated: None,
                source_loc: "Generated from: google3/ir_from_cc_virtual_header.h;l=15",
                unambiguous_public_bases: [],
                fields: [Field {
@@ -2330,15 +2449,15 @@ fn test_record_with_unsupported_base() -> Result<()> {
            }
         }
     );
-    // Verify that the NestedStruct is unsupported (this is mostly verification
+    // Verify that the `rs_char` is unsupported (this is mostly verification
     // that the test input correctly sets up the test scenario;  the real
     // verification is above).
     assert_ir_matches!(
         ir,
         quote! {
            UnsupportedItem {
-               name: "OuterStruct::NestedStruct",
-               message: "Nested classes are not supported yet",
+               name: "rs_std::rs_char",
+               message: "Round-tripping of `rs_char` is not supported yet (b/270160530)",
                ...
            }
         }
@@ -2348,40 +2467,40 @@ fn test_record_with_unsupported_base() -> Result<()> {
 
 #[test]
 fn test_do_not_import_static_member_functions_when_record_not_supported_yet() {
-    // only using nested struct as an example of a record we cannot import yet.
+    // only using `rs_char` as an example of a record we cannot import yet.
     let ir = ir_from_cc(
         "
-        struct SomeStruct {
-          struct NestedStruct {
+        namespace rs_std {
+          struct rs_char {
             static void StaticMemberFunction();
           };
-        };",
+        }",
     )
     .unwrap();
     assert_ir_matches!(
         ir,
         quote! { UnsupportedItem {
-          name: "SomeStruct::NestedStruct::StaticMemberFunction" ...
+          name: "rs_std::rs_char::StaticMemberFunction" ...
         }}
     );
 }
 
 #[test]
 fn test_do_not_import_nonstatic_member_functions_when_record_not_supported_yet() {
-    // only using nested struct as an example of a record we cannot import yet.
+    // only using `rs_char` as an example of a record we cannot import yet.
     let ir = ir_from_cc(
         "
-        struct SomeStruct {
-          struct NestedStruct {
+        namespace rs_std {
+          struct rs_char {
             void NonStaticMemberFunction();
           };
-        };",
+        }",
     )
     .unwrap();
     assert_ir_matches!(
         ir,
         quote! { UnsupportedItem {
-          name: "SomeStruct::NestedStruct::NonStaticMemberFunction" ...
+          name: "rs_std::rs_char::NonStaticMemberFunction" ...
         }}
     );
 }
@@ -2390,16 +2509,13 @@ fn test_do_not_import_nonstatic_member_functions_when_record_not_supported_yet()
 fn test_dont_import_injected_class_name() {
     let ir = ir_from_cc("struct SomeStruct {};").unwrap();
     let names = ir.records().map(|r| r.rs_name.as_ref()).filter(|n| n.contains("SomeStruct"));
-    // if we do support nested structs, we should not emit record for injected class
-    // name
+    // we should not emit a (nested) record for the injected class name
     assert_eq!(names.count(), 1);
-    // if we don't support nested structs, we should not emit unsupported item for
-    // injected class name
+    // we should not emit an unsupported item for the injected class name either
     assert_ir_not_matches!(
         ir,
         quote! { UnsupportedItem {
-          name: "SomeStruct::SomeStruct",
-          message: "Nested classes are not supported yet" ...
+          name: "SomeStruct::SomeStruct" ...
         }}
     );
 }
@@ -2617,6 +2733,43 @@ fn test_union_with_data_members_with_different_sizes() {
     );
 }
 
+#[test]
+fn test_array_field() {
+    let ir = ir_from_cc("struct Key { unsigned char bytes[32]; const int* ptrs[2]; };").unwrap();
+    assert_ir_matches!(
+        ir,
+        quote! {
+            Field {
+                identifier: Some("bytes") ...
+                type_: Ok(MappedType {
+                    rs_type: RsType {
+                        name: Some("#Array 32"), ...
+                        type_args: [RsType { name: Some("u8"), ... }], ...
+                    },
+                    cc_type: CcType {
+                        name: Some("#array 32"), ...
+                        type_args: [CcType { name: Some("unsigned char"), ... }], ...
+                    },
+                }) ...
+            }
+        }
+    );
+    assert_ir_matches!(
+        ir,
+        quote! {
+            Field {
+                identifier: Some("ptrs") ...
+                type_: Ok(MappedType {
+                    rs_type: RsType {
+                        name: Some("#Array 2"), ...
+                        type_args: [RsType { name: Some("*const"), ... }], ...
+                    }, ...
+                }) ...
+            }
+        }
+    );
+}
+
 #[test]
 fn test_member_function_params() {
     let ir = ir_from_cc(
@@ -2650,6 +2803,7 @@ fn assert_member_function_with_predicate_has_instance_method_metadata<F: FnMut(&
         .expect("Member function should specify member_func_metadata");
     assert_eq!(meta.record_id, record.id);
     assert_eq!(&meta.instance_method_metadata, expected_metadata);
+    assert_eq!(meta.is_static, expected_metadata.is_none());
 }
 
 fn assert_member_function_has_instance_method_metadata(
@@ -2745,6 +2899,27 @@ fn test_member_function_rvalue() {
     );
 }
 
+#[test]
+fn test_member_function_access() {
+    let ir = ir_from_cc(
+        r#"
+        class SomeClass {
+         public:
+          void PublicMethod();
+         private:
+          void PrivateMethod();
+        };
+        "#,
+    )
+    .unwrap();
+    let meta = retrieve_func(&ir, "PublicMethod").member_func_metadata.clone().unwrap();
+    assert_eq!(meta.access, AccessSpecifier::Public);
+    // Non-public member functions are only imported for friend crates.
+    assert!(ir
+        .functions()
+        .all(|f| f.name != UnqualifiedIdentifier::Identifier(ir_id("PrivateMethod"))));
+}
+
 #[test]
 fn test_member_function_explicit_constructor() {
     let ir = ir_from_cc(
@@ -2808,28 +2983,24 @@ fn test_identifier_function_name() {
 
 #[test]
 fn test_constructor_function_name() {
-    assert!(
-        get_func_names("struct Struct {Struct();};")
-            .contains(&ir::UnqualifiedIdentifier::Constructor)
-    );
+    assert!(get_func_names("struct Struct {Struct();};")
+        .contains(&ir::UnqualifiedIdentifier::Constructor));
 }
 
 #[test]
 fn test_destructor_function_name() {
-    assert!(
-        get_func_names("struct Struct {~Struct();};")
-            .contains(&ir::UnqualifiedIdentifier::Destructor)
-    );
+    assert!(get_func_names("struct Struct {~Struct();};")
+        .contains(&ir::UnqualifiedIdentifier::Destructor));
 }
 
 #[test]
 fn test_unsupported_items_are_emitted() -> Result<()> {
     // We will have to rewrite this test to use something else that is unsupported
-    // once we start importing nested structs.
-    let ir = ir_from_cc("struct X { struct Y {}; };")?;
+    // once we start supporting `rs_char`.
+    let ir = ir_from_cc("namespace rs_std { struct rs_char {}; }")?;
     assert_strings_contain(
         ir.unsupported_items().map(|i| i.name.as_ref()).collect_vec().as_slice(),
-        "X::Y",
+        "rs_std::rs_char",
     );
     Ok(())
 }
@@ -2837,28 +3008,28 @@ fn test_unsupported_items_are_emitted() -> Result<()> {
 #[test]
 fn test_unsupported_items_from_dependency_are_not_emitted() -> Result<()> {
     // We will have to rewrite this test to use something else that is unsupported
-    // once we start importing nested structs.
+    // once we start supporting `rs_char`.
     let ir = ir_from_cc_dependency(
-        "struct MyOtherStruct { OuterStruct::NestedStructIsUnsupported my_field; };",
-        "struct OuterStruct { struct NestedStructIsUnsupported {}; };",
+        "struct MyOtherStruct { rs_std::rs_char my_field; };",
+        "namespace rs_std { struct rs_char {}; }",
     )?;
     let names = ir.unsupported_items().map(|i| i.name.as_ref()).collect_vec();
-    assert_strings_dont_contain(names.as_slice(), "OuterStruct");
-    assert_strings_dont_contain(names.as_slice(), "NestedStructIsUnsupported");
+    assert_strings_dont_contain(names.as_slice(), "rs_std");
+    assert_strings_dont_contain(names.as_slice(), "rs_char");
     Ok(())
 }
 
 #[test]
 fn test_user_of_unsupported_type_is_unsupported() -> Result<()> {
     // We will have to rewrite this test to use something else that is unsupported
-    // once we start importing nested structs.
+    // once we start supporting `rs_char`.
     let ir = ir_from_cc(
-        r#"struct S { struct Nested {int x;}; int y; };
-           void f(S::Nested n);
+        r#"namespace rs_std { struct rs_char {int x;}; }
+           void f(rs_std::rs_char n);
         "#,
     )?;
     let names = ir.unsupported_items().map(|i| i.name.as_ref()).collect_vec();
-    assert_strings_contain(names.as_ref(), "S::Nested");
+    assert_strings_contain(names.as_ref(), "rs_std::rs_char");
     assert_strings_contain(names.as_ref(), "f");
     Ok(())
 }
@@ -3030,6 +3201,24 @@ fn test_c_style_struct_with_typedef_and_aligned_attr() {
     };
 }
 
+#[test]
+fn test_needs_opaque_layout() {
+    let ir = ir_from_cc(
+        r#"
+        struct Empty {};
+        struct Overlapping { int x; [[no_unique_address]] Empty empty; };
+        struct Packed { char c; int x; } __attribute__((packed));
+        struct Regular { char c; int x; };"#,
+    )
+    .unwrap();
+    let needs_opaque_layout =
+        |name: &str| ir.records().find(|r| r.rs_name.as_ref() == name).unwrap().needs_opaque_layout;
+    assert!(needs_opaque_layout("Overlapping"));
+    assert!(needs_opaque_layout("Packed"));
+    assert!(!needs_opaque_layout("Regular"));
+    assert!(!needs_opaque_layout("Empty"));
+}
+
 #[test]
 fn test_volatile_is_unsupported() {
     let ir = ir_from_cc("volatile int* foo();").unwrap();
@@ -3051,6 +3240,117 @@ fn test_unnamed_enum_unsupported() {
     );
 }
 
+#[test]
+fn test_constant_sizeof_alignof() {
+    let ir = ir_from_cc(
+        r#"
+        struct S { int i; double d; };
+
+        // Doc comment for kSize.
+        constexpr int kSize = sizeof(S);
+        constexpr unsigned long kAlign = alignof(S);
+        "#,
+    )
+    .unwrap();
+    assert_ir_matches!(
+        ir,
+        quote! {
+            Constant {
+                identifier: "kSize",
+                id: ItemId(...),
+                owning_target: BazelLabel("//test:testing_target"),
+                doc_comment: Some("Doc comment for kSize."),
+                type_: MappedType { rs_type: RsType { name: Some("i32"), ... }, ... },
+                value: Integer(IntegerConstant { is_negative: false, wrapped_value: 16 }),
+                source_loc: ...,
+                enclosing_namespace_id: None,
+            }
+        }
+    );
+    assert_ir_matches!(
+        ir,
+        quote! {
+            Constant {
+                identifier: "kAlign", ...
+                value: Integer(IntegerConstant { is_negative: false, wrapped_value: 8 }), ...
+            }
+        }
+    );
+}
+
+#[test]
+fn test_constant_non_integer_unsupported() {
+    let ir = ir_from_cc("constexpr double kPi = 3.14;").unwrap();
+    assert_ir_matches!(
+        ir,
+        quote! {
+            UnsupportedItem {
+                name: "kPi",
+                message: "Only constants of integer types are supported" ...
+            }
+        }
+    );
+}
+
+#[test]
+fn test_string_constants() {
+    let ir = ir_from_cc(
+        r#"
+        namespace std {
+        template <typename C>
+        class basic_string_view {
+         public:
+          constexpr basic_string_view(const C* data, unsigned long size)
+              : data_(data), size_(size) {}
+         private:
+          const C* data_;
+          unsigned long size_;
+        };
+        using string_view = basic_string_view<char>;
+        }
+
+        constexpr const char* kName = "abc";
+        const char* const kTruncated = "a\0b";
+        constexpr std::string_view kView("xyz", 2);
+        "#,
+    )
+    .unwrap();
+    assert_ir_matches!(
+        ir,
+        quote! {
+            Constant {
+                identifier: "kName", ...
+                value: String(StringConstant { is_c_string: true, bytes: [97, 98, 99] }), ...
+            }
+        }
+    );
+    assert_ir_matches!(
+        ir,
+        quote! {
+            Constant {
+                identifier: "kTruncated", ...
+                value: String(StringConstant { is_c_string: true, bytes: [97] }), ...
+            }
+        }
+    );
+    assert_ir_matches!(
+        ir,
+        quote! {
+            Constant {
+                identifier: "kView", ...
+                value: String(StringConstant { is_c_string: false, bytes: [120, 121] }), ...
+            }
+        }
+    );
+}
+
+#[test]
+fn test_non_constexpr_variable_is_skipped() {
+    let ir = ir_from_cc("extern int global_var;").unwrap();
+    assert_ir_not_matches!(ir, quote! { Constant { ... } });
+    assert_ir_not_matches!(ir, quote! { UnsupportedItem { name: "global_var", ... } });
+}
+
 #[test]
 fn test_literal_operator_unsupported() {
     let ir = ir_from_cc(
@@ -3074,9 +3374,9 @@ fn test_literal_operator_unsupported() {
 
 #[test]
 fn test_unsupported_item_has_item_id() {
-    let ir = ir_from_cc("struct SomeStruct { struct NestedStruct {}; };").unwrap();
+    let ir = ir_from_cc("namespace rs_std { struct rs_char {}; }").unwrap();
     let unsupported =
-        ir.unsupported_items().find(|i| i.name.as_ref() == "SomeStruct::NestedStruct").unwrap();
+        ir.unsupported_items().find(|i| i.name.as_ref() == "rs_std::rs_char").unwrap();
     assert_ne!(unsupported.id, ItemId::new_for_testing(0));
 }
 
@@ -3208,7 +3508,7 @@ fn test_record_items() {
               ... Func { ... name: "bar" ... }
             },
             quote! {
-              ... UnsupportedItem { ... name: "TopLevelStruct::Nested" ... }
+              ... Record { ... rs_name: "Nested" ... }
             },
             quote! {
               ...Func {
@@ -3359,11 +3659,9 @@ fn test_enclosing_namespace_ids() {
     let inner_namespace_items: Vec<&Item> =
         inner_namespace.child_item_ids.iter().map(|id| ir.find_decl(*id).unwrap()).collect_vec();
 
-    assert!(
-        inner_namespace_items
-            .iter()
-            .all(|item| item.enclosing_namespace_id() == Some(inner_namespace.id))
-    );
+    assert!(inner_namespace_items
+        .iter()
+        .all(|item| item.enclosing_namespace_id() == Some(inner_namespace.id)));
 
     let record = ir.records().find(|r| r.rs_name.as_ref() == "S").unwrap();
     let record_items: Vec<&Item> =
@@ -3588,6 +3886,608 @@ fn test_inline_namespace() {
     );
 }
 
+#[test]
+fn test_noexcept() {
+    let ir = ir_from_cc(
+        r#"
+        void MayThrow();
+        void NoExcept() noexcept;
+        void NoExceptFalse() noexcept(false);
+        struct S final {
+          ~S();
+        };
+        "#,
+    )
+    .unwrap();
+    assert!(!retrieve_func(&ir, "MayThrow").is_noexcept);
+    assert!(retrieve_func(&ir, "NoExcept").is_noexcept);
+    assert!(!retrieve_func(&ir, "NoExceptFalse").is_noexcept);
+    let destructor = ir.functions().find(|f| f.name == UnqualifiedIdentifier::Destructor).unwrap();
+    assert!(destructor.is_noexcept);
+}
+
+#[test]
+fn test_throwing_assignment_annotation() {
+    let ir = ir_from_cc(
+        r#"
+        struct [[clang::annotate("crubit_throwing_assignment")]] Throwing {};
+        struct NotThrowing {};
+        "#,
+    )
+    .unwrap();
+    assert!(retrieve_record(&ir, "Throwing").has_throwing_assignment);
+    assert!(!retrieve_record(&ir, "NotThrowing").has_throwing_assignment);
+}
+
+#[test]
+fn test_bytes_serializable_annotation() {
+    let ir = ir_from_cc(
+        r#"
+        struct [[clang::annotate("crubit_bytes_serializable")]] Serializable {};
+        struct NotSerializable {};
+        "#,
+    )
+    .unwrap();
+    assert!(retrieve_record(&ir, "Serializable").is_bytes_serializable);
+    assert!(!retrieve_record(&ir, "NotSerializable").is_bytes_serializable);
+}
+
+#[test]
+fn test_error_code_annotation() {
+    let ir = ir_from_cc(
+        r#"
+        enum class [[clang::annotate("crubit_error_code")]] Status { kOk, kNotFound };
+        enum class Color { kRed, kBlue };
+        enum class [[clang::annotate("crubit_error_code")]] NoSuccess { kFailed = 1 };
+        "#,
+    )
+    .unwrap();
+    assert_ir_matches!(
+        ir,
+        quote! {
+            Enum {
+                identifier: "Status", ...
+                is_error_code: true, ...
+            }
+        }
+    );
+    assert_ir_matches!(
+        ir,
+        quote! {
+            Enum {
+                identifier: "Color", ...
+                is_error_code: false, ...
+            }
+        }
+    );
+    assert_ir_matches!(
+        ir,
+        quote! { UnsupportedItem {
+            name: "NoSuccess", ...
+            message: "Error code enums must have an enumerator with the value 0, which denotes success"
+            ...
+        }}
+    );
+}
+
+#[test]
+fn test_stream_extractable() {
+    let ir = ir_from_cc(
+        r#"
+        namespace std {
+        template <typename CharT> class basic_istream;
+        using istream = basic_istream<char>;
+        }
+        struct Extractable {};
+        std::istream& operator>>(std::istream& stream, Extractable& value);
+        struct FriendExtractable {
+          friend std::istream& operator>>(std::istream& stream,
+                                          FriendExtractable& value) {
+            return stream;
+          }
+        };
+        struct NotDefaultConstructible {
+          NotDefaultConstructible(int);
+        };
+        std::istream& operator>>(std::istream& stream,
+                                 NotDefaultConstructible& value);
+        struct ConstParam {};
+        std::istream& operator>>(std::istream& stream, const ConstParam& value);
+        struct NotExtractable {};
+        "#,
+    )
+    .unwrap();
+    assert!(retrieve_record(&ir, "Extractable").is_stream_extractable);
+    assert!(retrieve_record(&ir, "FriendExtractable").is_stream_extractable);
+    assert!(!retrieve_record(&ir, "NotDefaultConstructible").is_stream_extractable);
+    assert!(!retrieve_record(&ir, "ConstParam").is_stream_extractable);
+    assert!(!retrieve_record(&ir, "NotExtractable").is_stream_extractable);
+}
+
+#[test]
+fn test_log_sink_annotation() {
+    let ir = ir_from_cc(
+        r#"
+        class [[clang::annotate("crubit_log_sink")]] LogSink {};
+        class NotLogSink {};
+        "#,
+    )
+    .unwrap();
+    assert!(retrieve_record(&ir, "LogSink").is_log_sink);
+    assert!(!retrieve_record(&ir, "NotLogSink").is_log_sink);
+}
+
+#[test]
+fn test_crubit_internal_rust_type_annotation() {
+    let ir = ir_from_cc(
+        r#"
+        struct [[clang::annotate("crubit_internal_rust_type", "::rust_crate::Point")]]
+            Point final { int x; };
+        void TakesPoint(Point* point);
+        "#,
+    )
+    .unwrap();
+    assert_ir_not_matches!(ir, quote! { Record { rs_name: "Point", ... } });
+    assert_ir_matches!(
+        ir,
+        quote! {
+            Func {
+                name: "TakesPoint", ...
+                params: [FuncParam {
+                    type_: MappedType {
+                        rs_type: RsType {
+                            name: Some("*mut") ...
+                            type_args: [RsType {
+                                name: Some("::rust_crate::Point") ...
+                                decl_id: None,
+                            }], ...
+                        },
+                        cc_type: CcType {
+                            name: Some("*") ...
+                            type_args: [CcType {
+                                name: Some("Point") ...
+                                decl_id: None,
+                            }], ...
+                        },
+                    },
+                    identifier: "point", ...
+                }], ...
+            }
+        }
+    );
+}
+
+#[test]
+fn test_safe_pointer_params_annotation() {
+    let ir = ir_from_cc(
+        r#"
+        [[clang::annotate("crubit_safe_pointer_params")]] void Safe(int* p);
+        void NotSafe(int* p);
+        "#,
+    )
+    .unwrap();
+    assert!(retrieve_func(&ir, "Safe").has_safe_pointer_params);
+    assert!(!retrieve_func(&ir, "NotSafe").has_safe_pointer_params);
+}
+
+#[test]
+fn test_requires_capability_annotation() {
+    let ir = ir_from_cc(
+        r#"
+        [[clang::annotate("crubit_requires_capability", "::ui::UiThread", "::init::Initialized")]]
+        void Repaint();
+        void Anywhere();
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        retrieve_func(&ir, "Repaint").required_capabilities.iter().map(|c| &**c).collect_vec(),
+        vec!["::ui::UiThread", "::init::Initialized"]
+    );
+    assert!(retrieve_func(&ir, "Anywhere").required_capabilities.is_empty());
+}
+
+#[test]
+fn test_item_annotations() {
+    let ir = ir_from_cc(
+        r#"
+        [[clang::annotate("crubit_rust_name", "is_empty")]] bool IsEmpty();
+        [[clang::annotate("crubit_skip")]] [[clang::annotate("crubit_doc_hidden")]] void Reset();
+        struct [[clang::annotate("crubit_rust_name", "Token")]] RegistrationToken final {};
+        void Plain();
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        retrieve_func(&ir, "IsEmpty").annotations,
+        ItemAnnotations { rust_name: Some("is_empty".into()), skip: false, doc_hidden: false }
+    );
+    assert_eq!(
+        retrieve_func(&ir, "Reset").annotations,
+        ItemAnnotations { rust_name: None, skip: true, doc_hidden: true }
+    );
+    assert_eq!(retrieve_func(&ir, "Plain").annotations, ItemAnnotations::default());
+    let record = ir.records().find(|r| r.cc_name.as_ref() == "RegistrationToken").unwrap();
+    assert_eq!(record.rs_name.as_ref(), "Token");
+    assert_eq!(record.annotations.rust_name.as_deref(), Some("Token"));
+}
+
+#[test]
+fn test_precondition_annotation() {
+    let ir = ir_from_cc(
+        r#"
+        [[clang::annotate("crubit_precondition", "index < size")]]
+        int At(const int* data, int size, int index);
+        int Unchecked(const int* data, int index);
+        "#,
+    )
+    .unwrap();
+    assert_eq!(retrieve_func(&ir, "At").precondition.as_deref(), Some("index < size"));
+    assert_eq!(retrieve_func(&ir, "Unchecked").precondition, None);
+}
+
+#[test]
+fn test_using_declarations_of_base_class_members() {
+    let ir = ir_from_cc(
+        r#"
+        struct Base {
+          Base(int x);
+          int Method() const;
+        };
+        struct Derived : Base {
+          using Base::Base;
+          using Base::Method;
+        };
+        "#,
+    )
+    .unwrap();
+    let base = retrieve_record(&ir, "Base");
+    let derived = retrieve_record(&ir, "Derived");
+    let derived_members = ir
+        .functions()
+        .filter(|func| {
+            func.member_func_metadata.as_ref().map(|meta| meta.record_id) == Some(derived.id)
+        })
+        .collect_vec();
+
+    let inherited_ctor = derived_members
+        .iter()
+        .find(|func| func.name == UnqualifiedIdentifier::Constructor && func.params.len() == 2)
+        .expect("Derived should have the constructor inherited from Base");
+    assert_eq!(inherited_ctor.params[1].identifier, ir_id("x"));
+    assert_eq!(inherited_ctor.inherited_from, None);
+
+    let method = derived_members
+        .iter()
+        .find(|func| func.name == UnqualifiedIdentifier::Identifier(ir_id("Method")))
+        .expect("Derived should have the method declared by `using Base::Method;`");
+    assert_eq!(method.inherited_from, Some(base.id));
+    assert!(derived.child_item_ids.contains(&method.id));
+    assert_ne!(method.mangled_name, retrieve_func(&ir, "Method").mangled_name);
+}
+
+#[test]
+fn test_callback_annotations() {
+    let ir = ir_from_cc(
+        r#"
+        void ForEachEntry(
+            [[clang::annotate("crubit_callback", "context")]]
+            void (*visit)(void* context, int key),
+            void* context);
+        void SetHandler(
+            [[clang::annotate("crubit_retained_callback", "context")]]
+            void (*handler)(void* context),
+            void* context);
+        void NoContext(
+            [[clang::annotate("crubit_callback", "context")]] void (*visit)(int key),
+            void* context);
+        "#,
+    )
+    .unwrap();
+    let for_each_entry = retrieve_func(&ir, "ForEachEntry");
+    assert_eq!(for_each_entry.params[0].callback_context, Some(ir_id("context")));
+    assert!(!for_each_entry.params[0].is_callback_retained);
+    assert_eq!(for_each_entry.params[1].callback_context, None);
+    let set_handler = retrieve_func(&ir, "SetHandler");
+    assert_eq!(set_handler.params[0].callback_context, Some(ir_id("context")));
+    assert!(set_handler.params[0].is_callback_retained);
+    assert_ir_matches!(
+        ir,
+        quote! { UnsupportedItem {
+          name: "NoContext", ...
+          message: "Parameter #0 is an invalid callback: The callback must take exactly one `void*` context parameter"
+          ...
+        }}
+    );
+}
+
+#[test]
+fn test_borrowed_callable_annotation() {
+    let ir = ir_from_cc(
+        r#"
+        namespace std {
+        template <typename F> class function;
+        template <typename R, typename... Args> class function<R(Args...)> {};
+        }
+        void ForEach([[clang::annotate("crubit_borrowed_callable")]]
+                     const std::function<void(int)>& f);
+        void SetHandler(const std::function<void(int)>& f);
+        "#,
+    )
+    .unwrap();
+    assert!(retrieve_func(&ir, "ForEach").params[0].is_callable_borrowed);
+    assert!(!retrieve_func(&ir, "SetHandler").params[0].is_callable_borrowed);
+}
+
+#[test]
+fn test_result_ownership_annotations() {
+    let ir = ir_from_cc(
+        r#"
+        struct Widget {};
+        void DestroyWidget(Widget* widget);
+        [[clang::annotate("crubit_owned_result", "DestroyWidget")]] Widget* CreateWidget();
+        [[clang::annotate("crubit_owned_result", "delete")]] Widget* NewWidget();
+        [[clang::annotate("crubit_borrowed_result")]] Widget* FindWidget(int id);
+        "#,
+    )
+    .unwrap();
+    assert_eq!(retrieve_func(&ir, "CreateWidget").result_deleter.as_deref(), Some("DestroyWidget"));
+    assert_eq!(retrieve_func(&ir, "NewWidget").result_deleter.as_deref(), Some("delete"));
+    assert_ir_matches!(
+        ir,
+        quote! { UnsupportedItem {
+          name: "FindWidget", ...
+          message: "`CRUBIT_BORROWED_RESULT` requires the lifetimes of the function to be known (e.g. with `#pragma clang lifetime_elision`)"
+          ...
+        }}
+    );
+}
+
+#[test]
+fn test_record_with_lifetime_params() {
+    let ir = ir_from_cc(
+        r#"
+        struct [[clang::annotate("lifetime_params", "a")]] IntView final {
+          [[clang::annotate("member_lifetimes", "a")]] const int* data;
+        };
+        "#,
+    )
+    .unwrap();
+    let record = retrieve_record(&ir, "IntView");
+    assert_eq!(record.lifetime_params.iter().map(|p| p.name.as_ref()).collect_vec(), vec!["a"]);
+    let field_type = record.fields[0].type_.as_ref().unwrap();
+    assert_eq!(field_type.rs_type.lifetime_args, vec![record.lifetime_params[0].id]);
+}
+
+#[test]
+fn test_variadic_function() {
+    let ir = ir_from_cc(
+        r#"
+        int Variadic(int count, ...);
+        int NotVariadic(int count);
+        "#,
+    )
+    .unwrap();
+    assert!(retrieve_func(&ir, "Variadic").is_variadic);
+    assert!(!retrieve_func(&ir, "NotVariadic").is_variadic);
+}
+
+#[test]
+fn test_linkage_and_visibility() {
+    let ir = ir_from_cc(
+        r#"
+        static int Internal(int x) { return x; }
+        __attribute__((visibility("hidden"))) int Hidden();
+        int Exported();
+        static int InternalWithoutDefinition();
+        __attribute__((visibility("hidden"))) int HiddenVariadic(int, ...);
+        "#,
+    )
+    .unwrap();
+    let internal = retrieve_func(&ir, "Internal");
+    assert!(internal.has_internal_linkage);
+    assert!(!internal.has_hidden_visibility);
+    let hidden = retrieve_func(&ir, "Hidden");
+    assert!(!hidden.has_internal_linkage);
+    assert!(hidden.has_hidden_visibility);
+    let exported = retrieve_func(&ir, "Exported");
+    assert!(!exported.has_internal_linkage);
+    assert!(!exported.has_hidden_visibility);
+    assert_ir_matches!(
+        ir,
+        quote! {
+            UnsupportedItem {
+                name: "InternalWithoutDefinition",
+                message: "Functions with internal linkage are only supported if they are defined in the header", ...
+            }
+        }
+    );
+    assert_ir_matches!(
+        ir,
+        quote! {
+            UnsupportedItem {
+                name: "HiddenVariadic",
+                message: "Variadic functions with internal linkage or hidden visibility are not supported, because they can't be called through a thunk", ...
+            }
+        }
+    );
+}
+
+#[test]
+fn test_extern_c() {
+    let ir = ir_from_cc(
+        r#"
+        extern "C" {
+          struct CStruct { int x; };
+          struct NotCLike { virtual void Method(); };
+          int CFunction(struct CStruct s);
+        }
+        struct CppStruct { int x; };
+        int CppFunction();
+        "#,
+    )
+    .unwrap();
+    assert!(retrieve_record(&ir, "CStruct").is_extern_c);
+    assert!(!retrieve_record(&ir, "NotCLike").is_extern_c);
+    assert!(!retrieve_record(&ir, "CppStruct").is_extern_c);
+    assert!(retrieve_func(&ir, "CFunction").is_extern_c);
+    assert!(!retrieve_func(&ir, "CppFunction").is_extern_c);
+}
+
+#[test]
+fn test_param_contracts() {
+    let ir = ir_from_cc(
+        r#"
+        void Fill(float* _Nonnull data [[clang::annotate("crubit_sized_by", "size")]]
+                      [[clang::annotate("crubit_aligned_to", "16")]],
+                  unsigned long size);
+        void NoContracts(float* data);
+        void BadSize(float* data [[clang::annotate("crubit_sized_by", "unknown")]]);
+        "#,
+    )
+    .unwrap();
+    let fill = retrieve_func(&ir, "Fill");
+    assert!(fill.params[0].is_nonnull);
+    assert_eq!(fill.params[0].sized_by.as_ref().map(|id| &*id.identifier), Some("size"));
+    assert_eq!(fill.params[0].alignment, Some(16));
+    let no_contracts = retrieve_func(&ir, "NoContracts");
+    assert!(!no_contracts.params[0].is_nonnull);
+    assert_eq!(no_contracts.params[0].sized_by, None);
+    assert_eq!(no_contracts.params[0].alignment, None);
+    assert_ir_matches!(
+        ir,
+        quote! {
+            UnsupportedItem {
+                name: "BadSize",
+                message: "Parameter #0 has an invalid contract: CRUBIT_SIZED_BY(unknown) must name an integer parameter", ...
+            }
+        }
+    );
+}
+
+#[test]
+fn test_member_pointers() {
+    let ir = ir_from_cc(
+        r#"
+        struct S {
+          int Get(int) const;
+          void Set(int);
+          int value;
+        };
+        struct Callbacks {
+          int (S::*getter)(int) const;
+          void (S::*setter)(int);
+          int S::*field;
+          void (S::*ref_qualified)() &;
+        };
+        "#,
+    )
+    .unwrap();
+    let callbacks = retrieve_record(&ir, "Callbacks");
+    let field_type = |index: usize| callbacks.fields[index].type_.as_ref();
+
+    let getter = field_type(0).unwrap();
+    assert_eq!(getter.rs_type.name.as_deref(), Some("#MemberFnPtr const"));
+    assert_eq!(getter.cc_type.name.as_deref(), Some("#memberFuncPtr const"));
+    let type_arg_names =
+        getter.rs_type.type_args.iter().map(|t| t.name.as_deref()).collect::<Vec<_>>();
+    assert_eq!(type_arg_names, [None, Some("i32"), Some("i32")]);
+    assert_eq!(getter.rs_type.type_args[0].decl_id, Some(retrieve_record(&ir, "S").id));
+
+    let setter = field_type(1).unwrap();
+    assert_eq!(setter.rs_type.name.as_deref(), Some("#MemberFnPtr mut"));
+    assert_eq!(setter.cc_type.name.as_deref(), Some("#memberFuncPtr"));
+
+    let field = field_type(2).unwrap();
+    assert_eq!(field.rs_type.name.as_deref(), Some("#DataMemberPtr"));
+    assert_eq!(field.cc_type.name.as_deref(), Some("#dataMemberPtr"));
+    assert_eq!(field.rs_type.type_args[1].name.as_deref(), Some("i32"));
+
+    assert!(field_type(3).unwrap_err().contains("ref-qualified"));
+}
+
+#[test]
+fn test_returns_static_local() {
+    let ir = ir_from_cc(
+        r#"
+        struct S {};
+        inline S& Instance() { static S s; return s; }
+        inline S* InstancePtr() { static S s; return &s; }
+        inline S& NotStatic(S& s) { return s; }
+        S& Declared();
+        "#,
+    )
+    .unwrap();
+    assert!(retrieve_func(&ir, "Instance").returns_static_local);
+    assert!(retrieve_func(&ir, "InstancePtr").returns_static_local);
+    assert!(!retrieve_func(&ir, "NotStatic").returns_static_local);
+    assert!(!retrieve_func(&ir, "Declared").returns_static_local);
+}
+
+#[test]
+fn test_trivial_body() {
+    let ir = ir_from_cc(
+        r#"
+        int Bar(int x, int y);
+        inline int Foo(int x, int y) { return Bar(x, y); }
+        inline int Swapped(int x, int y) { return Bar(y, x); }
+        inline int Answer() { return 42; }
+        inline bool True() { return true; }
+        inline int NotInline();
+        struct S final {
+          int x() const { return x_; }
+          void set_x(int x) { x_ = x; }
+          int twice_x() const { return x_ * 2; }
+          int x_;
+        };
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        retrieve_func(&ir, "Foo").trivial_body,
+        Some(TrivialBody {
+            kind: TrivialBodyKind::ForwardsCall,
+            field: None,
+            constant: None,
+            callee: Some(retrieve_func(&ir, "Bar").id),
+        })
+    );
+    assert_eq!(retrieve_func(&ir, "Swapped").trivial_body, None);
+    assert_eq!(
+        retrieve_func(&ir, "Answer").trivial_body,
+        Some(TrivialBody {
+            kind: TrivialBodyKind::ReturnsConstant,
+            field: None,
+            constant: Some("42".into()),
+            callee: None,
+        })
+    );
+    assert_eq!(
+        retrieve_func(&ir, "True").trivial_body.as_ref().and_then(|body| body.constant.clone()),
+        Some("true".into())
+    );
+    assert_eq!(retrieve_func(&ir, "NotInline").trivial_body, None);
+    assert_eq!(
+        retrieve_func(&ir, "x").trivial_body,
+        Some(TrivialBody {
+            kind: TrivialBodyKind::ReturnsField,
+            field: Some(ir_id("x_")),
+            constant: None,
+            callee: None,
+        })
+    );
+    assert_eq!(
+        retrieve_func(&ir, "set_x").trivial_body,
+        Some(TrivialBody {
+            kind: TrivialBodyKind::SetsField,
+            field: Some(ir_id("x_")),
+            constant: None,
+            callee: None,
+        })
+    );
+    assert_eq!(retrieve_func(&ir, "twice_x").trivial_body, None);
+}
+
 #[test]
 fn test_function_redeclared_as_friend() {
     let ir = ir_from_cc(
@@ -3724,6 +4624,8 @@ fn test_forward_declared_specialization_has_rs_name() {
               cc_name: "test_namespace_bindings::MyTemplate<test_namespace_bindings::Param>",
               rs_name: "__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_5ParamEEE",
               ...
+              is_template_instantiation: true,
+              ...
             } ...
         }
     );
@@ -3946,3 +4848,89 @@ fn test_source_location_class_template_specialization() {
         }
     );
 }
+
+#[test]
+fn test_deprecated() {
+    let ir = ir_from_cc(
+        r#"
+        [[deprecated("Use NewFunc instead")]] void OldFunc();
+        void NewFunc();
+        struct [[deprecated]] OldStruct final {
+          [[deprecated("Use y")]] int x;
+          int y;
+        };
+        enum [[deprecated("Use NewEnum")]] OldEnum { kOld };
+        "#,
+    )
+    .unwrap();
+    assert_eq!(retrieve_func(&ir, "OldFunc").deprecated.as_deref(), Some("Use NewFunc instead"));
+    assert_eq!(retrieve_func(&ir, "NewFunc").deprecated, None);
+    let record = retrieve_record(&ir, "OldStruct");
+    assert_eq!(record.deprecated.as_deref(), Some(""));
+    assert_eq!(record.fields[0].deprecated.as_deref(), Some("Use y"));
+    assert_eq!(record.fields[1].deprecated, None);
+    assert_ir_matches!(
+        ir,
+        quote! {
+            Enum {
+                identifier: "OldEnum", ...
+                deprecated: Some("Use NewEnum"), ...
+            }
+        }
+    );
+}
+
+#[test]
+fn test_mangled_names_of_functions_in_inline_namespaces() {
+    let ir = ir_from_cc(
+        r#"
+        namespace ns {
+          inline namespace v1 {
+            void InInlineNamespace();
+          }
+        }
+        [[gnu::abi_tag("tag")]] void WithAbiTag();
+        void WithAsmLabel() __asm__("custom_symbol");
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        &*retrieve_func(&ir, "InInlineNamespace").mangled_name,
+        "_ZN2ns2v117InInlineNamespaceEv"
+    );
+    assert_eq!(&*retrieve_func(&ir, "WithAbiTag").mangled_name, "_Z10WithAbiTagB3tagv");
+    assert_eq!(&*retrieve_func(&ir, "WithAsmLabel").mangled_name, "custom_symbol");
+}
+
+#[test]
+fn test_ir_schema_version() {
+    // `kIrSchemaVersion` in `ir.h` has to be the same as `IR_SCHEMA_VERSION`.
+    let ir = ir_from_cc("").unwrap();
+    assert_eq!(ir.schema_version(), IR_SCHEMA_VERSION);
+}
+
+#[test]
+fn test_ir_from_cc_is_valid() {
+    let ir = ir_from_cc(
+        r#"
+        namespace ns {
+          struct Outer {
+            struct Inner { int i; };
+            Outer();
+            ~Outer();
+            void Method();
+            Outer& operator=(const Outer&);
+            int b1: 1;
+            int : 7;
+            int b2: 24;
+            [[no_unique_address]] struct Empty {} empty;
+            union { int u1; char u2; };
+          };
+          union Union { int i; float f; };
+          using Alias = Outer;
+        }
+        "#,
+    )
+    .unwrap();
+    assert_eq!(validate(&ir), vec![]);
+}
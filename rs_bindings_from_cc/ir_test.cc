@@ -0,0 +1,62 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#include "rs_bindings_from_cc/ir.h"
+
+#include <cstdint>
+#include <initializer_list>
+#include <string>
+
+#include "gmock/gmock.h"
+#include "gtest/gtest.h"
+#include "rs_bindings_from_cc/bazel_types.h"
+#include "llvm/Support/JSON.h"
+
+namespace crubit {
+namespace {
+
+using ::testing::Eq;
+using ::testing::StartsWith;
+
+std::string Bytes(std::initializer_list<int> bytes) {
+  std::string result;
+  for (int byte : bytes) {
+    result.push_back(static_cast<char>(byte));
+  }
+  return result;
+}
+
+TEST(IrTest, JsonToBinaryIrScalars) {
+  EXPECT_THAT(JsonToBinaryIr(nullptr), Eq(Bytes({0})));
+  EXPECT_THAT(JsonToBinaryIr(false), Eq(Bytes({1})));
+  EXPECT_THAT(JsonToBinaryIr(true), Eq(Bytes({2})));
+  EXPECT_THAT(JsonToBinaryIr(-2), Eq(Bytes({3, 3})));
+  EXPECT_THAT(JsonToBinaryIr(300), Eq(Bytes({3, 0xd8, 0x04})));
+  EXPECT_THAT(JsonToBinaryIr(uint64_t{1} << 63),
+              Eq(Bytes({4, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+                        0x80, 0x01})));
+  EXPECT_THAT(JsonToBinaryIr(1.5),
+              Eq(Bytes({5, 0, 0, 0, 0, 0, 0, 0xf8, 0x3f})));
+  EXPECT_THAT(JsonToBinaryIr("ab"), Eq(Bytes({6, 2, 'a', 'b'})));
+}
+
+TEST(IrTest, JsonToBinaryIrArray) {
+  EXPECT_THAT(JsonToBinaryIr(llvm::json::Array{false, nullptr}),
+              Eq(Bytes({7, 2, 1, 0})));
+}
+
+TEST(IrTest, JsonToBinaryIrObjectHasSortedKeys) {
+  EXPECT_THAT(JsonToBinaryIr(llvm::json::Object{{"b", nullptr}, {"a", true}}),
+              Eq(Bytes({8, 2, 1, 'a', 2, 1, 'b', 0})));
+}
+
+TEST(IrTest, IrToBinary) {
+  IR ir{.current_target = BazelLabel("//foo:bar")};
+  std::string binary = IrToBinary(ir);
+  EXPECT_THAT(binary, StartsWith(std::string(1, kBinaryIrHeader)));
+  EXPECT_THAT(binary.substr(1), Eq(JsonToBinaryIr(ir.ToJson())));
+}
+
+}  // namespace
+}  // namespace crubit
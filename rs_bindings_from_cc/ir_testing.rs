@@ -7,10 +7,10 @@ use arc_anyhow::Result;
 use ffi_types::{FfiU8Slice, FfiU8SliceBox};
 use ir::{self, make_ir_from_parts, Func, Identifier, Item, Record, IR};
 use itertools::Itertools;
-use std::rc::Rc;
+use std::sync::Arc;
 
 /// Generates `IR` from a header containing `header_source`.
-pub fn ir_from_cc(header_source: &str) -> Result<Rc<IR>> {
+pub fn ir_from_cc(header_source: &str) -> Result<Arc<IR>> {
     ir_from_cc_dependency(header_source, "// empty header")
 }
 
@@ -56,7 +56,22 @@ pub const DEPENDENCY_TARGET: &str = "//test:dependency";
 pub fn ir_from_cc_dependency(
     header_source: &str,
     dependency_header_source: &str,
-) -> Result<Rc<IR>> {
+) -> Result<Arc<IR>> {
+    let json_utf8 = ir_json_from_cc_dependency(header_source, dependency_header_source);
+    Ok(Arc::new(ir::deserialize_ir(&*json_utf8)?))
+}
+
+/// Generates the JSON of the `IR` from a header containing `header_source`
+/// (e.g. to deserialize the `IR` on several threads).
+pub fn ir_json_from_cc(header_source: &str) -> Box<[u8]> {
+    ir_json_from_cc_dependency(header_source, "// empty header")
+}
+
+/// Like `ir_from_cc_dependency`, but returns the JSON of the `IR`.
+pub fn ir_json_from_cc_dependency(
+    header_source: &str,
+    dependency_header_source: &str,
+) -> Box<[u8]> {
     const DEPENDENCY_HEADER_NAME: &str = "test/dependency_header.h";
 
     extern "C" {
@@ -70,14 +85,13 @@ pub fn ir_from_cc_dependency(
         format!("#include \"{}\"\n\n{}", DEPENDENCY_HEADER_NAME, header_source);
     let header_source_with_include_u8 = header_source_with_include.as_bytes();
     let dependency_header_source_u8 = dependency_header_source.as_bytes();
-    let json_utf8 = unsafe {
+    unsafe {
         json_from_cc_dependency(
             FfiU8Slice::from_slice(header_source_with_include_u8),
             FfiU8Slice::from_slice(dependency_header_source_u8),
         )
         .into_boxed_slice()
-    };
-    Ok(Rc::new(ir::deserialize_ir(&*json_utf8)?))
+    }
 }
 
 /// Creates an identifier
@@ -0,0 +1,389 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+//! Validation of the IR (see `validate`), so that a malformed IR is reported
+//! along with the offending items, instead of failing deep in the bindings
+//! generator.
+
+use crate::{Field, Item, ItemId, Record, RecordType, UnqualifiedIdentifier, IR};
+use std::sync::Arc;
+
+/// A problem of a malformed IR, found by `validate`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub message: String,
+    /// The offending item, or `None` if the problem is in the list of the
+    /// top-level items.
+    pub item_id: Option<ItemId>,
+    /// The source location of the offending item (e.g. `foo.h;l=42`), if
+    /// known.
+    pub source_loc: Option<Arc<str>>,
+}
+
+/// Returns the problems of a malformed `ir` which the bindings generator can't
+/// handle: references to items which are missing from the IR (or which are of
+/// the wrong kind), constructors and destructors which aren't member
+/// functions, and fields which lie beyond the size of their record, or which
+/// overlap.
+///
+/// The `decl_id`s of types aren't checked, because the bindings generator
+/// already reports them as errors of the items which use the types.
+pub fn validate(ir: &IR) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    for &id in ir.top_level_item_ids() {
+        if ir.find_untyped_decl(id).is_err() {
+            diagnostics.push(Diagnostic {
+                message: format!("The top-level item {id:?} is missing from the IR"),
+                item_id: None,
+                source_loc: None,
+            });
+        }
+    }
+    for item in ir.items() {
+        let mut report = |message| {
+            diagnostics.push(Diagnostic {
+                message,
+                item_id: Some(item.id()),
+                source_loc: source_loc(item).cloned(),
+            })
+        };
+        validate_references(ir, item, &mut report);
+        if let Item::Record(record) = item {
+            validate_fields(record, &mut report);
+        }
+    }
+    diagnostics
+}
+
+fn source_loc(item: &Item) -> Option<&Arc<str>> {
+    match item {
+        Item::Func(func) => Some(&func.source_loc),
+        Item::Record(record) => Some(&record.source_loc),
+        Item::Enum(enum_) => Some(&enum_.source_loc),
+        Item::TypeAlias(type_alias) => Some(&type_alias.source_loc),
+        Item::Constant(constant) => Some(&constant.source_loc),
+        Item::UnsupportedItem(unsupported) => Some(&unsupported.source_loc),
+        Item::IncompleteRecord(_) | Item::Comment(_) | Item::Namespace(_) | Item::UseMod(_) => None,
+    }
+}
+
+fn is_namespace(item: &Item) -> bool {
+    matches!(item, Item::Namespace(_))
+}
+
+fn is_record(item: &Item) -> bool {
+    matches!(item, Item::Record(_))
+}
+
+fn is_any_item(_: &Item) -> bool {
+    true
+}
+
+/// Reports `reference` (e.g. "The enclosing namespace") to the item `id` if
+/// the item is missing, or if it isn't of the `expected_kind`.
+fn validate_reference(
+    ir: &IR,
+    reference: &str,
+    id: ItemId,
+    (is_expected_kind, expected_kind): (fn(&Item) -> bool, &str),
+    report: &mut dyn FnMut(String),
+) {
+    match ir.find_untyped_decl(id) {
+        Err(_) => report(format!("{reference} {id:?} is missing from the IR")),
+        Ok(item) if !is_expected_kind(item) => {
+            report(format!("{reference} {id:?} isn't a {expected_kind}"))
+        }
+        Ok(_) => {}
+    }
+}
+
+fn validate_references(ir: &IR, item: &Item, report: &mut dyn FnMut(String)) {
+    const NAMESPACE: (fn(&Item) -> bool, &str) = (is_namespace, "namespace");
+    const RECORD: (fn(&Item) -> bool, &str) = (is_record, "record");
+    const ANY_ITEM: (fn(&Item) -> bool, &str) = (is_any_item, "item");
+    if let Some(id) = item.enclosing_namespace_id() {
+        validate_reference(ir, "The enclosing namespace", id, NAMESPACE, report);
+    }
+    if let Some(id) = item.enclosing_record_id() {
+        validate_reference(ir, "The enclosing record", id, RECORD, report);
+    }
+    let child_item_ids: &[ItemId] = match item {
+        Item::Func(func) => {
+            match (&func.member_func_metadata, &func.name) {
+                (Some(meta), _) => validate_reference(
+                    ir,
+                    "The record of the member function",
+                    meta.record_id,
+                    RECORD,
+                    report,
+                ),
+                (None, UnqualifiedIdentifier::Constructor | UnqualifiedIdentifier::Destructor) => {
+                    report("The constructor or destructor isn't a member function".to_string())
+                }
+                (None, _) => {}
+            }
+            &[]
+        }
+        Item::TypeAlias(type_alias) => {
+            if let Some(id) = type_alias.enclosing_record_id {
+                validate_reference(ir, "The enclosing record", id, RECORD, report);
+            }
+            &[]
+        }
+        Item::Record(record) => &record.child_item_ids,
+        Item::Namespace(namespace) => &namespace.child_item_ids,
+        _ => &[],
+    };
+    for &id in child_item_ids {
+        validate_reference(ir, "The child item", id, ANY_ITEM, report);
+    }
+}
+
+/// Returns the name of `field` for diagnostics.
+fn field_name(field: &Field) -> String {
+    match &field.identifier {
+        Some(identifier) => format!("`{}`", identifier.identifier),
+        None => "<unnamed>".to_string(),
+    }
+}
+
+/// Reports the `fields` (and the members of the anonymous structs and unions
+/// among them) which lie beyond the `record_size`.
+fn validate_field_bounds(fields: &[Field], record_size: usize, report: &mut dyn FnMut(String)) {
+    for field in fields {
+        // The offsets and sizes of the fields are in bits, the size of the
+        // record in bytes.
+        let end = field.offset + field.size;
+        if end > record_size * 8 {
+            report(format!(
+                "The field {} (bits {}..{end}) lies beyond the size of the record \
+                 ({record_size} bytes)",
+                field_name(field),
+                field.offset,
+            ));
+        }
+        validate_field_bounds(&field.anonymous_members, record_size, report);
+    }
+}
+
+/// Reports the fields of `record` which lie beyond its size, and the fields
+/// which overlap (except for the fields of unions, and `[[no_unique_address]]`
+/// fields, which may overlap with other fields).
+fn validate_fields(record: &Record, report: &mut dyn FnMut(String)) {
+    validate_field_bounds(&record.fields, record.size.max(record.original_cc_size), report);
+
+    if record.record_type == RecordType::Union {
+        return;
+    }
+    let mut fields = record
+        .fields
+        .iter()
+        .filter(|field| !field.is_no_unique_address && field.size > 0)
+        .collect::<Vec<_>>();
+    fields.sort_by_key(|field| field.offset);
+    // The field which ends last, which the following fields may overlap.
+    let mut furthest: Option<&Field> = None;
+    for field in fields {
+        let end = field.offset + field.size;
+        if let Some(furthest) = furthest {
+            let furthest_end = furthest.offset + furthest.size;
+            if furthest_end > field.offset {
+                report(format!(
+                    "The fields {} (bits {}..{furthest_end}) and {} (bits {}..{end}) overlap",
+                    field_name(furthest),
+                    furthest.offset,
+                    field_name(field),
+                    field.offset,
+                ));
+            }
+            if furthest_end >= end {
+                continue;
+            }
+        }
+        furthest = Some(field);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deserialize_ir;
+
+    /// Returns the diagnostics of the IR whose `items` are given as JSON.
+    fn validate_items(items: &str) -> Vec<Diagnostic> {
+        let json = format!(
+            r#"{{
+                "current_target": "//foo:bar",
+                "items": {items},
+                "top_level_item_ids": [1, 2]
+            }}"#
+        );
+        validate(&deserialize_ir(json.as_bytes()).unwrap())
+    }
+
+    fn namespace(
+        id: usize,
+        child_item_ids: &[usize],
+        enclosing_namespace_id: Option<usize>,
+    ) -> String {
+        serde_json::json!({ "Namespace": {
+            "name": { "identifier": "ns" },
+            "id": id,
+            "canonical_namespace_id": id,
+            "owning_target": "//foo:bar",
+            "child_item_ids": child_item_ids,
+            "enclosing_namespace_id": enclosing_namespace_id,
+            "is_inline": false,
+        }})
+        .to_string()
+    }
+
+    fn field(name: &str, offset: usize, size: usize) -> serde_json::Value {
+        serde_json::json!({
+            "identifier": { "identifier": name },
+            "doc_comment": null,
+            "deprecated": null,
+            "type": { "Err": "unsupported" },
+            "access": "Public",
+            "offset": offset,
+            "size": size,
+            "is_no_unique_address": false,
+            "is_bitfield": false,
+            "is_inheritable": false,
+            "anonymous_members": [],
+            "is_anonymous_union": false,
+        })
+    }
+
+    fn record(id: usize, record_type: &str, size: usize, fields: Vec<serde_json::Value>) -> String {
+        serde_json::json!({ "Record": {
+            "rs_name": "S",
+            "cc_name": "S",
+            "mangled_cc_name": "1S",
+            "id": id,
+            "owning_target": "//foo:bar",
+            "doc_comment": null,
+            "deprecated": null,
+            "source_loc": "foo/bar.h;l=3",
+            "unambiguous_public_bases": [],
+            "fields": fields,
+            "lifetime_params": [],
+            "size": size,
+            "original_cc_size": size,
+            "alignment": 4,
+            "is_derived_class": false,
+            "override_alignment": false,
+            "needs_opaque_layout": false,
+            "copy_constructor": "Trivial",
+            "move_constructor": "Trivial",
+            "destructor": "Trivial",
+            "is_trivial_abi": true,
+            "is_inheritable": false,
+            "is_abstract": false,
+            "record_type": record_type,
+            "is_aggregate": true,
+            "is_anon_record_with_typedef": false,
+            "is_template_instantiation": false,
+            "has_throwing_assignment": false,
+            "is_bytes_serializable": false,
+            "is_stream_extractable": false,
+            "is_log_sink": false,
+            "is_extern_c": false,
+            "child_item_ids": [],
+            "enclosing_namespace_id": null,
+            "enclosing_record_id": null,
+        }})
+        .to_string()
+    }
+
+    #[test]
+    fn test_valid_ir() {
+        let items = format!(
+            "[{}, {}]",
+            namespace(1, &[2], None),
+            record(2, "Struct", 8, vec![field("a", 0, 32), field("b", 32, 32)])
+        );
+        assert_eq!(validate_items(&items), vec![]);
+    }
+
+    #[test]
+    fn test_dangling_item_ids() {
+        let items = format!("[{}]", namespace(1, &[42], Some(43)));
+        assert_eq!(
+            validate_items(&items),
+            vec![
+                Diagnostic {
+                    message: "The top-level item ItemId(2) is missing from the IR".to_string(),
+                    item_id: None,
+                    source_loc: None,
+                },
+                Diagnostic {
+                    message: "The enclosing namespace ItemId(43) is missing from the IR"
+                        .to_string(),
+                    item_id: Some(ItemId::new_for_testing(1)),
+                    source_loc: None,
+                },
+                Diagnostic {
+                    message: "The child item ItemId(42) is missing from the IR".to_string(),
+                    item_id: Some(ItemId::new_for_testing(1)),
+                    source_loc: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reference_to_wrong_kind_of_item() {
+        let items = format!("[{}, {}]", namespace(1, &[], Some(2)), record(2, "Struct", 4, vec![]));
+        let diagnostics = validate_items(&items);
+        assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+        assert_eq!(diagnostics[0].message, "The enclosing namespace ItemId(2) isn't a namespace");
+    }
+
+    #[test]
+    fn test_field_beyond_record_size() {
+        let items = format!(
+            "[{}, {}]",
+            namespace(1, &[], None),
+            record(2, "Struct", 4, vec![field("a", 0, 32), field("b", 32, 32)])
+        );
+        assert_eq!(
+            validate_items(&items),
+            vec![Diagnostic {
+                message: "The field `b` (bits 32..64) lies beyond the size of the record (4 bytes)"
+                    .to_string(),
+                item_id: Some(ItemId::new_for_testing(2)),
+                source_loc: Some("foo/bar.h;l=3".into()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_overlapping_fields() {
+        let items = format!(
+            "[{}, {}]",
+            namespace(1, &[], None),
+            record(2, "Struct", 8, vec![field("a", 0, 64), field("b", 32, 8), field("c", 48, 16)])
+        );
+        let diagnostics = validate_items(&items);
+        let messages = diagnostics.iter().map(|d| d.message.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                "The fields `a` (bits 0..64) and `b` (bits 32..40) overlap",
+                "The fields `a` (bits 0..64) and `c` (bits 48..64) overlap",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_union_fields_may_overlap() {
+        let items = format!(
+            "[{}, {}]",
+            namespace(1, &[], None),
+            record(2, "Union", 4, vec![field("a", 0, 32), field("b", 0, 8)])
+        );
+        assert_eq!(validate_items(&items), vec![]);
+    }
+}
@@ -13,9 +13,12 @@
 #include "absl/flags/parse.h"
 #include "absl/status/status.h"
 #include "absl/status/statusor.h"
+#include "absl/strings/str_cat.h"
 #include "absl/strings/string_view.h"
 #include "common/file_io.h"
 #include "common/status_macros.h"
+#include "common/text_diff.h"
+#include "rs_bindings_from_cc/cc_build_manifest.h"
 #include "rs_bindings_from_cc/cmdline.h"
 #include "rs_bindings_from_cc/collect_namespaces.h"
 #include "rs_bindings_from_cc/generate_bindings_and_metadata.h"
@@ -33,6 +36,27 @@ std::string InstantiationsAsJson(
   return std::string(llvm::formatv("{0:2}", llvm::json::Value(std::move(obj))));
 }
 
+// Compares the regenerated `outputs` (pairs of a path and its contents) with
+// the files on disk, and returns an error with the diff if any of them is out
+// of date. Missing files are treated as empty.
+absl::Status CheckOutputs(
+    const std::vector<std::pair<std::string, std::string>>& outputs) {
+  std::string diffs;
+  for (const auto& [path, contents] : outputs) {
+    absl::StatusOr<std::string> on_disk = GetFileContents(path);
+    absl::StrAppend(
+        &diffs, UnifiedDiff(on_disk.ok() ? *on_disk : "", contents, path,
+                            absl::StrCat(path, " (regenerated)")));
+  }
+  if (!diffs.empty()) {
+    return absl::FailedPreconditionError(absl::StrCat(
+        "The generated bindings are out of date (rerun the generator without "
+        "--check to update them):\n",
+        diffs));
+  }
+  return absl::OkStatus();
+}
+
 absl::Status Main(absl::Span<char* const> args) {
   CRUBIT_ASSIGN_OR_RETURN(Cmdline cmdline, Cmdline::Create());
 
@@ -56,35 +80,80 @@ absl::Status Main(absl::Span<char* const> args) {
   std::vector<std::string> clang_args;
   clang_args.insert(clang_args.end(), args.begin(), args.end());
 
+  // The first argument is the name of the program. The preprocessor overrides
+  // are passed to Clang after the other arguments.
+  std::vector<std::string> manifest_clang_args(clang_args.begin() + 1,
+                                               clang_args.end());
+  manifest_clang_args.insert(manifest_clang_args.end(),
+                             cmdline.preprocessor_overrides().begin(),
+                             cmdline.preprocessor_overrides().end());
+  CcBuildManifest cc_build_manifest = MakeCcBuildManifest(
+      cmdline.current_target(), cmdline.cc_out(), manifest_clang_args);
+
   CRUBIT_ASSIGN_OR_RETURN(
       BindingsAndMetadata bindings_and_metadata,
       GenerateBindingsAndMetadata(cmdline, std::move(clang_args)));
 
-  if (!cmdline.ir_out().empty()) {
-    CRUBIT_RETURN_IF_ERROR(
-        SetFileContents(cmdline.ir_out(), IrToJson(bindings_and_metadata.ir)));
+  if (!bindings_and_metadata.coverage_summary.empty()) {
+    llvm::errs() << bindings_and_metadata.coverage_summary;
   }
 
-  CRUBIT_RETURN_IF_ERROR(
-      SetFileContents(cmdline.rs_out(), bindings_and_metadata.rs_api));
-  CRUBIT_RETURN_IF_ERROR(
-      SetFileContents(cmdline.cc_out(), bindings_and_metadata.rs_api_impl));
-
+  std::vector<std::pair<std::string, std::string>> outputs;
+  if (!cmdline.ir_out().empty()) {
+    outputs.emplace_back(cmdline.ir_out(),
+                         cmdline.binary_ir_out()
+                             ? IrToBinary(bindings_and_metadata.ir)
+                             : IrToJson(bindings_and_metadata.ir));
+  }
+  // With --stream_output, the bindings have already been written out.
+  if (!cmdline.stream_output()) {
+    outputs.emplace_back(cmdline.rs_out(), bindings_and_metadata.rs_api);
+    outputs.emplace_back(cmdline.cc_out(), bindings_and_metadata.rs_api_impl);
+  }
   if (!cmdline.instantiations_out().empty()) {
-    CRUBIT_RETURN_IF_ERROR(
-        SetFileContents(cmdline.instantiations_out(),
-                        InstantiationsAsJson(bindings_and_metadata)));
+    outputs.emplace_back(cmdline.instantiations_out(),
+                         InstantiationsAsJson(bindings_and_metadata));
   }
-
   if (!cmdline.namespaces_out().empty()) {
-    CRUBIT_RETURN_IF_ERROR(SetFileContents(
+    outputs.emplace_back(
         cmdline.namespaces_out(),
-        crubit::NamespacesAsJson(bindings_and_metadata.namespaces)));
+        crubit::NamespacesAsJson(bindings_and_metadata.namespaces));
   }
-
   if (!cmdline.error_report_out().empty()) {
-    CRUBIT_RETURN_IF_ERROR(SetFileContents(cmdline.error_report_out(),
-                                           bindings_and_metadata.error_report));
+    outputs.emplace_back(cmdline.error_report_out(),
+                         bindings_and_metadata.error_report);
+  }
+  if (!cmdline.bindings_report_out().empty()) {
+    outputs.emplace_back(cmdline.bindings_report_out(),
+                         bindings_and_metadata.bindings_report);
+  }
+  if (!cmdline.cc_build_manifest_out().empty()) {
+    outputs.emplace_back(cmdline.cc_build_manifest_out(),
+                         CcBuildManifestAsJson(cc_build_manifest));
+  }
+  if (!cmdline.cmake_out().empty()) {
+    outputs.emplace_back(cmdline.cmake_out(), cc_build_manifest.ToCMake());
+  }
+
+  if (cmdline.check()) {
+    // The generation manifest only speeds up the next run of the generator,
+    // so it is neither compared nor written.
+    return CheckOutputs(outputs);
+  }
+
+  for (const auto& [path, contents] : outputs) {
+    CRUBIT_RETURN_IF_ERROR(SetFileContents(path, contents));
+  }
+
+  if (!cmdline.generation_manifest().empty()) {
+    CRUBIT_RETURN_IF_ERROR(
+        SetFileContents(cmdline.generation_manifest(),
+                        bindings_and_metadata.generation_manifest));
+  }
+  if (!cmdline.bindings_changelog_out().empty()) {
+    CRUBIT_RETURN_IF_ERROR(
+        SetFileContents(cmdline.bindings_changelog_out(),
+                        bindings_and_metadata.bindings_changelog));
   }
 
   return absl::OkStatus();
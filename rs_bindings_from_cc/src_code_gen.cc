@@ -4,13 +4,25 @@
 
 #include "rs_bindings_from_cc/src_code_gen.h"
 
+#include <optional>
 #include <string>
+#include <utility>
+#include <vector>
 
+#include "absl/status/status.h"
+#include "absl/strings/str_cat.h"
+#include "absl/strings/str_join.h"
 #include "common/ffi_types.h"
+#include "common/file_io.h"
 #include "common/status_macros.h"
+#include "rs_bindings_from_cc/generator_options.h"
 #include "rs_bindings_from_cc/ir.h"
+#include "llvm/ADT/StringExtras.h"
+#include "llvm/ADT/StringRef.h"
+#include "llvm/Support/ErrorHandling.h"
 #include "llvm/Support/FormatVariadic.h"
 #include "llvm/Support/JSON.h"
+#include "llvm/Support/SHA256.h"
 
 namespace crubit {
 
@@ -19,28 +31,90 @@ struct FfiBindings {
   FfiU8SliceBox rs_api;
   FfiU8SliceBox rs_api_impl;
   FfiU8SliceBox error_report;
+  FfiU8SliceBox generation_manifest;
+  FfiU8SliceBox bindings_report;
+  FfiU8SliceBox coverage_summary;
+  FfiU8SliceBox bindings_changelog;
+  // JSON list of diagnostics, empty unless the generation failed.
+  FfiU8SliceBox error;
 };
 
-// This function is implemented in Rust.
-extern "C" FfiBindings GenerateBindingsImpl(FfiU8Slice json,
-                                            FfiU8Slice crubit_support_path,
-                                            FfiU8Slice clang_format_exe_path,
-                                            FfiU8Slice rustfmt_exe_path,
-                                            FfiU8Slice rustfmt_config_path,
-                                            bool generate_error_report);
+// This function is implemented in Rust. `options` is the JSON of its
+// `BindingsOptions`.
+extern "C" FfiBindings GenerateBindingsImpl(FfiU8Slice serialized_ir,
+                                            FfiU8Slice options);
+
+// Formats the diagnostics of a failed generation (see `Diagnostic` in
+// `src_code_gen.rs`) as one line per diagnostic, e.g.
+// "foo.h;l=42: Failed to generate bindings for `Foo`: <message>", followed by
+// the rendered source snippet of the item, if any.
+static std::string FormatDiagnostics(absl::string_view json) {
+  llvm::Expected<llvm::json::Value> diagnostics =
+      llvm::json::parse(llvm::StringRef(json.data(), json.size()));
+  if (!diagnostics) {
+    return absl::StrCat("Invalid diagnostics (",
+                        llvm::toString(diagnostics.takeError()), "): ", json);
+  }
+  const llvm::json::Array* array = diagnostics->getAsArray();
+  if (array == nullptr) {
+    return absl::StrCat("Invalid diagnostics: ", json);
+  }
+  std::vector<std::string> lines;
+  for (const llvm::json::Value& value : *array) {
+    const llvm::json::Object* diagnostic = value.getAsObject();
+    if (diagnostic == nullptr) {
+      continue;
+    }
+    std::string line;
+    if (std::optional<llvm::StringRef> source_loc =
+            diagnostic->getString("source_loc")) {
+      absl::StrAppend(&line, source_loc->str(), ": ");
+    }
+    if (std::optional<llvm::StringRef> item = diagnostic->getString("item")) {
+      absl::StrAppend(&line, "Failed to generate bindings for `", item->str(),
+                      "`: ");
+    }
+    absl::StrAppend(&line, diagnostic->getString("message").value_or("").str());
+    if (std::optional<llvm::StringRef> snippet =
+            diagnostic->getString("snippet")) {
+      absl::StrAppend(&line, "\n", snippet->str());
+    }
+    lines.push_back(std::move(line));
+  }
+  return absl::StrJoin(lines, "\n");
+}
 
 // Creates `Bindings` instance from copied data from `ffi_bindings`.
 static absl::StatusOr<Bindings> MakeBindingsFromFfiBindings(
     const FfiBindings& ffi_bindings) {
+  const FfiU8SliceBox& error = ffi_bindings.error;
+  if (error.size != 0) {
+    return absl::InternalError(absl::StrCat(
+        "Failed to generate bindings:\n",
+        FormatDiagnostics(absl::string_view(error.ptr, error.size))));
+  }
+
   Bindings bindings;
 
   const FfiU8SliceBox& rs_api = ffi_bindings.rs_api;
   const FfiU8SliceBox& rs_api_impl = ffi_bindings.rs_api_impl;
   const FfiU8SliceBox& error_report = ffi_bindings.error_report;
+  const FfiU8SliceBox& generation_manifest = ffi_bindings.generation_manifest;
+  const FfiU8SliceBox& bindings_report = ffi_bindings.bindings_report;
+  const FfiU8SliceBox& coverage_summary = ffi_bindings.coverage_summary;
+  const FfiU8SliceBox& bindings_changelog = ffi_bindings.bindings_changelog;
 
   bindings.rs_api = std::string(rs_api.ptr, rs_api.size);
   bindings.rs_api_impl = std::string(rs_api_impl.ptr, rs_api_impl.size);
   bindings.error_report = std::string(error_report.ptr, error_report.size);
+  bindings.generation_manifest =
+      std::string(generation_manifest.ptr, generation_manifest.size);
+  bindings.bindings_report =
+      std::string(bindings_report.ptr, bindings_report.size);
+  bindings.coverage_summary =
+      std::string(coverage_summary.ptr, coverage_summary.size);
+  bindings.bindings_changelog =
+      std::string(bindings_changelog.ptr, bindings_changelog.size);
   return bindings;
 }
 
@@ -49,20 +123,167 @@ static void FreeFfiBindings(FfiBindings ffi_bindings) {
   FreeFfiU8SliceBox(ffi_bindings.rs_api);
   FreeFfiU8SliceBox(ffi_bindings.rs_api_impl);
   FreeFfiU8SliceBox(ffi_bindings.error_report);
+  FreeFfiU8SliceBox(ffi_bindings.generation_manifest);
+  FreeFfiU8SliceBox(ffi_bindings.bindings_report);
+  FreeFfiU8SliceBox(ffi_bindings.coverage_summary);
+  FreeFfiU8SliceBox(ffi_bindings.bindings_changelog);
+  FreeFfiU8SliceBox(ffi_bindings.error);
+}
+
+// Returns the SHA-256 hashes of the inputs of the generator (see
+// `GeneratorOptions::record_input_hashes`), one "<name>: <hash>" entry each.
+static absl::StatusOr<std::vector<std::string>> GetInputHashes(
+    const IR& ir, absl::string_view serialized_ir,
+    const GeneratorOptions& generator_options) {
+  auto sha256 = [](absl::string_view contents) {
+    return llvm::toHex(
+        llvm::SHA256::hash(llvm::arrayRefFromStringRef(
+            llvm::StringRef(contents.data(), contents.size()))),
+        /*LowerCase=*/true);
+  };
+  std::vector<std::string> input_hashes = {absl::StrCat(
+      "IR of ", ir.current_target.value(), ": ", sha256(serialized_ir))};
+  for (const std::string& path : generator_options.dependency_irs) {
+    CRUBIT_ASSIGN_OR_RETURN(std::string dependency_ir, GetFileContents(path));
+    input_hashes.push_back(absl::StrCat(path, ": ", sha256(dependency_ir)));
+  }
+  return input_hashes;
+}
+
+// Returns the names of the values of the enums in `BindingsOptions` (see e.g.
+// `ExceptionHandling` in `src_code_gen.rs`).
+static llvm::StringRef GetName(ExceptionHandling exception_handling) {
+  switch (exception_handling) {
+    case ExceptionHandling::kNone:
+      return "none";
+    case ExceptionHandling::kPanic:
+      return "panic";
+    case ExceptionHandling::kResult:
+      return "result";
+    case ExceptionHandling::kUnwind:
+      return "unwind";
+  }
+  llvm_unreachable("Unknown ExceptionHandling");
+}
+
+static llvm::StringRef GetName(ThunkLinkage thunk_linkage) {
+  switch (thunk_linkage) {
+    case ThunkLinkage::kExternal:
+      return "external";
+    case ThunkLinkage::kInline:
+      return "inline";
+  }
+  llvm_unreachable("Unknown ThunkLinkage");
+}
+
+static llvm::StringRef GetName(RsFormatter rs_formatter) {
+  switch (rs_formatter) {
+    case RsFormatter::kRustfmt:
+      return "rustfmt";
+    case RsFormatter::kBuiltin:
+      return "builtin";
+    case RsFormatter::kNone:
+      return "none";
+  }
+  llvm_unreachable("Unknown RsFormatter");
+}
+
+// Returns `value` as a JSON string, or `null` if it is empty (for the optional
+// strings of `BindingsOptions`).
+static llvm::json::Value StringOrNull(absl::string_view value) {
+  if (value.empty()) return nullptr;
+  return std::string(value);
 }
 
 absl::StatusOr<Bindings> GenerateBindings(
     const IR& ir, absl::string_view crubit_support_path,
     absl::string_view clang_format_exe_path, absl::string_view rustfmt_exe_path,
-    absl::string_view rustfmt_config_path, bool generate_error_report) {
-  std::string json = llvm::formatv("{0}", ir.ToJson());
+    absl::string_view rustfmt_config_path, bool generate_error_report,
+    const GeneratorOptions& generator_options,
+    std::optional<absl::string_view> previous_generation_manifest,
+    int codegen_threads, absl::string_view rs_api_out_path,
+    absl::string_view rs_api_impl_out_path, bool generate_bindings_report,
+    const CoverageOptions& coverage_options, bool generate_bindings_changelog,
+    RsFormatter rs_formatter) {
+  // The binary IR is faster to deserialize than JSON.
+  std::string serialized_ir = IrToBinary(ir);
+  std::vector<std::string> input_hashes;
+  if (generator_options.record_input_hashes) {
+    CRUBIT_ASSIGN_OR_RETURN(
+        input_hashes, GetInputHashes(ir, serialized_ir, generator_options));
+  }
+  llvm::json::Value previous_generation_manifest_json = nullptr;
+  if (previous_generation_manifest.has_value()) {
+    previous_generation_manifest_json =
+        std::string(*previous_generation_manifest);
+  }
+  // See `BindingsOptions` in `src_code_gen.rs`.
+  llvm::json::Object options{
+      {"crubit_support_path", std::string(crubit_support_path)},
+      {"clang_format_exe_path", std::string(clang_format_exe_path)},
+      {"rustfmt_exe_path", std::string(rustfmt_exe_path)},
+      {"rustfmt_config_path", StringOrNull(rustfmt_config_path)},
+      {"rs_formatter", GetName(rs_formatter)},
+      {"generate_error_report", generate_error_report},
+      {"generator_options",
+       llvm::json::Object{
+           {"deny_warnings", generator_options.deny_warnings},
+           {"allowed_lints",
+            llvm::json::Array(generator_options.allowed_lints)},
+           {"exception_handling",
+            GetName(generator_options.exception_handling)},
+           {"thunk_linkage", GetName(generator_options.thunk_linkage)},
+           {"inline_trivial_accessors",
+            generator_options.inline_trivial_accessors},
+           {"generate_smoke_tests", generator_options.generate_smoke_tests},
+           {"rename_overloads", generator_options.rename_overloads},
+           {"trace_calls", generator_options.trace_calls},
+           {"module_path", StringOrNull(generator_options.module_path)},
+           {"module_visibility",
+            StringOrNull(generator_options.module_visibility)},
+           {"field_accessors", generator_options.field_accessors},
+           {"snake_case_function_names",
+            generator_options.snake_case_function_names},
+           {"canonical_item_order", generator_options.canonical_item_order},
+           {"line_directives", generator_options.line_directives},
+           {"readable_thunk_names", generator_options.readable_thunk_names},
+           {"windows_import_lib",
+            StringOrNull(generator_options.windows_import_lib)},
+           {"crubit_features",
+            llvm::json::Array(generator_options.crubit_features)},
+       }},
+      {"dependency_irs", llvm::json::Array(generator_options.dependency_irs)},
+      {"shared_instantiations_targets",
+       llvm::json::Array(generator_options.shared_instantiations_targets)},
+      {"previous_generation_manifest",
+       std::move(previous_generation_manifest_json)},
+      {"codegen_threads", codegen_threads},
+      {"rs_api_out_path", StringOrNull(rs_api_out_path)},
+      {"rs_api_impl_out_path", StringOrNull(rs_api_impl_out_path)},
+      {"generate_bindings_report", generate_bindings_report},
+      {"coverage_options",
+       llvm::json::Object{
+           {"min_coverage_percent", coverage_options.min_coverage_percent},
+           {"fail_on_unsupported",
+            llvm::json::Array(coverage_options.fail_on_unsupported)},
+           {"print_summary", coverage_options.print_summary},
+       }},
+      {"generate_bindings_changelog", generate_bindings_changelog},
+      {"preamble_options",
+       llvm::json::Object{
+           {"license_header", StringOrNull(generator_options.license_header)},
+           {"do_not_edit_banner", generator_options.do_not_edit_banner},
+           {"generator_version",
+            StringOrNull(generator_options.generator_version)},
+           {"input_hashes", llvm::json::Array(input_hashes)},
+       }},
+  };
+  std::string serialized_options =
+      llvm::formatv("{0}", llvm::json::Value(std::move(options)));
 
   FfiBindings ffi_bindings = GenerateBindingsImpl(
-      MakeFfiU8Slice(json), MakeFfiU8Slice(crubit_support_path),
-      MakeFfiU8Slice(clang_format_exe_path), MakeFfiU8Slice(rustfmt_exe_path),
-      MakeFfiU8Slice(rustfmt_config_path), generate_error_report);
-  CRUBIT_ASSIGN_OR_RETURN(Bindings bindings,
-                          MakeBindingsFromFfiBindings(ffi_bindings));
+      MakeFfiU8Slice(serialized_ir), MakeFfiU8Slice(serialized_options));
+  absl::StatusOr<Bindings> bindings = MakeBindingsFromFfiBindings(ffi_bindings);
   FreeFfiBindings(ffi_bindings);
   return bindings;
 }
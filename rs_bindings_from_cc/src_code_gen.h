@@ -5,10 +5,12 @@
 #ifndef CRUBIT_RS_BINDINGS_FROM_CC_SRC_CODE_GEN_H_
 #define CRUBIT_RS_BINDINGS_FROM_CC_SRC_CODE_GEN_H_
 
+#include <optional>
 #include <string>
 
 #include "absl/status/statusor.h"
 #include "absl/strings/string_view.h"
+#include "rs_bindings_from_cc/generator_options.h"
 #include "rs_bindings_from_cc/ir.h"
 
 namespace crubit {
@@ -21,13 +23,57 @@ struct Bindings {
   std::string rs_api_impl;
   // Optional JSON error report.
   std::string error_report;
+  // Optional JSON generation manifest (see `--generation_manifest`).
+  std::string generation_manifest;
+  // Optional JSON bindings report (see `--bindings_report_out`).
+  std::string bindings_report;
+  // Optional summary table of the binding coverage of the target (see
+  // `CoverageOptions::print_summary`).
+  std::string coverage_summary;
+  // Optional human-readable changelog of the bindings (see
+  // `--bindings_changelog_out`).
+  std::string bindings_changelog;
 };
 
 // Generates bindings from the given `IR`.
+//
+// If `previous_generation_manifest` is set, the code of the items which are
+// unchanged since the manifest was generated is reused from it (the manifest
+// may be empty, e.g. in the first build), and `Bindings::generation_manifest`
+// is populated.
+//
+// The items of the target are generated on `codegen_threads` threads (the
+// generated bindings don't depend on the number of threads).
+//
+// If `rs_api_out_path` and `rs_api_impl_out_path` are set, the source code is
+// formatted and written into these files item by item, instead of being built
+// in memory, and `Bindings::rs_api` and `Bindings::rs_api_impl` are empty.
+//
+// If `generate_bindings_report` is set, `Bindings::bindings_report` lists the
+// C++ items of the target, and whether they got bindings.
+//
+// The generation fails if the binding coverage of the target doesn't satisfy
+// `coverage_options`.
+//
+// If `generate_bindings_changelog` is set, `Bindings::bindings_changelog` lists
+// the items which changed since the run which generated
+// `previous_generation_manifest`.
+//
+// The generated Rust source code is formatted as specified by `rs_formatter`
+// (the C++ source code is always formatted by `clang-format`).
 absl::StatusOr<Bindings> GenerateBindings(
     const IR& ir, absl::string_view crubit_support_path,
     absl::string_view clang_format_exe_path, absl::string_view rustfmt_exe_path,
-    absl::string_view rustfmt_config_path, bool generate_error_report);
+    absl::string_view rustfmt_config_path, bool generate_error_report,
+    const GeneratorOptions& generator_options,
+    std::optional<absl::string_view> previous_generation_manifest =
+        std::nullopt,
+    int codegen_threads = 1, absl::string_view rs_api_out_path = "",
+    absl::string_view rs_api_impl_out_path = "",
+    bool generate_bindings_report = false,
+    const CoverageOptions& coverage_options = {},
+    bool generate_bindings_changelog = false,
+    RsFormatter rs_formatter = RsFormatter::kRustfmt);
 
 }  // namespace crubit
 
@@ -0,0 +1,85 @@
+#!/bin/bash
+# Part of the Crubit project, under the Apache License v2.0 with LLVM
+# Exceptions. See /LICENSE for license information.
+# SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+# Compiles the golden `..._rs_api_impl.cc` files with a matrix of C++ compilers
+# and standard libraries.
+#
+# The generated C++ code contains `static_assert`s for the size, alignment, and
+# field offsets of every record, as computed by Clang when the bindings were
+# generated. Compiling it with another compiler verifies that the layout (and
+# therefore the Rust bindings) is the same for that compiler, and that the
+# thunks don't rely on Clang-specific extensions.
+#
+# Usage: abi_compat_test.sh <..._rs_api_impl.cc files>
+#
+# The matrix can be overridden with `CRUBIT_ABI_COMPAT_CONFIGS`: a
+# space-separated list of `compiler[:flag,flag...]` entries. The first entry is
+# the reference configuration. Compilers which aren't installed are skipped.
+
+readonly DEFAULT_CONFIGS="clang++:-stdlib=libc++ clang++:-stdlib=libstdc++ g++"
+read -r -a CONFIGS <<< "${CRUBIT_ABI_COMPAT_CONFIGS:-${DEFAULT_CONFIGS}}"
+readonly CONFIGS
+readonly LOG="${TEST_TMPDIR:-/tmp}/abi_compat.log"
+readonly COMMON_FLAGS=(-std=c++17 -fsyntax-only -I. -Wno-unknown-pragmas -Wno-attributes)
+
+# Prints the compiler of the configuration `$1`.
+function compiler_of() {
+  echo "${1%%:*}"
+}
+
+# Prints the extra flags of the configuration `$1`, one per line.
+function flags_of() {
+  if [[ "$1" == *:* ]]; then
+    echo "${1#*:}" | tr ',' '\n'
+  fi
+}
+
+# Returns success if `$2` compiles with the configuration `$1`, logging any
+# diagnostics to `$LOG`.
+function compiles() {
+  local flags
+  mapfile -t flags < <(flags_of "$1")
+  "$(compiler_of "$1")" "${COMMON_FLAGS[@]}" "${flags[@]}" "$2" > "${LOG}" 2>&1
+}
+
+reference=""
+declare -A reference_failures
+status=0
+for config in "${CONFIGS[@]}"; do
+  if ! command -v "$(compiler_of "${config}")" > /dev/null; then
+    echo "SKIPPED ${config}: compiler not found"
+    continue
+  fi
+  echo '#include <memory>' > "${TEST_TMPDIR:-/tmp}/abi_compat_stdlib.cc"
+  if ! compiles "${config}" "${TEST_TMPDIR:-/tmp}/abi_compat_stdlib.cc"; then
+    echo "SKIPPED ${config}: standard library not found"
+    continue
+  fi
+  for golden in "$@"; do
+    if compiles "${config}" "${golden}"; then
+      continue
+    fi
+    if [[ -z "${reference}" ]]; then
+      # Failures of the reference configuration are reported by the golden
+      # tests themselves, rather than as ABI incompatibilities.
+      reference_failures["${golden}"]=1
+      echo "WARNING: ${golden} doesn't compile with the reference ${config}"
+    elif [[ -z "${reference_failures[${golden}]}" ]]; then
+      echo "FAILED ${config}: ${golden} compiles with ${reference}, but not with ${config}:"
+      cat "${LOG}"
+      status=1
+    fi
+  done
+  if [[ -z "${reference}" ]]; then
+    reference="${config}"
+  fi
+  echo "PASSED ${config}"
+done
+
+if [[ -z "${reference}" ]]; then
+  echo "No compiler found"
+  exit 1
+fi
+exit "${status}"
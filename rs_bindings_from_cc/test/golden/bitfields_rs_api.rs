@@ -41,6 +41,22 @@ impl WithBitfields {
         unsafe { &*(&self.f7 as *const _ as *const u8) }
     }
 }
+impl WithBitfields {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/bitfields.h;l=10
 impl ::ctor::CtorNew<()> for WithBitfields {
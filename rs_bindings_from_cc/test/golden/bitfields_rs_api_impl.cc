@@ -16,22 +16,32 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/bitfields.h;l=10
 extern "C" void __rust_thunk___ZN13WithBitfieldsC1Ev(
     struct WithBitfields* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/bitfields.h;l=10
 extern "C" void __rust_thunk___ZN13WithBitfieldsC1ERKS_(
     struct WithBitfields* __this, const struct WithBitfields* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/bitfields.h;l=10
 extern "C" void __rust_thunk___ZN13WithBitfieldsC1EOS_(
     struct WithBitfields* __this, struct WithBitfields* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/bitfields.h;l=10
 extern "C" struct WithBitfields* __rust_thunk___ZN13WithBitfieldsaSERKS_(
     struct WithBitfields* __this, const struct WithBitfields* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/bitfields.h;l=10
 extern "C" struct WithBitfields* __rust_thunk___ZN13WithBitfieldsaSEOS_(
     struct WithBitfields* __this, struct WithBitfields* __param_0) {
   return &__this->operator=(std::move(*__param_0));
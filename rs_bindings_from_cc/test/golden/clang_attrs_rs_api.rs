@@ -27,6 +27,22 @@ forward_declare::unsafe_define!(
     forward_declare::symbol!("HasCustomAlignment"),
     crate::HasCustomAlignment
 );
+impl HasCustomAlignment {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=10
 impl ::ctor::CtorNew<()> for HasCustomAlignment {
@@ -127,6 +143,22 @@ forward_declare::unsafe_define!(
     forward_declare::symbol!("HasFieldWithCustomAlignment"),
     crate::HasFieldWithCustomAlignment
 );
+impl HasFieldWithCustomAlignment {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=12
 impl ::ctor::CtorNew<()> for HasFieldWithCustomAlignment {
@@ -227,6 +259,22 @@ forward_declare::unsafe_define!(
     forward_declare::symbol!("InheritsFromBaseWithCustomAlignment"),
     crate::InheritsFromBaseWithCustomAlignment
 );
+impl InheritsFromBaseWithCustomAlignment {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=16
 impl ::ctor::CtorNew<()> for InheritsFromBaseWithCustomAlignment {
@@ -343,6 +391,22 @@ forward_declare::unsafe_define!(
     forward_declare::symbol!("HasCustomAlignmentWithGnuAttr"),
     crate::HasCustomAlignmentWithGnuAttr
 );
+impl HasCustomAlignmentWithGnuAttr {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=18
 impl ::ctor::CtorNew<()> for HasCustomAlignmentWithGnuAttr {
@@ -443,7 +507,7 @@ pub mod template_with_preferred_name {
     ///
     /// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=32
     pub type SpecializedTypeAlias =
-        crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE;
+        crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE;
 
     // Based on `llvm/include/c++/v1/string_view` - mimics definition of
     // `basic_string_view` class template (focusing on the attributes related to the
@@ -454,119 +518,144 @@ pub mod template_with_preferred_name {
 
 // CRUBIT_RS_BINDINGS_FROM_CC_TEST_GOLDEN_CLANG_ATTRS_H_
 
-/// Based on `llvm/include/c++/v1/__fwd/string_view.h` - mimics
-/// forward declaration of `basic_string_view` class template.
-///
-/// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=38
-#[::ctor::recursively_pinned]
-#[repr(C)]
-pub struct __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE {
-    __non_field_data: [::std::mem::MaybeUninit<u8>; 1],
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("template_with_preferred_name::SomeTemplate<int>"),
-    crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE
-);
+/// Class template instantiations used by this crate.
+pub mod __instantiations {
+    /// Based on `llvm/include/c++/v1/__fwd/string_view.h` - mimics
+    /// forward declaration of `basic_string_view` class template.
+    ///
+    /// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=38
+    #[::ctor::recursively_pinned]
+    #[repr(C)]
+    pub struct __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE {
+        __non_field_data: [::std::mem::MaybeUninit<u8>; 1],
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("template_with_preferred_name::SomeTemplate<int>"),
+        crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE
+    );
+    impl __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE {
+        #[inline(always)]
+        pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+        where
+            Self: ::ctor::CtorNew<Args>,
+        {
+            <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+        }
+        #[inline(always)]
+        pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+        where
+            Self: ::ctor::CtorNew<Args>,
+        {
+            <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+        }
+    }
 
-/// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=38
-impl ::ctor::CtorNew<()> for __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE {
-    type CtorType = impl ::ctor::Ctor<Output = Self>;
-    #[inline(always)]
-    fn ctor_new(args: ()) -> Self::CtorType {
-        let () = args;
-        unsafe {
-            ::ctor::FnCtor::new(move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
-                crate::detail::__rust_thunk___ZN28template_with_preferred_name12SomeTemplateIiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3aclang_5fattrs_5fcc(::std::pin::Pin::into_inner_unchecked(dest));
-            })
+    /// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=38
+    impl ::ctor::CtorNew<()> for __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE {
+        type CtorType = impl ::ctor::Ctor<Output = Self>;
+        #[inline(always)]
+        fn ctor_new(args: ()) -> Self::CtorType {
+            let () = args;
+            unsafe {
+                ::ctor::FnCtor::new(
+                    move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
+                        crate::detail::__rust_thunk___ZN28template_with_preferred_name12SomeTemplateIiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3aclang_5fattrs_5fcc(::std::pin::Pin::into_inner_unchecked(dest));
+                    },
+                )
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=38
-impl<'b> ::ctor::CtorNew<&'b Self>
-    for __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE
-{
-    type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
-    #[inline(always)]
-    fn ctor_new(args: &'b Self) -> Self::CtorType {
-        let __param_0 = args;
-        unsafe {
-            ::ctor::FnCtor::new(move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
-                crate::detail::__rust_thunk___ZN28template_with_preferred_name12SomeTemplateIiEC1ERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3aclang_5fattrs_5fcc(::std::pin::Pin::into_inner_unchecked(dest),__param_0);
-            })
+    /// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=38
+    impl<'b> ::ctor::CtorNew<&'b Self>
+        for __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE
+    {
+        type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
+        #[inline(always)]
+        fn ctor_new(args: &'b Self) -> Self::CtorType {
+            let __param_0 = args;
+            unsafe {
+                ::ctor::FnCtor::new(
+                    move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
+                        crate::detail::__rust_thunk___ZN28template_with_preferred_name12SomeTemplateIiEC1ERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3aclang_5fattrs_5fcc(::std::pin::Pin::into_inner_unchecked(dest),__param_0);
+                    },
+                )
+            }
         }
     }
-}
-impl<'b> ::ctor::CtorNew<(&'b Self,)>
-    for __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE
-{
-    type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
-    #[inline(always)]
-    fn ctor_new(args: (&'b Self,)) -> Self::CtorType {
-        let (arg,) = args;
-        <Self as ::ctor::CtorNew<&'b Self>>::ctor_new(arg)
+    impl<'b> ::ctor::CtorNew<(&'b Self,)>
+        for __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE
+    {
+        type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
+        #[inline(always)]
+        fn ctor_new(args: (&'b Self,)) -> Self::CtorType {
+            let (arg,) = args;
+            <Self as ::ctor::CtorNew<&'b Self>>::ctor_new(arg)
+        }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=38
-impl<'b> ::ctor::CtorNew<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE
-{
-    type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
-    #[inline(always)]
-    fn ctor_new(args: ::ctor::RvalueReference<'b, Self>) -> Self::CtorType {
-        let __param_0 = args;
-        unsafe {
-            ::ctor::FnCtor::new(move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
-                crate::detail::__rust_thunk___ZN28template_with_preferred_name12SomeTemplateIiEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3aclang_5fattrs_5fcc(::std::pin::Pin::into_inner_unchecked(dest),__param_0);
-            })
+    /// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=38
+    impl<'b> ::ctor::CtorNew<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE
+    {
+        type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
+        #[inline(always)]
+        fn ctor_new(args: ::ctor::RvalueReference<'b, Self>) -> Self::CtorType {
+            let __param_0 = args;
+            unsafe {
+                ::ctor::FnCtor::new(
+                    move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
+                        crate::detail::__rust_thunk___ZN28template_with_preferred_name12SomeTemplateIiEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3aclang_5fattrs_5fcc(::std::pin::Pin::into_inner_unchecked(dest),__param_0);
+                    },
+                )
+            }
         }
     }
-}
-impl<'b> ::ctor::CtorNew<(::ctor::RvalueReference<'b, Self>,)>
-    for __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE
-{
-    type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
-    #[inline(always)]
-    fn ctor_new(args: (::ctor::RvalueReference<'b, Self>,)) -> Self::CtorType {
-        let (arg,) = args;
-        <Self as ::ctor::CtorNew<::ctor::RvalueReference<'b, Self>>>::ctor_new(arg)
+    impl<'b> ::ctor::CtorNew<(::ctor::RvalueReference<'b, Self>,)>
+        for __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE
+    {
+        type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
+        #[inline(always)]
+        fn ctor_new(args: (::ctor::RvalueReference<'b, Self>,)) -> Self::CtorType {
+            let (arg,) = args;
+            <Self as ::ctor::CtorNew<::ctor::RvalueReference<'b, Self>>>::ctor_new(arg)
+        }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=38
-impl<'b> ::ctor::Assign<&'b Self>
-    for __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE
-{
-    #[inline(always)]
-    fn assign<'a>(self: ::std::pin::Pin<&'a mut Self>, __param_0: &'b Self) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN28template_with_preferred_name12SomeTemplateIiEaSERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3aclang_5fattrs_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=38
+    impl<'b> ::ctor::Assign<&'b Self>
+        for __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE
+    {
+        #[inline(always)]
+        fn assign<'a>(self: ::std::pin::Pin<&'a mut Self>, __param_0: &'b Self) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN28template_with_preferred_name12SomeTemplateIiEaSERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3aclang_5fattrs_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=38
-impl<'b> ::ctor::Assign<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE
-{
-    #[inline(always)]
-    fn assign<'a>(
-        self: ::std::pin::Pin<&'a mut Self>,
-        __param_0: ::ctor::RvalueReference<'b, Self>,
-    ) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN28template_with_preferred_name12SomeTemplateIiEaSEOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3aclang_5fattrs_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=38
+    impl<'b> ::ctor::Assign<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE
+    {
+        #[inline(always)]
+        fn assign<'a>(
+            self: ::std::pin::Pin<&'a mut Self>,
+            __param_0: ::ctor::RvalueReference<'b, Self>,
+        ) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN28template_with_preferred_name12SomeTemplateIiEaSEOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3aclang_5fattrs_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-impl __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE {
-    /// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=39
-    #[inline(always)]
-    pub fn foo<'a>(self: ::std::pin::Pin<&'a mut Self>) -> i32 {
-        unsafe {
-            crate::detail::__rust_thunk___ZN28template_with_preferred_name12SomeTemplateIiE3fooEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3aclang_5fattrs_5fcc(self)
+    impl __CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE {
+        /// Generated from: rs_bindings_from_cc/test/golden/clang_attrs.h;l=39
+        #[inline(always)]
+        pub fn foo<'a>(self: ::std::pin::Pin<&'a mut Self>) -> i32 {
+            unsafe {
+                crate::detail::__rust_thunk___ZN28template_with_preferred_name12SomeTemplateIiE3fooEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3aclang_5fattrs_5fcc(self)
+            }
         }
     }
 }
@@ -655,7 +744,7 @@ mod detail {
             'a,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
+                crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
             >,
         );
         pub(crate) fn __rust_thunk___ZN28template_with_preferred_name12SomeTemplateIiEC1ERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3aclang_5fattrs_5fcc<
@@ -663,20 +752,20 @@ mod detail {
             'b,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
+                crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
             >,
-            __param_0: &'b crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
+            __param_0: &'b crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
         );
         pub(crate) fn __rust_thunk___ZN28template_with_preferred_name12SomeTemplateIiEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3aclang_5fattrs_5fcc<
             'a,
             'b,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
+                crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
             >,
             __param_0: ::ctor::RvalueReference<
                 'b,
-                crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
+                crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
             >,
         );
         pub(crate) fn __rust_thunk___ZN28template_with_preferred_name12SomeTemplateIiEaSERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3aclang_5fattrs_5fcc<
@@ -684,31 +773,31 @@ mod detail {
             'b,
         >(
             __this: ::std::pin::Pin<
-                &'a mut crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
+                &'a mut crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
             >,
-            __param_0: &'b crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
+            __param_0: &'b crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
         ) -> ::std::pin::Pin<
-            &'a mut crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
+            &'a mut crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
         >;
         pub(crate) fn __rust_thunk___ZN28template_with_preferred_name12SomeTemplateIiEaSEOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3aclang_5fattrs_5fcc<
             'a,
             'b,
         >(
             __this: ::std::pin::Pin<
-                &'a mut crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
+                &'a mut crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
             >,
             __param_0: ::ctor::RvalueReference<
                 'b,
-                crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
+                crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
             >,
         ) -> ::std::pin::Pin<
-            &'a mut crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
+            &'a mut crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
         >;
         pub(crate) fn __rust_thunk___ZN28template_with_preferred_name12SomeTemplateIiE3fooEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3aclang_5fattrs_5fcc<
             'a,
         >(
             __this: ::std::pin::Pin<
-                &'a mut crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
+                &'a mut crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
             >,
         ) -> i32;
     }
@@ -754,20 +843,22 @@ const _: () = {
 };
 
 const _: () = assert!(
-    ::std::mem::size_of::<crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE>(
-    ) == 1
+    ::std::mem::size_of::<
+        crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
+    >() == 1
 );
 const _: () = assert!(
-    ::std::mem::align_of::<crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE>(
-    ) == 1
+    ::std::mem::align_of::<
+        crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE,
+    >() == 1
 );
 const _: () = {
     static_assertions::assert_not_impl_any!(
-        crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE: Copy
+        crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE: Copy
     );
 };
 const _: () = {
     static_assertions::assert_not_impl_any!(
-        crate::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE: Drop
+        crate::__instantiations::__CcTemplateInstN28template_with_preferred_name12SomeTemplateIiEE: Drop
     );
 };
@@ -16,49 +16,75 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/comment.h;l=17
 extern "C" void __rust_thunk___ZN3FooC1Ev(struct Foo* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/comment.h;l=17
 extern "C" void __rust_thunk___ZN3FooC1EOS_(struct Foo* __this,
                                             struct Foo* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/comment.h;l=17
 extern "C" struct Foo* __rust_thunk___ZN3FooaSERKS_(
     struct Foo* __this, const struct Foo* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/comment.h;l=17
 extern "C" struct Foo* __rust_thunk___ZN3FooaSEOS_(struct Foo* __this,
                                                    struct Foo* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/comment.h;l=41
 extern "C" void __rust_thunk___Z3foov() { foo(); }
+
+// Generated from: rs_bindings_from_cc/test/golden/comment.h;l=46
 extern "C" void __rust_thunk___ZN3BarC1Ev(struct Bar* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/comment.h;l=46
 extern "C" void __rust_thunk___ZN3BarC1EOS_(struct Bar* __this,
                                             struct Bar* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/comment.h;l=46
 extern "C" struct Bar* __rust_thunk___ZN3BaraSERKS_(
     struct Bar* __this, const struct Bar* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/comment.h;l=46
 extern "C" struct Bar* __rust_thunk___ZN3BaraSEOS_(struct Bar* __this,
                                                    struct Bar* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/comment.h;l=52
 extern "C" void __rust_thunk___ZN13HasNoCommentsC1Ev(
     struct HasNoComments* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/comment.h;l=52
 extern "C" void __rust_thunk___ZN13HasNoCommentsC1EOS_(
     struct HasNoComments* __this, struct HasNoComments* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/comment.h;l=52
 extern "C" struct HasNoComments* __rust_thunk___ZN13HasNoCommentsaSERKS_(
     struct HasNoComments* __this, const struct HasNoComments* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/comment.h;l=52
 extern "C" struct HasNoComments* __rust_thunk___ZN13HasNoCommentsaSEOS_(
     struct HasNoComments* __this, struct HasNoComments* __param_0) {
   return &__this->operator=(std::move(*__param_0));
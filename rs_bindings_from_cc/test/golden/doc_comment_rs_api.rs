@@ -388,12 +388,12 @@ pub type MyTypeAlias = crate::DocCommentSlashes;
 /// Type alias to template instantiation.
 ///
 /// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=95
-pub type MyInstantiation = crate::__CcTemplateInst10MyTemplateIiE;
+pub type MyInstantiation = crate::__instantiations::__CcTemplateInst10MyTemplateIiE;
 
 /// Type alias to instantiation of a template specialization.
 ///
 /// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=98
-pub type MySpecializedInstantiation = crate::__CcTemplateInst10MyTemplateIfE;
+pub type MySpecializedInstantiation = crate::__instantiations::__CcTemplateInst10MyTemplateIfE;
 
 // Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=101
 // Error while generating bindings for item 'OuterTemplate':
@@ -405,148 +405,151 @@ pub type MySpecializedInstantiation = crate::__CcTemplateInst10MyTemplateIfE;
 
 // CRUBIT_RS_BINDINGS_FROM_CC_TEST_GOLDEN_DOC_COMMENT_H_
 
-/// Class template.
-///
-/// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=76
-#[derive(Clone, Copy)]
-#[repr(C)]
-pub struct __CcTemplateInst10MyTemplateIiE {
-    /// Data member.
-    pub value: i32,
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("MyTemplate<int>"),
-    crate::__CcTemplateInst10MyTemplateIiE
-);
+/// Class template instantiations used by this crate.
+pub mod __instantiations {
+    /// Class template.
+    ///
+    /// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=76
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub struct __CcTemplateInst10MyTemplateIiE {
+        /// Data member.
+        pub value: i32,
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("MyTemplate<int>"),
+        crate::__instantiations::__CcTemplateInst10MyTemplateIiE
+    );
 
-/// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=76
-impl Default for __CcTemplateInst10MyTemplateIiE {
-    #[inline(always)]
-    fn default() -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN10MyTemplateIiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(&mut tmp);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=76
+    impl Default for __CcTemplateInst10MyTemplateIiE {
+        #[inline(always)]
+        fn default() -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN10MyTemplateIiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(&mut tmp);
+                tmp.assume_init()
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=76
-impl<'b> From<::ctor::RvalueReference<'b, Self>> for __CcTemplateInst10MyTemplateIiE {
-    #[inline(always)]
-    fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN10MyTemplateIiEC1EOS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(&mut tmp,__param_0);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=76
+    impl<'b> From<::ctor::RvalueReference<'b, Self>> for __CcTemplateInst10MyTemplateIiE {
+        #[inline(always)]
+        fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN10MyTemplateIiEC1EOS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(&mut tmp,__param_0);
+                tmp.assume_init()
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=76
-impl<'b> ::ctor::UnpinAssign<&'b Self> for __CcTemplateInst10MyTemplateIiE {
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN10MyTemplateIiEaSERKS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=76
+    impl<'b> ::ctor::UnpinAssign<&'b Self> for __CcTemplateInst10MyTemplateIiE {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN10MyTemplateIiEaSERKS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=76
-impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInst10MyTemplateIiE
-{
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN10MyTemplateIiEaSEOS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=76
+    impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInst10MyTemplateIiE
+    {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN10MyTemplateIiEaSEOS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-// A non-static member function.
+    // A non-static member function.
 
-impl __CcTemplateInst10MyTemplateIiE {
-    /// A non-static member function.
-    ///
-    /// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=78
-    #[inline(always)]
-    pub fn get_field_value<'a>(&'a self) -> &'a i32 {
-        unsafe {
-            crate::detail::__rust_thunk___ZNK10MyTemplateIiE15get_field_valueEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(self)
+    impl __CcTemplateInst10MyTemplateIiE {
+        /// A non-static member function.
+        ///
+        /// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=78
+        #[inline(always)]
+        pub fn get_field_value<'a>(&'a self) -> &'a i32 {
+            unsafe {
+                crate::detail::__rust_thunk___ZNK10MyTemplateIiE15get_field_valueEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(self)
+            }
         }
     }
-}
 
-/// Class template specialization.
-///
-/// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=86
-#[derive(Clone, Copy)]
-#[repr(C)]
-pub struct __CcTemplateInst10MyTemplateIfE {
-    /// Data member in a specialization.
-    pub value: f32,
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("MyTemplate<float>"),
-    crate::__CcTemplateInst10MyTemplateIfE
-);
+    /// Class template specialization.
+    ///
+    /// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=86
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub struct __CcTemplateInst10MyTemplateIfE {
+        /// Data member in a specialization.
+        pub value: f32,
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("MyTemplate<float>"),
+        crate::__instantiations::__CcTemplateInst10MyTemplateIfE
+    );
 
-/// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=86
-impl Default for __CcTemplateInst10MyTemplateIfE {
-    #[inline(always)]
-    fn default() -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN10MyTemplateIfEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(&mut tmp);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=86
+    impl Default for __CcTemplateInst10MyTemplateIfE {
+        #[inline(always)]
+        fn default() -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN10MyTemplateIfEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(&mut tmp);
+                tmp.assume_init()
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=86
-impl<'b> From<::ctor::RvalueReference<'b, Self>> for __CcTemplateInst10MyTemplateIfE {
-    #[inline(always)]
-    fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN10MyTemplateIfEC1EOS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(&mut tmp,__param_0);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=86
+    impl<'b> From<::ctor::RvalueReference<'b, Self>> for __CcTemplateInst10MyTemplateIfE {
+        #[inline(always)]
+        fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN10MyTemplateIfEC1EOS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(&mut tmp,__param_0);
+                tmp.assume_init()
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=86
-impl<'b> ::ctor::UnpinAssign<&'b Self> for __CcTemplateInst10MyTemplateIfE {
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN10MyTemplateIfEaSERKS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=86
+    impl<'b> ::ctor::UnpinAssign<&'b Self> for __CcTemplateInst10MyTemplateIfE {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN10MyTemplateIfEaSERKS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=86
-impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInst10MyTemplateIfE
-{
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN10MyTemplateIfEaSEOS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=86
+    impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInst10MyTemplateIfE
+    {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN10MyTemplateIfEaSEOS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-impl __CcTemplateInst10MyTemplateIfE {
-    /// A non-static member function in a specialization.
-    ///
-    /// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=88
-    #[inline(always)]
-    pub fn get_field_value<'a>(&'a self) -> &'a f32 {
-        unsafe {
-            crate::detail::__rust_thunk___ZNK10MyTemplateIfE15get_field_valueEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(self)
+    impl __CcTemplateInst10MyTemplateIfE {
+        /// A non-static member function in a specialization.
+        ///
+        /// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=88
+        #[inline(always)]
+        pub fn get_field_value<'a>(&'a self) -> &'a f32 {
+            unsafe {
+                crate::detail::__rust_thunk___ZNK10MyTemplateIfE15get_field_valueEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(self)
+            }
         }
     }
 }
@@ -651,64 +654,84 @@ mod detail {
         pub(crate) fn __rust_thunk___ZN10MyTemplateIiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc<
             'a,
         >(
-            __this: &'a mut ::std::mem::MaybeUninit<crate::__CcTemplateInst10MyTemplateIiE>,
+            __this: &'a mut ::std::mem::MaybeUninit<
+                crate::__instantiations::__CcTemplateInst10MyTemplateIiE,
+            >,
         );
         pub(crate) fn __rust_thunk___ZN10MyTemplateIiEC1EOS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc<
             'a,
             'b,
         >(
-            __this: &'a mut ::std::mem::MaybeUninit<crate::__CcTemplateInst10MyTemplateIiE>,
-            __param_0: ::ctor::RvalueReference<'b, crate::__CcTemplateInst10MyTemplateIiE>,
+            __this: &'a mut ::std::mem::MaybeUninit<
+                crate::__instantiations::__CcTemplateInst10MyTemplateIiE,
+            >,
+            __param_0: ::ctor::RvalueReference<
+                'b,
+                crate::__instantiations::__CcTemplateInst10MyTemplateIiE,
+            >,
         );
         pub(crate) fn __rust_thunk___ZN10MyTemplateIiEaSERKS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc<
             'a,
             'b,
         >(
-            __this: &'a mut crate::__CcTemplateInst10MyTemplateIiE,
-            __param_0: &'b crate::__CcTemplateInst10MyTemplateIiE,
-        ) -> &'a mut crate::__CcTemplateInst10MyTemplateIiE;
+            __this: &'a mut crate::__instantiations::__CcTemplateInst10MyTemplateIiE,
+            __param_0: &'b crate::__instantiations::__CcTemplateInst10MyTemplateIiE,
+        ) -> &'a mut crate::__instantiations::__CcTemplateInst10MyTemplateIiE;
         pub(crate) fn __rust_thunk___ZN10MyTemplateIiEaSEOS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc<
             'a,
             'b,
         >(
-            __this: &'a mut crate::__CcTemplateInst10MyTemplateIiE,
-            __param_0: ::ctor::RvalueReference<'b, crate::__CcTemplateInst10MyTemplateIiE>,
-        ) -> &'a mut crate::__CcTemplateInst10MyTemplateIiE;
+            __this: &'a mut crate::__instantiations::__CcTemplateInst10MyTemplateIiE,
+            __param_0: ::ctor::RvalueReference<
+                'b,
+                crate::__instantiations::__CcTemplateInst10MyTemplateIiE,
+            >,
+        ) -> &'a mut crate::__instantiations::__CcTemplateInst10MyTemplateIiE;
         pub(crate) fn __rust_thunk___ZNK10MyTemplateIiE15get_field_valueEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc<
             'a,
         >(
-            __this: &'a crate::__CcTemplateInst10MyTemplateIiE,
+            __this: &'a crate::__instantiations::__CcTemplateInst10MyTemplateIiE,
         ) -> &'a i32;
         pub(crate) fn __rust_thunk___ZN10MyTemplateIfEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc<
             'a,
         >(
-            __this: &'a mut ::std::mem::MaybeUninit<crate::__CcTemplateInst10MyTemplateIfE>,
+            __this: &'a mut ::std::mem::MaybeUninit<
+                crate::__instantiations::__CcTemplateInst10MyTemplateIfE,
+            >,
         );
         pub(crate) fn __rust_thunk___ZN10MyTemplateIfEC1EOS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc<
             'a,
             'b,
         >(
-            __this: &'a mut ::std::mem::MaybeUninit<crate::__CcTemplateInst10MyTemplateIfE>,
-            __param_0: ::ctor::RvalueReference<'b, crate::__CcTemplateInst10MyTemplateIfE>,
+            __this: &'a mut ::std::mem::MaybeUninit<
+                crate::__instantiations::__CcTemplateInst10MyTemplateIfE,
+            >,
+            __param_0: ::ctor::RvalueReference<
+                'b,
+                crate::__instantiations::__CcTemplateInst10MyTemplateIfE,
+            >,
         );
         pub(crate) fn __rust_thunk___ZN10MyTemplateIfEaSERKS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc<
             'a,
             'b,
         >(
-            __this: &'a mut crate::__CcTemplateInst10MyTemplateIfE,
-            __param_0: &'b crate::__CcTemplateInst10MyTemplateIfE,
-        ) -> &'a mut crate::__CcTemplateInst10MyTemplateIfE;
+            __this: &'a mut crate::__instantiations::__CcTemplateInst10MyTemplateIfE,
+            __param_0: &'b crate::__instantiations::__CcTemplateInst10MyTemplateIfE,
+        ) -> &'a mut crate::__instantiations::__CcTemplateInst10MyTemplateIfE;
         pub(crate) fn __rust_thunk___ZN10MyTemplateIfEaSEOS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc<
             'a,
             'b,
         >(
-            __this: &'a mut crate::__CcTemplateInst10MyTemplateIfE,
-            __param_0: ::ctor::RvalueReference<'b, crate::__CcTemplateInst10MyTemplateIfE>,
-        ) -> &'a mut crate::__CcTemplateInst10MyTemplateIfE;
+            __this: &'a mut crate::__instantiations::__CcTemplateInst10MyTemplateIfE,
+            __param_0: ::ctor::RvalueReference<
+                'b,
+                crate::__instantiations::__CcTemplateInst10MyTemplateIfE,
+            >,
+        ) -> &'a mut crate::__instantiations::__CcTemplateInst10MyTemplateIfE;
         pub(crate) fn __rust_thunk___ZNK10MyTemplateIfE15get_field_valueEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc<
             'a,
         >(
-            __this: &'a crate::__CcTemplateInst10MyTemplateIfE,
+            __this: &'a crate::__instantiations::__CcTemplateInst10MyTemplateIfE,
         ) -> &'a f32;
     }
 }
@@ -780,28 +803,38 @@ const _: () = {
 };
 const _: () = assert!(memoffset::offset_of!(crate::MultilineOneStar, i) == 0);
 
-const _: () = assert!(::std::mem::size_of::<crate::__CcTemplateInst10MyTemplateIiE>() == 4);
-const _: () = assert!(::std::mem::align_of::<crate::__CcTemplateInst10MyTemplateIiE>() == 4);
+const _: () =
+    assert!(::std::mem::size_of::<crate::__instantiations::__CcTemplateInst10MyTemplateIiE>() == 4);
+const _: () = assert!(
+    ::std::mem::align_of::<crate::__instantiations::__CcTemplateInst10MyTemplateIiE>() == 4
+);
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateIiE: Clone);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateIiE: Clone);
 };
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateIiE: Copy);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateIiE: Copy);
 };
 const _: () = {
-    static_assertions::assert_not_impl_any!(crate::__CcTemplateInst10MyTemplateIiE: Drop);
+    static_assertions::assert_not_impl_any!(crate::__instantiations::__CcTemplateInst10MyTemplateIiE: Drop);
 };
-const _: () = assert!(memoffset::offset_of!(crate::__CcTemplateInst10MyTemplateIiE, value) == 0);
+const _: () = assert!(
+    memoffset::offset_of!(crate::__instantiations::__CcTemplateInst10MyTemplateIiE, value) == 0
+);
 
-const _: () = assert!(::std::mem::size_of::<crate::__CcTemplateInst10MyTemplateIfE>() == 4);
-const _: () = assert!(::std::mem::align_of::<crate::__CcTemplateInst10MyTemplateIfE>() == 4);
+const _: () =
+    assert!(::std::mem::size_of::<crate::__instantiations::__CcTemplateInst10MyTemplateIfE>() == 4);
+const _: () = assert!(
+    ::std::mem::align_of::<crate::__instantiations::__CcTemplateInst10MyTemplateIfE>() == 4
+);
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateIfE: Clone);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateIfE: Clone);
 };
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateIfE: Copy);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateIfE: Copy);
 };
 const _: () = {
-    static_assertions::assert_not_impl_any!(crate::__CcTemplateInst10MyTemplateIfE: Drop);
+    static_assertions::assert_not_impl_any!(crate::__instantiations::__CcTemplateInst10MyTemplateIfE: Drop);
 };
-const _: () = assert!(memoffset::offset_of!(crate::__CcTemplateInst10MyTemplateIfE, value) == 0);
+const _: () = assert!(
+    memoffset::offset_of!(crate::__instantiations::__CcTemplateInst10MyTemplateIfE, value) == 0
+);
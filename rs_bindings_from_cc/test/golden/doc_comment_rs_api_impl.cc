@@ -16,99 +16,145 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=13
 extern "C" void __rust_thunk___ZN17DocCommentSlashesC1EOS_(
     struct DocCommentSlashes* __this, struct DocCommentSlashes* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=13
 extern "C" struct DocCommentSlashes*
 __rust_thunk___ZN17DocCommentSlashesaSERKS_(
     struct DocCommentSlashes* __this,
     const struct DocCommentSlashes* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=13
 extern "C" struct DocCommentSlashes* __rust_thunk___ZN17DocCommentSlashesaSEOS_(
     struct DocCommentSlashes* __this, struct DocCommentSlashes* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=39
 extern "C" void __rust_thunk___ZN14DocCommentBangC1Ev(
     struct DocCommentBang* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=39
 extern "C" void __rust_thunk___ZN14DocCommentBangC1EOS_(
     struct DocCommentBang* __this, struct DocCommentBang* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=39
 extern "C" struct DocCommentBang* __rust_thunk___ZN14DocCommentBangaSERKS_(
     struct DocCommentBang* __this, const struct DocCommentBang* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=39
 extern "C" struct DocCommentBang* __rust_thunk___ZN14DocCommentBangaSEOS_(
     struct DocCommentBang* __this, struct DocCommentBang* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=47
 extern "C" void __rust_thunk___ZN24MultilineCommentTwoStarsC1Ev(
     struct MultilineCommentTwoStars* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=47
 extern "C" void __rust_thunk___ZN24MultilineCommentTwoStarsC1EOS_(
     struct MultilineCommentTwoStars* __this,
     struct MultilineCommentTwoStars* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=47
 extern "C" struct MultilineCommentTwoStars*
 __rust_thunk___ZN24MultilineCommentTwoStarsaSERKS_(
     struct MultilineCommentTwoStars* __this,
     const struct MultilineCommentTwoStars* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=47
 extern "C" struct MultilineCommentTwoStars*
 __rust_thunk___ZN24MultilineCommentTwoStarsaSEOS_(
     struct MultilineCommentTwoStars* __this,
     struct MultilineCommentTwoStars* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=55
 extern "C" void __rust_thunk___ZN11LineCommentC1Ev(struct LineComment* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=55
 extern "C" void __rust_thunk___ZN11LineCommentC1EOS_(
     struct LineComment* __this, struct LineComment* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=55
 extern "C" struct LineComment* __rust_thunk___ZN11LineCommentaSERKS_(
     struct LineComment* __this, const struct LineComment* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=55
 extern "C" struct LineComment* __rust_thunk___ZN11LineCommentaSEOS_(
     struct LineComment* __this, struct LineComment* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=63
 extern "C" void __rust_thunk___ZN16MultilineOneStarC1Ev(
     struct MultilineOneStar* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=63
 extern "C" void __rust_thunk___ZN16MultilineOneStarC1EOS_(
     struct MultilineOneStar* __this, struct MultilineOneStar* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=63
 extern "C" struct MultilineOneStar* __rust_thunk___ZN16MultilineOneStaraSERKS_(
     struct MultilineOneStar* __this, const struct MultilineOneStar* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=63
 extern "C" struct MultilineOneStar* __rust_thunk___ZN16MultilineOneStaraSEOS_(
     struct MultilineOneStar* __this, struct MultilineOneStar* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=69
 extern "C" int __rust_thunk___Z3foov() { return foo(); }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=76
 extern "C" void
 __rust_thunk___ZN10MyTemplateIiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(
     struct MyTemplate<int>* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=76
 extern "C" void
 __rust_thunk___ZN10MyTemplateIiEC1EOS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(
     struct MyTemplate<int>* __this, struct MyTemplate<int>* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=76
 extern "C" struct MyTemplate<int>*
 __rust_thunk___ZN10MyTemplateIiEaSERKS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(
     struct MyTemplate<int>* __this, const struct MyTemplate<int>* __param_0) {
@@ -122,16 +168,22 @@ __rust_thunk___ZNK10MyTemplateIiE15get_field_valueEv__2f_2fthird_5fparty_2fcrubi
     const struct MyTemplate<int>* __this) {
   return &__this->get_field_value();
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=86
 extern "C" void
 __rust_thunk___ZN10MyTemplateIfEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(
     struct MyTemplate<float>* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=86
 extern "C" void
 __rust_thunk___ZN10MyTemplateIfEC1EOS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(
     struct MyTemplate<float>* __this, struct MyTemplate<float>* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/doc_comment.h;l=86
 extern "C" struct MyTemplate<float>*
 __rust_thunk___ZN10MyTemplateIfEaSERKS0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3adoc_5fcomment_5fcc(
     struct MyTemplate<float>* __this,
@@ -16,17 +16,25 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/escaping_keywords.h;l=10
 extern "C" void __rust_thunk___ZN4typeC1Ev(struct type* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/escaping_keywords.h;l=10
 extern "C" void __rust_thunk___ZN4typeC1EOS_(struct type* __this,
                                              struct type* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/escaping_keywords.h;l=10
 extern "C" struct type* __rust_thunk___ZN4typeaSERKS_(
     struct type* __this, const struct type* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/escaping_keywords.h;l=10
 extern "C" struct type* __rust_thunk___ZN4typeaSEOS_(struct type* __this,
                                                      struct type* __param_0) {
   return &__this->operator=(std::move(*__param_0));
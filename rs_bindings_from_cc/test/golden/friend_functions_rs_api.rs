@@ -95,6 +95,12 @@ pub fn visible_rref<'a>(__param_0: ::ctor::RvalueReference<'a, crate::SomeClass>
     unsafe { crate::detail::__rust_thunk___Z12visible_rrefO9SomeClass(__param_0) }
 }
 
+/// Version of `visible_rref` taking `impl Ctor` instead of rvalue references.
+#[inline(always)]
+pub fn visible_rref_from_ctor(__param_0: impl ::ctor::Ctor<Output = crate::SomeClass>) {
+    visible_rref(::ctor::RvalueReference(::ctor::emplace!(__param_0)))
+}
+
 /// A function can be declared multiple times - e.g. once in a friend
 /// declaration below + in a definition below.  This example mimics
 /// Uint128Low64 declarations from absl/numeric/int128.h.  This is a
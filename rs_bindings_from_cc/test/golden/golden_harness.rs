@@ -0,0 +1,305 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+//! Compares the golden files of this directory with freshly generated
+//! bindings, and prints the diffs of the ones which are out of date:
+//!
+//! ```sh
+//! bazel run //rs_bindings_from_cc/test/golden:golden_harness
+//! ```
+//!
+//! With `--update`, the golden files are overwritten with the generated
+//! bindings instead (this also adds the golden files of new headers).
+
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+
+/// The lines which are ignored when comparing the files (like `-I` in
+/// `test.sh`), as they change whenever the headers are edited.
+const IGNORED_LINES: [&str; 2] = ["// Generated from: ", "// Expanded at: "];
+
+/// The number of unchanged lines around the changes in a diff.
+const CONTEXT_LINES: usize = 3;
+
+/// The largest number of line pairs compared to find the smallest diff. Larger
+/// changes are shown as the removal of all of the old lines, and the addition
+/// of all of the new ones.
+const MAX_COMPARED_LINE_PAIRS: usize = 50_000_000;
+
+/// Returns the name of the golden file of a generated file, e.g.
+/// `types_rs_api.rs` for `types_cc_rust_api.rs` (see `update.sh`).
+fn golden_file_name(generated_path: &str) -> Result<String> {
+    let file_name = Path::new(generated_path)
+        .file_name()
+        .and_then(|file_name| file_name.to_str())
+        .with_context(|| format!("Invalid path of a generated file: {generated_path}"))?;
+    if let Some(test) = file_name.strip_suffix("_cc_rust_api.rs") {
+        Ok(format!("{test}_rs_api.rs"))
+    } else if let Some(test) = file_name.strip_suffix("_cc_rust_api_impl.cc") {
+        Ok(format!("{test}_rs_api_impl.cc"))
+    } else {
+        bail!("Unexpected generated file: {generated_path}")
+    }
+}
+
+/// Whether `golden` and `generated` only differ by their ignored lines.
+fn is_up_to_date(golden: &str, generated: &str) -> bool {
+    fn significant_lines(contents: &str) -> impl Iterator<Item = &str> {
+        contents.lines().filter(|line| !IGNORED_LINES.iter().any(|ignored| line.contains(ignored)))
+    }
+    significant_lines(golden).eq(significant_lines(generated))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DiffLine<'a> {
+    Unchanged(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Returns a smallest sequence of line removals and additions which turns
+/// `old` into `new`.
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<DiffLine<'a>> {
+    let prefix = old.iter().zip(new).take_while(|(old, new)| old == new).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+    let (old_changed, new_changed) =
+        (&old[prefix..old.len() - suffix], &new[prefix..new.len() - suffix]);
+
+    let mut lines: Vec<DiffLine> =
+        old[..prefix].iter().map(|line| DiffLine::Unchanged(line)).collect();
+    if old_changed.len().saturating_mul(new_changed.len()) > MAX_COMPARED_LINE_PAIRS {
+        lines.extend(old_changed.iter().map(|line| DiffLine::Removed(line)));
+        lines.extend(new_changed.iter().map(|line| DiffLine::Added(line)));
+    } else {
+        // `common[i * width + j]` is the length of the longest common
+        // subsequence of `old_changed[i..]` and `new_changed[j..]`.
+        let width = new_changed.len() + 1;
+        let mut common = vec![0u32; (old_changed.len() + 1) * width];
+        for i in (0..old_changed.len()).rev() {
+            for j in (0..new_changed.len()).rev() {
+                common[i * width + j] = if old_changed[i] == new_changed[j] {
+                    common[(i + 1) * width + j + 1] + 1
+                } else {
+                    common[(i + 1) * width + j].max(common[i * width + j + 1])
+                };
+            }
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < old_changed.len() || j < new_changed.len() {
+            if i < old_changed.len() && j < new_changed.len() && old_changed[i] == new_changed[j] {
+                lines.push(DiffLine::Unchanged(old_changed[i]));
+                i += 1;
+                j += 1;
+            } else if i < old_changed.len()
+                && (j == new_changed.len()
+                    || common[(i + 1) * width + j] >= common[i * width + j + 1])
+            {
+                lines.push(DiffLine::Removed(old_changed[i]));
+                i += 1;
+            } else {
+                lines.push(DiffLine::Added(new_changed[j]));
+                j += 1;
+            }
+        }
+    }
+    lines.extend(old[old.len() - suffix..].iter().map(|line| DiffLine::Unchanged(line)));
+    lines
+}
+
+/// Returns the diff of `old` and `new` in the unified format (like `diff -u`).
+fn unified_diff(old_name: &str, new_name: &str, old: &str, new: &str) -> String {
+    let lines = diff_lines(&old.lines().collect::<Vec<_>>(), &new.lines().collect::<Vec<_>>());
+    let changes: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, DiffLine::Unchanged(_)))
+        .map(|(index, _)| index)
+        .collect();
+    let mut diff = format!("--- {old_name}\n+++ {new_name}\n");
+    let mut change = 0;
+    while change < changes.len() {
+        // The changes which are close enough to share their context lines are
+        // shown in the same hunk.
+        let start = changes[change].saturating_sub(CONTEXT_LINES);
+        let mut end = changes[change] + 1;
+        while change < changes.len() && changes[change] <= end + 2 * CONTEXT_LINES {
+            end = changes[change] + 1;
+            change += 1;
+        }
+        let end = (end + CONTEXT_LINES).min(lines.len());
+        let old_start =
+            lines[..start].iter().filter(|line| !matches!(line, DiffLine::Added(_))).count();
+        let new_start =
+            lines[..start].iter().filter(|line| !matches!(line, DiffLine::Removed(_))).count();
+        let hunk = &lines[start..end];
+        let old_len = hunk.iter().filter(|line| !matches!(line, DiffLine::Added(_))).count();
+        let new_len = hunk.iter().filter(|line| !matches!(line, DiffLine::Removed(_))).count();
+        // Like in `diff -u`, an empty range starts at the line before it.
+        let first_line = |start: usize, len: usize| if len == 0 { start } else { start + 1 };
+        diff += &format!(
+            "@@ -{},{old_len} +{},{new_len} @@\n",
+            first_line(old_start, old_len),
+            first_line(new_start, new_len)
+        );
+        for line in hunk {
+            let (prefix, text) = match line {
+                DiffLine::Unchanged(text) => (' ', text),
+                DiffLine::Removed(text) => ('-', text),
+                DiffLine::Added(text) => ('+', text),
+            };
+            diff += &format!("{prefix}{text}\n");
+        }
+    }
+    diff
+}
+
+fn main() -> Result<()> {
+    let mut update = false;
+    let mut golden_dir = None;
+    let mut license_header = None;
+    let mut generated_paths = vec![];
+    for arg in std::env::args().skip(1) {
+        if arg == "--update" {
+            update = true;
+        } else if let Some(value) = arg.strip_prefix("--golden_dir=") {
+            golden_dir = Some(value.to_string());
+        } else if let Some(value) = arg.strip_prefix("--license_header=") {
+            license_header = Some(value.to_string());
+        } else if arg.starts_with("--") {
+            bail!("Unknown flag: {arg}");
+        } else {
+            generated_paths.push(arg);
+        }
+    }
+    let golden_dir = golden_dir.context("Missing `--golden_dir`")?;
+    let license_header = license_header.context("Missing `--license_header`")?;
+    let license_header = std::fs::read_to_string(&license_header)
+        .with_context(|| format!("Failed to read the license header {license_header}"))?;
+    // The golden files are in the source tree rather than in the runfiles, so
+    // that they can be updated.
+    let workspace = std::env::var_os("BUILD_WORKSPACE_DIRECTORY")
+        .context("The golden harness has to be run with `bazel run`")?;
+    let golden_dir = Path::new(&workspace).join(golden_dir);
+
+    let mut out_of_date = vec![];
+    for generated_path in &generated_paths {
+        let golden_name = golden_file_name(generated_path)?;
+        let golden_path = golden_dir.join(&golden_name);
+        // Since the golden files are checked in, they need a license header.
+        let generated = license_header.clone()
+            + &std::fs::read_to_string(generated_path)
+                .with_context(|| format!("Failed to read {generated_path}"))?;
+        let golden = match std::fs::read_to_string(&golden_path) {
+            Ok(golden) => golden,
+            // The header was just added.
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(error) => {
+                return Err(error).with_context(|| format!("Failed to read {golden_name}"));
+            }
+        };
+        if golden == generated {
+            continue;
+        }
+        if update {
+            // The ignored lines are updated too.
+            std::fs::write(&golden_path, &generated)
+                .with_context(|| format!("Failed to write {golden_name}"))?;
+        }
+        if !is_up_to_date(&golden, &generated) {
+            print!(
+                "{}",
+                unified_diff(
+                    &format!("a/{golden_name}"),
+                    &format!("b/{golden_name}"),
+                    &golden,
+                    &generated
+                )
+            );
+            out_of_date.push(golden_name);
+        }
+    }
+
+    if out_of_date.is_empty() {
+        println!("All of the {} golden files are up to date.", generated_paths.len());
+    } else if update {
+        println!("Updated {} golden files: {}", out_of_date.len(), out_of_date.join(", "));
+    } else {
+        eprintln!(
+            "{} golden files are out of date: {}\n\
+             Run `bazel run //rs_bindings_from_cc/test/golden:golden_harness -- --update` to \
+             update them.",
+            out_of_date.len(),
+            out_of_date.join(", ")
+        );
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_golden_file_name() {
+        assert_eq!(
+            golden_file_name("bazel-bin/x/types_cc_rust_api.rs").unwrap(),
+            "types_rs_api.rs"
+        );
+        assert_eq!(
+            golden_file_name("bazel-bin/x/types_cc_rust_api_impl.cc").unwrap(),
+            "types_rs_api_impl.cc"
+        );
+        assert!(golden_file_name("types.h").is_err());
+    }
+
+    #[test]
+    fn test_is_up_to_date() {
+        assert!(is_up_to_date(
+            "a\n// Generated from: x.h;l=1\n",
+            "a\n// Generated from: x.h;l=2\n"
+        ));
+        assert!(!is_up_to_date("a\nb\n", "a\nc\n"));
+    }
+
+    #[test]
+    fn test_diff_lines() {
+        assert_eq!(
+            diff_lines(&["a", "b", "c", "d"], &["a", "c", "x", "d"]),
+            vec![
+                DiffLine::Unchanged("a"),
+                DiffLine::Removed("b"),
+                DiffLine::Unchanged("c"),
+                DiffLine::Added("x"),
+                DiffLine::Unchanged("d"),
+            ]
+        );
+        assert_eq!(diff_lines(&[], &["a"]), vec![DiffLine::Added("a")]);
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n13\n14\n15\n16\n";
+        let new = old.replacen("2\n", "two\n", 1).replace("15\n", "");
+        assert_eq!(
+            unified_diff("a/f", "b/f", old, &new),
+            "--- a/f\n+++ b/f\n\
+             @@ -1,5 +1,5 @@\n 1\n-2\n+two\n 3\n 4\n 5\n\
+             @@ -12,5 +12,4 @@\n 12\n 13\n 14\n-15\n 16\n"
+        );
+    }
+
+    #[test]
+    fn test_unified_diff_of_new_file() {
+        assert_eq!(
+            unified_diff("a/f", "b/f", "", "x\n"),
+            "--- a/f\n+++ b/f\n@@ -0,0 +1,1 @@\n+x\n"
+        );
+    }
+}
@@ -28,6 +28,22 @@ pub struct Base0 {
     __non_field_data: [::std::mem::MaybeUninit<u8>; 1],
 }
 forward_declare::unsafe_define!(forward_declare::symbol!("Base0"), crate::Base0);
+impl Base0 {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=14
 impl ::ctor::CtorNew<()> for Base0 {
@@ -131,6 +147,22 @@ pub struct Base1 {
     pub(crate) b1_2_: [::std::mem::MaybeUninit<u8>; 8],
 }
 forward_declare::unsafe_define!(forward_declare::symbol!("Base1"), crate::Base1);
+impl Base1 {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=15
 impl ::ctor::CtorNew<()> for Base1 {
@@ -231,6 +263,22 @@ pub struct Base2 {
     pub(crate) b2_1_: [::std::mem::MaybeUninit<u8>; 2],
 }
 forward_declare::unsafe_define!(forward_declare::symbol!("Base2"), crate::Base2);
+impl Base2 {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=20
 impl ::ctor::CtorNew<()> for Base2 {
@@ -397,6 +445,22 @@ pub struct VirtualBase1 {
     __non_field_data: [::std::mem::MaybeUninit<u8>; 24],
 }
 forward_declare::unsafe_define!(forward_declare::symbol!("VirtualBase1"), crate::VirtualBase1);
+impl VirtualBase1 {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=28
 impl ::ctor::CtorNew<()> for VirtualBase1 {
@@ -500,6 +564,22 @@ pub struct VirtualBase2 {
     __non_field_data: [::std::mem::MaybeUninit<u8>; 24],
 }
 forward_declare::unsafe_define!(forward_declare::symbol!("VirtualBase2"), crate::VirtualBase2);
+impl VirtualBase2 {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=29
 impl ::ctor::CtorNew<()> for VirtualBase2 {
@@ -603,6 +683,22 @@ pub struct VirtualDerived {
     __non_field_data: [::std::mem::MaybeUninit<u8>; 32],
 }
 forward_declare::unsafe_define!(forward_declare::symbol!("VirtualDerived"), crate::VirtualDerived);
+impl VirtualDerived {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=30
 impl ::ctor::CtorNew<()> for VirtualDerived {
@@ -747,6 +843,22 @@ pub struct MethodBase1 {
     __non_field_data: [::std::mem::MaybeUninit<u8>; 1],
 }
 forward_declare::unsafe_define!(forward_declare::symbol!("MethodBase1"), crate::MethodBase1);
+impl MethodBase1 {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=40
 impl ::ctor::CtorNew<()> for MethodBase1 {
@@ -868,6 +980,22 @@ pub struct MethodBase2 {
     __non_field_data: [::std::mem::MaybeUninit<u8>; 1],
 }
 forward_declare::unsafe_define!(forward_declare::symbol!("MethodBase2"), crate::MethodBase2);
+impl MethodBase2 {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=51
 impl ::ctor::CtorNew<()> for MethodBase2 {
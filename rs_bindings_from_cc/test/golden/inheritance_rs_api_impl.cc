@@ -16,192 +16,290 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=14
 extern "C" void __rust_thunk___ZN5Base0C1Ev(class Base0* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=14
 extern "C" void __rust_thunk___ZN5Base0C1ERKS_(class Base0* __this,
                                                const class Base0* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=14
 extern "C" void __rust_thunk___ZN5Base0C1EOS_(class Base0* __this,
                                               class Base0* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=14
 extern "C" class Base0* __rust_thunk___ZN5Base0aSERKS_(
     class Base0* __this, const class Base0* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=14
 extern "C" class Base0* __rust_thunk___ZN5Base0aSEOS_(class Base0* __this,
                                                       class Base0* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=15
 extern "C" void __rust_thunk___ZN5Base1C1Ev(class Base1* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=15
 extern "C" void __rust_thunk___ZN5Base1C1ERKS_(class Base1* __this,
                                                const class Base1* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=15
 extern "C" void __rust_thunk___ZN5Base1C1EOS_(class Base1* __this,
                                               class Base1* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=15
 extern "C" class Base1* __rust_thunk___ZN5Base1aSERKS_(
     class Base1* __this, const class Base1* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=15
 extern "C" class Base1* __rust_thunk___ZN5Base1aSEOS_(class Base1* __this,
                                                       class Base1* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=20
 extern "C" void __rust_thunk___ZN5Base2C1Ev(class Base2* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=20
 extern "C" void __rust_thunk___ZN5Base2C1ERKS_(class Base2* __this,
                                                const class Base2* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=20
 extern "C" void __rust_thunk___ZN5Base2C1EOS_(class Base2* __this,
                                               class Base2* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=20
 extern "C" class Base2* __rust_thunk___ZN5Base2aSERKS_(
     class Base2* __this, const class Base2* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=20
 extern "C" class Base2* __rust_thunk___ZN5Base2aSEOS_(class Base2* __this,
                                                       class Base2* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=24
 extern "C" void __rust_thunk___ZN7DerivedC1Ev(struct Derived* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=24
 extern "C" void __rust_thunk___ZN7DerivedC1EOS_(struct Derived* __this,
                                                 struct Derived* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=24
 extern "C" struct Derived* __rust_thunk___ZN7DerivedaSERKS_(
     struct Derived* __this, const struct Derived* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=24
 extern "C" struct Derived* __rust_thunk___ZN7DerivedaSEOS_(
     struct Derived* __this, struct Derived* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=28
 extern "C" void __rust_thunk___ZN12VirtualBase1C1Ev(
     class VirtualBase1* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=28
 extern "C" void __rust_thunk___ZN12VirtualBase1C1ERKS_(
     class VirtualBase1* __this, const class VirtualBase1* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=28
 extern "C" void __rust_thunk___ZN12VirtualBase1C1EOS_(
     class VirtualBase1* __this, class VirtualBase1* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=28
 extern "C" class VirtualBase1* __rust_thunk___ZN12VirtualBase1aSERKS_(
     class VirtualBase1* __this, const class VirtualBase1* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=28
 extern "C" class VirtualBase1* __rust_thunk___ZN12VirtualBase1aSEOS_(
     class VirtualBase1* __this, class VirtualBase1* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=29
 extern "C" void __rust_thunk___ZN12VirtualBase2C1Ev(
     class VirtualBase2* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=29
 extern "C" void __rust_thunk___ZN12VirtualBase2C1ERKS_(
     class VirtualBase2* __this, const class VirtualBase2* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=29
 extern "C" void __rust_thunk___ZN12VirtualBase2C1EOS_(
     class VirtualBase2* __this, class VirtualBase2* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=29
 extern "C" class VirtualBase2* __rust_thunk___ZN12VirtualBase2aSERKS_(
     class VirtualBase2* __this, const class VirtualBase2* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=29
 extern "C" class VirtualBase2* __rust_thunk___ZN12VirtualBase2aSEOS_(
     class VirtualBase2* __this, class VirtualBase2* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=30
 extern "C" void __rust_thunk___ZN14VirtualDerivedC1Ev(
     class VirtualDerived* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=30
 extern "C" void __rust_thunk___ZN14VirtualDerivedC1ERKS_(
     class VirtualDerived* __this, const class VirtualDerived* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=30
 extern "C" void __rust_thunk___ZN14VirtualDerivedC1EOS_(
     class VirtualDerived* __this, class VirtualDerived* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=30
 extern "C" class VirtualDerived* __rust_thunk___ZN14VirtualDerivedaSERKS_(
     class VirtualDerived* __this, const class VirtualDerived* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=30
 extern "C" class VirtualDerived* __rust_thunk___ZN14VirtualDerivedaSEOS_(
     class VirtualDerived* __this, class VirtualDerived* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=33
 extern "C" class MyAbstractClass* __rust_thunk___ZN15MyAbstractClassaSERKS_(
     class MyAbstractClass* __this, const class MyAbstractClass* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=40
 extern "C" void __rust_thunk___ZN11MethodBase1C1Ev(class MethodBase1* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=40
 extern "C" void __rust_thunk___ZN11MethodBase1C1ERKS_(
     class MethodBase1* __this, const class MethodBase1* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=40
 extern "C" void __rust_thunk___ZN11MethodBase1C1EOS_(
     class MethodBase1* __this, class MethodBase1* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=40
 extern "C" class MethodBase1* __rust_thunk___ZN11MethodBase1aSERKS_(
     class MethodBase1* __this, const class MethodBase1* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=40
 extern "C" class MethodBase1* __rust_thunk___ZN11MethodBase1aSEOS_(
     class MethodBase1* __this, class MethodBase1* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=51
 extern "C" void __rust_thunk___ZN11MethodBase2C1Ev(class MethodBase2* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=51
 extern "C" void __rust_thunk___ZN11MethodBase2C1ERKS_(
     class MethodBase2* __this, const class MethodBase2* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=51
 extern "C" void __rust_thunk___ZN11MethodBase2C1EOS_(
     class MethodBase2* __this, class MethodBase2* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=51
 extern "C" class MethodBase2* __rust_thunk___ZN11MethodBase2aSERKS_(
     class MethodBase2* __this, const class MethodBase2* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=51
 extern "C" class MethodBase2* __rust_thunk___ZN11MethodBase2aSEOS_(
     class MethodBase2* __this, class MethodBase2* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=57
 extern "C" void __rust_thunk___ZN13MethodDerivedC1Ev(
     class MethodDerived* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=57
 extern "C" void __rust_thunk___ZN13MethodDerivedC1EOS_(
     class MethodDerived* __this, class MethodDerived* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=57
 extern "C" class MethodDerived* __rust_thunk___ZN13MethodDerivedaSERKS_(
     class MethodDerived* __this, const class MethodDerived* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/inheritance.h;l=57
 extern "C" class MethodDerived* __rust_thunk___ZN13MethodDerivedaSEOS_(
     class MethodDerived* __this, class MethodDerived* __param_0) {
   return &__this->operator=(std::move(*__param_0));
@@ -16,38 +16,58 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/item_order.h;l=10
 extern "C" void __rust_thunk___ZN11FirstStructC1Ev(struct FirstStruct* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/item_order.h;l=10
 extern "C" void __rust_thunk___ZN11FirstStructC1EOS_(
     struct FirstStruct* __this, struct FirstStruct* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/item_order.h;l=10
 extern "C" struct FirstStruct* __rust_thunk___ZN11FirstStructaSERKS_(
     struct FirstStruct* __this, const struct FirstStruct* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/item_order.h;l=10
 extern "C" struct FirstStruct* __rust_thunk___ZN11FirstStructaSEOS_(
     struct FirstStruct* __this, struct FirstStruct* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/item_order.h;l=14
 extern "C" int __rust_thunk___Z10first_funcv() { return first_func(); }
+
+// Generated from: rs_bindings_from_cc/test/golden/item_order.h;l=16
 extern "C" void __rust_thunk___ZN12SecondStructC1Ev(
     struct SecondStruct* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/item_order.h;l=16
 extern "C" void __rust_thunk___ZN12SecondStructC1EOS_(
     struct SecondStruct* __this, struct SecondStruct* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/item_order.h;l=16
 extern "C" struct SecondStruct* __rust_thunk___ZN12SecondStructaSERKS_(
     struct SecondStruct* __this, const struct SecondStruct* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/item_order.h;l=16
 extern "C" struct SecondStruct* __rust_thunk___ZN12SecondStructaSEOS_(
     struct SecondStruct* __this, struct SecondStruct* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/item_order.h;l=20
 extern "C" int __rust_thunk___Z11second_funcv() { return second_func(); }
 
 static_assert(sizeof(struct FirstStruct) == 4);
@@ -53,9 +53,8 @@ pub unsafe fn ConsumeArray(pair: *mut i32) {
     crate::detail::__rust_thunk___Z12ConsumeArrayPi(pair)
 }
 
-// Generated from: rs_bindings_from_cc/test/golden/lifetimes.h;l=20
-// Error while generating bindings for item 'Arr':
-// Unsupported type 'int[2]': Unsupported clang::Type class 'ConstantArray'
+/// Generated from: rs_bindings_from_cc/test/golden/lifetimes.h;l=20
+pub type Arr = [i32; 2];
 
 /// Generated from: rs_bindings_from_cc/test/golden/lifetimes.h;l=21
 #[inline(always)]
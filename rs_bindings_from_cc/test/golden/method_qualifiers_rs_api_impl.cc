@@ -16,22 +16,32 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/method_qualifiers.h;l=17
 extern "C" void __rust_thunk___ZN6Inline17UnqualifiedMethodEv(
     struct Inline* __this) {
   __this->UnqualifiedMethod();
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/method_qualifiers.h;l=18
 extern "C" void __rust_thunk___ZNR6Inline12LvalueMethodEv(
     struct Inline* __this) {
   __this->LvalueMethod();
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/method_qualifiers.h;l=19
 extern "C" void __rust_thunk___ZNKR6Inline17LvalueMethodConstEv(
     const struct Inline* __this) {
   __this->LvalueMethodConst();
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/method_qualifiers.h;l=20
 extern "C" void __rust_thunk___ZNO6Inline12RvalueMethodEv(
     struct Inline* __this) {
   std::move(*__this).RvalueMethod();
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/method_qualifiers.h;l=21
 extern "C" void __rust_thunk___ZNKO6Inline17RvalueMethodConstEv(
     const struct Inline* __this) {
   std::move(*__this).RvalueMethodConst();
@@ -210,63 +210,64 @@ pub mod test_namespace_bindings_reopened {
 // namespace test_namespace_bindings_reopened
 
 pub mod test_namespace_bindings_inline {
-    pub mod inner {
-        /// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=44
-        #[derive(Clone, Copy)]
-        #[repr(C)]
-        pub struct StructInInlineNamespace {
-            __non_field_data: [::std::mem::MaybeUninit<u8>; 1],
-        }
-        forward_declare::unsafe_define!(
-            forward_declare::symbol!("StructInInlineNamespace"),
-            crate::test_namespace_bindings_inline::inner::StructInInlineNamespace
-        );
+    /// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=44
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub struct StructInInlineNamespace {
+        __non_field_data: [::std::mem::MaybeUninit<u8>; 1],
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("StructInInlineNamespace"),
+        crate::test_namespace_bindings_inline::StructInInlineNamespace
+    );
 
-        /// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=44
-        impl Default for StructInInlineNamespace {
-            #[inline(always)]
-            fn default() -> Self {
-                let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-                unsafe {
-                    crate::detail::__rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespaceC1Ev(&mut tmp);
-                    tmp.assume_init()
-                }
+    /// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=44
+    impl Default for StructInInlineNamespace {
+        #[inline(always)]
+        fn default() -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespaceC1Ev(&mut tmp);
+                tmp.assume_init()
             }
         }
+    }
 
-        /// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=44
-        impl<'b> From<::ctor::RvalueReference<'b, Self>> for StructInInlineNamespace {
-            #[inline(always)]
-            fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
-                let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-                unsafe {
-                    crate::detail::__rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespaceC1EOS1_(&mut tmp,__param_0);
-                    tmp.assume_init()
-                }
+    /// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=44
+    impl<'b> From<::ctor::RvalueReference<'b, Self>> for StructInInlineNamespace {
+        #[inline(always)]
+        fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespaceC1EOS1_(&mut tmp,__param_0);
+                tmp.assume_init()
             }
         }
+    }
 
-        /// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=44
-        impl<'b> ::ctor::UnpinAssign<&'b Self> for StructInInlineNamespace {
-            #[inline(always)]
-            fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
-                unsafe {
-                    crate::detail::__rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespaceaSERKS1_(self,__param_0);
-                }
+    /// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=44
+    impl<'b> ::ctor::UnpinAssign<&'b Self> for StructInInlineNamespace {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespaceaSERKS1_(self,__param_0);
             }
         }
+    }
 
-        /// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=44
-        impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>> for StructInInlineNamespace {
-            #[inline(always)]
-            fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
-                unsafe {
-                    crate::detail::__rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespaceaSEOS1_(self,__param_0);
-                }
+    /// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=44
+    impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>> for StructInInlineNamespace {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespaceaSEOS1_(self,__param_0);
             }
         }
     }
-    pub use inner::*;
+
+    pub mod inner {
+        pub use super::*;
+    }
 
     // namespace inner
 }
@@ -276,7 +277,7 @@ pub mod test_namespace_bindings_inline {
 /// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=48
 #[inline(always)]
 pub fn useStructInInlineNamespaceWithFullQualifier(
-    s: crate::test_namespace_bindings_inline::inner::StructInInlineNamespace,
+    s: crate::test_namespace_bindings_inline::StructInInlineNamespace,
 ) {
     unsafe {
         crate::detail::__rust_thunk___Z43useStructInInlineNamespaceWithFullQualifierN30test_namespace_bindings_inline5inner23StructInInlineNamespaceE(s)
@@ -286,7 +287,7 @@ pub fn useStructInInlineNamespaceWithFullQualifier(
 /// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=50
 #[inline(always)]
 pub fn useStructInInlineNamespaceSkipInlineQualifier(
-    s: crate::test_namespace_bindings_inline::inner::StructInInlineNamespace,
+    s: crate::test_namespace_bindings_inline::StructInInlineNamespace,
 ) {
     unsafe {
         crate::detail::__rust_thunk___Z45useStructInInlineNamespaceSkipInlineQualifierN30test_namespace_bindings_inline5inner23StructInInlineNamespaceE(s)
@@ -374,7 +375,7 @@ mod detail {
             'a,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::test_namespace_bindings_inline::inner::StructInInlineNamespace,
+                crate::test_namespace_bindings_inline::StructInInlineNamespace,
             >,
         );
         pub(crate) fn __rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespaceC1EOS1_<
@@ -382,37 +383,37 @@ mod detail {
             'b,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::test_namespace_bindings_inline::inner::StructInInlineNamespace,
+                crate::test_namespace_bindings_inline::StructInInlineNamespace,
             >,
             __param_0: ::ctor::RvalueReference<
                 'b,
-                crate::test_namespace_bindings_inline::inner::StructInInlineNamespace,
+                crate::test_namespace_bindings_inline::StructInInlineNamespace,
             >,
         );
         pub(crate) fn __rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespaceaSERKS1_<
             'a,
             'b,
         >(
-            __this: &'a mut crate::test_namespace_bindings_inline::inner::StructInInlineNamespace,
-            __param_0: &'b crate::test_namespace_bindings_inline::inner::StructInInlineNamespace,
-        ) -> &'a mut crate::test_namespace_bindings_inline::inner::StructInInlineNamespace;
+            __this: &'a mut crate::test_namespace_bindings_inline::StructInInlineNamespace,
+            __param_0: &'b crate::test_namespace_bindings_inline::StructInInlineNamespace,
+        ) -> &'a mut crate::test_namespace_bindings_inline::StructInInlineNamespace;
         pub(crate) fn __rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespaceaSEOS1_<
             'a,
             'b,
         >(
-            __this: &'a mut crate::test_namespace_bindings_inline::inner::StructInInlineNamespace,
+            __this: &'a mut crate::test_namespace_bindings_inline::StructInInlineNamespace,
             __param_0: ::ctor::RvalueReference<
                 'b,
-                crate::test_namespace_bindings_inline::inner::StructInInlineNamespace,
+                crate::test_namespace_bindings_inline::StructInInlineNamespace,
             >,
-        ) -> &'a mut crate::test_namespace_bindings_inline::inner::StructInInlineNamespace;
+        ) -> &'a mut crate::test_namespace_bindings_inline::StructInInlineNamespace;
         #[link_name = "_Z43useStructInInlineNamespaceWithFullQualifierN30test_namespace_bindings_inline5inner23StructInInlineNamespaceE"]
         pub(crate) fn __rust_thunk___Z43useStructInInlineNamespaceWithFullQualifierN30test_namespace_bindings_inline5inner23StructInInlineNamespaceE(
-            s: crate::test_namespace_bindings_inline::inner::StructInInlineNamespace,
+            s: crate::test_namespace_bindings_inline::StructInInlineNamespace,
         );
         #[link_name = "_Z45useStructInInlineNamespaceSkipInlineQualifierN30test_namespace_bindings_inline5inner23StructInInlineNamespaceE"]
         pub(crate) fn __rust_thunk___Z45useStructInInlineNamespaceSkipInlineQualifierN30test_namespace_bindings_inline5inner23StructInInlineNamespaceE(
-            s: crate::test_namespace_bindings_inline::inner::StructInInlineNamespace,
+            s: crate::test_namespace_bindings_inline::StructInInlineNamespace,
         );
         pub(crate) fn __rust_thunk___ZN4impl3fooEv();
     }
@@ -450,25 +451,23 @@ const _: () = {
 };
 
 const _: () = assert!(
-    ::std::mem::size_of::<crate::test_namespace_bindings_inline::inner::StructInInlineNamespace>()
-        == 1
+    ::std::mem::size_of::<crate::test_namespace_bindings_inline::StructInInlineNamespace>() == 1
 );
 const _: () = assert!(
-    ::std::mem::align_of::<crate::test_namespace_bindings_inline::inner::StructInInlineNamespace>()
-        == 1
+    ::std::mem::align_of::<crate::test_namespace_bindings_inline::StructInInlineNamespace>() == 1
 );
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::test_namespace_bindings_inline::inner::StructInInlineNamespace: Clone
+        crate::test_namespace_bindings_inline::StructInInlineNamespace: Clone
     );
 };
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::test_namespace_bindings_inline::inner::StructInInlineNamespace: Copy
+        crate::test_namespace_bindings_inline::StructInInlineNamespace: Copy
     );
 };
 const _: () = {
     static_assertions::assert_not_impl_any!(
-        crate::test_namespace_bindings_inline::inner::StructInInlineNamespace: Drop
+        crate::test_namespace_bindings_inline::StructInInlineNamespace: Drop
     );
 };
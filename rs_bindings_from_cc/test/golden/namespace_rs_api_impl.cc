@@ -16,59 +16,81 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=11
 extern "C" void __rust_thunk___ZN23test_namespace_bindings1SC1Ev(
     struct test_namespace_bindings::S* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=11
 extern "C" void __rust_thunk___ZN23test_namespace_bindings1SC1EOS0_(
     struct test_namespace_bindings::S* __this,
     struct test_namespace_bindings::S* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=11
 extern "C" struct test_namespace_bindings::S*
 __rust_thunk___ZN23test_namespace_bindings1SaSERKS0_(
     struct test_namespace_bindings::S* __this,
     const struct test_namespace_bindings::S* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=11
 extern "C" struct test_namespace_bindings::S*
 __rust_thunk___ZN23test_namespace_bindings1SaSEOS0_(
     struct test_namespace_bindings::S* __this,
     struct test_namespace_bindings::S* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=19
 extern "C" void
 __rust_thunk___ZN23test_namespace_bindings15inline_functionEv() {
   test_namespace_bindings::inline_function();
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=31
 extern "C" void __rust_thunk___ZN32test_namespace_bindings_reopened5inner1SC1Ev(
     struct test_namespace_bindings_reopened::inner::S* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=31
 extern "C" void
 __rust_thunk___ZN32test_namespace_bindings_reopened5inner1SC1EOS1_(
     struct test_namespace_bindings_reopened::inner::S* __this,
     struct test_namespace_bindings_reopened::inner::S* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=31
 extern "C" struct test_namespace_bindings_reopened::inner::S*
 __rust_thunk___ZN32test_namespace_bindings_reopened5inner1SaSERKS1_(
     struct test_namespace_bindings_reopened::inner::S* __this,
     const struct test_namespace_bindings_reopened::inner::S* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=31
 extern "C" struct test_namespace_bindings_reopened::inner::S*
 __rust_thunk___ZN32test_namespace_bindings_reopened5inner1SaSEOS1_(
     struct test_namespace_bindings_reopened::inner::S* __this,
     struct test_namespace_bindings_reopened::inner::S* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=44
 extern "C" void
 __rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespaceC1Ev(
     struct test_namespace_bindings_inline::inner::StructInInlineNamespace*
         __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=44
 extern "C" void
 __rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespaceC1EOS1_(
     struct test_namespace_bindings_inline::inner::StructInInlineNamespace*
@@ -77,6 +99,8 @@ __rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespace
         __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=44
 extern "C" struct test_namespace_bindings_inline::inner::StructInInlineNamespace*
 __rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespaceaSERKS1_(
     struct test_namespace_bindings_inline::inner::StructInInlineNamespace*
@@ -85,6 +109,8 @@ __rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespace
         __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=44
 extern "C" struct test_namespace_bindings_inline::inner::StructInInlineNamespace*
 __rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespaceaSEOS1_(
     struct test_namespace_bindings_inline::inner::StructInInlineNamespace*
@@ -93,6 +119,8 @@ __rust_thunk___ZN30test_namespace_bindings_inline5inner23StructInInlineNamespace
         __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/namespace.h;l=54
 extern "C" void __rust_thunk___ZN4impl3fooEv() { impl::foo(); }
 
 static_assert(sizeof(struct test_namespace_bindings::S) == 4);
@@ -191,6 +191,22 @@ forward_declare::unsafe_define!(
     forward_declare::symbol!("FieldInTailPadding_InnerStruct"),
     crate::FieldInTailPadding_InnerStruct
 );
+impl FieldInTailPadding_InnerStruct {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/no_unique_address.h;l=42
 impl ::ctor::CtorNew<()> for FieldInTailPadding_InnerStruct {
@@ -285,6 +301,22 @@ impl FieldInTailPadding {
         }
     }
 }
+impl FieldInTailPadding {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/no_unique_address.h;l=59
 impl<'b> ::ctor::CtorNew<&'b Self> for FieldInTailPadding {
@@ -31,6 +31,22 @@ pub struct Nontrivial {
     pub field: i32,
 }
 forward_declare::unsafe_define!(forward_declare::symbol!("Nontrivial"), crate::Nontrivial);
+impl Nontrivial {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=16
 impl ::ctor::CtorNew<()> for Nontrivial {
@@ -264,6 +280,22 @@ forward_declare::unsafe_define!(
     forward_declare::symbol!("NontrivialInline"),
     crate::NontrivialInline
 );
+impl NontrivialInline {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=43
 impl ::ctor::CtorNew<()> for NontrivialInline {
@@ -430,15 +462,31 @@ impl NontrivialInline {
 /// each field.
 ///
 /// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=63
-#[::ctor::recursively_pinned(PinnedDrop)]
+#[::ctor::recursively_pinned]
 #[repr(C)]
 pub struct NontrivialMembers {
-    pub nontrivial_member: ::std::mem::ManuallyDrop<crate::Nontrivial>,
+    pub nontrivial_member: crate::Nontrivial,
 }
 forward_declare::unsafe_define!(
     forward_declare::symbol!("NontrivialMembers"),
     crate::NontrivialMembers
 );
+impl NontrivialMembers {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=63
 impl ::ctor::CtorNew<()> for NontrivialMembers {
@@ -506,14 +554,6 @@ impl<'b> ::ctor::CtorNew<(::ctor::RvalueReference<'b, Self>,)> for NontrivialMem
     }
 }
 
-/// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=63
-impl ::ctor::PinnedDrop for NontrivialMembers {
-    #[inline(always)]
-    unsafe fn pinned_drop<'a>(self: ::std::pin::Pin<&'a mut Self>) {
-        crate::detail::__rust_thunk___ZN17NontrivialMembersD1Ev(self)
-    }
-}
-
 /// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=63
 impl<'b> ::ctor::Assign<&'b Self> for NontrivialMembers {
     #[inline(always)]
@@ -574,9 +614,18 @@ impl From<i32> for NontrivialUnpin {
     }
 }
 
-// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=71
-// Error while generating bindings for item 'NontrivialUnpin::NontrivialUnpin':
-// More than 1 constructor parameter is not supported yet
+/// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=71
+impl From<(i32, i32)> for NontrivialUnpin {
+    #[inline(always)]
+    fn from(args: (i32, i32)) -> Self {
+        let (field, unused) = args;
+        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+        unsafe {
+            crate::detail::__rust_thunk___ZN15NontrivialUnpinC1Eii(&mut tmp, field, unused);
+            tmp.assume_init()
+        }
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=72
 impl Clone for NontrivialUnpin {
@@ -784,6 +833,22 @@ forward_declare::unsafe_define!(
     forward_declare::symbol!("NontrivialByValue"),
     crate::NontrivialByValue
 );
+impl NontrivialByValue {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=105
 impl<'b> ::ctor::CtorNew<&'b Self> for NontrivialByValue {
@@ -889,6 +954,22 @@ pub struct Nonmovable {
     __non_field_data: [::std::mem::MaybeUninit<u8>; 1],
 }
 forward_declare::unsafe_define!(forward_declare::symbol!("Nonmovable"), crate::Nonmovable);
+impl Nonmovable {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=115
 impl ::ctor::CtorNew<()> for Nonmovable {
@@ -1068,9 +1149,6 @@ mod detail {
             __this: &'a mut ::std::mem::MaybeUninit<crate::NontrivialMembers>,
             __param_0: ::ctor::RvalueReference<'b, crate::NontrivialMembers>,
         );
-        pub(crate) fn __rust_thunk___ZN17NontrivialMembersD1Ev<'a>(
-            __this: ::std::pin::Pin<&'a mut crate::NontrivialMembers>,
-        );
         pub(crate) fn __rust_thunk___ZN17NontrivialMembersaSERKS_<'a, 'b>(
             __this: ::std::pin::Pin<&'a mut crate::NontrivialMembers>,
             __param_0: &'b crate::NontrivialMembers,
@@ -1088,6 +1166,12 @@ mod detail {
             __this: &'a mut ::std::mem::MaybeUninit<crate::NontrivialUnpin>,
             field: i32,
         );
+        #[link_name = "_ZN15NontrivialUnpinC1Eii"]
+        pub(crate) fn __rust_thunk___ZN15NontrivialUnpinC1Eii<'a>(
+            __this: &'a mut ::std::mem::MaybeUninit<crate::NontrivialUnpin>,
+            field: i32,
+            unused: i32,
+        );
         #[link_name = "_ZN15NontrivialUnpinC1ERKS_"]
         pub(crate) fn __rust_thunk___ZN15NontrivialUnpinC1ERKS_<'a, 'b>(
             __this: &'a mut ::std::mem::MaybeUninit<crate::NontrivialUnpin>,
@@ -1243,7 +1327,7 @@ const _: () = {
     static_assertions::assert_not_impl_any!(crate::NontrivialMembers: Copy);
 };
 const _: () = {
-    static_assertions::assert_impl_all!(crate::NontrivialMembers: Drop);
+    static_assertions::assert_not_impl_any!(crate::NontrivialMembers: Drop);
 };
 const _: () = assert!(memoffset::offset_of!(crate::NontrivialMembers, nontrivial_member) == 0);
 
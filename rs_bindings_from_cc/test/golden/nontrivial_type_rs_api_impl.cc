@@ -16,108 +16,152 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=25
 extern "C" void __rust_thunk___ZN10NontrivialaSEf(struct Nontrivial* __return,
                                                   struct Nontrivial* __this,
                                                   float __param_0) {
   new (__return) auto(__this->operator=(__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=43
 extern "C" void __rust_thunk___ZN16NontrivialInlineC1Ev(
     struct NontrivialInline* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=44
 extern "C" void __rust_thunk___ZN16NontrivialInlineC1Ei(
     struct NontrivialInline* __this, int field) {
   crubit::construct_at(__this, field);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=45
 extern "C" void __rust_thunk___ZN16NontrivialInlineC1Eii(
     struct NontrivialInline* __this, int field, int unused) {
   crubit::construct_at(__this, field, unused);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=46
 extern "C" void __rust_thunk___ZN16NontrivialInlineC1ERKS_(
     struct NontrivialInline* __this, const struct NontrivialInline* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=47
 extern "C" void __rust_thunk___ZN16NontrivialInlineC1EOS_(
     struct NontrivialInline* __this, struct NontrivialInline* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=48
 extern "C" struct NontrivialInline* __rust_thunk___ZN16NontrivialInlineaSERKS_(
     struct NontrivialInline* __this, const struct NontrivialInline* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=49
 extern "C" struct NontrivialInline* __rust_thunk___ZN16NontrivialInlineaSEOS_(
     struct NontrivialInline* __this, struct NontrivialInline* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=50
 extern "C" struct NontrivialInline* __rust_thunk___ZN16NontrivialInlineaSEi(
     struct NontrivialInline* __this, int __param_0) {
   return &__this->operator=(__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=51
 extern "C" void __rust_thunk___ZN16NontrivialInlineD1Ev(
     struct NontrivialInline* __this) {
   std::destroy_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=53
 extern "C" void __rust_thunk___ZN16NontrivialInline14MemberFunctionEv(
     struct NontrivialInline* __this) {
   __this->MemberFunction();
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=63
 extern "C" void __rust_thunk___ZN17NontrivialMembersC1Ev(
     struct NontrivialMembers* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=63
 extern "C" void __rust_thunk___ZN17NontrivialMembersC1ERKS_(
     struct NontrivialMembers* __this,
     const struct NontrivialMembers* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=63
 extern "C" void __rust_thunk___ZN17NontrivialMembersC1EOS_(
     struct NontrivialMembers* __this, struct NontrivialMembers* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
-extern "C" void __rust_thunk___ZN17NontrivialMembersD1Ev(
-    struct NontrivialMembers* __this) {
-  std::destroy_at(__this);
-}
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=63
 extern "C" struct NontrivialMembers*
 __rust_thunk___ZN17NontrivialMembersaSERKS_(
     struct NontrivialMembers* __this,
     const struct NontrivialMembers* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=63
 extern "C" struct NontrivialMembers* __rust_thunk___ZN17NontrivialMembersaSEOS_(
     struct NontrivialMembers* __this, struct NontrivialMembers* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=85
 extern "C" void __rust_thunk___Z12TakesByValue10Nontrivial(
     struct Nontrivial* __return, struct Nontrivial* nontrivial) {
   new (__return) auto(TakesByValue(std::move(*nontrivial)));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=86
 extern "C" void __rust_thunk___Z18TakesByValueInline16NontrivialInline(
     struct NontrivialInline* __return, struct NontrivialInline* nontrivial) {
   new (__return) auto(TakesByValueInline(std::move(*nontrivial)));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=105
 extern "C" void __rust_thunk___ZN17NontrivialByValueC1ERKS_(
     struct NontrivialByValue* __this, const struct NontrivialByValue* other) {
   crubit::construct_at(__this, *other);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=106
 extern "C" void __rust_thunk___ZN17NontrivialByValueC1EOS_(
     struct NontrivialByValue* __this, struct NontrivialByValue* other) {
   crubit::construct_at(__this, std::move(*other));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=107
 extern "C" struct NontrivialByValue*
 __rust_thunk___ZN17NontrivialByValueaSERKS_(
     struct NontrivialByValue* __this, const struct NontrivialByValue* other) {
   return &__this->operator=(*other);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=108
 extern "C" struct NontrivialByValue* __rust_thunk___ZN17NontrivialByValueaSEOS_(
     struct NontrivialByValue* __this, struct NontrivialByValue* other) {
   return &__this->operator=(std::move(*other));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=110
 extern "C" void __rust_thunk___ZN17NontrivialByValueaSE10Nontrivial(
     struct NontrivialByValue* __return, struct NontrivialByValue* __this,
     struct Nontrivial* other) {
   new (__return) auto(__this->operator=(std::move(*other)));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/nontrivial_type.h;l=124
 extern "C" void __rust_thunk___Z24ReturnsNonmovableByValuev(
     struct Nonmovable* __return) {
   new (__return) auto(ReturnsNonmovableByValue());
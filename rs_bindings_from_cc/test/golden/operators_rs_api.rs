@@ -513,6 +513,22 @@ forward_declare::unsafe_define!(
     forward_declare::symbol!("AddableConstMemberNonunpin"),
     crate::AddableConstMemberNonunpin
 );
+impl AddableConstMemberNonunpin {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/operators.h;l=57
 impl ::ctor::CtorNew<()> for AddableConstMemberNonunpin {
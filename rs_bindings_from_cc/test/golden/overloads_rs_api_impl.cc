@@ -15,6 +15,8 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/overloads.h;l=31
 extern "C" void __rust_thunk___Z20AlsoTemplateOverloadv() {
   AlsoTemplateOverload();
 }
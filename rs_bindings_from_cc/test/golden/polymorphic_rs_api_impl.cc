@@ -16,65 +16,95 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/polymorphic.h;l=10
 extern "C" void __rust_thunk___ZN15PolymorphicBaseC1Ev(
     class PolymorphicBase* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/polymorphic.h;l=10
 extern "C" void __rust_thunk___ZN15PolymorphicBaseC1ERKS_(
     class PolymorphicBase* __this, const class PolymorphicBase* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/polymorphic.h;l=10
 extern "C" class PolymorphicBase* __rust_thunk___ZN15PolymorphicBaseaSERKS_(
     class PolymorphicBase* __this, const class PolymorphicBase* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/polymorphic.h;l=12
 extern "C" void __rust_thunk___ZN15PolymorphicBaseD1Ev(
     class PolymorphicBase* __this) {
   std::destroy_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/polymorphic.h;l=14
 extern "C" void __rust_thunk___ZN16PolymorphicBase2C1Ev(
     class PolymorphicBase2* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/polymorphic.h;l=14
 extern "C" void __rust_thunk___ZN16PolymorphicBase2C1ERKS_(
     class PolymorphicBase2* __this, const class PolymorphicBase2* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/polymorphic.h;l=14
 extern "C" class PolymorphicBase2* __rust_thunk___ZN16PolymorphicBase2aSERKS_(
     class PolymorphicBase2* __this, const class PolymorphicBase2* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/polymorphic.h;l=16
 extern "C" void __rust_thunk___ZN16PolymorphicBase23FooEv(
     class PolymorphicBase2* __this) {
   __this->Foo();
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/polymorphic.h;l=17
 extern "C" void __rust_thunk___ZN16PolymorphicBase2D1Ev(
     class PolymorphicBase2* __this) {
   std::destroy_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/polymorphic.h;l=20
 extern "C" void __rust_thunk___ZN18PolymorphicDerivedC1Ev(
     class PolymorphicDerived* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/polymorphic.h;l=20
 extern "C" void __rust_thunk___ZN18PolymorphicDerivedC1ERKS_(
     class PolymorphicDerived* __this,
     const class PolymorphicDerived* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/polymorphic.h;l=20
 extern "C" void __rust_thunk___ZN18PolymorphicDerivedC1EOS_(
     class PolymorphicDerived* __this, class PolymorphicDerived* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/polymorphic.h;l=20
 extern "C" void __rust_thunk___ZN18PolymorphicDerivedD1Ev(
     class PolymorphicDerived* __this) {
   std::destroy_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/polymorphic.h;l=20
 extern "C" class PolymorphicDerived*
 __rust_thunk___ZN18PolymorphicDerivedaSERKS_(
     class PolymorphicDerived* __this,
     const class PolymorphicDerived* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/polymorphic.h;l=20
 extern "C" class PolymorphicDerived*
 __rust_thunk___ZN18PolymorphicDerivedaSEOS_(
     class PolymorphicDerived* __this, class PolymorphicDerived* __param_0) {
@@ -16,21 +16,29 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/private_members.h;l=11
 extern "C" void __rust_thunk___ZN23test_namespace_bindings9SomeClassC1Ev(
     class test_namespace_bindings::SomeClass* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/private_members.h;l=11
 extern "C" void __rust_thunk___ZN23test_namespace_bindings9SomeClassC1EOS0_(
     class test_namespace_bindings::SomeClass* __this,
     class test_namespace_bindings::SomeClass* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/private_members.h;l=11
 extern "C" class test_namespace_bindings::SomeClass*
 __rust_thunk___ZN23test_namespace_bindings9SomeClassaSERKS0_(
     class test_namespace_bindings::SomeClass* __this,
     const class test_namespace_bindings::SomeClass* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/private_members.h;l=11
 extern "C" class test_namespace_bindings::SomeClass*
 __rust_thunk___ZN23test_namespace_bindings9SomeClassaSEOS0_(
     class test_namespace_bindings::SomeClass* __this,
@@ -16,17 +16,25 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/static_methods.h;l=10
 extern "C" void __rust_thunk___ZN9SomeClassC1Ev(class SomeClass* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/static_methods.h;l=10
 extern "C" void __rust_thunk___ZN9SomeClassC1EOS_(class SomeClass* __this,
                                                   class SomeClass* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/static_methods.h;l=10
 extern "C" class SomeClass* __rust_thunk___ZN9SomeClassaSERKS_(
     class SomeClass* __this, const class SomeClass* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/static_methods.h;l=10
 extern "C" class SomeClass* __rust_thunk___ZN9SomeClassaSEOS_(
     class SomeClass* __this, class SomeClass* __param_0) {
   return &__this->operator=(std::move(*__param_0));
@@ -75,11 +75,12 @@ pub mod test_namespace_bindings {
     // Class templates are not supported yet
 
     /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=29
-    pub type MyTypeAlias = crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE;
+    pub type MyTypeAlias =
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE;
 
     /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=30
     pub type OtherTypeAliasInSameTarget =
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE;
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE;
 
     /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=32
     #[derive(Clone, Copy)]
@@ -146,11 +147,11 @@ pub mod test_namespace_bindings {
 
     /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=33
     pub type TemplateWithStructTemplateParam =
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE;
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE;
 
     /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=34
     pub type ParamFromDifferentScope =
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE;
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE;
 
     // Generated from: rs_bindings_from_cc/test/golden/templates.h;l=36
     // Error while generating bindings for item 'test_namespace_bindings::TemplateWithTwoParams':
@@ -158,11 +159,11 @@ pub mod test_namespace_bindings {
 
     /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=42
     pub type AliasToTemplateWithTwoParams =
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE;
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE;
 
     /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=44
     pub type AliasToTemplateOfATemplate =
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE;
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE;
 
     // Generated from: rs_bindings_from_cc/test/golden/templates.h;l=47
     // Error while generating bindings for item 'test_namespace_bindings::MyStruct':
@@ -175,7 +176,8 @@ pub mod test_namespace_bindings {
     // even when not instantiated if there is a type alias for it.
 
     /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=61
-    pub type MyCharStruct = crate::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE;
+    pub type MyCharStruct =
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE;
 
     // Forward declared explicit class template specialization should be imported
     // so the forward declaration code is generated (`forward_declare!`).
@@ -196,12 +198,14 @@ pub mod test_namespace_bindings {
 
 /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=84
 pub type TopLevelTemplateWithNonTopLevelParam =
-    crate::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE;
+    crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE;
 
 /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=90
 #[inline(always)]
 pub fn processForwardDeclaredSpecialization<'a>(
-    i: Option<::std::pin::Pin<&'a mut crate::__CcTemplateInst18MyTopLevelTemplateIiE>>,
+    i: Option<
+        ::std::pin::Pin<&'a mut crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIiE>,
+    >,
 ) {
     unsafe {
         crate::detail::__rust_thunk___Z36processForwardDeclaredSpecializationP18MyTopLevelTemplateIiE(i)
@@ -219,7 +223,7 @@ pub mod template_template_params {
 
     /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=110
     pub type MyTypeAlias =
-        crate::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE;
+        crate::__instantiations::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE;
 }
 
 // namespace template_template_params
@@ -231,7 +235,7 @@ pub mod forward_declared_template {
 
     /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=125
     pub type TypeAliasToForwardDeclaredTemplate =
-        crate::__CcTemplateInstN25forward_declared_template23ForwardDeclaredTemplateIiEE;
+        crate::__instantiations::__CcTemplateInstN25forward_declared_template23ForwardDeclaredTemplateIiEE;
 }
 
 // namespace forward_declared_template
@@ -247,6 +251,22 @@ pub mod private_classes {
         forward_declare::symbol!("HasPrivateType"),
         crate::private_classes::HasPrivateType
     );
+    impl HasPrivateType {
+        #[inline(always)]
+        pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+        where
+            Self: ::ctor::CtorNew<Args>,
+        {
+            <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+        }
+        #[inline(always)]
+        pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+        where
+            Self: ::ctor::CtorNew<Args>,
+        {
+            <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+        }
+    }
 
     /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=131
     impl<'b> ::ctor::CtorNew<&'b Self> for HasPrivateType {
@@ -334,733 +354,783 @@ pub mod private_classes {
 
 // THIRD_PARTY_CRUBIT_RS_BINDINGS_FROM_CC_TEST_GOLDEN_TEMPLATES_H_
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
-#[derive(Clone, Copy)]
-#[repr(C)]
-pub struct __CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE {
-    __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
-    /// Reason for representing this field as a blob of bytes:
-    /// Types of non-public C++ fields can be elided away
-    pub(crate) value_: [::std::mem::MaybeUninit<u8>; 1],
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("test_namespace_bindings::MyTemplate<DifferentScope>"),
-    crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE
-);
+/// Class template instantiations used by this crate.
+pub mod __instantiations {
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub struct __CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE {
+        __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
+        /// Reason for representing this field as a blob of bytes:
+        /// Types of non-public C++ fields can be elided away
+        pub(crate) value_: [::std::mem::MaybeUninit<u8>; 1],
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("test_namespace_bindings::MyTemplate<DifferentScope>"),
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE
+    );
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
-impl Default for __CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE {
-    #[inline(always)]
-    fn default() -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateI14DifferentScopeEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
+    impl Default for __CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE {
+        #[inline(always)]
+        fn default() -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateI14DifferentScopeEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp);
+                tmp.assume_init()
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
-impl<'b> From<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE
-{
-    #[inline(always)]
-    fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateI14DifferentScopeEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp,__param_0);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
+    impl<'b> From<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE
+    {
+        #[inline(always)]
+        fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateI14DifferentScopeEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp,__param_0);
+                tmp.assume_init()
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
-impl<'b> ::ctor::UnpinAssign<&'b Self>
-    for __CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE
-{
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateI14DifferentScopeEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
+    impl<'b> ::ctor::UnpinAssign<&'b Self>
+        for __CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE
+    {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateI14DifferentScopeEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
-impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE
-{
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateI14DifferentScopeEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
+    impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE
+    {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateI14DifferentScopeEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-impl __CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE {
-    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=17
-    #[inline(always)]
-    pub fn Create(
-        value: crate::DifferentScope,
-    ) -> crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE {
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateI14DifferentScopeE6CreateES1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(value)
+    impl __CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE {
+        /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=17
+        #[inline(always)]
+        pub fn Create(
+            value: crate::DifferentScope,
+        ) -> crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE{
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateI14DifferentScopeE6CreateES1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(value)
+            }
         }
     }
-}
 
-impl __CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE {
-    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=23
-    #[inline(always)]
-    pub fn value<'a>(&'a self) -> &'a crate::DifferentScope {
-        unsafe {
-            crate::detail::__rust_thunk___ZNK23test_namespace_bindings10MyTemplateI14DifferentScopeE5valueEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self)
+    impl __CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE {
+        /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=23
+        #[inline(always)]
+        pub fn value<'a>(&'a self) -> &'a crate::DifferentScope {
+            unsafe {
+                crate::detail::__rust_thunk___ZNK23test_namespace_bindings10MyTemplateI14DifferentScopeE5valueEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self)
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
-#[derive(Clone, Copy)]
-#[repr(C)]
-pub struct __CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE {
-    __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
-    /// Reason for representing this field as a blob of bytes:
-    /// Types of non-public C++ fields can be elided away
-    pub(crate) value_: [::std::mem::MaybeUninit<u8>; 1],
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!(
-        "test_namespace_bindings::MyTemplate<test_namespace_bindings::TemplateParam>"
-    ),
-    crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE
-);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub struct __CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE {
+        __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
+        /// Reason for representing this field as a blob of bytes:
+        /// Types of non-public C++ fields can be elided away
+        pub(crate) value_: [::std::mem::MaybeUninit<u8>; 1],
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!(
+            "test_namespace_bindings::MyTemplate<test_namespace_bindings::TemplateParam>"
+        ),
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE
+    );
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
-impl Default for __CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE {
-    #[inline(always)]
-    fn default() -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
+    impl Default for __CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE {
+        #[inline(always)]
+        fn default() -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp);
+                tmp.assume_init()
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
-impl<'b> From<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE
-{
-    #[inline(always)]
-    fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp,__param_0);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
+    impl<'b> From<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE
+    {
+        #[inline(always)]
+        fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp,__param_0);
+                tmp.assume_init()
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
-impl<'b> ::ctor::UnpinAssign<&'b Self>
-    for __CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE
-{
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
+    impl<'b> ::ctor::UnpinAssign<&'b Self>
+        for __CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE
+    {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
-impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE
-{
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
+    impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE
+    {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-impl __CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE {
-    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=17
-    #[inline(always)]
-    pub fn Create(
-        value: crate::test_namespace_bindings::TemplateParam,
-    ) -> crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE {
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEE6CreateES1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(value)
+    impl __CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE {
+        /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=17
+        #[inline(always)]
+        pub fn Create(
+            value: crate::test_namespace_bindings::TemplateParam,
+        ) -> crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE{
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEE6CreateES1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(value)
+            }
         }
     }
-}
 
-impl __CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE {
-    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=23
-    #[inline(always)]
-    pub fn value<'a>(&'a self) -> &'a crate::test_namespace_bindings::TemplateParam {
-        unsafe {
-            crate::detail::__rust_thunk___ZNK23test_namespace_bindings10MyTemplateINS_13TemplateParamEE5valueEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self)
+    impl __CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE {
+        /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=23
+        #[inline(always)]
+        pub fn value<'a>(&'a self) -> &'a crate::test_namespace_bindings::TemplateParam {
+            unsafe {
+                crate::detail::__rust_thunk___ZNK23test_namespace_bindings10MyTemplateINS_13TemplateParamEE5valueEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self)
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
-#[derive(Clone, Copy)]
-#[repr(C, align(4))]
-pub struct __CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE {
-    __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
-    /// Reason for representing this field as a blob of bytes:
-    /// Types of non-public C++ fields can be elided away
-    pub(crate) value_: [::std::mem::MaybeUninit<u8>; 4],
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("test_namespace_bindings::MyTemplate<int>"),
-    crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE
-);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
+    #[derive(Clone, Copy)]
+    #[repr(C, align(4))]
+    pub struct __CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE {
+        __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
+        /// Reason for representing this field as a blob of bytes:
+        /// Types of non-public C++ fields can be elided away
+        pub(crate) value_: [::std::mem::MaybeUninit<u8>; 4],
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("test_namespace_bindings::MyTemplate<int>"),
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE
+    );
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
-impl Default for __CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE {
-    #[inline(always)]
-    fn default() -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateIiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
+    impl Default for __CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE {
+        #[inline(always)]
+        fn default() -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateIiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp);
+                tmp.assume_init()
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
-impl<'b> From<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE
-{
-    #[inline(always)]
-    fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateIiEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp,__param_0);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
+    impl<'b> From<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE
+    {
+        #[inline(always)]
+        fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateIiEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp,__param_0);
+                tmp.assume_init()
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
-impl<'b> ::ctor::UnpinAssign<&'b Self>
-    for __CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE
-{
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateIiEaSERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
+    impl<'b> ::ctor::UnpinAssign<&'b Self>
+        for __CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE
+    {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateIiEaSERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
-impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE
-{
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateIiEaSEOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
+    impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE
+    {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateIiEaSEOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-impl __CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE {
-    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=17
-    #[inline(always)]
-    pub fn Create(value: i32) -> crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE {
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateIiE6CreateEi__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(value)
+    impl __CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE {
+        /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=17
+        #[inline(always)]
+        pub fn Create(
+            value: i32,
+        ) -> crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE
+        {
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings10MyTemplateIiE6CreateEi__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(value)
+            }
         }
     }
-}
 
-impl __CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE {
-    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=23
-    #[inline(always)]
-    pub fn value<'a>(&'a self) -> &'a i32 {
-        unsafe {
-            crate::detail::__rust_thunk___ZNK23test_namespace_bindings10MyTemplateIiE5valueEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self)
+    impl __CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE {
+        /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=23
+        #[inline(always)]
+        pub fn value<'a>(&'a self) -> &'a i32 {
+            unsafe {
+                crate::detail::__rust_thunk___ZNK23test_namespace_bindings10MyTemplateIiE5valueEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self)
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
-#[derive(Clone, Copy)]
-#[repr(C)]
-pub struct __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE {
-    pub value1: crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
-    pub value2: i32,
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!(
-        "test_namespace_bindings::TemplateWithTwoParams<test_namespace_bindings::TemplateWithTwoParams<int, int>, int>"
-    ),
-    crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE
-);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub struct __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE {
+        pub value1: crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
+        pub value2: i32,
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!(
+            "test_namespace_bindings::TemplateWithTwoParams<test_namespace_bindings::TemplateWithTwoParams<int, int>, int>"
+        ),
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE
+    );
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
-impl Default for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE {
-    #[inline(always)]
-    fn default() -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
+    impl Default for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE {
+        #[inline(always)]
+        fn default() -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp);
+                tmp.assume_init()
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
-impl<'b> From<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE
-{
-    #[inline(always)]
-    fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp,__param_0);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
+    impl<'b> From<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE
+    {
+        #[inline(always)]
+        fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp,__param_0);
+                tmp.assume_init()
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
-impl<'b> ::ctor::UnpinAssign<&'b Self>
-    for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE
-{
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
+    impl<'b> ::ctor::UnpinAssign<&'b Self>
+        for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE
+    {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
-impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE
-{
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
+    impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE
+    {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
-#[derive(Clone, Copy)]
-#[repr(C)]
-pub struct __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE {
-    pub value1: i32,
-    pub value2: f32,
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("test_namespace_bindings::TemplateWithTwoParams<int, float>"),
-    crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE
-);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub struct __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE {
+        pub value1: i32,
+        pub value2: f32,
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("test_namespace_bindings::TemplateWithTwoParams<int, float>"),
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE
+    );
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
-impl Default for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE {
-    #[inline(always)]
-    fn default() -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIifEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
+    impl Default for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE {
+        #[inline(always)]
+        fn default() -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIifEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp);
+                tmp.assume_init()
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
-impl<'b> From<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE
-{
-    #[inline(always)]
-    fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIifEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp,__param_0);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
+    impl<'b> From<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE
+    {
+        #[inline(always)]
+        fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIifEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp,__param_0);
+                tmp.assume_init()
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
-impl<'b> ::ctor::UnpinAssign<&'b Self>
-    for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE
-{
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIifEaSERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
+    impl<'b> ::ctor::UnpinAssign<&'b Self>
+        for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE
+    {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIifEaSERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
-impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE
-{
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIifEaSEOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
+    impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE
+    {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIifEaSEOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
-#[derive(Clone, Copy)]
-#[repr(C)]
-pub struct __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE {
-    pub value1: i32,
-    pub value2: i32,
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("test_namespace_bindings::TemplateWithTwoParams<int, int>"),
-    crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE
-);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub struct __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE {
+        pub value1: i32,
+        pub value2: i32,
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("test_namespace_bindings::TemplateWithTwoParams<int, int>"),
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE
+    );
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
-impl Default for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE {
-    #[inline(always)]
-    fn default() -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIiiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
+    impl Default for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE {
+        #[inline(always)]
+        fn default() -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIiiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp);
+                tmp.assume_init()
+            }
+        }
+    }
+
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
+    impl<'b> From<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE
+    {
+        #[inline(always)]
+        fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIiiEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp,__param_0);
+                tmp.assume_init()
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
-impl<'b> From<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE
-{
-    #[inline(always)]
-    fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIiiEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp,__param_0);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
+    impl<'b> ::ctor::UnpinAssign<&'b Self>
+        for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE
+    {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIiiEaSERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
-impl<'b> ::ctor::UnpinAssign<&'b Self>
-    for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE
-{
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIiiEaSERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
+    impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE
+    {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIiiEaSEOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
-impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE
-{
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIiiEaSEOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Explicit class template specialization with definition should be imported
+    /// even when not instantiated if there is a type alias for it.
+    ///
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=60
+    #[::ctor::recursively_pinned]
+    #[repr(C)]
+    pub struct __CcTemplateInstN23test_namespace_bindings8MyStructIcEE {
+        __non_field_data: [::std::mem::MaybeUninit<u8>; 1],
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("test_namespace_bindings::MyStruct<char>"),
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE
+    );
+    impl __CcTemplateInstN23test_namespace_bindings8MyStructIcEE {
+        #[inline(always)]
+        pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+        where
+            Self: ::ctor::CtorNew<Args>,
+        {
+            <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+        }
+        #[inline(always)]
+        pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+        where
+            Self: ::ctor::CtorNew<Args>,
+        {
+            <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
         }
     }
-}
-
-/// Explicit class template specialization with definition should be imported
-/// even when not instantiated if there is a type alias for it.
-///
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=60
-#[::ctor::recursively_pinned]
-#[repr(C)]
-pub struct __CcTemplateInstN23test_namespace_bindings8MyStructIcEE {
-    __non_field_data: [::std::mem::MaybeUninit<u8>; 1],
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("test_namespace_bindings::MyStruct<char>"),
-    crate::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE
-);
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=60
-impl ::ctor::CtorNew<()> for __CcTemplateInstN23test_namespace_bindings8MyStructIcEE {
-    type CtorType = impl ::ctor::Ctor<Output = Self>;
-    #[inline(always)]
-    fn ctor_new(args: ()) -> Self::CtorType {
-        let () = args;
-        unsafe {
-            ::ctor::FnCtor::new(move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
-                crate::detail::__rust_thunk___ZN23test_namespace_bindings8MyStructIcEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(::std::pin::Pin::into_inner_unchecked(dest));
-            })
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=60
+    impl ::ctor::CtorNew<()> for __CcTemplateInstN23test_namespace_bindings8MyStructIcEE {
+        type CtorType = impl ::ctor::Ctor<Output = Self>;
+        #[inline(always)]
+        fn ctor_new(args: ()) -> Self::CtorType {
+            let () = args;
+            unsafe {
+                ::ctor::FnCtor::new(
+                    move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
+                        crate::detail::__rust_thunk___ZN23test_namespace_bindings8MyStructIcEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(::std::pin::Pin::into_inner_unchecked(dest));
+                    },
+                )
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=60
-impl<'b> ::ctor::CtorNew<&'b Self> for __CcTemplateInstN23test_namespace_bindings8MyStructIcEE {
-    type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
-    #[inline(always)]
-    fn ctor_new(args: &'b Self) -> Self::CtorType {
-        let __param_0 = args;
-        unsafe {
-            ::ctor::FnCtor::new(move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
-                crate::detail::__rust_thunk___ZN23test_namespace_bindings8MyStructIcEC1ERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(::std::pin::Pin::into_inner_unchecked(dest),__param_0);
-            })
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=60
+    impl<'b> ::ctor::CtorNew<&'b Self> for __CcTemplateInstN23test_namespace_bindings8MyStructIcEE {
+        type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
+        #[inline(always)]
+        fn ctor_new(args: &'b Self) -> Self::CtorType {
+            let __param_0 = args;
+            unsafe {
+                ::ctor::FnCtor::new(
+                    move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
+                        crate::detail::__rust_thunk___ZN23test_namespace_bindings8MyStructIcEC1ERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(::std::pin::Pin::into_inner_unchecked(dest),__param_0);
+                    },
+                )
+            }
         }
     }
-}
-impl<'b> ::ctor::CtorNew<(&'b Self,)> for __CcTemplateInstN23test_namespace_bindings8MyStructIcEE {
-    type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
-    #[inline(always)]
-    fn ctor_new(args: (&'b Self,)) -> Self::CtorType {
-        let (arg,) = args;
-        <Self as ::ctor::CtorNew<&'b Self>>::ctor_new(arg)
+    impl<'b> ::ctor::CtorNew<(&'b Self,)> for __CcTemplateInstN23test_namespace_bindings8MyStructIcEE {
+        type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
+        #[inline(always)]
+        fn ctor_new(args: (&'b Self,)) -> Self::CtorType {
+            let (arg,) = args;
+            <Self as ::ctor::CtorNew<&'b Self>>::ctor_new(arg)
+        }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=60
-impl<'b> ::ctor::CtorNew<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN23test_namespace_bindings8MyStructIcEE
-{
-    type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
-    #[inline(always)]
-    fn ctor_new(args: ::ctor::RvalueReference<'b, Self>) -> Self::CtorType {
-        let __param_0 = args;
-        unsafe {
-            ::ctor::FnCtor::new(move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
-                crate::detail::__rust_thunk___ZN23test_namespace_bindings8MyStructIcEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(::std::pin::Pin::into_inner_unchecked(dest),__param_0);
-            })
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=60
+    impl<'b> ::ctor::CtorNew<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN23test_namespace_bindings8MyStructIcEE
+    {
+        type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
+        #[inline(always)]
+        fn ctor_new(args: ::ctor::RvalueReference<'b, Self>) -> Self::CtorType {
+            let __param_0 = args;
+            unsafe {
+                ::ctor::FnCtor::new(
+                    move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
+                        crate::detail::__rust_thunk___ZN23test_namespace_bindings8MyStructIcEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(::std::pin::Pin::into_inner_unchecked(dest),__param_0);
+                    },
+                )
+            }
         }
     }
-}
-impl<'b> ::ctor::CtorNew<(::ctor::RvalueReference<'b, Self>,)>
-    for __CcTemplateInstN23test_namespace_bindings8MyStructIcEE
-{
-    type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
-    #[inline(always)]
-    fn ctor_new(args: (::ctor::RvalueReference<'b, Self>,)) -> Self::CtorType {
-        let (arg,) = args;
-        <Self as ::ctor::CtorNew<::ctor::RvalueReference<'b, Self>>>::ctor_new(arg)
+    impl<'b> ::ctor::CtorNew<(::ctor::RvalueReference<'b, Self>,)>
+        for __CcTemplateInstN23test_namespace_bindings8MyStructIcEE
+    {
+        type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
+        #[inline(always)]
+        fn ctor_new(args: (::ctor::RvalueReference<'b, Self>,)) -> Self::CtorType {
+            let (arg,) = args;
+            <Self as ::ctor::CtorNew<::ctor::RvalueReference<'b, Self>>>::ctor_new(arg)
+        }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=60
-impl<'b> ::ctor::Assign<&'b Self> for __CcTemplateInstN23test_namespace_bindings8MyStructIcEE {
-    #[inline(always)]
-    fn assign<'a>(self: ::std::pin::Pin<&'a mut Self>, __param_0: &'b Self) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings8MyStructIcEaSERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=60
+    impl<'b> ::ctor::Assign<&'b Self> for __CcTemplateInstN23test_namespace_bindings8MyStructIcEE {
+        #[inline(always)]
+        fn assign<'a>(self: ::std::pin::Pin<&'a mut Self>, __param_0: &'b Self) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings8MyStructIcEaSERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=60
-impl<'b> ::ctor::Assign<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN23test_namespace_bindings8MyStructIcEE
-{
-    #[inline(always)]
-    fn assign<'a>(
-        self: ::std::pin::Pin<&'a mut Self>,
-        __param_0: ::ctor::RvalueReference<'b, Self>,
-    ) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN23test_namespace_bindings8MyStructIcEaSEOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=60
+    impl<'b> ::ctor::Assign<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN23test_namespace_bindings8MyStructIcEE
+    {
+        #[inline(always)]
+        fn assign<'a>(
+            self: ::std::pin::Pin<&'a mut Self>,
+            __param_0: ::ctor::RvalueReference<'b, Self>,
+        ) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN23test_namespace_bindings8MyStructIcEaSEOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=80
-#[derive(Clone, Copy)]
-#[repr(C)]
-pub struct __CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE {
-    pub value: crate::test_namespace_bindings::TemplateParam,
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("MyTopLevelTemplate<test_namespace_bindings::TemplateParam>"),
-    crate::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE
-);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=80
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub struct __CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE {
+        pub value: crate::test_namespace_bindings::TemplateParam,
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("MyTopLevelTemplate<test_namespace_bindings::TemplateParam>"),
+        crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE
+    );
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=80
-impl Default for __CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE {
-    #[inline(always)]
-    fn default() -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=80
+    impl Default for __CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE {
+        #[inline(always)]
+        fn default() -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp);
+                tmp.assume_init()
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=80
-impl<'b> From<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE
-{
-    #[inline(always)]
-    fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
-        let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
-        unsafe {
-            crate::detail::__rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp,__param_0);
-            tmp.assume_init()
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=80
+    impl<'b> From<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE
+    {
+        #[inline(always)]
+        fn from(__param_0: ::ctor::RvalueReference<'b, Self>) -> Self {
+            let mut tmp = ::std::mem::MaybeUninit::<Self>::zeroed();
+            unsafe {
+                crate::detail::__rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(&mut tmp,__param_0);
+                tmp.assume_init()
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=80
-impl<'b> ::ctor::UnpinAssign<&'b Self>
-    for __CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE
-{
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=80
+    impl<'b> ::ctor::UnpinAssign<&'b Self>
+        for __CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE
+    {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: &'b Self) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=80
-impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE
-{
-    #[inline(always)]
-    fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=80
+    impl<'b> ::ctor::UnpinAssign<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE
+    {
+        #[inline(always)]
+        fn unpin_assign<'a>(&'a mut self, __param_0: ::ctor::RvalueReference<'b, Self>) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-forward_declare::forward_declare!(pub __CcTemplateInst18MyTopLevelTemplateIiE = forward_declare::symbol!("__CcTemplateInst18MyTopLevelTemplateIiE"));
+    forward_declare::forward_declare!(pub __CcTemplateInst18MyTopLevelTemplateIiE = forward_declare::symbol!("__CcTemplateInst18MyTopLevelTemplateIiE"));
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=105
-#[::ctor::recursively_pinned]
-#[repr(C)]
-pub struct __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE {
-    __non_field_data: [::std::mem::MaybeUninit<u8>; 1],
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!(
-        "template_template_params::MyTemplate<template_template_params::Policy>"
-    ),
-    crate::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE
-);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=105
+    #[::ctor::recursively_pinned]
+    #[repr(C)]
+    pub struct __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE {
+        __non_field_data: [::std::mem::MaybeUninit<u8>; 1],
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!(
+            "template_template_params::MyTemplate<template_template_params::Policy>"
+        ),
+        crate::__instantiations::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE
+    );
+    impl __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE {
+        #[inline(always)]
+        pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+        where
+            Self: ::ctor::CtorNew<Args>,
+        {
+            <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+        }
+        #[inline(always)]
+        pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+        where
+            Self: ::ctor::CtorNew<Args>,
+        {
+            <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+        }
+    }
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=105
-impl ::ctor::CtorNew<()> for __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE {
-    type CtorType = impl ::ctor::Ctor<Output = Self>;
-    #[inline(always)]
-    fn ctor_new(args: ()) -> Self::CtorType {
-        let () = args;
-        unsafe {
-            ::ctor::FnCtor::new(move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
-                crate::detail::__rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(::std::pin::Pin::into_inner_unchecked(dest));
-            })
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=105
+    impl ::ctor::CtorNew<()> for __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE {
+        type CtorType = impl ::ctor::Ctor<Output = Self>;
+        #[inline(always)]
+        fn ctor_new(args: ()) -> Self::CtorType {
+            let () = args;
+            unsafe {
+                ::ctor::FnCtor::new(
+                    move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
+                        crate::detail::__rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(::std::pin::Pin::into_inner_unchecked(dest));
+                    },
+                )
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=105
-impl<'b> ::ctor::CtorNew<&'b Self>
-    for __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE
-{
-    type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
-    #[inline(always)]
-    fn ctor_new(args: &'b Self) -> Self::CtorType {
-        let __param_0 = args;
-        unsafe {
-            ::ctor::FnCtor::new(move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
-                crate::detail::__rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEC1ERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(::std::pin::Pin::into_inner_unchecked(dest),__param_0);
-            })
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=105
+    impl<'b> ::ctor::CtorNew<&'b Self>
+        for __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE
+    {
+        type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
+        #[inline(always)]
+        fn ctor_new(args: &'b Self) -> Self::CtorType {
+            let __param_0 = args;
+            unsafe {
+                ::ctor::FnCtor::new(
+                    move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
+                        crate::detail::__rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEC1ERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(::std::pin::Pin::into_inner_unchecked(dest),__param_0);
+                    },
+                )
+            }
         }
     }
-}
-impl<'b> ::ctor::CtorNew<(&'b Self,)>
-    for __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE
-{
-    type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
-    #[inline(always)]
-    fn ctor_new(args: (&'b Self,)) -> Self::CtorType {
-        let (arg,) = args;
-        <Self as ::ctor::CtorNew<&'b Self>>::ctor_new(arg)
+    impl<'b> ::ctor::CtorNew<(&'b Self,)>
+        for __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE
+    {
+        type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
+        #[inline(always)]
+        fn ctor_new(args: (&'b Self,)) -> Self::CtorType {
+            let (arg,) = args;
+            <Self as ::ctor::CtorNew<&'b Self>>::ctor_new(arg)
+        }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=105
-impl<'b> ::ctor::CtorNew<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE
-{
-    type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
-    #[inline(always)]
-    fn ctor_new(args: ::ctor::RvalueReference<'b, Self>) -> Self::CtorType {
-        let __param_0 = args;
-        unsafe {
-            ::ctor::FnCtor::new(move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
-                crate::detail::__rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(::std::pin::Pin::into_inner_unchecked(dest),__param_0);
-            })
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=105
+    impl<'b> ::ctor::CtorNew<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE
+    {
+        type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
+        #[inline(always)]
+        fn ctor_new(args: ::ctor::RvalueReference<'b, Self>) -> Self::CtorType {
+            let __param_0 = args;
+            unsafe {
+                ::ctor::FnCtor::new(
+                    move |dest: ::std::pin::Pin<&mut ::std::mem::MaybeUninit<Self>>| {
+                        crate::detail::__rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(::std::pin::Pin::into_inner_unchecked(dest),__param_0);
+                    },
+                )
+            }
         }
     }
-}
-impl<'b> ::ctor::CtorNew<(::ctor::RvalueReference<'b, Self>,)>
-    for __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE
-{
-    type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
-    #[inline(always)]
-    fn ctor_new(args: (::ctor::RvalueReference<'b, Self>,)) -> Self::CtorType {
-        let (arg,) = args;
-        <Self as ::ctor::CtorNew<::ctor::RvalueReference<'b, Self>>>::ctor_new(arg)
+    impl<'b> ::ctor::CtorNew<(::ctor::RvalueReference<'b, Self>,)>
+        for __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE
+    {
+        type CtorType = impl ::ctor::Ctor<Output = Self> + ::ctor::Captures<'b>;
+        #[inline(always)]
+        fn ctor_new(args: (::ctor::RvalueReference<'b, Self>,)) -> Self::CtorType {
+            let (arg,) = args;
+            <Self as ::ctor::CtorNew<::ctor::RvalueReference<'b, Self>>>::ctor_new(arg)
+        }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=105
-impl<'b> ::ctor::Assign<&'b Self>
-    for __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE
-{
-    #[inline(always)]
-    fn assign<'a>(self: ::std::pin::Pin<&'a mut Self>, __param_0: &'b Self) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=105
+    impl<'b> ::ctor::Assign<&'b Self>
+        for __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE
+    {
+        #[inline(always)]
+        fn assign<'a>(self: ::std::pin::Pin<&'a mut Self>, __param_0: &'b Self) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=105
-impl<'b> ::ctor::Assign<::ctor::RvalueReference<'b, Self>>
-    for __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE
-{
-    #[inline(always)]
-    fn assign<'a>(
-        self: ::std::pin::Pin<&'a mut Self>,
-        __param_0: ::ctor::RvalueReference<'b, Self>,
-    ) {
-        unsafe {
-            crate::detail::__rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=105
+    impl<'b> ::ctor::Assign<::ctor::RvalueReference<'b, Self>>
+        for __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE
+    {
+        #[inline(always)]
+        fn assign<'a>(
+            self: ::std::pin::Pin<&'a mut Self>,
+            __param_0: ::ctor::RvalueReference<'b, Self>,
+        ) {
+            unsafe {
+                crate::detail::__rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(self,__param_0);
+            }
         }
     }
-}
 
-impl __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE {
-    /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=107
-    #[inline(always)]
-    pub fn GetPolicy() -> i32 {
-        unsafe {
-            crate::detail::__rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEE9GetPolicyEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc()
+    impl __CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE {
+        /// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=107
+        #[inline(always)]
+        pub fn GetPolicy() -> i32 {
+            unsafe {
+                crate::detail::__rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEE9GetPolicyEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc()
+            }
         }
     }
-}
 
-forward_declare::forward_declare!(pub __CcTemplateInstN25forward_declared_template23ForwardDeclaredTemplateIiEE = forward_declare::symbol!("__CcTemplateInstN25forward_declared_template23ForwardDeclaredTemplateIiEE"));
+    forward_declare::forward_declare!(pub __CcTemplateInstN25forward_declared_template23ForwardDeclaredTemplateIiEE = forward_declare::symbol!("__CcTemplateInstN25forward_declared_template23ForwardDeclaredTemplateIiEE"));
+}
 
 mod detail {
     #[allow(unused_imports)]
@@ -1100,7 +1170,11 @@ mod detail {
         pub(crate) fn __rust_thunk___Z36processForwardDeclaredSpecializationP18MyTopLevelTemplateIiE<
             'a,
         >(
-            i: Option<::std::pin::Pin<&'a mut crate::__CcTemplateInst18MyTopLevelTemplateIiE>>,
+            i: Option<
+                ::std::pin::Pin<
+                    &'a mut crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIiE,
+                >,
+            >,
         );
         pub(crate) fn __rust_thunk___ZN15private_classes14HasPrivateTypeC1ERKS0_<'a, 'b>(
             __this: &'a mut ::std::mem::MaybeUninit<crate::private_classes::HasPrivateType>,
@@ -1122,7 +1196,7 @@ mod detail {
             'a,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
             >,
         );
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings10MyTemplateI14DifferentScopeEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
@@ -1130,43 +1204,43 @@ mod detail {
             'b,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
             >,
             __param_0: ::ctor::RvalueReference<
                 'b,
-                crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
             >,
         );
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings10MyTemplateI14DifferentScopeEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
             'b,
         >(
-            __this:&'a mut crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
-            __param_0:&'b crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
-        ) -> &'a mut crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE;
+            __this:&'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
+            __param_0:&'b crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
+        ) -> &'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE;
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings10MyTemplateI14DifferentScopeEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
             'b,
         >(
-            __this:&'a mut crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
+            __this:&'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
             __param_0: ::ctor::RvalueReference<
                 'b,
-                crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
             >,
-        ) -> &'a mut crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE;
+        ) -> &'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE;
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings10MyTemplateI14DifferentScopeE6CreateES1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
             value: crate::DifferentScope,
-        ) -> crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE;
+        ) -> crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE;
         pub(crate) fn __rust_thunk___ZNK23test_namespace_bindings10MyTemplateI14DifferentScopeE5valueEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
         >(
-            __this:&'a crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
+            __this:&'a crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
         ) -> &'a crate::DifferentScope;
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,
             >,
         );
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
@@ -1174,28 +1248,28 @@ mod detail {
             'b,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,
             >,
             __param_0: ::ctor::RvalueReference<
                 'b,
-                crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,
             >,
         );
-        pub(crate)fn __rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<'a,'b>(__this:&'a mut crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,__param_0:&'b crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE)->&'a mut crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE;
-        pub(crate)fn __rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<'a,'b>(__this:&'a mut crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,__param_0: ::ctor::RvalueReference<'b,crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE>)->&'a mut crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE;
+        pub(crate)fn __rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<'a,'b>(__this:&'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,__param_0:&'b crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE)->&'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE;
+        pub(crate)fn __rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<'a,'b>(__this:&'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,__param_0: ::ctor::RvalueReference<'b,crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE>)->&'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE;
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEE6CreateES1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
             value: crate::test_namespace_bindings::TemplateParam,
-        ) -> crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE;
+        ) -> crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE;
         pub(crate) fn __rust_thunk___ZNK23test_namespace_bindings10MyTemplateINS_13TemplateParamEE5valueEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
         >(
-            __this:&'a crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,
+            __this:&'a crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,
         ) -> &'a crate::test_namespace_bindings::TemplateParam;
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings10MyTemplateIiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
             >,
         );
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings10MyTemplateIiEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
@@ -1203,57 +1277,57 @@ mod detail {
             'b,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
             >,
             __param_0: ::ctor::RvalueReference<
                 'b,
-                crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
             >,
         );
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings10MyTemplateIiEaSERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
             'b,
         >(
-            __this: &'a mut crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
-            __param_0: &'b crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
-        ) -> &'a mut crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE;
+            __this: &'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
+            __param_0: &'b crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
+        ) -> &'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE;
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings10MyTemplateIiEaSEOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
             'b,
         >(
-            __this: &'a mut crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
+            __this: &'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
             __param_0: ::ctor::RvalueReference<
                 'b,
-                crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
             >,
-        ) -> &'a mut crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE;
+        ) -> &'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE;
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings10MyTemplateIiE6CreateEi__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
             value: i32,
-        ) -> crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE;
+        ) -> crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE;
         pub(crate) fn __rust_thunk___ZNK23test_namespace_bindings10MyTemplateIiE5valueEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
         >(
-            __this: &'a crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
+            __this: &'a crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
         ) -> &'a i32;
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
         >(
-            __this:&'a mut::std::mem::MaybeUninit<crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE>,
+            __this:&'a mut::std::mem::MaybeUninit<crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE>,
         );
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
             'b,
         >(
-            __this:&'a mut::std::mem::MaybeUninit<crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE>,
-            __param_0: ::ctor::RvalueReference<'b,crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE>,
+            __this:&'a mut::std::mem::MaybeUninit<crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE>,
+            __param_0: ::ctor::RvalueReference<'b,crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE>,
         );
-        pub(crate)fn __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<'a,'b>(__this:&'a mut crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE,__param_0:&'b crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE)->&'a mut crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE;
-        pub(crate)fn __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<'a,'b>(__this:&'a mut crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE,__param_0: ::ctor::RvalueReference<'b,crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE>)->&'a mut crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE;
+        pub(crate)fn __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<'a,'b>(__this:&'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE,__param_0:&'b crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE)->&'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE;
+        pub(crate)fn __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<'a,'b>(__this:&'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE,__param_0: ::ctor::RvalueReference<'b,crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE>)->&'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE;
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIifEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
             >,
         );
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIifEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
@@ -1261,35 +1335,35 @@ mod detail {
             'b,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
             >,
             __param_0: ::ctor::RvalueReference<
                 'b,
-                crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
             >,
         );
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIifEaSERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
             'b,
         >(
-            __this:&'a mut crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
-            __param_0:&'b crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
-        ) -> &'a mut crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE;
+            __this:&'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
+            __param_0:&'b crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
+        ) -> &'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE;
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIifEaSEOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
             'b,
         >(
-            __this:&'a mut crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
+            __this:&'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
             __param_0: ::ctor::RvalueReference<
                 'b,
-                crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
             >,
-        ) -> &'a mut crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE;
+        ) -> &'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE;
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIiiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
             >,
         );
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIiiEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
@@ -1297,35 +1371,35 @@ mod detail {
             'b,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
             >,
             __param_0: ::ctor::RvalueReference<
                 'b,
-                crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
             >,
         );
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIiiEaSERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
             'b,
         >(
-            __this:&'a mut crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
-            __param_0:&'b crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
-        ) -> &'a mut crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE;
+            __this:&'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
+            __param_0:&'b crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
+        ) -> &'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE;
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIiiEaSEOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
             'b,
         >(
-            __this:&'a mut crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
+            __this:&'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
             __param_0: ::ctor::RvalueReference<
                 'b,
-                crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
             >,
-        ) -> &'a mut crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE;
+        ) -> &'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE;
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings8MyStructIcEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
             >,
         );
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings8MyStructIcEC1ERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
@@ -1333,20 +1407,20 @@ mod detail {
             'b,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
             >,
-            __param_0: &'b crate::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
+            __param_0: &'b crate::__instantiations::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
         );
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings8MyStructIcEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
             'b,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
             >,
             __param_0: ::ctor::RvalueReference<
                 'b,
-                crate::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
             >,
         );
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings8MyStructIcEaSERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
@@ -1354,41 +1428,41 @@ mod detail {
             'b,
         >(
             __this: ::std::pin::Pin<
-                &'a mut crate::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
+                &'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
             >,
-            __param_0: &'b crate::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
-        ) -> ::std::pin::Pin<&'a mut crate::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE>;
+            __param_0: &'b crate::__instantiations::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
+        ) -> ::std::pin::Pin<&'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE>;
         pub(crate) fn __rust_thunk___ZN23test_namespace_bindings8MyStructIcEaSEOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
             'b,
         >(
             __this: ::std::pin::Pin<
-                &'a mut crate::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
+                &'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
             >,
             __param_0: ::ctor::RvalueReference<
                 'b,
-                crate::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
+                crate::__instantiations::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
             >,
-        ) -> ::std::pin::Pin<&'a mut crate::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE>;
+        ) -> ::std::pin::Pin<&'a mut crate::__instantiations::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE>;
         pub(crate) fn __rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
         >(
-            __this:&'a mut::std::mem::MaybeUninit<crate::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE>,
+            __this:&'a mut::std::mem::MaybeUninit<crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE>,
         );
         pub(crate) fn __rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
             'b,
         >(
-            __this:&'a mut::std::mem::MaybeUninit<crate::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE>,
-            __param_0: ::ctor::RvalueReference<'b,crate::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE>,
+            __this:&'a mut::std::mem::MaybeUninit<crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE>,
+            __param_0: ::ctor::RvalueReference<'b,crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE>,
         );
-        pub(crate)fn __rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<'a,'b>(__this:&'a mut crate::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE,__param_0:&'b crate::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE)->&'a mut crate::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE;
-        pub(crate)fn __rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<'a,'b>(__this:&'a mut crate::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE,__param_0: ::ctor::RvalueReference<'b,crate::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE>)->&'a mut crate::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE;
+        pub(crate)fn __rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<'a,'b>(__this:&'a mut crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE,__param_0:&'b crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE)->&'a mut crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE;
+        pub(crate)fn __rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<'a,'b>(__this:&'a mut crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE,__param_0: ::ctor::RvalueReference<'b,crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE>)->&'a mut crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE;
         pub(crate) fn __rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
+                crate::__instantiations::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
             >,
         );
         pub(crate) fn __rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEC1ERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
@@ -1396,45 +1470,45 @@ mod detail {
             'b,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
+                crate::__instantiations::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
             >,
-            __param_0:&'b crate::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
+            __param_0:&'b crate::__instantiations::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
         );
         pub(crate) fn __rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
             'b,
         >(
             __this: &'a mut ::std::mem::MaybeUninit<
-                crate::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
+                crate::__instantiations::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
             >,
             __param_0: ::ctor::RvalueReference<
                 'b,
-                crate::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
+                crate::__instantiations::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
             >,
         );
         pub(crate) fn __rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
             'b,
         >(
-            __this: ::std::pin::Pin<&'a mut crate::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE>,
-            __param_0:&'b crate::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
+            __this: ::std::pin::Pin<&'a mut crate::__instantiations::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE>,
+            __param_0:&'b crate::__instantiations::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
         ) -> ::std::pin::Pin<
-            &'a mut crate::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
+            &'a mut crate::__instantiations::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
         >;
         pub(crate) fn __rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEaSEOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc<
             'a,
             'b,
         >(
-            __this: ::std::pin::Pin<&'a mut crate::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE>,
+            __this: ::std::pin::Pin<&'a mut crate::__instantiations::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE>,
             __param_0: ::ctor::RvalueReference<
                 'b,
-                crate::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
+                crate::__instantiations::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
             >,
         ) -> ::std::pin::Pin<
-            &'a mut crate::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
+            &'a mut crate::__instantiations::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
         >;
-        pub(crate) fn __rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEE9GetPolicyEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc()
-        -> i32;
+        pub(crate) fn __rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEE9GetPolicyEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
+        ) -> i32;
     }
 }
 
@@ -1475,281 +1549,288 @@ const _: () = {
 
 const _: () = assert!(
     ::std::mem::size_of::<
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
     >() == 1
 );
 const _: () = assert!(
     ::std::mem::align_of::<
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
     >() == 1
 );
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE: Clone
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE: Clone
     );
 };
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE: Copy
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE: Copy
     );
 };
 const _: () = {
     static_assertions::assert_not_impl_any!(
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE: Drop
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE: Drop
     );
 };
 const _: () = assert!(
     memoffset::offset_of!(
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateI14DifferentScopeEE,
         value_
     ) == 0
 );
 
 const _: () = assert!(
     ::std::mem::size_of::<
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,
     >() == 1
 );
 const _: () = assert!(
     ::std::mem::align_of::<
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,
     >() == 1
 );
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE: Clone
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE: Clone
     );
 };
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE: Copy
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE: Copy
     );
 };
 const _: () = {
     static_assertions::assert_not_impl_any!(
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE: Drop
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE: Drop
     );
 };
 const _: () = assert!(
     memoffset::offset_of!(
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEE,
         value_
     ) == 0
 );
 
 const _: () = assert!(
-    ::std::mem::size_of::<crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE>() == 4
+    ::std::mem::size_of::<
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
+    >() == 4
 );
 const _: () = assert!(
-    ::std::mem::align_of::<crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE>()
-        == 4
+    ::std::mem::align_of::<
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
+    >() == 4
 );
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE: Clone
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE: Clone
     );
 };
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE: Copy
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE: Copy
     );
 };
 const _: () = {
     static_assertions::assert_not_impl_any!(
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE: Drop
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE: Drop
     );
 };
 const _: () = assert!(
     memoffset::offset_of!(
-        crate::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings10MyTemplateIiEE,
         value_
     ) == 0
 );
 
 const _: () = assert!(
     ::std::mem::size_of::<
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE,
     >() == 12
 );
 const _: () = assert!(
     ::std::mem::align_of::<
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE,
     >() == 4
 );
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE:
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE:
             Clone
     );
 };
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE: Copy
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE: Copy
     );
 };
 const _: () = {
     static_assertions::assert_not_impl_any!(
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE: Drop
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE: Drop
     );
 };
 const _: () = assert!(
     memoffset::offset_of!(
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE,
         value1
     ) == 0
 );
 const _: () = assert!(
     memoffset::offset_of!(
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEE,
         value2
     ) == 8
 );
 
 const _: () = assert!(
     ::std::mem::size_of::<
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
     >() == 8
 );
 const _: () = assert!(
     ::std::mem::align_of::<
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
     >() == 4
 );
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE: Clone
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE: Clone
     );
 };
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE: Copy
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE: Copy
     );
 };
 const _: () = {
     static_assertions::assert_not_impl_any!(
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE: Drop
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE: Drop
     );
 };
 const _: () = assert!(
     memoffset::offset_of!(
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
         value1
     ) == 0
 );
 const _: () = assert!(
     memoffset::offset_of!(
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIifEE,
         value2
     ) == 4
 );
 
 const _: () = assert!(
     ::std::mem::size_of::<
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
     >() == 8
 );
 const _: () = assert!(
     ::std::mem::align_of::<
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
     >() == 4
 );
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE: Clone
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE: Clone
     );
 };
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE: Copy
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE: Copy
     );
 };
 const _: () = {
     static_assertions::assert_not_impl_any!(
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE: Drop
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE: Drop
     );
 };
 const _: () = assert!(
     memoffset::offset_of!(
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
         value1
     ) == 0
 );
 const _: () = assert!(
     memoffset::offset_of!(
-        crate::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings21TemplateWithTwoParamsIiiEE,
         value2
     ) == 4
 );
 
 const _: () = assert!(
-    ::std::mem::size_of::<crate::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE>() == 1
+    ::std::mem::size_of::<
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
+    >() == 1
 );
 const _: () = assert!(
-    ::std::mem::align_of::<crate::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE>() == 1
+    ::std::mem::align_of::<
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE,
+    >() == 1
 );
 const _: () = {
     static_assertions::assert_not_impl_any!(
-        crate::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE: Copy
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE: Copy
     );
 };
 const _: () = {
     static_assertions::assert_not_impl_any!(
-        crate::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE: Drop
+        crate::__instantiations::__CcTemplateInstN23test_namespace_bindings8MyStructIcEE: Drop
     );
 };
 
 const _: () = assert!(
     ::std::mem::size_of::<
-        crate::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE,
+        crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE,
     >() == 1
 );
 const _: () = assert!(
     ::std::mem::align_of::<
-        crate::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE,
+        crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE,
     >() == 1
 );
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE:
+        crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE:
             Clone
     );
 };
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE:
+        crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE:
             Copy
     );
 };
 const _: () = {
     static_assertions::assert_not_impl_any!(
-        crate::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE:
+        crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE:
             Drop
     );
 };
 const _: () = assert!(
     memoffset::offset_of!(
-        crate::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE,
+        crate::__instantiations::__CcTemplateInst18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEE,
         value
     ) == 0
 );
 
 const _: () = assert!(
     ::std::mem::size_of::<
-        crate::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
+        crate::__instantiations::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
     >() == 1
 );
 const _: () = assert!(
     ::std::mem::align_of::<
-        crate::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
+        crate::__instantiations::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE,
     >() == 1
 );
 const _: () = {
     static_assertions::assert_not_impl_any!(
-        crate::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE: Copy
+        crate::__instantiations::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE: Copy
     );
 };
 const _: () = {
     static_assertions::assert_not_impl_any!(
-        crate::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE: Drop
+        crate::__instantiations::__CcTemplateInstN24template_template_params10MyTemplateINS_6PolicyEEE: Drop
     );
 };
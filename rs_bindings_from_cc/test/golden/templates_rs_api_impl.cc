@@ -16,44 +16,62 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=10
 extern "C" void __rust_thunk___ZN14DifferentScopeC1Ev(
     struct DifferentScope* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=10
 extern "C" void __rust_thunk___ZN14DifferentScopeC1EOS_(
     struct DifferentScope* __this, struct DifferentScope* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=10
 extern "C" struct DifferentScope* __rust_thunk___ZN14DifferentScopeaSERKS_(
     struct DifferentScope* __this, const struct DifferentScope* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=10
 extern "C" struct DifferentScope* __rust_thunk___ZN14DifferentScopeaSEOS_(
     struct DifferentScope* __this, struct DifferentScope* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
 extern "C" void
 __rust_thunk___ZN23test_namespace_bindings10MyTemplateI14DifferentScopeEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     class test_namespace_bindings::MyTemplate<DifferentScope>* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
 extern "C" void
 __rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     class test_namespace_bindings::MyTemplate<
         test_namespace_bindings::TemplateParam>* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
 extern "C" void
 __rust_thunk___ZN23test_namespace_bindings10MyTemplateIiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     class test_namespace_bindings::MyTemplate<int>* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
 extern "C" void
 __rust_thunk___ZN23test_namespace_bindings10MyTemplateI14DifferentScopeEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     class test_namespace_bindings::MyTemplate<DifferentScope>* __this,
     class test_namespace_bindings::MyTemplate<DifferentScope>* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
 extern "C" void
 __rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     class test_namespace_bindings::MyTemplate<
@@ -62,12 +80,16 @@ __rust_thunk___ZN23test_namespace_bindings10MyTemplateINS_13TemplateParamEEC1EOS
         test_namespace_bindings::TemplateParam>* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
 extern "C" void
 __rust_thunk___ZN23test_namespace_bindings10MyTemplateIiEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     class test_namespace_bindings::MyTemplate<int>* __this,
     class test_namespace_bindings::MyTemplate<int>* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=15
 extern "C" class test_namespace_bindings::MyTemplate<DifferentScope>*
 __rust_thunk___ZN23test_namespace_bindings10MyTemplateI14DifferentScopeEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     class test_namespace_bindings::MyTemplate<DifferentScope>* __this,
@@ -124,39 +146,53 @@ __rust_thunk___ZNK23test_namespace_bindings10MyTemplateI14DifferentScopeE5valueE
     const class test_namespace_bindings::MyTemplate<DifferentScope>* __this) {
   return &__this->value();
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=23
 extern "C" const struct test_namespace_bindings::TemplateParam*
 __rust_thunk___ZNK23test_namespace_bindings10MyTemplateINS_13TemplateParamEE5valueEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     const class test_namespace_bindings::MyTemplate<
         test_namespace_bindings::TemplateParam>* __this) {
   return &__this->value();
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=23
 extern "C" int const*
 __rust_thunk___ZNK23test_namespace_bindings10MyTemplateIiE5valueEv__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     const class test_namespace_bindings::MyTemplate<int>* __this) {
   return &__this->value();
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=32
 extern "C" void __rust_thunk___ZN23test_namespace_bindings13TemplateParamC1Ev(
     struct test_namespace_bindings::TemplateParam* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=32
 extern "C" void
 __rust_thunk___ZN23test_namespace_bindings13TemplateParamC1EOS0_(
     struct test_namespace_bindings::TemplateParam* __this,
     struct test_namespace_bindings::TemplateParam* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=32
 extern "C" struct test_namespace_bindings::TemplateParam*
 __rust_thunk___ZN23test_namespace_bindings13TemplateParamaSERKS0_(
     struct test_namespace_bindings::TemplateParam* __this,
     const struct test_namespace_bindings::TemplateParam* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=32
 extern "C" struct test_namespace_bindings::TemplateParam*
 __rust_thunk___ZN23test_namespace_bindings13TemplateParamaSEOS0_(
     struct test_namespace_bindings::TemplateParam* __this,
     struct test_namespace_bindings::TemplateParam* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
 extern "C" void
 __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     struct test_namespace_bindings::TemplateWithTwoParams<
@@ -164,16 +200,22 @@ __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEC1E
         __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
 extern "C" void
 __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIifEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     struct test_namespace_bindings::TemplateWithTwoParams<int, float>* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
 extern "C" void
 __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIiiEC1Ev__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     struct test_namespace_bindings::TemplateWithTwoParams<int, int>* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
 extern "C" void
 __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     struct test_namespace_bindings::TemplateWithTwoParams<
@@ -183,6 +225,8 @@ __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEC1E
         __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
 extern "C" void
 __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIifEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     struct test_namespace_bindings::TemplateWithTwoParams<int, float>* __this,
@@ -190,6 +234,8 @@ __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIifEC1EOS1___2f
         __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
 extern "C" void
 __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIiiEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     struct test_namespace_bindings::TemplateWithTwoParams<int, int>* __this,
@@ -197,6 +243,8 @@ __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsIiiEC1EOS1___2f
         __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=37
 extern "C" struct test_namespace_bindings::TemplateWithTwoParams<
     test_namespace_bindings::TemplateWithTwoParams<int, int>, int>*
 __rust_thunk___ZN23test_namespace_bindings21TemplateWithTwoParamsINS0_IiiEEiEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
@@ -244,18 +292,24 @@ __rust_thunk___ZN23test_namespace_bindings8MyStructIcEC1Ev__2f_2fthird_5fparty_2
     struct test_namespace_bindings::MyStruct<char>* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=60
 extern "C" void
 __rust_thunk___ZN23test_namespace_bindings8MyStructIcEC1ERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     struct test_namespace_bindings::MyStruct<char>* __this,
     const struct test_namespace_bindings::MyStruct<char>* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=60
 extern "C" void
 __rust_thunk___ZN23test_namespace_bindings8MyStructIcEC1EOS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     struct test_namespace_bindings::MyStruct<char>* __this,
     struct test_namespace_bindings::MyStruct<char>* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=60
 extern "C" struct test_namespace_bindings::MyStruct<char>*
 __rust_thunk___ZN23test_namespace_bindings8MyStructIcEaSERKS1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     struct test_namespace_bindings::MyStruct<char>* __this,
@@ -271,6 +325,8 @@ __rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamE
     struct MyTopLevelTemplate<test_namespace_bindings::TemplateParam>* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=80
 extern "C" void
 __rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     struct MyTopLevelTemplate<test_namespace_bindings::TemplateParam>* __this,
@@ -278,6 +334,8 @@ __rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamE
         __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=80
 extern "C" struct MyTopLevelTemplate<test_namespace_bindings::TemplateParam>*
 __rust_thunk___ZN18MyTopLevelTemplateIN23test_namespace_bindings13TemplateParamEEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     struct MyTopLevelTemplate<test_namespace_bindings::TemplateParam>* __this,
@@ -296,6 +354,8 @@ __rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEC1Ev__2f_2ft
         template_template_params::Policy>* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=105
 extern "C" void
 __rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEC1ERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     class template_template_params::MyTemplate<
@@ -304,6 +364,8 @@ __rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEC1ERKS2___2f
         template_template_params::Policy>* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=105
 extern "C" void
 __rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEC1EOS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
     class template_template_params::MyTemplate<
@@ -312,6 +374,8 @@ __rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEC1EOS2___2f_
         template_template_params::Policy>* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=105
 extern "C" class template_template_params::MyTemplate<
     template_template_params::Policy>*
 __rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEEaSERKS2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fcc(
@@ -333,22 +397,30 @@ __rust_thunk___ZN24template_template_params10MyTemplateINS_6PolicyEE9GetPolicyEv
   return template_template_params::MyTemplate<
       template_template_params::Policy>::GetPolicy();
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=131
 extern "C" void __rust_thunk___ZN15private_classes14HasPrivateTypeC1ERKS0_(
     class private_classes::HasPrivateType* __this,
     const class private_classes::HasPrivateType* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=131
 extern "C" void __rust_thunk___ZN15private_classes14HasPrivateTypeC1EOS0_(
     class private_classes::HasPrivateType* __this,
     class private_classes::HasPrivateType* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=131
 extern "C" class private_classes::HasPrivateType*
 __rust_thunk___ZN15private_classes14HasPrivateTypeaSERKS0_(
     class private_classes::HasPrivateType* __this,
     const class private_classes::HasPrivateType* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates.h;l=131
 extern "C" class private_classes::HasPrivateType*
 __rust_thunk___ZN15private_classes14HasPrivateTypeaSEOS0_(
     class private_classes::HasPrivateType* __this,
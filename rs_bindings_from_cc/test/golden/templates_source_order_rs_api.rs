@@ -50,22 +50,22 @@ forward_declare::unsafe_define!(forward_declare::symbol!("TopLevel"), crate::Top
 // Parameter #0 is not supported: Unsupported type 'TopLevel &&': Unsupported type: && without lifetime
 
 /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=18
-pub type Alias1 = crate::__CcTemplateInst10MyTemplateIiE;
+pub type Alias1 = crate::__instantiations::__CcTemplateInst10MyTemplateIiE;
 
 /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=19
-pub type Alias2 = crate::__CcTemplateInst10MyTemplateIfE;
+pub type Alias2 = crate::__instantiations::__CcTemplateInst10MyTemplateIfE;
 
 /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=20
-pub type Alias3 = crate::__CcTemplateInst10MyTemplateI8TopLevelE;
+pub type Alias3 = crate::__instantiations::__CcTemplateInst10MyTemplateI8TopLevelE;
 
 /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=21
-pub type Alias4 = crate::__CcTemplateInst10MyTemplateIdE;
+pub type Alias4 = crate::__instantiations::__CcTemplateInst10MyTemplateIdE;
 
 /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=22
-pub type Alias5 = crate::__CcTemplateInst10MyTemplateIbE;
+pub type Alias5 = crate::__instantiations::__CcTemplateInst10MyTemplateIbE;
 
 /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=23
-pub type Alias6 = crate::__CcTemplateInst10MyTemplateIS_I8TopLevelEE;
+pub type Alias6 = crate::__instantiations::__CcTemplateInst10MyTemplateIS_I8TopLevelEE;
 
 pub mod test_namespace_bindings {
     /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=26
@@ -100,397 +100,404 @@ pub mod test_namespace_bindings {
     // Parameter #0 is not supported: Unsupported type 'Inner &&': Unsupported type: && without lifetime
 
     /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=27
-    pub type Alias7 = crate::__CcTemplateInst10MyTemplateIcE;
+    pub type Alias7 = crate::__instantiations::__CcTemplateInst10MyTemplateIcE;
 
     /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=28
-    pub type Alias8 = crate::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE;
+    pub type Alias8 =
+        crate::__instantiations::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE;
 
     /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=29
-    pub type Alias9 = crate::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE;
+    pub type Alias9 = crate::__instantiations::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE;
 }
 
 // namespace test_namespace_bindings
 
 // THIRD_PARTY_CRUBIT_RS_BINDINGS_FROM_CC_TEST_GOLDEN_TEMPLATES_SOURCE_ORDER_H_
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-#[derive(Clone, Copy)]
-#[repr(C)]
-pub struct __CcTemplateInst10MyTemplateI8TopLevelE {
-    __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
-    /// Reason for representing this field as a blob of bytes:
-    /// Types of non-public C++ fields can be elided away
-    pub(crate) t: [::std::mem::MaybeUninit<u8>; 1],
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("MyTemplate<TopLevel>"),
-    crate::__CcTemplateInst10MyTemplateI8TopLevelE
-);
+/// Class template instantiations used by this crate.
+pub mod __instantiations {
+    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub struct __CcTemplateInst10MyTemplateI8TopLevelE {
+        __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
+        /// Reason for representing this field as a blob of bytes:
+        /// Types of non-public C++ fields can be elided away
+        pub(crate) t: [::std::mem::MaybeUninit<u8>; 1],
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("MyTemplate<TopLevel>"),
+        crate::__instantiations::__CcTemplateInst10MyTemplateI8TopLevelE
+    );
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<TopLevel>::MyTemplate<TopLevel>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<TopLevel>::MyTemplate<TopLevel>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<TopLevel>::MyTemplate<TopLevel>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<TopLevel>::MyTemplate<TopLevel>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<TopLevel>::MyTemplate':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<TopLevel> &&': Unsupported type: && without lifetime
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<TopLevel>::MyTemplate':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<TopLevel> &&': Unsupported type: && without lifetime
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<TopLevel>::operator=':
-// `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<TopLevel>::operator=':
+    // `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<TopLevel>::operator=':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<TopLevel> &&': Unsupported type: && without lifetime
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<TopLevel>::operator=':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<TopLevel> &&': Unsupported type: && without lifetime
 
-impl __CcTemplateInst10MyTemplateI8TopLevelE {
-    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
-    #[inline(always)]
-    pub unsafe fn processT(__this: *mut Self, t: crate::TopLevel) {
-        crate::detail::__rust_thunk___ZN10MyTemplateI8TopLevelE8processTES0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+    impl __CcTemplateInst10MyTemplateI8TopLevelE {
+        /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
+        #[inline(always)]
+        pub unsafe fn processT(__this: *mut Self, t: crate::TopLevel) {
+            crate::detail::__rust_thunk___ZN10MyTemplateI8TopLevelE8processTES0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+        }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-#[derive(Clone, Copy)]
-#[repr(C)]
-pub struct __CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE {
-    __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
-    /// Reason for representing this field as a blob of bytes:
-    /// Types of non-public C++ fields can be elided away
-    pub(crate) t: [::std::mem::MaybeUninit<u8>; 1],
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("MyTemplate<test_namespace_bindings::Inner>"),
-    crate::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE
-);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub struct __CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE {
+        __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
+        /// Reason for representing this field as a blob of bytes:
+        /// Types of non-public C++ fields can be elided away
+        pub(crate) t: [::std::mem::MaybeUninit<u8>; 1],
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("MyTemplate<test_namespace_bindings::Inner>"),
+        crate::__instantiations::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE
+    );
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<test_namespace_bindings::Inner>::MyTemplate<test_namespace_bindings::Inner>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<test_namespace_bindings::Inner>::MyTemplate<test_namespace_bindings::Inner>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<test_namespace_bindings::Inner>::MyTemplate<test_namespace_bindings::Inner>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<test_namespace_bindings::Inner>::MyTemplate<test_namespace_bindings::Inner>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<test_namespace_bindings::Inner>::MyTemplate':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<Inner> &&': Unsupported type: && without lifetime
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<test_namespace_bindings::Inner>::MyTemplate':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<Inner> &&': Unsupported type: && without lifetime
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<test_namespace_bindings::Inner>::operator=':
-// `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<test_namespace_bindings::Inner>::operator=':
+    // `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<test_namespace_bindings::Inner>::operator=':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<Inner> &&': Unsupported type: && without lifetime
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<test_namespace_bindings::Inner>::operator=':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<Inner> &&': Unsupported type: && without lifetime
 
-impl __CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE {
-    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
-    #[inline(always)]
-    pub unsafe fn processT(__this: *mut Self, t: crate::test_namespace_bindings::Inner) {
-        crate::detail::__rust_thunk___ZN10MyTemplateIN23test_namespace_bindings5InnerEE8processTES1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+    impl __CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE {
+        /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
+        #[inline(always)]
+        pub unsafe fn processT(__this: *mut Self, t: crate::test_namespace_bindings::Inner) {
+            crate::detail::__rust_thunk___ZN10MyTemplateIN23test_namespace_bindings5InnerEE8processTES1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+        }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-#[derive(Clone, Copy)]
-#[repr(C)]
-pub struct __CcTemplateInst10MyTemplateIS_I8TopLevelEE {
-    __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
-    /// Reason for representing this field as a blob of bytes:
-    /// Types of non-public C++ fields can be elided away
-    pub(crate) t: [::std::mem::MaybeUninit<u8>; 1],
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("MyTemplate<MyTemplate<TopLevel>>"),
-    crate::__CcTemplateInst10MyTemplateIS_I8TopLevelEE
-);
-
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<MyTemplate<TopLevel>>::MyTemplate<MyTemplate<TopLevel>>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub struct __CcTemplateInst10MyTemplateIS_I8TopLevelEE {
+        __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
+        /// Reason for representing this field as a blob of bytes:
+        /// Types of non-public C++ fields can be elided away
+        pub(crate) t: [::std::mem::MaybeUninit<u8>; 1],
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("MyTemplate<MyTemplate<TopLevel>>"),
+        crate::__instantiations::__CcTemplateInst10MyTemplateIS_I8TopLevelEE
+    );
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<MyTemplate<TopLevel>>::MyTemplate<MyTemplate<TopLevel>>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<MyTemplate<TopLevel>>::MyTemplate<MyTemplate<TopLevel>>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<MyTemplate<TopLevel>>::MyTemplate':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<MyTemplate<TopLevel> > &&': Unsupported type: && without lifetime
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<MyTemplate<TopLevel>>::MyTemplate<MyTemplate<TopLevel>>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<MyTemplate<TopLevel>>::operator=':
-// `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<MyTemplate<TopLevel>>::MyTemplate':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<MyTemplate<TopLevel> > &&': Unsupported type: && without lifetime
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<MyTemplate<TopLevel>>::operator=':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<MyTemplate<TopLevel> > &&': Unsupported type: && without lifetime
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<MyTemplate<TopLevel>>::operator=':
+    // `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
 
-impl __CcTemplateInst10MyTemplateIS_I8TopLevelEE {
-    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
-    #[inline(always)]
-    pub unsafe fn processT(__this: *mut Self, t: crate::__CcTemplateInst10MyTemplateI8TopLevelE) {
-        crate::detail::__rust_thunk___ZN10MyTemplateIS_I8TopLevelEE8processTES1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<MyTemplate<TopLevel>>::operator=':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<MyTemplate<TopLevel> > &&': Unsupported type: && without lifetime
+
+    impl __CcTemplateInst10MyTemplateIS_I8TopLevelEE {
+        /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
+        #[inline(always)]
+        pub unsafe fn processT(
+            __this: *mut Self,
+            t: crate::__instantiations::__CcTemplateInst10MyTemplateI8TopLevelE,
+        ) {
+            crate::detail::__rust_thunk___ZN10MyTemplateIS_I8TopLevelEE8processTES1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+        }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-#[derive(Clone, Copy)]
-#[repr(C)]
-pub struct __CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE {
-    __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
-    /// Reason for representing this field as a blob of bytes:
-    /// Types of non-public C++ fields can be elided away
-    pub(crate) t: [::std::mem::MaybeUninit<u8>; 1],
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("MyTemplate<MyTemplate<test_namespace_bindings::Inner>>"),
-    crate::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE
-);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub struct __CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE {
+        __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
+        /// Reason for representing this field as a blob of bytes:
+        /// Types of non-public C++ fields can be elided away
+        pub(crate) t: [::std::mem::MaybeUninit<u8>; 1],
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("MyTemplate<MyTemplate<test_namespace_bindings::Inner>>"),
+        crate::__instantiations::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE
+    );
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<MyTemplate<test_namespace_bindings::Inner>>::MyTemplate<MyTemplate<test_namespace_bindings::Inner>>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<MyTemplate<test_namespace_bindings::Inner>>::MyTemplate<MyTemplate<test_namespace_bindings::Inner>>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<MyTemplate<test_namespace_bindings::Inner>>::MyTemplate<MyTemplate<test_namespace_bindings::Inner>>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<MyTemplate<test_namespace_bindings::Inner>>::MyTemplate<MyTemplate<test_namespace_bindings::Inner>>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<MyTemplate<test_namespace_bindings::Inner>>::MyTemplate':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<MyTemplate<Inner> > &&': Unsupported type: && without lifetime
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<MyTemplate<test_namespace_bindings::Inner>>::MyTemplate':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<MyTemplate<Inner> > &&': Unsupported type: && without lifetime
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<MyTemplate<test_namespace_bindings::Inner>>::operator=':
-// `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<MyTemplate<test_namespace_bindings::Inner>>::operator=':
+    // `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<MyTemplate<test_namespace_bindings::Inner>>::operator=':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<MyTemplate<Inner> > &&': Unsupported type: && without lifetime
-
-impl __CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE {
-    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
-    #[inline(always)]
-    pub unsafe fn processT(
-        __this: *mut Self,
-        t: crate::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE,
-    ) {
-        crate::detail::__rust_thunk___ZN10MyTemplateIS_IN23test_namespace_bindings5InnerEEE8processTES2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<MyTemplate<test_namespace_bindings::Inner>>::operator=':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<MyTemplate<Inner> > &&': Unsupported type: && without lifetime
+
+    impl __CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE {
+        /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
+        #[inline(always)]
+        pub unsafe fn processT(
+            __this: *mut Self,
+            t: crate::__instantiations::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE,
+        ) {
+            crate::detail::__rust_thunk___ZN10MyTemplateIS_IN23test_namespace_bindings5InnerEEE8processTES2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+        }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-#[derive(Clone, Copy)]
-#[repr(C)]
-pub struct __CcTemplateInst10MyTemplateIbE {
-    __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
-    /// Reason for representing this field as a blob of bytes:
-    /// Types of non-public C++ fields can be elided away
-    pub(crate) t: [::std::mem::MaybeUninit<u8>; 1],
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("MyTemplate<bool>"),
-    crate::__CcTemplateInst10MyTemplateIbE
-);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub struct __CcTemplateInst10MyTemplateIbE {
+        __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
+        /// Reason for representing this field as a blob of bytes:
+        /// Types of non-public C++ fields can be elided away
+        pub(crate) t: [::std::mem::MaybeUninit<u8>; 1],
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("MyTemplate<bool>"),
+        crate::__instantiations::__CcTemplateInst10MyTemplateIbE
+    );
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<bool>::MyTemplate<bool>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<bool>::MyTemplate<bool>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<bool>::MyTemplate<bool>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<bool>::MyTemplate<bool>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<bool>::MyTemplate':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<_Bool> &&': Unsupported type: && without lifetime
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<bool>::MyTemplate':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<_Bool> &&': Unsupported type: && without lifetime
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<bool>::operator=':
-// `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<bool>::operator=':
+    // `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<bool>::operator=':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<_Bool> &&': Unsupported type: && without lifetime
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<bool>::operator=':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<_Bool> &&': Unsupported type: && without lifetime
 
-impl __CcTemplateInst10MyTemplateIbE {
-    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
-    #[inline(always)]
-    pub unsafe fn processT(__this: *mut Self, t: bool) {
-        crate::detail::__rust_thunk___ZN10MyTemplateIbE8processTEb__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+    impl __CcTemplateInst10MyTemplateIbE {
+        /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
+        #[inline(always)]
+        pub unsafe fn processT(__this: *mut Self, t: bool) {
+            crate::detail::__rust_thunk___ZN10MyTemplateIbE8processTEb__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+        }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-#[derive(Clone, Copy)]
-#[repr(C)]
-pub struct __CcTemplateInst10MyTemplateIcE {
-    __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
-    /// Reason for representing this field as a blob of bytes:
-    /// Types of non-public C++ fields can be elided away
-    pub(crate) t: [::std::mem::MaybeUninit<u8>; 1],
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("MyTemplate<char>"),
-    crate::__CcTemplateInst10MyTemplateIcE
-);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub struct __CcTemplateInst10MyTemplateIcE {
+        __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
+        /// Reason for representing this field as a blob of bytes:
+        /// Types of non-public C++ fields can be elided away
+        pub(crate) t: [::std::mem::MaybeUninit<u8>; 1],
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("MyTemplate<char>"),
+        crate::__instantiations::__CcTemplateInst10MyTemplateIcE
+    );
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<char>::MyTemplate<char>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<char>::MyTemplate<char>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<char>::MyTemplate<char>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<char>::MyTemplate<char>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<char>::MyTemplate':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<char> &&': Unsupported type: && without lifetime
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<char>::MyTemplate':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<char> &&': Unsupported type: && without lifetime
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<char>::operator=':
-// `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<char>::operator=':
+    // `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<char>::operator=':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<char> &&': Unsupported type: && without lifetime
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<char>::operator=':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<char> &&': Unsupported type: && without lifetime
 
-impl __CcTemplateInst10MyTemplateIcE {
-    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
-    #[inline(always)]
-    pub unsafe fn processT(__this: *mut Self, t: u8) {
-        crate::detail::__rust_thunk___ZN10MyTemplateIcE8processTEc__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+    impl __CcTemplateInst10MyTemplateIcE {
+        /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
+        #[inline(always)]
+        pub unsafe fn processT(__this: *mut Self, t: u8) {
+            crate::detail::__rust_thunk___ZN10MyTemplateIcE8processTEc__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+        }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-#[derive(Clone, Copy)]
-#[repr(C, align(8))]
-pub struct __CcTemplateInst10MyTemplateIdE {
-    __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
-    /// Reason for representing this field as a blob of bytes:
-    /// Types of non-public C++ fields can be elided away
-    pub(crate) t: [::std::mem::MaybeUninit<u8>; 8],
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("MyTemplate<double>"),
-    crate::__CcTemplateInst10MyTemplateIdE
-);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    #[derive(Clone, Copy)]
+    #[repr(C, align(8))]
+    pub struct __CcTemplateInst10MyTemplateIdE {
+        __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
+        /// Reason for representing this field as a blob of bytes:
+        /// Types of non-public C++ fields can be elided away
+        pub(crate) t: [::std::mem::MaybeUninit<u8>; 8],
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("MyTemplate<double>"),
+        crate::__instantiations::__CcTemplateInst10MyTemplateIdE
+    );
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<double>::MyTemplate<double>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<double>::MyTemplate<double>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<double>::MyTemplate<double>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<double>::MyTemplate<double>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<double>::MyTemplate':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<double> &&': Unsupported type: && without lifetime
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<double>::MyTemplate':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<double> &&': Unsupported type: && without lifetime
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<double>::operator=':
-// `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<double>::operator=':
+    // `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<double>::operator=':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<double> &&': Unsupported type: && without lifetime
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<double>::operator=':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<double> &&': Unsupported type: && without lifetime
 
-impl __CcTemplateInst10MyTemplateIdE {
-    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
-    #[inline(always)]
-    pub unsafe fn processT(__this: *mut Self, t: f64) {
-        crate::detail::__rust_thunk___ZN10MyTemplateIdE8processTEd__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+    impl __CcTemplateInst10MyTemplateIdE {
+        /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
+        #[inline(always)]
+        pub unsafe fn processT(__this: *mut Self, t: f64) {
+            crate::detail::__rust_thunk___ZN10MyTemplateIdE8processTEd__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+        }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-#[derive(Clone, Copy)]
-#[repr(C, align(4))]
-pub struct __CcTemplateInst10MyTemplateIfE {
-    __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
-    /// Reason for representing this field as a blob of bytes:
-    /// Types of non-public C++ fields can be elided away
-    pub(crate) t: [::std::mem::MaybeUninit<u8>; 4],
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("MyTemplate<float>"),
-    crate::__CcTemplateInst10MyTemplateIfE
-);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    #[derive(Clone, Copy)]
+    #[repr(C, align(4))]
+    pub struct __CcTemplateInst10MyTemplateIfE {
+        __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
+        /// Reason for representing this field as a blob of bytes:
+        /// Types of non-public C++ fields can be elided away
+        pub(crate) t: [::std::mem::MaybeUninit<u8>; 4],
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("MyTemplate<float>"),
+        crate::__instantiations::__CcTemplateInst10MyTemplateIfE
+    );
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<float>::MyTemplate<float>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<float>::MyTemplate<float>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<float>::MyTemplate<float>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<float>::MyTemplate<float>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<float>::MyTemplate':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<float> &&': Unsupported type: && without lifetime
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<float>::MyTemplate':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<float> &&': Unsupported type: && without lifetime
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<float>::operator=':
-// `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<float>::operator=':
+    // `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<float>::operator=':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<float> &&': Unsupported type: && without lifetime
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<float>::operator=':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<float> &&': Unsupported type: && without lifetime
 
-impl __CcTemplateInst10MyTemplateIfE {
-    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
-    #[inline(always)]
-    pub unsafe fn processT(__this: *mut Self, t: f32) {
-        crate::detail::__rust_thunk___ZN10MyTemplateIfE8processTEf__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+    impl __CcTemplateInst10MyTemplateIfE {
+        /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
+        #[inline(always)]
+        pub unsafe fn processT(__this: *mut Self, t: f32) {
+            crate::detail::__rust_thunk___ZN10MyTemplateIfE8processTEf__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+        }
     }
-}
 
-/// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-#[derive(Clone, Copy)]
-#[repr(C, align(4))]
-pub struct __CcTemplateInst10MyTemplateIiE {
-    __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
-    /// Reason for representing this field as a blob of bytes:
-    /// Types of non-public C++ fields can be elided away
-    pub(crate) t: [::std::mem::MaybeUninit<u8>; 4],
-}
-forward_declare::unsafe_define!(
-    forward_declare::symbol!("MyTemplate<int>"),
-    crate::__CcTemplateInst10MyTemplateIiE
-);
+    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    #[derive(Clone, Copy)]
+    #[repr(C, align(4))]
+    pub struct __CcTemplateInst10MyTemplateIiE {
+        __non_field_data: [::std::mem::MaybeUninit<u8>; 0],
+        /// Reason for representing this field as a blob of bytes:
+        /// Types of non-public C++ fields can be elided away
+        pub(crate) t: [::std::mem::MaybeUninit<u8>; 4],
+    }
+    forward_declare::unsafe_define!(
+        forward_declare::symbol!("MyTemplate<int>"),
+        crate::__instantiations::__CcTemplateInst10MyTemplateIiE
+    );
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<int>::MyTemplate<int>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<int>::MyTemplate<int>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<int>::MyTemplate<int>':
-// Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<int>::MyTemplate<int>':
+    // Unsafe constructors (e.g. with no elided or explicit lifetimes) are intentionally not supported
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<int>::MyTemplate':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<int> &&': Unsupported type: && without lifetime
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<int>::MyTemplate':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<int> &&': Unsupported type: && without lifetime
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<int>::operator=':
-// `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<int>::operator=':
+    // `self` has no lifetime. Use lifetime annotations or `#pragma clang lifetime_elision` to create bindings for this function.
 
-// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
-// Error while generating bindings for item 'MyTemplate<int>::operator=':
-// Parameter #0 is not supported: Unsupported type 'MyTemplate<int> &&': Unsupported type: && without lifetime
+    // Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=9
+    // Error while generating bindings for item 'MyTemplate<int>::operator=':
+    // Parameter #0 is not supported: Unsupported type 'MyTemplate<int> &&': Unsupported type: && without lifetime
 
-impl __CcTemplateInst10MyTemplateIiE {
-    /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
-    #[inline(always)]
-    pub unsafe fn processT(__this: *mut Self, t: i32) {
-        crate::detail::__rust_thunk___ZN10MyTemplateIiE8processTEi__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+    impl __CcTemplateInst10MyTemplateIiE {
+        /// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
+        #[inline(always)]
+        pub unsafe fn processT(__this: *mut Self, t: i32) {
+            crate::detail::__rust_thunk___ZN10MyTemplateIiE8processTEi__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(__this,t)
+        }
     }
 }
 
@@ -499,39 +506,39 @@ mod detail {
     use super::*;
     extern "C" {
         pub(crate) fn __rust_thunk___ZN10MyTemplateI8TopLevelE8processTES0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
-            __this: *mut crate::__CcTemplateInst10MyTemplateI8TopLevelE,
+            __this: *mut crate::__instantiations::__CcTemplateInst10MyTemplateI8TopLevelE,
             t: crate::TopLevel,
         );
         pub(crate) fn __rust_thunk___ZN10MyTemplateIN23test_namespace_bindings5InnerEE8processTES1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
-            __this: *mut crate::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE,
+            __this: *mut crate::__instantiations::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE,
             t: crate::test_namespace_bindings::Inner,
         );
         pub(crate) fn __rust_thunk___ZN10MyTemplateIS_I8TopLevelEE8processTES1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
-            __this: *mut crate::__CcTemplateInst10MyTemplateIS_I8TopLevelEE,
-            t: crate::__CcTemplateInst10MyTemplateI8TopLevelE,
+            __this: *mut crate::__instantiations::__CcTemplateInst10MyTemplateIS_I8TopLevelEE,
+            t: crate::__instantiations::__CcTemplateInst10MyTemplateI8TopLevelE,
         );
         pub(crate) fn __rust_thunk___ZN10MyTemplateIS_IN23test_namespace_bindings5InnerEEE8processTES2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
-            __this: *mut crate::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE,
-            t: crate::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE,
+            __this: *mut crate::__instantiations::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE,
+            t: crate::__instantiations::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE,
         );
         pub(crate) fn __rust_thunk___ZN10MyTemplateIbE8processTEb__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
-            __this: *mut crate::__CcTemplateInst10MyTemplateIbE,
+            __this: *mut crate::__instantiations::__CcTemplateInst10MyTemplateIbE,
             t: bool,
         );
         pub(crate) fn __rust_thunk___ZN10MyTemplateIcE8processTEc__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
-            __this: *mut crate::__CcTemplateInst10MyTemplateIcE,
+            __this: *mut crate::__instantiations::__CcTemplateInst10MyTemplateIcE,
             t: u8,
         );
         pub(crate) fn __rust_thunk___ZN10MyTemplateIdE8processTEd__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
-            __this: *mut crate::__CcTemplateInst10MyTemplateIdE,
+            __this: *mut crate::__instantiations::__CcTemplateInst10MyTemplateIdE,
             t: f64,
         );
         pub(crate) fn __rust_thunk___ZN10MyTemplateIfE8processTEf__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
-            __this: *mut crate::__CcTemplateInst10MyTemplateIfE,
+            __this: *mut crate::__instantiations::__CcTemplateInst10MyTemplateIfE,
             t: f32,
         );
         pub(crate) fn __rust_thunk___ZN10MyTemplateIiE8processTEi__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
-            __this: *mut crate::__CcTemplateInst10MyTemplateIiE,
+            __this: *mut crate::__instantiations::__CcTemplateInst10MyTemplateIiE,
             t: i32,
         );
     }
@@ -563,161 +570,198 @@ const _: () = {
     static_assertions::assert_not_impl_any!(crate::test_namespace_bindings::Inner: Drop);
 };
 
-const _: () = assert!(::std::mem::size_of::<crate::__CcTemplateInst10MyTemplateI8TopLevelE>() == 1);
-const _: () =
-    assert!(::std::mem::align_of::<crate::__CcTemplateInst10MyTemplateI8TopLevelE>() == 1);
+const _: () = assert!(
+    ::std::mem::size_of::<crate::__instantiations::__CcTemplateInst10MyTemplateI8TopLevelE>() == 1
+);
+const _: () = assert!(
+    ::std::mem::align_of::<crate::__instantiations::__CcTemplateInst10MyTemplateI8TopLevelE>() == 1
+);
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateI8TopLevelE: Clone);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateI8TopLevelE: Clone);
 };
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateI8TopLevelE: Copy);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateI8TopLevelE: Copy);
 };
 const _: () = {
-    static_assertions::assert_not_impl_any!(crate::__CcTemplateInst10MyTemplateI8TopLevelE: Drop);
+    static_assertions::assert_not_impl_any!(crate::__instantiations::__CcTemplateInst10MyTemplateI8TopLevelE: Drop);
 };
-const _: () =
-    assert!(memoffset::offset_of!(crate::__CcTemplateInst10MyTemplateI8TopLevelE, t) == 0);
+const _: () = assert!(
+    memoffset::offset_of!(crate::__instantiations::__CcTemplateInst10MyTemplateI8TopLevelE, t) == 0
+);
 
 const _: () = assert!(
-    ::std::mem::size_of::<crate::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE>()
-        == 1
+    ::std::mem::size_of::<
+        crate::__instantiations::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE,
+    >() == 1
 );
 const _: () = assert!(
-    ::std::mem::align_of::<crate::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE>(
-    ) == 1
+    ::std::mem::align_of::<
+        crate::__instantiations::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE,
+    >() == 1
 );
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE: Clone
+        crate::__instantiations::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE: Clone
     );
 };
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE: Copy
+        crate::__instantiations::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE: Copy
     );
 };
 const _: () = {
     static_assertions::assert_not_impl_any!(
-        crate::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE: Drop
+        crate::__instantiations::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE: Drop
     );
 };
 const _: () = assert!(
     memoffset::offset_of!(
-        crate::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE,
+        crate::__instantiations::__CcTemplateInst10MyTemplateIN23test_namespace_bindings5InnerEE,
         t
     ) == 0
 );
 
-const _: () =
-    assert!(::std::mem::size_of::<crate::__CcTemplateInst10MyTemplateIS_I8TopLevelEE>() == 1);
-const _: () =
-    assert!(::std::mem::align_of::<crate::__CcTemplateInst10MyTemplateIS_I8TopLevelEE>() == 1);
+const _: () = assert!(
+    ::std::mem::size_of::<crate::__instantiations::__CcTemplateInst10MyTemplateIS_I8TopLevelEE>()
+        == 1
+);
+const _: () = assert!(
+    ::std::mem::align_of::<crate::__instantiations::__CcTemplateInst10MyTemplateIS_I8TopLevelEE>()
+        == 1
+);
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateIS_I8TopLevelEE: Clone);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateIS_I8TopLevelEE: Clone);
 };
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateIS_I8TopLevelEE: Copy);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateIS_I8TopLevelEE: Copy);
 };
 const _: () = {
     static_assertions::assert_not_impl_any!(
-        crate::__CcTemplateInst10MyTemplateIS_I8TopLevelEE: Drop
+        crate::__instantiations::__CcTemplateInst10MyTemplateIS_I8TopLevelEE: Drop
     );
 };
-const _: () =
-    assert!(memoffset::offset_of!(crate::__CcTemplateInst10MyTemplateIS_I8TopLevelEE, t) == 0);
+const _: () = assert!(
+    memoffset::offset_of!(crate::__instantiations::__CcTemplateInst10MyTemplateIS_I8TopLevelEE, t)
+        == 0
+);
 
 const _: () = assert!(
-    ::std::mem::size_of::<crate::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE>(
+    ::std::mem::size_of::<crate::__instantiations::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE>(
     ) == 1
 );
 const _: () = assert!(
     ::std::mem::align_of::<
-        crate::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE,
+        crate::__instantiations::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE,
     >() == 1
 );
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE: Clone
+        crate::__instantiations::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE: Clone
     );
 };
 const _: () = {
     static_assertions::assert_impl_all!(
-        crate::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE: Copy
+        crate::__instantiations::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE: Copy
     );
 };
 const _: () = {
     static_assertions::assert_not_impl_any!(
-        crate::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE: Drop
+        crate::__instantiations::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE: Drop
     );
 };
 const _: () = assert!(
     memoffset::offset_of!(
-        crate::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE,
+        crate::__instantiations::__CcTemplateInst10MyTemplateIS_IN23test_namespace_bindings5InnerEEE,
         t
     ) == 0
 );
 
-const _: () = assert!(::std::mem::size_of::<crate::__CcTemplateInst10MyTemplateIbE>() == 1);
-const _: () = assert!(::std::mem::align_of::<crate::__CcTemplateInst10MyTemplateIbE>() == 1);
+const _: () =
+    assert!(::std::mem::size_of::<crate::__instantiations::__CcTemplateInst10MyTemplateIbE>() == 1);
+const _: () = assert!(
+    ::std::mem::align_of::<crate::__instantiations::__CcTemplateInst10MyTemplateIbE>() == 1
+);
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateIbE: Clone);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateIbE: Clone);
 };
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateIbE: Copy);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateIbE: Copy);
 };
 const _: () = {
-    static_assertions::assert_not_impl_any!(crate::__CcTemplateInst10MyTemplateIbE: Drop);
+    static_assertions::assert_not_impl_any!(crate::__instantiations::__CcTemplateInst10MyTemplateIbE: Drop);
 };
-const _: () = assert!(memoffset::offset_of!(crate::__CcTemplateInst10MyTemplateIbE, t) == 0);
+const _: () = assert!(
+    memoffset::offset_of!(crate::__instantiations::__CcTemplateInst10MyTemplateIbE, t) == 0
+);
 
-const _: () = assert!(::std::mem::size_of::<crate::__CcTemplateInst10MyTemplateIcE>() == 1);
-const _: () = assert!(::std::mem::align_of::<crate::__CcTemplateInst10MyTemplateIcE>() == 1);
+const _: () =
+    assert!(::std::mem::size_of::<crate::__instantiations::__CcTemplateInst10MyTemplateIcE>() == 1);
+const _: () = assert!(
+    ::std::mem::align_of::<crate::__instantiations::__CcTemplateInst10MyTemplateIcE>() == 1
+);
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateIcE: Clone);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateIcE: Clone);
 };
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateIcE: Copy);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateIcE: Copy);
 };
 const _: () = {
-    static_assertions::assert_not_impl_any!(crate::__CcTemplateInst10MyTemplateIcE: Drop);
+    static_assertions::assert_not_impl_any!(crate::__instantiations::__CcTemplateInst10MyTemplateIcE: Drop);
 };
-const _: () = assert!(memoffset::offset_of!(crate::__CcTemplateInst10MyTemplateIcE, t) == 0);
+const _: () = assert!(
+    memoffset::offset_of!(crate::__instantiations::__CcTemplateInst10MyTemplateIcE, t) == 0
+);
 
-const _: () = assert!(::std::mem::size_of::<crate::__CcTemplateInst10MyTemplateIdE>() == 8);
-const _: () = assert!(::std::mem::align_of::<crate::__CcTemplateInst10MyTemplateIdE>() == 8);
+const _: () =
+    assert!(::std::mem::size_of::<crate::__instantiations::__CcTemplateInst10MyTemplateIdE>() == 8);
+const _: () = assert!(
+    ::std::mem::align_of::<crate::__instantiations::__CcTemplateInst10MyTemplateIdE>() == 8
+);
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateIdE: Clone);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateIdE: Clone);
 };
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateIdE: Copy);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateIdE: Copy);
 };
 const _: () = {
-    static_assertions::assert_not_impl_any!(crate::__CcTemplateInst10MyTemplateIdE: Drop);
+    static_assertions::assert_not_impl_any!(crate::__instantiations::__CcTemplateInst10MyTemplateIdE: Drop);
 };
-const _: () = assert!(memoffset::offset_of!(crate::__CcTemplateInst10MyTemplateIdE, t) == 0);
+const _: () = assert!(
+    memoffset::offset_of!(crate::__instantiations::__CcTemplateInst10MyTemplateIdE, t) == 0
+);
 
-const _: () = assert!(::std::mem::size_of::<crate::__CcTemplateInst10MyTemplateIfE>() == 4);
-const _: () = assert!(::std::mem::align_of::<crate::__CcTemplateInst10MyTemplateIfE>() == 4);
+const _: () =
+    assert!(::std::mem::size_of::<crate::__instantiations::__CcTemplateInst10MyTemplateIfE>() == 4);
+const _: () = assert!(
+    ::std::mem::align_of::<crate::__instantiations::__CcTemplateInst10MyTemplateIfE>() == 4
+);
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateIfE: Clone);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateIfE: Clone);
 };
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateIfE: Copy);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateIfE: Copy);
 };
 const _: () = {
-    static_assertions::assert_not_impl_any!(crate::__CcTemplateInst10MyTemplateIfE: Drop);
+    static_assertions::assert_not_impl_any!(crate::__instantiations::__CcTemplateInst10MyTemplateIfE: Drop);
 };
-const _: () = assert!(memoffset::offset_of!(crate::__CcTemplateInst10MyTemplateIfE, t) == 0);
+const _: () = assert!(
+    memoffset::offset_of!(crate::__instantiations::__CcTemplateInst10MyTemplateIfE, t) == 0
+);
 
-const _: () = assert!(::std::mem::size_of::<crate::__CcTemplateInst10MyTemplateIiE>() == 4);
-const _: () = assert!(::std::mem::align_of::<crate::__CcTemplateInst10MyTemplateIiE>() == 4);
+const _: () =
+    assert!(::std::mem::size_of::<crate::__instantiations::__CcTemplateInst10MyTemplateIiE>() == 4);
+const _: () = assert!(
+    ::std::mem::align_of::<crate::__instantiations::__CcTemplateInst10MyTemplateIiE>() == 4
+);
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateIiE: Clone);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateIiE: Clone);
 };
 const _: () = {
-    static_assertions::assert_impl_all!(crate::__CcTemplateInst10MyTemplateIiE: Copy);
+    static_assertions::assert_impl_all!(crate::__instantiations::__CcTemplateInst10MyTemplateIiE: Copy);
 };
 const _: () = {
-    static_assertions::assert_not_impl_any!(crate::__CcTemplateInst10MyTemplateIiE: Drop);
+    static_assertions::assert_not_impl_any!(crate::__instantiations::__CcTemplateInst10MyTemplateIiE: Drop);
 };
-const _: () = assert!(memoffset::offset_of!(crate::__CcTemplateInst10MyTemplateIiE, t) == 0);
+const _: () = assert!(
+    memoffset::offset_of!(crate::__instantiations::__CcTemplateInst10MyTemplateIiE, t) == 0
+);
@@ -16,49 +16,67 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
 extern "C" void
 __rust_thunk___ZN10MyTemplateI8TopLevelE8processTES0___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
     class MyTemplate<TopLevel>* __this, struct TopLevel t) {
   __this->processT(t);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
 extern "C" void
 __rust_thunk___ZN10MyTemplateIN23test_namespace_bindings5InnerEE8processTES1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
     class MyTemplate<test_namespace_bindings::Inner>* __this,
     struct test_namespace_bindings::Inner t) {
   __this->processT(t);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
 extern "C" void
 __rust_thunk___ZN10MyTemplateIS_I8TopLevelEE8processTES1___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
     class MyTemplate<MyTemplate<TopLevel>>* __this,
     class MyTemplate<TopLevel> t) {
   __this->processT(t);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
 extern "C" void
 __rust_thunk___ZN10MyTemplateIS_IN23test_namespace_bindings5InnerEEE8processTES2___2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
     class MyTemplate<MyTemplate<test_namespace_bindings::Inner>>* __this,
     class MyTemplate<test_namespace_bindings::Inner> t) {
   __this->processT(t);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
 extern "C" void
 __rust_thunk___ZN10MyTemplateIbE8processTEb__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
     class MyTemplate<bool>* __this, bool t) {
   __this->processT(t);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
 extern "C" void
 __rust_thunk___ZN10MyTemplateIcE8processTEc__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
     class MyTemplate<char>* __this, char t) {
   __this->processT(t);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
 extern "C" void
 __rust_thunk___ZN10MyTemplateIdE8processTEd__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
     class MyTemplate<double>* __this, double t) {
   __this->processT(t);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
 extern "C" void
 __rust_thunk___ZN10MyTemplateIfE8processTEf__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
     class MyTemplate<float>* __this, float t) {
   __this->processT(t);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/templates_source_order.h;l=13
 extern "C" void
 __rust_thunk___ZN10MyTemplateIiE8processTEi__2f_2fthird_5fparty_2fcrubit_2frs_5fbindings_5ffrom_5fcc_2ftest_2fgolden_3atemplates_5fsource_5forder_5fcc(
     class MyTemplate<int>* __this, int t) {
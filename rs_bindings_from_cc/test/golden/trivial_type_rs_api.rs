@@ -210,6 +210,22 @@ pub mod ns {
         forward_declare::symbol!("TrivialNonfinal"),
         crate::ns::TrivialNonfinal
     );
+    impl TrivialNonfinal {
+        #[inline(always)]
+        pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+        where
+            Self: ::ctor::CtorNew<Args>,
+        {
+            <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+        }
+        #[inline(always)]
+        pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+        where
+            Self: ::ctor::CtorNew<Args>,
+        {
+            <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+        }
+    }
 
     /// Generated from: rs_bindings_from_cc/test/golden/trivial_type.h;l=41
     impl ::ctor::CtorNew<()> for TrivialNonfinal {
@@ -332,7 +348,7 @@ pub mod ns {
                 move |dest: ::std::pin::Pin<
                     &mut ::std::mem::MaybeUninit<crate::ns::TrivialNonfinal>,
                 >| {
-                    crate::detail::__rust_thunk___ZN2ns27TakesTrivialNonfinalByValueENS_15TrivialNonfinalE(::std::pin::Pin::into_inner_unchecked(dest),::std::pin::Pin::into_inner_unchecked(::ctor::emplace!(trivial)));
+                    ::std::pin::Pin::into_inner_unchecked(dest).write(crate::detail::__rust_thunk___ZN2ns27TakesTrivialNonfinalByValueENS_15TrivialNonfinalE(::std::pin::Pin::into_inner_unchecked(::ctor::emplace!(trivial))));
                 },
             )
         }
@@ -543,9 +559,8 @@ mod detail {
             trivial: crate::ns::TrivialWithDefaulted,
         ) -> crate::ns::TrivialWithDefaulted;
         pub(crate) fn __rust_thunk___ZN2ns27TakesTrivialNonfinalByValueENS_15TrivialNonfinalE(
-            __return: &mut ::std::mem::MaybeUninit<crate::ns::TrivialNonfinal>,
             trivial: &mut crate::ns::TrivialNonfinal,
-        );
+        ) -> crate::ns::TrivialNonfinal;
         #[link_name = "_ZN2ns16TakesByReferenceERNS_7TrivialE"]
         pub(crate) fn __rust_thunk___ZN2ns16TakesByReferenceERNS_7TrivialE<'a>(
             trivial: &'a mut crate::ns::Trivial,
@@ -7,6 +7,7 @@
 
 #include <cstddef>
 #include <memory>
+#include <type_traits>
 
 #include "support/internal/cxx20_backports.h"
 #include "support/internal/offsetof.h"
@@ -16,70 +17,99 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/trivial_type.h;l=13
 extern "C" void __rust_thunk___ZN2ns7TrivialC1Ev(struct ns::Trivial* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/trivial_type.h;l=13
 extern "C" void __rust_thunk___ZN2ns7TrivialC1EOS0_(
     struct ns::Trivial* __this, struct ns::Trivial* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/trivial_type.h;l=13
 extern "C" struct ns::Trivial* __rust_thunk___ZN2ns7TrivialaSERKS0_(
     struct ns::Trivial* __this, const struct ns::Trivial* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/trivial_type.h;l=13
 extern "C" struct ns::Trivial* __rust_thunk___ZN2ns7TrivialaSEOS0_(
     struct ns::Trivial* __this, struct ns::Trivial* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/trivial_type.h;l=20
 extern "C" void __rust_thunk___ZN2ns20TrivialWithDefaultedC1Ev(
     struct ns::TrivialWithDefaulted* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/trivial_type.h;l=23
 extern "C" struct ns::TrivialWithDefaulted*
 __rust_thunk___ZN2ns20TrivialWithDefaultedaSERKS0_(
     struct ns::TrivialWithDefaulted* __this,
     const struct ns::TrivialWithDefaulted* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/trivial_type.h;l=24
 extern "C" void __rust_thunk___ZN2ns20TrivialWithDefaultedC1EOS0_(
     struct ns::TrivialWithDefaulted* __this,
     struct ns::TrivialWithDefaulted* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/trivial_type.h;l=25
 extern "C" struct ns::TrivialWithDefaulted*
 __rust_thunk___ZN2ns20TrivialWithDefaultedaSEOS0_(
     struct ns::TrivialWithDefaulted* __this,
     struct ns::TrivialWithDefaulted* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/trivial_type.h;l=41
 extern "C" void __rust_thunk___ZN2ns15TrivialNonfinalC1Ev(
     struct ns::TrivialNonfinal* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/trivial_type.h;l=41
 extern "C" void __rust_thunk___ZN2ns15TrivialNonfinalC1ERKS0_(
     struct ns::TrivialNonfinal* __this,
     const struct ns::TrivialNonfinal* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/trivial_type.h;l=41
 extern "C" void __rust_thunk___ZN2ns15TrivialNonfinalC1EOS0_(
     struct ns::TrivialNonfinal* __this, struct ns::TrivialNonfinal* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/trivial_type.h;l=41
 extern "C" struct ns::TrivialNonfinal*
 __rust_thunk___ZN2ns15TrivialNonfinalaSERKS0_(
     struct ns::TrivialNonfinal* __this,
     const struct ns::TrivialNonfinal* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/trivial_type.h;l=41
 extern "C" struct ns::TrivialNonfinal*
 __rust_thunk___ZN2ns15TrivialNonfinalaSEOS0_(
     struct ns::TrivialNonfinal* __this, struct ns::TrivialNonfinal* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
-extern "C" void
+
+// Generated from: rs_bindings_from_cc/test/golden/trivial_type.h;l=47
+extern "C" struct ns::TrivialNonfinal
 __rust_thunk___ZN2ns27TakesTrivialNonfinalByValueENS_15TrivialNonfinalE(
-    struct ns::TrivialNonfinal* __return, struct ns::TrivialNonfinal* trivial) {
-  new (__return) auto(ns::TakesTrivialNonfinalByValue(std::move(*trivial)));
+    struct ns::TrivialNonfinal* trivial) {
+  static_assert(std::is_trivially_copyable_v<struct ns::TrivialNonfinal>);
+  return ns::TakesTrivialNonfinalByValue(std::move(*trivial));
 }
 
 static_assert(sizeof(struct ns::Trivial) == 4);
@@ -16,71 +16,107 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=10
 extern "C" void __rust_thunk___ZN10SomeStructC1Ev(struct SomeStruct* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=10
 extern "C" void __rust_thunk___ZN10SomeStructC1ERKS_(
     struct SomeStruct* __this, const struct SomeStruct* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=10
 extern "C" void __rust_thunk___ZN10SomeStructC1EOS_(
     struct SomeStruct* __this, struct SomeStruct* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=10
 extern "C" struct SomeStruct* __rust_thunk___ZN10SomeStructaSERKS_(
     struct SomeStruct* __this, const struct SomeStruct* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=10
 extern "C" struct SomeStruct* __rust_thunk___ZN10SomeStructaSEOS_(
     struct SomeStruct* __this, struct SomeStruct* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=15
 extern "C" void __rust_thunk___ZN15SomeOtherStructC1Ev(
     SomeOtherStruct* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=15
 extern "C" void __rust_thunk___ZN15SomeOtherStructC1ERKS_(
     SomeOtherStruct* __this, const SomeOtherStruct* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=15
 extern "C" void __rust_thunk___ZN15SomeOtherStructC1EOS_(
     SomeOtherStruct* __this, SomeOtherStruct* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=15
 extern "C" SomeOtherStruct* __rust_thunk___ZN15SomeOtherStructaSERKS_(
     SomeOtherStruct* __this, const SomeOtherStruct* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=15
 extern "C" SomeOtherStruct* __rust_thunk___ZN15SomeOtherStructaSEOS_(
     SomeOtherStruct* __this, SomeOtherStruct* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=18
 extern "C" void __rust_thunk___ZN9SomeUnionC1Ev(union SomeUnion* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=18
 extern "C" void __rust_thunk___ZN9SomeUnionC1EOS_(union SomeUnion* __this,
                                                   union SomeUnion* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=18
 extern "C" union SomeUnion* __rust_thunk___ZN9SomeUnionaSERKS_(
     union SomeUnion* __this, const union SomeUnion* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=18
 extern "C" union SomeUnion* __rust_thunk___ZN9SomeUnionaSEOS_(
     union SomeUnion* __this, union SomeUnion* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=21
 extern "C" void __rust_thunk___ZN14SomeOtherUnionC1Ev(SomeOtherUnion* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=21
 extern "C" void __rust_thunk___ZN14SomeOtherUnionC1EOS_(
     SomeOtherUnion* __this, SomeOtherUnion* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=21
 extern "C" SomeOtherUnion* __rust_thunk___ZN14SomeOtherUnionaSERKS_(
     SomeOtherUnion* __this, const SomeOtherUnion* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/typedefs.h;l=21
 extern "C" SomeOtherUnion* __rust_thunk___ZN14SomeOtherUnionaSEOS_(
     SomeOtherUnion* __this, SomeOtherUnion* __param_0) {
   return &__this->operator=(std::move(*__param_0));
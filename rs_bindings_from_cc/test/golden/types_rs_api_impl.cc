@@ -16,32 +16,48 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/types.h;l=15
 extern "C" void __rust_thunk___ZN10SomeStructC1Ev(struct SomeStruct* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/types.h;l=15
 extern "C" void __rust_thunk___ZN10SomeStructC1EOS_(
     struct SomeStruct* __this, struct SomeStruct* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/types.h;l=15
 extern "C" struct SomeStruct* __rust_thunk___ZN10SomeStructaSERKS_(
     struct SomeStruct* __this, const struct SomeStruct* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/types.h;l=15
 extern "C" struct SomeStruct* __rust_thunk___ZN10SomeStructaSEOS_(
     struct SomeStruct* __this, struct SomeStruct* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/types.h;l=19
 extern "C" void __rust_thunk___ZN19FieldTypeTestStructC1EOS_(
     struct FieldTypeTestStruct* __this, struct FieldTypeTestStruct* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/types.h;l=67
 extern "C" void __rust_thunk___Z21VoidReturningFunctionv() {
   VoidReturningFunction();
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/types.h;l=73
 extern "C" crubit::type_identity_t<int&(int const&, int*)>*
 __rust_thunk___Z32FunctionPointerReturningFunctionv() {
   return FunctionPointerReturningFunction();
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/types.h;l=77
 extern "C" void* __rust_thunk___Z24FunctionWithVoidPointersPvPKv(
     void* __param_0, void const* __param_1) {
   return FunctionWithVoidPointers(__param_0, __param_1);
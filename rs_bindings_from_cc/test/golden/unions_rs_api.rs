@@ -77,6 +77,22 @@ pub struct Nontrivial {
     pub field: i32,
 }
 forward_declare::unsafe_define!(forward_declare::symbol!("Nontrivial"), crate::Nontrivial);
+impl Nontrivial {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=13
 impl ::ctor::CtorNew<()> for Nontrivial {
@@ -129,6 +145,22 @@ forward_declare::unsafe_define!(
     forward_declare::symbol!("TriviallyCopyableButNontriviallyDestructible"),
     crate::TriviallyCopyableButNontriviallyDestructible
 );
+impl TriviallyCopyableButNontriviallyDestructible {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=19
 impl<'b> ::ctor::Assign<&'b Self> for TriviallyCopyableButNontriviallyDestructible {
@@ -281,9 +313,7 @@ impl<'b> ::ctor::Assign<::ctor::RvalueReference<'b, Self>> for NonCopyUnion2 {
 #[derive(Clone, Copy)]
 #[repr(C)]
 pub union UnionWithOpaqueField {
-    /// Reason for representing this field as a blob of bytes:
-    /// Unsupported type 'char[42]': Unsupported clang::Type class 'ConstantArray'
-    pub(crate) constant_array_field_not_yet_supported: [::std::mem::MaybeUninit<u8>; 42],
+    pub constant_array_field_not_yet_supported: [u8; 42],
 }
 forward_declare::unsafe_define!(
     forward_declare::symbol!("UnionWithOpaqueField"),
@@ -344,6 +374,22 @@ forward_declare::unsafe_define!(
     forward_declare::symbol!("TrivialButInheritable"),
     crate::TrivialButInheritable
 );
+impl TrivialButInheritable {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=46
 impl ::ctor::CtorNew<()> for TrivialButInheritable {
@@ -444,6 +490,22 @@ forward_declare::unsafe_define!(
     forward_declare::symbol!("UnionWithInheritable"),
     crate::UnionWithInheritable
 );
+impl UnionWithInheritable {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=50
 impl ::ctor::CtorNew<()> for UnionWithInheritable {
@@ -596,6 +658,22 @@ forward_declare::unsafe_define!(
     forward_declare::symbol!("TypedefUnionWithInheritable"),
     crate::TypedefUnionWithInheritable
 );
+impl TypedefUnionWithInheritable {
+    #[inline(always)]
+    pub fn new<Args>(args: Args) -> <Self as ::ctor::CtorNew<Args>>::CtorType
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <Self as ::ctor::CtorNew<Args>>::ctor_new(args)
+    }
+    #[inline(always)]
+    pub fn boxed_new<Args>(args: Args) -> ::std::pin::Pin<::std::boxed::Box<Self>>
+    where
+        Self: ::ctor::CtorNew<Args>,
+    {
+        <::std::boxed::Box<Self> as ::ctor::Emplace<Self>>::emplace(Self::new(args))
+    }
+}
 
 /// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=58
 impl ::ctor::CtorNew<()> for TypedefUnionWithInheritable {
@@ -944,6 +1022,9 @@ const _: () = {
 const _: () = {
     static_assertions::assert_not_impl_any!(crate::UnionWithOpaqueField: Drop);
 };
+const _: () = {
+    static_assertions::assert_impl_all!([u8; 42]: Copy);
+};
 
 const _: () = assert!(::std::mem::size_of::<crate::TrivialButInheritable>() == 4);
 const _: () = assert!(::std::mem::align_of::<crate::TrivialButInheritable>() == 4);
@@ -16,166 +16,238 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=10
 extern "C" void __rust_thunk___ZN10EmptyUnionC1Ev(union EmptyUnion* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=10
 extern "C" void __rust_thunk___ZN10EmptyUnionC1EOS_(
     union EmptyUnion* __this, union EmptyUnion* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=10
 extern "C" union EmptyUnion* __rust_thunk___ZN10EmptyUnionaSERKS_(
     union EmptyUnion* __this, const union EmptyUnion* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=10
 extern "C" union EmptyUnion* __rust_thunk___ZN10EmptyUnionaSEOS_(
     union EmptyUnion* __this, union EmptyUnion* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=19
 extern "C" struct TriviallyCopyableButNontriviallyDestructible*
 __rust_thunk___ZN44TriviallyCopyableButNontriviallyDestructibleaSERKS_(
     struct TriviallyCopyableButNontriviallyDestructible* __this,
     const struct TriviallyCopyableButNontriviallyDestructible* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=20
 extern "C" void
 __rust_thunk___ZN44TriviallyCopyableButNontriviallyDestructibleC1ERKS_(
     struct TriviallyCopyableButNontriviallyDestructible* __this,
     const struct TriviallyCopyableButNontriviallyDestructible* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=22
 extern "C" void
 __rust_thunk___ZN44TriviallyCopyableButNontriviallyDestructibleD1Ev(
     struct TriviallyCopyableButNontriviallyDestructible* __this) {
   std::destroy_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=25
 extern "C" void __rust_thunk___ZN13NonEmptyUnionC1Ev(
     union NonEmptyUnion* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=25
 extern "C" void __rust_thunk___ZN13NonEmptyUnionC1EOS_(
     union NonEmptyUnion* __this, union NonEmptyUnion* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=25
 extern "C" union NonEmptyUnion* __rust_thunk___ZN13NonEmptyUnionaSERKS_(
     union NonEmptyUnion* __this, const union NonEmptyUnion* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=25
 extern "C" union NonEmptyUnion* __rust_thunk___ZN13NonEmptyUnionaSEOS_(
     union NonEmptyUnion* __this, union NonEmptyUnion* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=37
 extern "C" union NonCopyUnion2* __rust_thunk___ZN13NonCopyUnion2aSERKS_(
     union NonCopyUnion2* __this, const union NonCopyUnion2* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=37
 extern "C" union NonCopyUnion2* __rust_thunk___ZN13NonCopyUnion2aSEOS_(
     union NonCopyUnion2* __this, union NonCopyUnion2* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=42
 extern "C" void __rust_thunk___ZN20UnionWithOpaqueFieldC1Ev(
     union UnionWithOpaqueField* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=42
 extern "C" void __rust_thunk___ZN20UnionWithOpaqueFieldC1EOS_(
     union UnionWithOpaqueField* __this, union UnionWithOpaqueField* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=42
 extern "C" union UnionWithOpaqueField*
 __rust_thunk___ZN20UnionWithOpaqueFieldaSERKS_(
     union UnionWithOpaqueField* __this,
     const union UnionWithOpaqueField* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=42
 extern "C" union UnionWithOpaqueField*
 __rust_thunk___ZN20UnionWithOpaqueFieldaSEOS_(
     union UnionWithOpaqueField* __this, union UnionWithOpaqueField* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=46
 extern "C" void __rust_thunk___ZN21TrivialButInheritableC1Ev(
     struct TrivialButInheritable* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=46
 extern "C" void __rust_thunk___ZN21TrivialButInheritableC1ERKS_(
     struct TrivialButInheritable* __this,
     const struct TrivialButInheritable* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=46
 extern "C" void __rust_thunk___ZN21TrivialButInheritableC1EOS_(
     struct TrivialButInheritable* __this,
     struct TrivialButInheritable* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=46
 extern "C" struct TrivialButInheritable*
 __rust_thunk___ZN21TrivialButInheritableaSERKS_(
     struct TrivialButInheritable* __this,
     const struct TrivialButInheritable* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=46
 extern "C" struct TrivialButInheritable*
 __rust_thunk___ZN21TrivialButInheritableaSEOS_(
     struct TrivialButInheritable* __this,
     struct TrivialButInheritable* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=50
 extern "C" void __rust_thunk___ZN20UnionWithInheritableC1Ev(
     union UnionWithInheritable* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=50
 extern "C" void __rust_thunk___ZN20UnionWithInheritableC1ERKS_(
     union UnionWithInheritable* __this,
     const union UnionWithInheritable* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=50
 extern "C" void __rust_thunk___ZN20UnionWithInheritableC1EOS_(
     union UnionWithInheritable* __this, union UnionWithInheritable* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=50
 extern "C" union UnionWithInheritable*
 __rust_thunk___ZN20UnionWithInheritableaSERKS_(
     union UnionWithInheritable* __this,
     const union UnionWithInheritable* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=50
 extern "C" union UnionWithInheritable*
 __rust_thunk___ZN20UnionWithInheritableaSEOS_(
     union UnionWithInheritable* __this, union UnionWithInheritable* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=54
 extern "C" void __rust_thunk___ZN12TypedefUnionC1Ev(TypedefUnion* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=54
 extern "C" void __rust_thunk___ZN12TypedefUnionC1EOS_(TypedefUnion* __this,
                                                       TypedefUnion* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=54
 extern "C" TypedefUnion* __rust_thunk___ZN12TypedefUnionaSERKS_(
     TypedefUnion* __this, const TypedefUnion* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=54
 extern "C" TypedefUnion* __rust_thunk___ZN12TypedefUnionaSEOS_(
     TypedefUnion* __this, TypedefUnion* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=58
 extern "C" void __rust_thunk___ZN27TypedefUnionWithInheritableC1Ev(
     TypedefUnionWithInheritable* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=58
 extern "C" void __rust_thunk___ZN27TypedefUnionWithInheritableC1ERKS_(
     TypedefUnionWithInheritable* __this,
     const TypedefUnionWithInheritable* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=58
 extern "C" void __rust_thunk___ZN27TypedefUnionWithInheritableC1EOS_(
     TypedefUnionWithInheritable* __this,
     TypedefUnionWithInheritable* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=58
 extern "C" TypedefUnionWithInheritable*
 __rust_thunk___ZN27TypedefUnionWithInheritableaSERKS_(
     TypedefUnionWithInheritable* __this,
     const TypedefUnionWithInheritable* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unions.h;l=58
 extern "C" TypedefUnionWithInheritable*
 __rust_thunk___ZN27TypedefUnionWithInheritableaSEOS_(
     TypedefUnionWithInheritable* __this,
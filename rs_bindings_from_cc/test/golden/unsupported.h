@@ -36,11 +36,14 @@ struct NontrivialCustomType final {
 volatile int* MultipleReasons(volatile int* n);
 
 struct ContainingStruct final {
+ private:
+  // No bindings are generated for private nested classes.
   struct NestedStruct final {
     void NonStaticMemberFunction();
     void StaticMemberFunction();
   };
 
+ public:
   // Doc comment for an unsupported field.
   NestedStruct nested_struct;
 };
@@ -16,36 +16,52 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/unsupported.h;l=10
 extern "C" void __rust_thunk___ZN17TrivialCustomTypeC1Ev(
     struct TrivialCustomType* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unsupported.h;l=10
 extern "C" void __rust_thunk___ZN17TrivialCustomTypeC1EOS_(
     struct TrivialCustomType* __this, struct TrivialCustomType* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unsupported.h;l=10
 extern "C" struct TrivialCustomType*
 __rust_thunk___ZN17TrivialCustomTypeaSERKS_(
     struct TrivialCustomType* __this,
     const struct TrivialCustomType* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unsupported.h;l=10
 extern "C" struct TrivialCustomType* __rust_thunk___ZN17TrivialCustomTypeaSEOS_(
     struct TrivialCustomType* __this, struct TrivialCustomType* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unsupported.h;l=38
 extern "C" void __rust_thunk___ZN16ContainingStructC1Ev(
     struct ContainingStruct* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unsupported.h;l=38
 extern "C" void __rust_thunk___ZN16ContainingStructC1EOS_(
     struct ContainingStruct* __this, struct ContainingStruct* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unsupported.h;l=38
 extern "C" struct ContainingStruct* __rust_thunk___ZN16ContainingStructaSERKS_(
     struct ContainingStruct* __this, const struct ContainingStruct* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/unsupported.h;l=38
 extern "C" struct ContainingStruct* __rust_thunk___ZN16ContainingStructaSEOS_(
     struct ContainingStruct* __this, struct ContainingStruct* __param_0) {
   return &__this->operator=(std::move(*__param_0));
@@ -16,41 +16,61 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/user_of_base_class.h;l=17
 extern "C" void __rust_thunk___ZN8Derived2C1Ev(struct Derived2* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/user_of_base_class.h;l=17
 extern "C" void __rust_thunk___ZN8Derived2C1ERKS_(
     struct Derived2* __this, const struct Derived2* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/user_of_base_class.h;l=17
 extern "C" void __rust_thunk___ZN8Derived2C1EOS_(struct Derived2* __this,
                                                  struct Derived2* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/user_of_base_class.h;l=17
 extern "C" struct Derived2* __rust_thunk___ZN8Derived2aSERKS_(
     struct Derived2* __this, const struct Derived2* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/user_of_base_class.h;l=17
 extern "C" struct Derived2* __rust_thunk___ZN8Derived2aSEOS_(
     struct Derived2* __this, struct Derived2* __param_0) {
   return &__this->operator=(std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/user_of_base_class.h;l=21
 extern "C" void __rust_thunk___ZN15VirtualDerived2C1Ev(
     class VirtualDerived2* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/user_of_base_class.h;l=21
 extern "C" void __rust_thunk___ZN15VirtualDerived2C1ERKS_(
     class VirtualDerived2* __this, const class VirtualDerived2* __param_0) {
   crubit::construct_at(__this, *__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/user_of_base_class.h;l=21
 extern "C" void __rust_thunk___ZN15VirtualDerived2C1EOS_(
     class VirtualDerived2* __this, class VirtualDerived2* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/user_of_base_class.h;l=21
 extern "C" class VirtualDerived2* __rust_thunk___ZN15VirtualDerived2aSERKS_(
     class VirtualDerived2* __this, const class VirtualDerived2* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/user_of_base_class.h;l=21
 extern "C" class VirtualDerived2* __rust_thunk___ZN15VirtualDerived2aSEOS_(
     class VirtualDerived2* __this, class VirtualDerived2* __param_0) {
   return &__this->operator=(std::move(*__param_0));
@@ -16,20 +16,28 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/user_of_imported_type.h;l=14
 extern "C" void __rust_thunk___ZN18UserOfImportedTypeC1Ev(
     struct UserOfImportedType* __this) {
   crubit::construct_at(__this);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/user_of_imported_type.h;l=14
 extern "C" void __rust_thunk___ZN18UserOfImportedTypeC1EOS_(
     struct UserOfImportedType* __this, struct UserOfImportedType* __param_0) {
   crubit::construct_at(__this, std::move(*__param_0));
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/user_of_imported_type.h;l=14
 extern "C" struct UserOfImportedType*
 __rust_thunk___ZN18UserOfImportedTypeaSERKS_(
     struct UserOfImportedType* __this,
     const struct UserOfImportedType* __param_0) {
   return &__this->operator=(*__param_0);
 }
+
+// Generated from: rs_bindings_from_cc/test/golden/user_of_imported_type.h;l=14
 extern "C" struct UserOfImportedType*
 __rust_thunk___ZN18UserOfImportedTypeaSEOS_(
     struct UserOfImportedType* __this, struct UserOfImportedType* __param_0) {
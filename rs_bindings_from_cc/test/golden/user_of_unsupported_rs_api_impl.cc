@@ -16,6 +16,8 @@
 
 #pragma clang diagnostic push
 #pragma clang diagnostic ignored "-Wthread-safety-analysis"
+
+// Generated from: rs_bindings_from_cc/test/golden/user_of_unsupported.h;l=12
 extern "C" void __rust_thunk___Z23UseNontrivialCustomType20NontrivialCustomType(
     struct NontrivialCustomType* non_trivial_custom_type) {
   UseNontrivialCustomType(std::move(*non_trivial_custom_type));
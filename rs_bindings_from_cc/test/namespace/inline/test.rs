@@ -16,11 +16,9 @@ mod tests {
 
         // Notably, the C++ standard library uses `inline` namespaces, but we
         // still want to be able to refer to `std::string`, rather than
-        // `std::__u::string`. Therefore the test verifies that the
-        // inner types and functions are also available in the parent
-        // namespace.
-        // `foo::MyStruct` should be a type alias for
-        // `foo::inline1::MyStruct`.
+        // `std::__u::string`. Therefore the inner types and functions are
+        // generated directly in the module of the parent namespace, and
+        // `foo::inline1` merely re-exports them.
         #[allow(unused_assignments)]
         let mut s2 = foo::MyStruct { value: 456 };
         s2 = s; // these are literally the same type.
@@ -0,0 +1,195 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+//! Translates the symbols of the C++ thunks generated by `rs_bindings_from_cc`
+//! back to the C++ functions they call, for tools like profilers and stack
+//! trace symbolizers, e.g. `__rust_thunk__ns_Foo_ctor___ZN2ns3FooC1Ei` to
+//! `ns::Foo::Foo(int)`.
+//!
+//! The signatures come from the table of the thunks of the bindings reports
+//! (see `--bindings_report_out`) of the targets generated with
+//! `--readable_thunk_names`:
+//!
+//! ```ignore
+//! let mut symbolizer = ThunkSymbolizer::default();
+//! symbolizer.add_bindings_report(&std::fs::read_to_string("foo_report.json")?)?;
+//! if let Some(thunk) = symbolizer.symbolize(symbol) {
+//!     println!("{} ({})", thunk.cc_signature, thunk.source_loc.unwrap_or_default());
+//! }
+//! ```
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// The prefixes of the symbols of the thunks (see `thunk_ident` and
+/// `result_deleter_ident` in `src_code_gen.rs`).
+const THUNK_PREFIXES: [&str; 2] = ["__rust_thunk__", "__crubit_result_deleter__"];
+
+/// The part of a bindings report used by the symbolizer.
+#[derive(Deserialize)]
+struct BindingsReport {
+    /// Empty unless the target was generated with `--readable_thunk_names`.
+    #[serde(default)]
+    thunks: Vec<ReportedThunk>,
+}
+
+#[derive(Deserialize)]
+struct ReportedThunk {
+    symbol: String,
+    cc_signature: String,
+    #[serde(default)]
+    source_loc: Option<String>,
+}
+
+/// The C++ function called by a thunk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThunkTarget {
+    /// The C++ signature of the function, e.g. `int ns::Foo::Get() const`.
+    pub cc_signature: String,
+    /// The source location of the function (e.g.
+    /// `Generated from: foo.h;l=42`), if known.
+    pub source_loc: Option<String>,
+}
+
+/// Maps the symbols of the thunks of the targets whose bindings reports were
+/// added to the C++ functions they call.
+#[derive(Clone, Debug, Default)]
+pub struct ThunkSymbolizer {
+    thunks: HashMap<String, ThunkTarget>,
+}
+
+impl ThunkSymbolizer {
+    /// Adds the thunks of the bindings report `json` (the contents of a
+    /// `--bindings_report_out` file).
+    pub fn add_bindings_report(&mut self, json: &str) -> Result<()> {
+        let report: BindingsReport =
+            serde_json::from_str(json).context("Failed to parse the bindings report")?;
+        for thunk in report.thunks {
+            self.thunks.insert(
+                thunk.symbol,
+                ThunkTarget { cc_signature: thunk.cc_signature, source_loc: thunk.source_loc },
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the C++ function called by the thunk `symbol`, or `None` if
+    /// `symbol` isn't a thunk of the added reports.
+    ///
+    /// The leading `_` that some platforms (e.g. macOS) add to the symbols is
+    /// ignored.
+    pub fn symbolize(&self, symbol: &str) -> Option<&ThunkTarget> {
+        self.thunks
+            .get(symbol)
+            .or_else(|| self.thunks.get(symbol.strip_prefix('_').unwrap_or(symbol)))
+    }
+
+    /// Returns a description of the thunk `symbol` for humans: the C++
+    /// signature of its function if `symbol` is in the added reports, and
+    /// otherwise its readable name (see `readable_thunk_name`), if any.
+    pub fn describe(&self, symbol: &str) -> Option<String> {
+        match self.symbolize(symbol) {
+            Some(thunk) => Some(format!("thunk for {}", thunk.cc_signature)),
+            None => readable_thunk_name(symbol).map(|name| format!("thunk for {name}")),
+        }
+    }
+}
+
+/// Returns the readable name of the C++ function at the beginning of the
+/// symbol of a thunk generated with `--readable_thunk_names` (e.g.
+/// `ns_Foo_ctor` for `__rust_thunk__ns_Foo_ctor___ZN2ns3FooC1Ei`), or `None`
+/// if `symbol` isn't such a thunk.
+///
+/// This doesn't need a bindings report, but it is a heuristic: the readable
+/// name doesn't start with `_` nor contain `__`, but the mangled names of the
+/// `extern "C"` functions don't start with `_` either (see
+/// `readable_thunk_name` in `src_code_gen.rs`).
+pub fn readable_thunk_name(symbol: &str) -> Option<&str> {
+    let rest = THUNK_PREFIXES.iter().find_map(|prefix| {
+        symbol.strip_prefix(prefix).or_else(|| symbol.strip_prefix('_')?.strip_prefix(prefix))
+    })?;
+    if rest.starts_with('_') {
+        return None;
+    }
+    let (readable_name, mangled_name) = rest.split_once("__")?;
+    (!mangled_name.is_empty()).then_some(readable_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPORT: &str = r#"{
+      "target": "//foo:bar",
+      "items": [],
+      "thunks": [
+        {
+          "symbol": "__rust_thunk__ns_Foo_ctor___ZN2ns3FooC1Ei",
+          "cc_signature": "ns::Foo::Foo(int)",
+          "source_loc": "Generated from: foo/bar.h;l=3"
+        }
+      ]
+    }"#;
+
+    #[test]
+    fn test_symbolize() -> Result<()> {
+        let mut symbolizer = ThunkSymbolizer::default();
+        symbolizer.add_bindings_report(REPORT)?;
+        let expected = ThunkTarget {
+            cc_signature: "ns::Foo::Foo(int)".to_string(),
+            source_loc: Some("Generated from: foo/bar.h;l=3".to_string()),
+        };
+        assert_eq!(
+            symbolizer.symbolize("__rust_thunk__ns_Foo_ctor___ZN2ns3FooC1Ei"),
+            Some(&expected)
+        );
+        assert_eq!(
+            symbolizer.symbolize("___rust_thunk__ns_Foo_ctor___ZN2ns3FooC1Ei"),
+            Some(&expected)
+        );
+        assert_eq!(symbolizer.symbolize("__rust_thunk__ns_Foo_dtor___ZN2ns3FooD1Ev"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_report_without_thunks() -> Result<()> {
+        let mut symbolizer = ThunkSymbolizer::default();
+        symbolizer.add_bindings_report(r#"{"target": "//foo:bar", "items": []}"#)?;
+        assert_eq!(symbolizer.symbolize("__rust_thunk__ns_Foo_ctor___ZN2ns3FooC1Ei"), None);
+        assert!(symbolizer.add_bindings_report("[").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() -> Result<()> {
+        let mut symbolizer = ThunkSymbolizer::default();
+        symbolizer.add_bindings_report(REPORT)?;
+        assert_eq!(
+            symbolizer.describe("__rust_thunk__ns_Foo_ctor___ZN2ns3FooC1Ei").as_deref(),
+            Some("thunk for ns::Foo::Foo(int)")
+        );
+        assert_eq!(
+            symbolizer.describe("__rust_thunk__ns_Foo_dtor___ZN2ns3FooD1Ev").as_deref(),
+            Some("thunk for ns_Foo_dtor")
+        );
+        assert_eq!(symbolizer.describe("__rust_thunk___ZN2ns3FooD1Ev"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_readable_thunk_name() {
+        assert_eq!(
+            readable_thunk_name("__rust_thunk__ns_Foo_Get___ZNK2ns3Foo3GetEv"),
+            Some("ns_Foo_Get")
+        );
+        assert_eq!(readable_thunk_name("___rust_thunk__Add___Z3Addii"), Some("Add"));
+        assert_eq!(readable_thunk_name("__crubit_result_deleter__Make___Z4Makev"), Some("Make"));
+        assert_eq!(readable_thunk_name("__rust_thunk__c_function__c_function"), Some("c_function"));
+        // Without `--readable_thunk_names`.
+        assert_eq!(readable_thunk_name("__rust_thunk___Z3Addii"), None);
+        assert_eq!(readable_thunk_name("__rust_thunk__c_function"), None);
+        assert_eq!(readable_thunk_name("_Z3Addii"), None);
+    }
+}
@@ -0,0 +1,302 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#ifndef CRUBIT_SUPPORT_ANNOTATIONS_H_
+#define CRUBIT_SUPPORT_ANNOTATIONS_H_
+
+// Annotations which C++ libraries can use to customize their Rust bindings.
+
+// Marks a class whose assignment operators may throw, and may leave the
+// assigned-to object in an invalid state when they do.
+//
+// The generated `Assign` / `UnpinAssign` implementations of such a class use
+// the copy-and-swap idiom instead of calling `operator=` on the object
+// directly: the assignment is performed on a copy, which is then swapped with
+// the object (using an unqualified `swap` call, so that a class-specific,
+// non-throwing `swap` is found by ADL). This preserves the strong exception
+// guarantee when exceptions are translated into Rust panics or `Result`s.
+//
+// Example:
+//
+//   class CRUBIT_THROWING_ASSIGNMENT Buffer {
+//    public:
+//     Buffer(const Buffer&);
+//     Buffer& operator=(const Buffer&);
+//     friend void swap(Buffer& a, Buffer& b) noexcept;
+//     ...
+//   };
+#define CRUBIT_THROWING_ASSIGNMENT \
+  [[clang::annotate("crubit_throwing_assignment")]]
+
+// Marks a trivially copyable class whose object representation can be
+// reinterpreted as bytes, e.g. to share it through shared memory or to send it
+// over the wire.
+//
+// The generated Rust struct of such a class gets `as_bytes()` and (unsafe)
+// `from_bytes()` methods. Bindings generation reports an error if the class is
+// not trivially copyable, and the generated bindings don't compile if the class
+// contains padding.
+//
+// Example:
+//
+//   struct CRUBIT_BYTES_SERIALIZABLE Header {
+//     int32_t length;
+//     int32_t checksum;
+//   };
+#define CRUBIT_BYTES_SERIALIZABLE \
+  [[clang::annotate("crubit_bytes_serializable")]]
+
+// Marks a function whose pointer parameters are never null, and are not
+// retained by the function after it returns.
+//
+// In addition to the `unsafe` binding taking raw pointers, such a function gets
+// a safe wrapper named `<function>_safe`, which takes `&T` / `&mut T`
+// references instead of `*const T` / `*mut T` pointers. Functions that can't be
+// annotated (e.g. because their header is not owned by the caller) can be
+// listed in the file passed to `--safe_pointer_params_allowlist` instead.
+//
+// Example:
+//
+//   CRUBIT_SAFE_POINTER_PARAMS void Normalize(Vector* v);
+#define CRUBIT_SAFE_POINTER_PARAMS \
+  [[clang::annotate("crubit_safe_pointer_params")]]
+
+// Memory-safety contracts of pointer parameters, which the generated Rust
+// bindings check with `debug_assert!`s before calling the C++ function, so that
+// contract violations are caught early during testing:
+//
+// * `CRUBIT_SIZED_BY(size)` marks a pointer to the first of `size` elements,
+//   where `size` is another (integer) parameter. The pointer may only be null
+//   if `size` is 0.
+// * `CRUBIT_ALIGNED_TO(alignment)` marks a pointer aligned to `alignment`
+//   bytes (a power of two).
+//
+// Pointers which must not be null are marked with the `_Nonnull` qualifier (or
+// `absl::Nonnull`).
+//
+// Example:
+//
+//   void Fill(float* _Nonnull data CRUBIT_SIZED_BY(size) CRUBIT_ALIGNED_TO(16),
+//             size_t size, float value);
+#define CRUBIT_SIZED_BY(size) [[clang::annotate("crubit_sized_by", #size)]]
+#define CRUBIT_ALIGNED_TO(alignment) \
+  [[clang::annotate("crubit_aligned_to", #alignment)]]
+
+// Marks a function which CHECK-fails (aborts the program) unless `condition`
+// holds. `condition` is a C++ boolean expression over the parameters of the
+// function.
+//
+// The generated Rust bindings are `#[track_caller]`, and evaluate the condition
+// before calling the C++ function, so that a violated precondition causes a
+// Rust panic at the location of the caller rather than an abort inside of C++.
+// The condition is only checked when this is cheap: for functions (and static
+// member functions) whose parameters are all `Copy` in Rust.
+//
+// Example:
+//
+//   CRUBIT_PRECONDITION(index >= 0 && index < size)
+//   int At(const int* data, int size, int index);
+#define CRUBIT_PRECONDITION(condition) \
+  [[clang::annotate("crubit_precondition", #condition)]]
+
+// Marks an enum whose values are error codes returned by functions, where the
+// enumerator with the value 0 denotes success.
+//
+// The generated Rust type of such an enum is `#[must_use]`, has `is_ok()` /
+// `is_err()` methods, and converts into a `Result<(), Enum>` (e.g.
+// `Result::from(code)?`). The error code types of a target are also
+// re-exported from its `errors` module, which additionally defines an `Error`
+// type unifying them with `CcException` when the bindings are generated with
+// `--exception_handling=result`.
+//
+// Example:
+//
+//   enum class CRUBIT_ERROR_CODE Status { kOk = 0, kNotFound, kInvalid };
+//   Status Lookup(int key, int* value);
+#define CRUBIT_ERROR_CODE [[clang::annotate("crubit_error_code")]]
+
+// Declares the lifetime parameters of a struct which borrows from other
+// objects through pointer or reference fields (e.g. a view into a buffer). The
+// lifetime arguments of such fields are given by `CRUBIT_MEMBER_LIFETIMES`,
+// with the lifetime of the outermost pointer last.
+//
+// The generated Rust struct has the same lifetime parameters, and the
+// annotated fields are references rather than raw pointers, so that the Rust
+// compiler checks that the struct doesn't outlive the objects it borrows from.
+// Functions taking or returning the struct only get bindings if the lifetimes
+// of their parameters are known (e.g. with `#pragma clang lifetime_elision`).
+//
+// Example:
+//
+//   struct CRUBIT_LIFETIME_PARAMS("a") IntView {
+//     CRUBIT_MEMBER_LIFETIMES("a") const int* data;
+//     int size;
+//   };
+//
+// is bound to `pub struct IntView<'a> { pub data: Option<&'a i32>, ... }`.
+#define CRUBIT_LIFETIME_PARAMS(...) \
+  [[clang::annotate("lifetime_params", __VA_ARGS__)]]
+#define CRUBIT_MEMBER_LIFETIMES(...) \
+  [[clang::annotate("member_lifetimes", __VA_ARGS__)]]
+
+// Marks who owns the pointer returned by a function:
+//
+// * `CRUBIT_OWNED_RESULT(deleter)`: the caller owns the returned object, and
+//   deletes it with `deleter` - either `delete`, or a (fully qualified)
+//   function taking the pointer. The generated Rust function returns an
+//   `Option<CcOwned<T>>` (see `support/cc_owned.rs`), which calls `deleter`
+//   when dropped. This requires the `bridging` Crubit feature.
+// * `CRUBIT_BORROWED_RESULT`: the returned object is borrowed from the
+//   parameters (e.g. from `this`), and the generated Rust function returns a
+//   reference. This requires the lifetimes of the function to be known (e.g.
+//   with `#pragma clang lifetime_elision`).
+//
+// Example:
+//
+//   CRUBIT_OWNED_RESULT(DestroyWidget) Widget* CreateWidget();
+//   void DestroyWidget(Widget* widget);
+//
+//   class WidgetRegistry {
+//    public:
+//     CRUBIT_BORROWED_RESULT Widget* Find(int id);
+//   };
+#define CRUBIT_OWNED_RESULT(deleter) \
+  [[clang::annotate("crubit_owned_result", #deleter)]]
+#define CRUBIT_BORROWED_RESULT [[clang::annotate("crubit_borrowed_result")]]
+
+// Marks a C-style callback parameter: a function pointer taking a `void*`
+// context, which is passed to the function as the `void*` parameter `context`.
+//
+// The generated Rust function additionally gets a `..._with_closure` wrapper,
+// which takes a Rust closure instead of the pair of parameters: the closure is
+// passed as the context, and called by a trampoline passed as the callback.
+// `CRUBIT_CALLBACK` closures may only be called until the function returns,
+// and are taken by `&mut dyn FnMut(...)`. `CRUBIT_RETAINED_CALLBACK` closures
+// may be called at any later time and from any thread, and are taken by
+// `Box<dyn FnMut(...) + Send + 'static>`, which is never dropped: the C API
+// has no way to release the context.
+//
+// Example:
+//
+//   void ForEachEntry(
+//       CRUBIT_CALLBACK(context) void (*visit)(void* context, int key),
+//       void* context);
+//
+// gets the wrapper `ForEachEntry_with_closure(visit: &mut dyn FnMut(i32))`.
+#define CRUBIT_CALLBACK(context) \
+  [[clang::annotate("crubit_callback", #context)]]
+#define CRUBIT_RETAINED_CALLBACK(context) \
+  [[clang::annotate("crubit_retained_callback", #context)]]
+
+// Marks a `std::function` parameter which the function only calls before it
+// returns, and never copies nor otherwise retains.
+//
+// The Rust bindings take the closures of such parameters by
+// `&mut dyn FnMut(...)`. Without the annotation, the function may retain the
+// `std::function` (even one taken by `const&`), and call it from any thread,
+// so the closures are taken by `Box<dyn FnMut(...) + Send + 'static>`.
+//
+// Example:
+//
+//   void ForEach(CRUBIT_BORROWED_CALLABLE const std::function<void(int)>& f);
+//
+// gets the binding `ForEach(f: &mut dyn FnMut(i32))`.
+#define CRUBIT_BORROWED_CALLABLE [[clang::annotate("crubit_borrowed_callable")]]
+
+// Marks a logging interface: a class with a public virtual method
+// `void(int severity, const char* file, int line, const char* message)`, where
+// `severity` is an `absl::LogSeverity`.
+//
+// The generated Rust struct of such a class gets a `rust_log_sink()` function,
+// which returns an implementation of the interface forwarding the messages to
+// the `log` crate (see `support/cc_log_bridge.rs`), so that the logs of C++
+// components accepting the interface integrate with the logs of the Rust
+// program. This requires the `bridging` Crubit feature.
+//
+// Example:
+//
+//   class CRUBIT_LOG_SINK LogSink {
+//    public:
+//     virtual ~LogSink() = default;
+//     virtual void Send(int severity, const char* file, int line,
+//                       const char* message) = 0;
+//   };
+//   void SetLogSink(LogSink* sink);
+#define CRUBIT_LOG_SINK [[clang::annotate("crubit_log_sink")]]
+
+// Marks a C++ type as the C++ bindings of an existing Rust type, given by its
+// fully-qualified path. `cc_bindings_from_rs` marks all the C++ structs it
+// generates this way, and the bindings generated by `rs_bindings_from_cc` use
+// the Rust type directly (instead of wrapping the C++ type into another Rust
+// struct), so the type keeps its identity when it crosses the language
+// boundary in both directions. The crate of the Rust type has to be a
+// dependency of the generated bindings.
+//
+// In the other direction, `cc_bindings_from_rs` recognizes the Rust structs
+// generated by `rs_bindings_from_cc` through their `forward_declare::CcType`
+// implementation, and uses the original C++ type.
+//
+// Example:
+//
+//   struct CRUBIT_INTERNAL_RUST_TYPE("::rust_crate::Point") Point final {
+//     ...
+//   };
+#define CRUBIT_INTERNAL_RUST_TYPE(path) \
+  [[clang::annotate("crubit_internal_rust_type", path)]]
+
+// Marks a function which may only be called in a certain calling context, e.g.
+// after a library was initialized, or on the UI thread. Each context is
+// represented by a capability token: a zero-sized Rust type, given by its
+// fully-qualified path, whose values can only be obtained while the capability
+// is held (e.g. from an initialization function, or from the event loop of the
+// UI thread).
+//
+// The generated Rust function takes a reference to a token of each required
+// capability (after the other parameters), so that calling the function
+// outside of its calling context is a compile error rather than a runtime bug.
+// The tokens aren't passed to C++. The crate of the token types has to be a
+// dependency of the generated bindings.
+//
+// Example:
+//
+//   // In Rust: `pub struct UiThread { _private: () }`.
+//   CRUBIT_REQUIRES_CAPABILITY("::ui::UiThread") void Repaint(int widget_id);
+//
+// is bound to `pub fn Repaint(widget_id: i32, __capability_0: &::ui::UiThread)`.
+#define CRUBIT_REQUIRES_CAPABILITY(...) \
+  [[clang::annotate("crubit_requires_capability", __VA_ARGS__)]]
+
+// Renames the Rust bindings of a function, struct, enum, type alias or
+// constant, e.g. to follow Rust naming conventions, or to avoid a conflict with
+// another item. Only the Rust name changes: the generated bindings still call
+// (or refer to) the C++ item by its C++ name. The name takes precedence over
+// `--snake_case_function_names` (e.g. to keep the C++ name of a function).
+//
+// Example:
+//
+//   CRUBIT_RUST_NAME("is_empty") bool IsEmpty(const Queue& queue);
+//
+// is bound to `pub fn is_empty(queue: &Queue) -> bool`.
+#define CRUBIT_RUST_NAME(name) [[clang::annotate("crubit_rust_name", name)]]
+
+// Excludes a function, struct, enum, type alias or constant from the Rust
+// bindings, e.g. because it is an implementation detail which Rust callers
+// shouldn't use. Other items which refer to a skipped type (e.g. functions
+// taking it as a parameter) don't get bindings either.
+//
+// Example:
+//
+//   CRUBIT_SKIP void InternalResetForTesting();
+#define CRUBIT_SKIP [[clang::annotate("crubit_skip")]]
+
+// Marks the Rust bindings of a function, struct, enum, type alias or constant
+// as `#[doc(hidden)]`, so that they are usable, but don't show up in the
+// generated documentation.
+//
+// Example:
+//
+//   struct CRUBIT_DOC_HIDDEN RegistrationToken final { int id; };
+#define CRUBIT_DOC_HIDDEN [[clang::annotate("crubit_doc_hidden")]]
+
+#endif  // CRUBIT_SUPPORT_ANNOTATIONS_H_
@@ -0,0 +1,104 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+//! # Tracing of the calls made through the generated bindings.
+//!
+//! When the bindings are generated with `--trace_calls`, each function of the
+//! bindings creates a `CallTrace` for the duration of the call, which reports
+//! the name of the C++ function and the duration of the call to the `Tracer`
+//! installed with `set_tracer`.
+//!
+//! While no tracer is installed, the overhead of a call is a relaxed atomic
+//! load: in particular, the clock isn't read.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+/// Receives the calls made through the generated bindings.
+pub trait Tracer: Sync {
+    /// Called after each call of the function `function_name` (the qualified
+    /// name of the C++ function, e.g. `ns::SomeStruct::Method`) returned.
+    fn on_call(&self, function_name: &'static str, duration: Duration);
+}
+
+static IS_ENABLED: AtomicBool = AtomicBool::new(false);
+static TRACER: RwLock<Option<&'static dyn Tracer>> = RwLock::new(None);
+
+/// Installs `tracer`, replacing the previously installed tracer (if any).
+pub fn set_tracer(tracer: &'static dyn Tracer) {
+    *TRACER.write().unwrap() = Some(tracer);
+    IS_ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Uninstalls the installed tracer (if any).
+pub fn clear_tracer() {
+    IS_ENABLED.store(false, Ordering::Relaxed);
+    *TRACER.write().unwrap() = None;
+}
+
+/// Measures a call while it is alive, and reports it to the installed tracer
+/// when dropped.
+#[must_use]
+pub struct CallTrace {
+    function_name: &'static str,
+    start: Option<Instant>,
+}
+
+impl CallTrace {
+    /// Starts measuring a call of the function `function_name`.
+    #[inline(always)]
+    pub fn start(function_name: &'static str) -> Self {
+        let start = if IS_ENABLED.load(Ordering::Relaxed) { Some(Instant::now()) } else { None };
+        CallTrace { function_name, start }
+    }
+}
+
+impl Drop for CallTrace {
+    #[inline(always)]
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            let duration = start.elapsed();
+            // The tracer may have been uninstalled during the call.
+            if let Some(tracer) = *TRACER.read().unwrap() {
+                tracer.on_call(self.function_name, duration);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    struct RecordingTracer {
+        calls: Mutex<Vec<&'static str>>,
+    }
+
+    impl Tracer for RecordingTracer {
+        fn on_call(&self, function_name: &'static str, _duration: Duration) {
+            self.calls.lock().unwrap().push(function_name);
+        }
+    }
+
+    static RECORDING_TRACER: RecordingTracer = RecordingTracer { calls: Mutex::new(Vec::new()) };
+
+    // A single test, because the tracer is global.
+    #[test]
+    fn test_tracer() {
+        drop(CallTrace::start("NotTraced"));
+
+        set_tracer(&RECORDING_TRACER);
+        {
+            let _trace = CallTrace::start("ns::Foo");
+            drop(CallTrace::start("ns::Bar"));
+        }
+
+        clear_tracer();
+        drop(CallTrace::start("AlsoNotTraced"));
+
+        assert_eq!(*RECORDING_TRACER.calls.lock().unwrap(), ["ns::Bar", "ns::Foo"]);
+    }
+}
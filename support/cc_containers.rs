@@ -0,0 +1,199 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+//! # Adapters for C++ standard library containers.
+//!
+//! Bindings generated by Crubit with the `value_semantics` Crubit feature map
+//! `std::vector<T>` and `std::optional<T>` to `CcVector<T>` and `CcOptional<T>`,
+//! instead of generating bindings for each instantiation of these class
+//! templates. The adapters have the same layout
+//! as the C++ types (in both libc++ and libstdc++), and give access to the
+//! elements of the containers.
+//!
+//! The containers themselves are created, resized and destroyed by C++, so
+//! they are only used behind pointers and references, e.g. to bind
+//! `void Process(const std::vector<int>& values)` as
+//! `fn Process(values: &CcVector<i32>)`.
+
+use cpp_value::CppValue;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+
+/// The Rust equivalent of `std::vector<T>`.
+#[repr(C)]
+pub struct CcVector<T: CppValue> {
+    begin: *mut T,
+    end: *mut T,
+    end_of_storage: *mut T,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: CppValue> CcVector<T> {
+    /// Returns the number of elements in the vector.
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Returns true if the vector contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.begin == self.end
+    }
+
+    /// Returns the number of elements that the vector can hold without
+    /// reallocating.
+    pub fn capacity(&self) -> usize {
+        if self.begin.is_null() {
+            return 0;
+        }
+        // SAFETY: `begin` and `end_of_storage` point into the same allocation.
+        unsafe { self.end_of_storage.offset_from(self.begin) as usize }
+    }
+
+    /// Returns the elements of the vector.
+    pub fn as_slice(&self) -> &[T] {
+        if self.begin.is_null() {
+            return &[];
+        }
+        // SAFETY: the elements in `[begin, end)` are initialized, and owned by
+        // the vector.
+        unsafe { std::slice::from_raw_parts(self.begin, self.end.offset_from(self.begin) as usize) }
+    }
+
+    /// Returns the elements of the vector, which can be modified in place.
+    pub fn as_mut_slice(&mut self) -> &mut [T]
+    where
+        T: Unpin,
+    {
+        if self.begin.is_null() {
+            return &mut [];
+        }
+        // SAFETY: see `as_slice`.
+        unsafe {
+            std::slice::from_raw_parts_mut(self.begin, self.end.offset_from(self.begin) as usize)
+        }
+    }
+
+    /// Returns a pinned reference to the element at `index`, if any. Unlike
+    /// `as_mut_slice`, this also supports `!Unpin` elements.
+    pub fn get_pin_mut(self: Pin<&mut Self>, index: usize) -> Option<Pin<&mut T>> {
+        if index >= self.len() {
+            return None;
+        }
+        // SAFETY: `index` is in bounds, and the elements of a vector are never
+        // moved by Rust.
+        unsafe { Some(Pin::new_unchecked(&mut *self.begin.add(index))) }
+    }
+}
+
+impl<T: CppValue> Deref for CcVector<T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: CppValue + Unpin> DerefMut for CcVector<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: CppValue + fmt::Debug> fmt::Debug for CcVector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+/// The Rust equivalent of `std::optional<T>`.
+#[repr(C)]
+pub struct CcOptional<T: CppValue> {
+    value: MaybeUninit<T>,
+    has_value: bool,
+}
+
+impl<T: CppValue> CcOptional<T> {
+    /// Returns true if the optional contains a value.
+    pub fn has_value(&self) -> bool {
+        self.has_value
+    }
+
+    /// Returns a reference to the value, if any.
+    pub fn as_ref(&self) -> Option<&T> {
+        // SAFETY: `value` is initialized if `has_value` is true.
+        self.has_value.then(|| unsafe { self.value.assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the value, if any.
+    pub fn as_mut(&mut self) -> Option<&mut T>
+    where
+        T: Unpin,
+    {
+        // SAFETY: see `as_ref`.
+        self.has_value.then(|| unsafe { self.value.assume_init_mut() })
+    }
+
+    /// Returns a pinned reference to the value, if any. Unlike `as_mut`, this
+    /// also supports `!Unpin` values.
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> Option<Pin<&mut T>> {
+        // SAFETY: the value is structurally pinned, and is initialized if
+        // `has_value` is true.
+        unsafe {
+            let this = Pin::into_inner_unchecked(self);
+            this.has_value.then(|| Pin::new_unchecked(this.value.assume_init_mut()))
+        }
+    }
+}
+
+impl<T: CppValue + fmt::Debug> fmt::Debug for CcOptional<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector() {
+        let mut elements = [1, 2, 3, 0];
+        let begin = elements.as_mut_ptr();
+        let vector = CcVector::<i32> {
+            begin,
+            end: unsafe { begin.add(3) },
+            end_of_storage: unsafe { begin.add(4) },
+            _phantom: PhantomData,
+        };
+        assert_eq!(vector.as_slice(), &[1, 2, 3]);
+        assert_eq!(vector.len(), 3);
+        assert_eq!(vector.capacity(), 4);
+        assert_eq!(format!("{vector:?}"), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn test_empty_vector() {
+        let vector = CcVector::<i32> {
+            begin: std::ptr::null_mut(),
+            end: std::ptr::null_mut(),
+            end_of_storage: std::ptr::null_mut(),
+            _phantom: PhantomData,
+        };
+        assert!(vector.is_empty());
+        assert_eq!(vector.as_slice(), &[] as &[i32]);
+        assert_eq!(vector.capacity(), 0);
+    }
+
+    #[test]
+    fn test_optional() {
+        let mut some = CcOptional::<i32> { value: MaybeUninit::new(42), has_value: true };
+        *some.as_mut().unwrap() += 1;
+        assert_eq!(some.as_ref(), Some(&43));
+        let none = CcOptional::<i32> { value: MaybeUninit::uninit(), has_value: false };
+        assert_eq!(none.as_ref(), None);
+        assert_eq!(format!("{none:?}"), "None");
+    }
+}
@@ -0,0 +1,71 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+//! # C++ exceptions caught by the generated bindings.
+//!
+//! C++ exceptions must not unwind across the FFI boundary. When the bindings
+//! are generated with `--exception_handling=panic` or
+//! `--exception_handling=result`, the C++ thunks catch all exceptions, and
+//! report them to Rust as a `CcException` (see also
+//! `support/internal/exceptions.h`).
+
+use std::ffi::CStr;
+use std::fmt;
+use std::os::raw::{c_char, c_void};
+
+extern "C" {
+    fn free(ptr: *mut c_void);
+}
+
+/// A C++ exception that was thrown by a function called from Rust.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CcException {
+    message: String,
+}
+
+impl CcException {
+    /// Creates a `CcException` from a message reported by a C++ thunk, and
+    /// frees the message.
+    ///
+    /// # Safety
+    ///
+    /// `message` must be a non-null, NUL-terminated string allocated with
+    /// `malloc` (as done by `crubit::StoreCurrentException`), and must not be
+    /// used after this call.
+    pub unsafe fn from_raw(message: *mut c_char) -> Self {
+        let message_str = CStr::from_ptr(message).to_string_lossy().into_owned();
+        free(message as *mut c_void);
+        CcException { message: message_str }
+    }
+
+    /// Returns the message of the exception (e.g. `std::exception::what()`).
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for CcException {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "C++ exception: {}", self.message)
+    }
+}
+
+impl std::error::Error for CcException {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern "C" {
+        fn strdup(s: *const c_char) -> *mut c_char;
+    }
+
+    #[test]
+    fn test_from_raw() {
+        let message = unsafe { strdup(b"oh no\0".as_ptr() as *const c_char) };
+        let exception = unsafe { CcException::from_raw(message) };
+        assert_eq!(exception.message(), "oh no");
+        assert_eq!(exception.to_string(), "C++ exception: oh no");
+    }
+}
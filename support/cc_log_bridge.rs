@@ -0,0 +1,128 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+//! # Forwarding of C++ log messages to the `log` crate.
+//!
+//! The bindings of a C++ logging interface annotated with `CRUBIT_LOG_SINK`
+//! (see `support/annotations.h`) have a `rust_log_sink()` function, which
+//! returns an implementation of the interface forwarding the messages to
+//! `forward_to_log`. Passing it to the C++ components which accept the
+//! interface integrates their logs with the `log` records of the Rust program
+//! (and with `tracing`, using e.g. `tracing_log::LogTracer`).
+//!
+//! The records have the target `TARGET`, the file and line of the C++ logging
+//! statement, and a level derived from the `absl::LogSeverity` of the message
+//! (see `level_of_severity`).
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+
+/// The target of the records of the forwarded C++ log messages.
+pub const TARGET: &str = "cc";
+
+/// Returns the level of a message with the given `absl::LogSeverity`:
+/// `kInfo` (0), `kWarning` (1), `kError` (2) or `kFatal` (3). Negative
+/// severities (used by verbose logging) are mapped to `Debug` and `Trace`.
+pub fn level_of_severity(severity: c_int) -> log::Level {
+    match severity {
+        c_int::MIN..=-2 => log::Level::Trace,
+        -1 => log::Level::Debug,
+        0 => log::Level::Info,
+        1 => log::Level::Warn,
+        _ => log::Level::Error,
+    }
+}
+
+/// Forwards a C++ log message to the `log` crate.
+///
+/// # Safety
+///
+/// `file` and `message` must be null, or point to nul-terminated strings which
+/// are valid for the duration of the call.
+pub unsafe extern "C" fn forward_to_log(
+    severity: c_int,
+    file: *const c_char,
+    line: c_int,
+    message: *const c_char,
+) {
+    let level = level_of_severity(severity);
+    if level > log::max_level() {
+        return;
+    }
+    let to_str = |s: *const c_char| {
+        if s.is_null() {
+            None
+        } else {
+            Some(CStr::from_ptr(s).to_string_lossy())
+        }
+    };
+    let file = to_str(file);
+    let message = to_str(message).unwrap_or_default();
+    log::logger().log(
+        &log::Record::builder()
+            .level(level)
+            .target(TARGET)
+            .file(file.as_deref())
+            .line(u32::try_from(line).ok())
+            .args(format_args!("{message}"))
+            .build(),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    struct RecordingLogger {
+        records: Mutex<Vec<String>>,
+    }
+
+    impl log::Log for RecordingLogger {
+        fn enabled(&self, _metadata: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            self.records.lock().unwrap().push(format!(
+                "{} {} {}:{} {}",
+                record.level(),
+                record.target(),
+                record.file().unwrap_or("?"),
+                record.line().unwrap_or(0),
+                record.args()
+            ));
+        }
+
+        fn flush(&self) {}
+    }
+
+    static RECORDING_LOGGER: RecordingLogger = RecordingLogger { records: Mutex::new(Vec::new()) };
+
+    #[test]
+    fn test_level_of_severity() {
+        assert_eq!(level_of_severity(-2), log::Level::Trace);
+        assert_eq!(level_of_severity(-1), log::Level::Debug);
+        assert_eq!(level_of_severity(0), log::Level::Info);
+        assert_eq!(level_of_severity(1), log::Level::Warn);
+        assert_eq!(level_of_severity(2), log::Level::Error);
+        assert_eq!(level_of_severity(3), log::Level::Error);
+    }
+
+    // A single test, because the logger is global.
+    #[test]
+    fn test_forward_to_log() {
+        log::set_logger(&RECORDING_LOGGER).unwrap();
+        log::set_max_level(log::LevelFilter::Info);
+        unsafe {
+            forward_to_log(1, b"foo.cc\0".as_ptr().cast(), 12, b"hello\0".as_ptr().cast());
+            forward_to_log(-1, b"foo.cc\0".as_ptr().cast(), 13, b"verbose\0".as_ptr().cast());
+            forward_to_log(0, std::ptr::null(), -1, b"no file\0".as_ptr().cast());
+        }
+        assert_eq!(
+            *RECORDING_LOGGER.records.lock().unwrap(),
+            ["WARN cc foo.cc:12 hello", "INFO cc ?:0 no file"]
+        );
+    }
+}
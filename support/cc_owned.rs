@@ -0,0 +1,131 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+//! # C++ objects owned by Rust.
+//!
+//! C++ functions returning a pointer which the caller must delete are marked
+//! with `CRUBIT_OWNED_RESULT(deleter)` (see `support/annotations.h`). Their
+//! bindings return an `Option<CcOwned<T>>`, which deletes the object with
+//! `deleter` when dropped, instead of a raw pointer that could be leaked (or
+//! deleted twice).
+
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::ptr::NonNull;
+
+/// An owning pointer to a C++ object, which is deleted by the C++ deleter of
+/// the function which returned it.
+pub struct CcOwned<T> {
+    ptr: NonNull<T>,
+    deleter: unsafe fn(*mut T),
+}
+
+impl<T> CcOwned<T> {
+    /// Takes ownership of the object pointed to by `ptr`, which is deleted by
+    /// calling `deleter` with `ptr`. Returns `None` if `ptr` is null.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be null, or point to a valid object which isn't owned by
+    /// anything else, and which can be deleted with `deleter`.
+    pub unsafe fn from_raw(ptr: *mut T, deleter: unsafe fn(*mut T)) -> Option<Self> {
+        NonNull::new(ptr).map(|ptr| CcOwned { ptr, deleter })
+    }
+
+    /// Returns the pointer to the object, which is still owned by `self`.
+    pub fn as_ptr(&self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    /// Returns a pinned mutable reference to the object, which (unlike
+    /// `DerefMut`) is available even if `T` isn't `Unpin`.
+    pub fn as_mut(&mut self) -> Pin<&mut T> {
+        // SAFETY: The object is never moved by `CcOwned`, which only deletes it
+        // in place.
+        unsafe { Pin::new_unchecked(&mut *self.ptr.as_ptr()) }
+    }
+
+    /// Releases the ownership of the object, which must then be deleted by
+    /// other means (e.g. by passing the returned pointer back to C++).
+    pub fn into_raw(self) -> *mut T {
+        let ptr = self.ptr.as_ptr();
+        std::mem::forget(self);
+        ptr
+    }
+}
+
+impl<T> Deref for CcOwned<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        // SAFETY: `self.ptr` points to a valid object owned by `self`.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T: Unpin> DerefMut for CcOwned<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: `self.ptr` points to a valid object owned by `self`.
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+impl<T> Drop for CcOwned<T> {
+    fn drop(&mut self) {
+        // SAFETY: `from_raw` requires that the object can be deleted with
+        // `self.deleter`.
+        unsafe { (self.deleter)(self.ptr.as_ptr()) }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CcOwned<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("CcOwned").field(&**self).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    unsafe fn delete_box(ptr: *mut i32) {
+        drop(Box::from_raw(ptr));
+    }
+
+    #[test]
+    fn test_from_raw_null() {
+        assert!(unsafe { CcOwned::from_raw(std::ptr::null_mut(), delete_box) }.is_none());
+    }
+
+    #[test]
+    fn test_deref() {
+        let ptr = Box::into_raw(Box::new(42));
+        let mut owned = unsafe { CcOwned::from_raw(ptr, delete_box) }.unwrap();
+        assert_eq!(*owned, 42);
+        *owned += 1;
+        assert_eq!(*owned.as_mut(), 43);
+        assert_eq!(owned.as_ptr(), ptr);
+        assert_eq!(format!("{owned:?}"), "CcOwned(43)");
+    }
+
+    #[test]
+    fn test_drop_calls_deleter() {
+        static mut DELETED: Option<i32> = None;
+        unsafe fn record_delete(ptr: *mut i32) {
+            DELETED = Some(*Box::from_raw(ptr));
+        }
+        let owned = unsafe { CcOwned::from_raw(Box::into_raw(Box::new(7)), record_delete) };
+        assert_eq!(unsafe { DELETED }, None);
+        drop(owned);
+        assert_eq!(unsafe { DELETED }, Some(7));
+    }
+
+    #[test]
+    fn test_into_raw() {
+        let ptr = Box::into_raw(Box::new(1));
+        let owned = unsafe { CcOwned::from_raw(ptr, delete_box) }.unwrap();
+        assert_eq!(owned.into_raw(), ptr);
+        unsafe { delete_box(ptr) };
+    }
+}
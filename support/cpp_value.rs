@@ -0,0 +1,148 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+//! # Value semantics of C++ types.
+//!
+//! Bindings generated by Crubit with the `value_semantics` Crubit feature
+//! implement `CppValue` for every C++ record. This allows generic Rust code to default-construct, copy and move values of
+//! arbitrary C++ types in the same way, whether or not they are `Unpin`:
+//!
+//! ```
+//! use cpp_value::CppValue;
+//! use ctor::{CtorNew, Emplace};
+//! use std::pin::Pin;
+//!
+//! fn boxed_copy<T: CppValue + for<'a> CtorNew<&'a T>>(value: &T) -> Pin<Box<T>> {
+//!     Box::emplace(value.cpp_clone())
+//! }
+//! ```
+//!
+//! Each operation is only available if the corresponding C++ special member
+//! function has bindings. For `Unpin` types, `ctor` implements `CtorNew` in
+//! terms of `Default` and `Clone`, so these work as well.
+
+use ctor::{CtorNew, RvalueReference};
+use std::pin::Pin;
+
+/// A C++ object type with value semantics.
+///
+/// # Safety
+///
+/// `IS_TRIVIALLY_RELOCATABLE` must only be `true` if a value of this type can
+/// be moved to a different address with a `memcpy` (after which the original
+/// is no longer used, nor destroyed). This is the case for the `Unpin` records
+/// generated by Crubit.
+pub unsafe trait CppValue: Sized {
+    /// Whether values of this type can be relocated with a `memcpy` (e.g. by a
+    /// Rust move).
+    const IS_TRIVIALLY_RELOCATABLE: bool;
+
+    /// Returns a `Ctor` which default-constructs a new value.
+    fn cpp_default() -> <Self as CtorNew<()>>::CtorType
+    where
+        Self: CtorNew<()>,
+    {
+        Self::ctor_new(())
+    }
+
+    /// Returns a `Ctor` which copy-constructs a new value from `self`.
+    fn cpp_clone<'a>(&'a self) -> <Self as CtorNew<&'a Self>>::CtorType
+    where
+        Self: CtorNew<&'a Self>,
+    {
+        Self::ctor_new(self)
+    }
+
+    /// Returns a `Ctor` which move-constructs a new value from `self`, leaving
+    /// `self` in its moved-from state.
+    fn cpp_move<'a>(
+        self: Pin<&'a mut Self>,
+    ) -> <Self as CtorNew<RvalueReference<'a, Self>>>::CtorType
+    where
+        Self: CtorNew<RvalueReference<'a, Self>>,
+    {
+        Self::ctor_new(RvalueReference(self))
+    }
+}
+
+macro_rules! impl_cpp_value_for_primitive_types {
+    ($($t:ty),*) => {
+        $(
+            unsafe impl CppValue for $t {
+                const IS_TRIVIALLY_RELOCATABLE: bool = true;
+            }
+        )*
+    };
+}
+
+// The Rust equivalents of C++ fundamental types, so that e.g. `std::vector<int>`
+// can be bound to `CcVector<i32>`.
+impl_cpp_value_for_primitive_types!(
+    bool, i8, i16, i32, i64, u8, u16, u32, u64, isize, usize, f32, f64
+);
+
+unsafe impl<T> CppValue for *const T {
+    const IS_TRIVIALLY_RELOCATABLE: bool = true;
+}
+
+unsafe impl<T> CppValue for *mut T {
+    const IS_TRIVIALLY_RELOCATABLE: bool = true;
+}
+
+/// Relocates the value at `src` to `dest` if `T` is trivially relocatable, and
+/// returns whether it did.
+///
+/// Values which are not trivially relocatable must be moved using
+/// `CppValue::cpp_move` instead.
+///
+/// # Safety
+///
+/// `src` must point to a valid value, and `dest` must be valid for writes and
+/// must not overlap with `src`. If this returns `true`, the value at `src` must
+/// neither be used nor dropped anymore.
+pub unsafe fn try_relocate<T: CppValue>(src: *mut T, dest: *mut T) -> bool {
+    if !T::IS_TRIVIALLY_RELOCATABLE {
+        return false;
+    }
+    std::ptr::copy_nonoverlapping(src, dest, 1);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ctor::emplace;
+    use std::mem::MaybeUninit;
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    unsafe impl CppValue for Point {
+        const IS_TRIVIALLY_RELOCATABLE: bool = true;
+    }
+
+    #[test]
+    fn test_cpp_default() {
+        emplace! { let p = Point::cpp_default(); }
+        assert_eq!(*p, Point::default());
+    }
+
+    #[test]
+    fn test_cpp_clone() {
+        let p = Point { x: 1, y: 2 };
+        emplace! { let copy = p.cpp_clone(); }
+        assert_eq!(*copy, p);
+    }
+
+    #[test]
+    fn test_try_relocate() {
+        let mut p = MaybeUninit::new(Point { x: 1, y: 2 });
+        let mut dest = MaybeUninit::<Point>::uninit();
+        assert!(unsafe { try_relocate(p.as_mut_ptr(), dest.as_mut_ptr()) });
+        assert_eq!(unsafe { dest.assume_init() }, Point { x: 1, y: 2 });
+    }
+}
@@ -48,6 +48,8 @@
 //! And so copy-constructible types are not things which implement `CopyCtor`,
 //! but rather, things which implement `CtorNew<&T>`. Similarly,
 //! move-constructible things implement `CtorNew<RvalueReference<'_, T>>`.
+//! (`CopyCtor` and `MoveCtor` do exist, but only as shorthands for these bounds,
+//! with blanket impls.)
 //!
 //! ## Blanket impls
 //!
@@ -105,6 +107,8 @@ use std::marker::PhantomData;
 use std::mem::{ManuallyDrop, MaybeUninit};
 use std::ops::Deref;
 use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
 
 pub use ctor_proc_macros::*;
 
@@ -156,6 +160,10 @@ pub trait Ctor {
     }
 }
 
+/// A smart pointer which can be allocated directly from a `Ctor`, e.g.
+/// `Box::emplace(ctor)`.
+///
+/// This is implemented for `Box`, `Rc`, and `Arc`.
 pub trait Emplace<T>: Sized {
     fn emplace<C: Ctor<Output = T>>(c: C) -> Pin<Self>;
 }
@@ -171,6 +179,82 @@ impl<T> Emplace<T> for Box<T> {
     }
 }
 
+impl<T> Emplace<T> for Rc<T> {
+    fn emplace<C: Ctor<Output = T>>(ctor: C) -> Pin<Rc<T>> {
+        let mut uninit = Rc::new(MaybeUninit::<T>::uninit());
+        unsafe {
+            // The `Rc` was just created, so there are no other references to it.
+            let pinned = Pin::new_unchecked(Rc::get_mut(&mut uninit).unwrap());
+            ctor.ctor(pinned);
+            Pin::new_unchecked(Rc::from_raw(Rc::into_raw(uninit).cast::<T>()))
+        }
+    }
+}
+
+impl<T> Emplace<T> for Arc<T> {
+    fn emplace<C: Ctor<Output = T>>(ctor: C) -> Pin<Arc<T>> {
+        let mut uninit = Arc::new(MaybeUninit::<T>::uninit());
+        unsafe {
+            // The `Arc` was just created, so there are no other references to it.
+            let pinned = Pin::new_unchecked(Arc::get_mut(&mut uninit).unwrap());
+            ctor.ctor(pinned);
+            Pin::new_unchecked(Arc::from_raw(Arc::into_raw(uninit).cast::<T>()))
+        }
+    }
+}
+
+/// An allocator which a `Ctor` can be emplaced into, e.g.
+/// `(&arena).emplace_in(ctor)`.
+///
+/// Unlike `Emplace`, the result borrows from the allocator rather than owning
+/// its storage, so that many pinned objects can be constructed in one arena
+/// without a separate heap allocation for each.
+///
+/// # Safety
+///
+/// `alloc_uninit` must return storage which is valid for `'a`, and which is
+/// not reused or deallocated until the `T` inside it has been dropped. (It may
+/// be leaked.) For example, an arena must either run the destructors of the
+/// objects it contains before freeing them, or never free its memory.
+pub unsafe trait EmplaceIn<'a, T>: Sized {
+    /// Returns uninitialized storage for a `T`.
+    fn alloc_uninit(self) -> &'a mut MaybeUninit<T>;
+
+    fn emplace_in<C: Ctor<Output = T>>(self, ctor: C) -> Pin<&'a mut T> {
+        let uninit = self.alloc_uninit();
+        unsafe {
+            ctor.ctor(Pin::new_unchecked(&mut *uninit));
+            Pin::new_unchecked(uninit.assume_init_mut())
+        }
+    }
+}
+
+/// Emplaces a constructor into caller-supplied storage.
+///
+/// # Safety
+///
+/// `dest` must be valid for writes and properly aligned for `'a`, and its
+/// memory must not be reused or deallocated until the `T` has been dropped.
+pub unsafe fn emplace_at<'a, C: Ctor>(
+    dest: *mut MaybeUninit<C::Output>,
+    ctor: C,
+) -> Pin<&'a mut C::Output> {
+    let uninit = &mut *dest;
+    ctor.ctor(Pin::new_unchecked(&mut *uninit));
+    Pin::new_unchecked(uninit.assume_init_mut())
+}
+
+/// Emplaces a constructor into a new `Pin<Box<T>>`.
+///
+/// `emplace_box!(ctor)` is equivalent to `Box::emplace(ctor)`, but doesn't
+/// require the `Emplace` trait to be imported.
+#[macro_export]
+macro_rules! emplace_box {
+    ($expr:expr) => {
+        <::std::boxed::Box<_> as $crate::Emplace<_>>::emplace($expr)
+    };
+}
+
 #[must_use = must_use_ctor!()]
 pub struct FnCtor<Output, F: FnOnce(Pin<&mut MaybeUninit<Output>>)>(pub F, PhantomData<fn(Output)>);
 impl<Output, F: FnOnce(Pin<&mut MaybeUninit<Output>>)> FnCtor<Output, F> {
@@ -190,6 +274,51 @@ impl<Output, F: FnOnce(Pin<&mut MaybeUninit<Output>>)> Ctor for FnCtor<Output, F
 /// !Unpin to override the blanket Ctor impl.
 impl<Output, F> !Unpin for FnCtor<Output, F> {}
 
+/// A constructor which can fail, e.g. one which wraps a throwing C++
+/// constructor.
+///
+/// If `try_ctor` returns an error, it must have rolled back any partially
+/// initialized state, leaving `dest` uninitialized.
+#[must_use = must_use_ctor!()]
+pub trait TryCtor {
+    type Output;
+    type Error;
+    unsafe fn try_ctor(self, dest: Pin<&mut MaybeUninit<Self::Output>>) -> Result<(), Self::Error>;
+}
+
+/// A constructor which fails before it starts constructing anything.
+impl<C: Ctor, E> TryCtor for Result<C, E> {
+    type Output = C::Output;
+    type Error = E;
+
+    unsafe fn try_ctor(self, dest: Pin<&mut MaybeUninit<C::Output>>) -> Result<(), E> {
+        self?.ctor(dest);
+        Ok(())
+    }
+}
+
+#[must_use = must_use_ctor!()]
+pub struct FnTryCtor<Output, E, F: FnOnce(Pin<&mut MaybeUninit<Output>>) -> Result<(), E>>(
+    pub F,
+    PhantomData<fn(Output) -> E>,
+);
+impl<Output, E, F: FnOnce(Pin<&mut MaybeUninit<Output>>) -> Result<(), E>> FnTryCtor<Output, E, F> {
+    pub fn new(f: F) -> Self {
+        Self(f, PhantomData)
+    }
+}
+
+impl<Output, E, F: FnOnce(Pin<&mut MaybeUninit<Output>>) -> Result<(), E>> TryCtor
+    for FnTryCtor<Output, E, F>
+{
+    type Output = Output;
+    type Error = E;
+
+    unsafe fn try_ctor(self, dest: Pin<&mut MaybeUninit<Output>>) -> Result<(), E> {
+        self.0(dest)
+    }
+}
+
 /// Copy type.
 ///
 /// This creates a new `P::Target` by copying -- either copy-construction
@@ -215,10 +344,26 @@ impl<P> !Unpin for Copy<P> {}
 /// a `Ctor` directly.
 ///
 /// Note: this does not actually copy the parameter until it is used.
-pub fn copy<T: for<'a> CtorNew<&'a T>, P: Deref<Target = T>>(src: P) -> Copy<P> {
+pub fn copy<T: CopyCtor, P: Deref<Target = T>>(src: P) -> Copy<P> {
     Copy(src)
 }
 
+/// Copy-constructible types: shorthand for `for<'a> CtorNew<&'a Self>`.
+pub trait CopyCtor: for<'a> CtorNew<&'a Self> {}
+impl<T: for<'a> CtorNew<&'a T>> CopyCtor for T {}
+
+/// Types which can be copied in place, whether or not they are `Unpin`.
+///
+/// Bindings generated by Crubit implement this for every C++ record with a
+/// copy constructor, so that generic code can copy both `Unpin` records (which
+/// are also `Clone`) and `!Unpin` records.
+pub trait CloneCtor: CopyCtor + Sized {
+    /// Returns a `Ctor` which copy-constructs a new value from `self`.
+    fn clone_ctor(&self) -> Copy<&Self> {
+        copy(self)
+    }
+}
+
 // ================================
 // DerefMut based move construction
 // ================================
@@ -320,6 +465,22 @@ macro_rules! mov {
     };
 }
 
+/// Returns an `RvalueReference` to `src`, which will move-construct (or
+/// move-assign) from it.
+///
+/// This is the function form of `mov!`, for when the source is already a
+/// pinned reference: e.g. `takes_by_value(ctor::mov(x.as_mut()))`.
+///
+/// Note: this does not actually move the parameter until it is used.
+pub fn mov<T>(src: Pin<&mut T>) -> RvalueReference<'_, T> {
+    RvalueReference(src)
+}
+
+/// Move-constructible types: shorthand for
+/// `for<'a> CtorNew<RvalueReference<'a, Self>>`.
+pub trait MoveCtor: for<'a> CtorNew<RvalueReference<'a, Self>> {}
+impl<T: for<'a> CtorNew<RvalueReference<'a, T>>> MoveCtor for T {}
+
 #[macro_export]
 macro_rules! const_mov {
     ($p:expr) => {
@@ -466,6 +627,45 @@ macro_rules! emplace {
     };
 }
 
+/// Emplace a fallible constructor into a local or temporary.
+/// Syntax: `try_emplace! { let mut varname = expr() }`, where `expr()`
+/// evaluates to a `TryCtor<Output=T>`. `varname` will be a `Pin<&mut T>`. If
+/// construction fails, the error is returned from the enclosing function using
+/// `?`.
+///
+/// The `try_emplace!(ctor)` syntax emplaces the ctor into a temporary, and
+/// evaluates to a `Result<Pin<&mut T>, E>` instead.
+#[macro_export]
+macro_rules! try_emplace {
+    ($expr:expr) => {
+        $crate::Slot::unsafe_new()
+            .unsafe_try_construct($expr)
+            .map(|slot| slot.unsafe_as_pin_unchecked())
+    };
+    (@try_emplace_one let [$($mut_:tt)?] $var:ident [$($type_:tt)*]= $expr:expr;) => {
+        let mut $var = $crate::Slot::unsafe_new();
+        $var.unsafe_try_construct($expr)?;
+        let $($mut_)* $var $($type_)* = $var.unsafe_as_pin_unchecked();
+    };
+    () => {};
+    (let mut $var:ident : $t:ty = $expr:expr; $($remaining_lets:tt)*) => {
+        $crate::try_emplace! {@try_emplace_one let [mut] $var [:$t] = $expr;}
+        $crate::try_emplace! {$($remaining_lets)*};
+    };
+    (let mut $var:ident = $expr:expr; $($remaining_lets:tt)*) => {
+        $crate::try_emplace! {@try_emplace_one let [mut] $var []= $expr;}
+        $crate::try_emplace! {$($remaining_lets)*};
+    };
+    (let $var:ident : $t:ty  = $expr:expr; $($remaining_lets:tt)*) => {
+        $crate::try_emplace! {@try_emplace_one let [] $var [:$t] = $expr;}
+        $crate::try_emplace! {$($remaining_lets)*};
+    };
+    (let $var:ident = $expr:expr; $($remaining_lets:tt)*) => {
+        $crate::try_emplace! {@try_emplace_one let [] $var [] = $expr;}
+        $crate::try_emplace! {$($remaining_lets)*};
+    };
+}
+
 // ====
 // Slot
 // ====
@@ -606,6 +806,17 @@ impl<T> Slot<T> {
         self
     }
 
+    /// Safety: must not have already been constructed, as that would violate
+    /// the pin guarantee. On failure, the slot remains uninitialized.
+    pub fn unsafe_try_construct<E>(
+        &mut self,
+        ctor: impl TryCtor<Output = T, Error = E>,
+    ) -> Result<&mut Self, E> {
+        unsafe { ctor.try_ctor(Pin::new_unchecked(&mut self.maybe_uninit)) }?;
+        self.is_initialized = true;
+        Ok(self)
+    }
+
     /// Safety: pin guarantee, assumes init.
     pub fn unsafe_as_pin_unchecked(&mut self) -> Pin<&mut T> {
         unsafe { Pin::new_unchecked(self.maybe_uninit.assume_init_mut()) }
@@ -1220,6 +1431,82 @@ mod test {
         assert_eq!(*y, 100);
     }
 
+    #[test]
+    fn test_emplace_rc_and_arc() {
+        let x: u32 = 42;
+        let rc: Pin<Rc<u32>> = Rc::emplace(copy(&x));
+        assert_eq!(*rc, 42);
+        let arc: Pin<Arc<u32>> = Arc::emplace(copy(&x));
+        assert_eq!(*arc, 42);
+    }
+
+    /// An arena which never frees its memory.
+    struct LeakingArena;
+
+    unsafe impl<'a, T> EmplaceIn<'a, T> for &'a LeakingArena {
+        fn alloc_uninit(self) -> &'a mut MaybeUninit<T> {
+            Box::leak(Box::new(MaybeUninit::uninit()))
+        }
+    }
+
+    #[test]
+    fn test_emplace_in() {
+        let arena = LeakingArena;
+        let x: u32 = 42;
+        let y: Pin<&mut u32> = (&arena).emplace_in(copy(&x));
+        let z: Pin<&mut u32> = (&arena).emplace_in(copy(&*y));
+        assert_eq!(*y, 42);
+        assert_eq!(*z, 42);
+    }
+
+    #[test]
+    fn test_emplace_at() {
+        let mut storage = MaybeUninit::<u32>::uninit();
+        let x: u32 = 42;
+        let y = unsafe { emplace_at(&mut storage, copy(&x)) };
+        assert_eq!(*y, 42);
+    }
+
+    #[test]
+    fn test_try_emplace() {
+        fn try_emplace_both(fail: bool) -> Result<u32, &'static str> {
+            let x: u32 = 1;
+            try_emplace! {
+                let y = Ok::<_, &str>(copy(&x));
+                let z = if fail { Err("failed") } else { Ok(copy(&*y)) };
+            }
+            Ok(*y + *z)
+        }
+        assert_eq!(try_emplace_both(false), Ok(2));
+        assert_eq!(try_emplace_both(true), Err("failed"));
+    }
+
+    #[test]
+    fn test_try_emplace_rolls_back() {
+        struct CountDrops<'a>(&'a std::cell::Cell<u32>);
+        impl Drop for CountDrops<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = std::cell::Cell::new(0);
+        let failing = FnTryCtor::new(|_: Pin<&mut MaybeUninit<CountDrops>>| Err(()));
+        assert!(try_emplace!(failing).is_err());
+        // The slot was left uninitialized, so nothing was dropped.
+        assert_eq!(drops.get(), 0);
+
+        assert!(try_emplace!(Ok::<_, ()>(RustMoveCtor(CountDrops(&drops)))).is_ok());
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn test_emplace_box_macro() {
+        let x: u32 = 42;
+        let y: Pin<Box<u32>> = crate::emplace_box!(copy(&x));
+        assert_eq!(*y, 42);
+    }
+
     #[test]
     fn test_copy_smart_ptr() {
         let x = Box::new(42_u32);
@@ -1619,6 +1906,35 @@ mod test {
         assert_eq!(*log.borrow(), vec!["move ctor", "drop"]);
     }
 
+    #[test]
+    fn test_copy_and_mov_fns() {
+        fn takes_by_value<T: CopyCtor + MoveCtor>(value: impl Ctor<Output = T>) {
+            emplace! { let _value = value; }
+        }
+        let log = RefCell::new(vec![]);
+        let log = &log;
+
+        emplace! {
+            let mut x = DropCtorLogger {log};
+        }
+        takes_by_value(copy(&*x));
+        takes_by_value(mov(x.as_mut()));
+        assert_eq!(*log.borrow(), vec!["copy ctor", "drop", "move ctor", "drop"]);
+    }
+
+    #[test]
+    fn test_clone_ctor() {
+        impl CloneCtor for DropCtorLogger<'_> {}
+        let log = RefCell::new(vec![]);
+        let log = &log;
+
+        emplace! {
+            let x = DropCtorLogger {log};
+            let _y = x.clone_ctor();
+        }
+        assert_eq!(*log.borrow(), vec!["copy ctor"]);
+    }
+
     /// Non-obvious fact: you can mov() an owned reference type! Moving anything
     /// also performs a rust move, but the resulting rvalue reference is
     /// still valid for a temporary's lifetime.
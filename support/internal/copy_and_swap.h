@@ -0,0 +1,30 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#ifndef CRUBIT_RS_BINDINGS_FROM_CC_SUPPORT_COPY_AND_SWAP_H_
+#define CRUBIT_RS_BINDINGS_FROM_CC_SUPPORT_COPY_AND_SWAP_H_
+
+#include <utility>
+
+namespace crubit {
+
+// Assigns `value` to `self` using the copy-and-swap idiom: `operator=` is
+// called on a copy of `self`, which is then swapped into `self`.
+//
+// If `operator=` throws, `self` is left untouched, which provides the strong
+// exception guarantee for classes whose `operator=` only provides the basic
+// one (see `CRUBIT_THROWING_ASSIGNMENT` in `support/annotations.h`). The swap
+// is an unqualified call, so that a class-specific `swap` is found by ADL.
+template <typename T, typename U>
+T& CopyAndSwapAssign(T& self, U&& value) {
+  T copy(self);
+  copy = std::forward<U>(value);
+  using std::swap;
+  swap(self, copy);
+  return self;
+}
+
+}  // namespace crubit
+
+#endif  // CRUBIT_RS_BINDINGS_FROM_CC_SUPPORT_COPY_AND_SWAP_H_
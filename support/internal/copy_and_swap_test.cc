@@ -0,0 +1,62 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#include "support/internal/copy_and_swap.h"
+
+#include <stdexcept>
+#include <string>
+#include <utility>
+
+#include "gtest/gtest.h"
+
+namespace crubit {
+namespace {
+
+// Assignment which modifies the object before throwing (i.e. which only
+// provides the basic exception guarantee).
+struct HalfAssignable {
+  HalfAssignable(std::string first, std::string second)
+      : first(std::move(first)), second(std::move(second)) {}
+  HalfAssignable(const HalfAssignable&) = default;
+
+  HalfAssignable& operator=(const HalfAssignable& other) {
+    first = other.first;
+    if (other.second.empty()) throw std::invalid_argument("empty");
+    second = other.second;
+    return *this;
+  }
+
+  friend void swap(HalfAssignable& a, HalfAssignable& b) noexcept {
+    ++swap_count;
+    std::swap(a.first, b.first);
+    std::swap(a.second, b.second);
+  }
+
+  static int swap_count;
+  std::string first;
+  std::string second;
+};
+
+int HalfAssignable::swap_count = 0;
+
+TEST(CopyAndSwapTest, Assigns) {
+  HalfAssignable::swap_count = 0;
+  HalfAssignable self("a", "b");
+  HalfAssignable& result = CopyAndSwapAssign(self, HalfAssignable("c", "d"));
+  EXPECT_EQ(&result, &self);
+  EXPECT_EQ(self.first, "c");
+  EXPECT_EQ(self.second, "d");
+  EXPECT_EQ(HalfAssignable::swap_count, 1);
+}
+
+TEST(CopyAndSwapTest, LeavesObjectUntouchedOnException) {
+  HalfAssignable self("a", "b");
+  HalfAssignable other("c", "");
+  EXPECT_THROW(CopyAndSwapAssign(self, other), std::invalid_argument);
+  EXPECT_EQ(self.first, "a");
+  EXPECT_EQ(self.second, "b");
+}
+
+}  // namespace
+}  // namespace crubit
@@ -0,0 +1,41 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#ifndef CRUBIT_RS_BINDINGS_FROM_CC_SUPPORT_EXCEPTIONS_H_
+#define CRUBIT_RS_BINDINGS_FROM_CC_SUPPORT_EXCEPTIONS_H_
+
+#include <cstdlib>
+#include <cstring>
+#include <exception>
+
+namespace crubit {
+
+// Stores a description of the exception that is currently being handled into
+// `*exception`. Must be called from within a `catch` block.
+//
+// The description is a NUL-terminated string allocated with `malloc`. The
+// generated Rust bindings take ownership of it (see `cc_exception.rs`), and
+// free it with `free`.
+//
+// Thunks generated with `--exception_handling=panic` or
+// `--exception_handling=result` use this to translate C++ exceptions (which
+// must not unwind across the FFI boundary) into a Rust panic or `Err`.
+inline void StoreCurrentException(char** exception) noexcept {
+  const char* message = "unknown C++ exception";
+  try {
+    throw;
+  } catch (const std::exception& e) {
+    message = e.what();
+  } catch (...) {
+  }
+  *exception = strdup(message);
+  if (*exception == nullptr) {
+    // A null `*exception` would mean "no exception" to the Rust side.
+    std::abort();
+  }
+}
+
+}  // namespace crubit
+
+#endif  // CRUBIT_RS_BINDINGS_FROM_CC_SUPPORT_EXCEPTIONS_H_
@@ -0,0 +1,40 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#include "support/internal/exceptions.h"
+
+#include <cstdlib>
+#include <stdexcept>
+
+#include "gtest/gtest.h"
+
+namespace crubit {
+namespace {
+
+TEST(ExceptionsTest, StdException) {
+  char* exception = nullptr;
+  try {
+    throw std::runtime_error("something went wrong");
+  } catch (...) {
+    StoreCurrentException(&exception);
+  }
+  ASSERT_NE(exception, nullptr);
+  EXPECT_STREQ(exception, "something went wrong");
+  free(exception);
+}
+
+TEST(ExceptionsTest, NonStdException) {
+  char* exception = nullptr;
+  try {
+    throw 42;
+  } catch (...) {
+    StoreCurrentException(&exception);
+  }
+  ASSERT_NE(exception, nullptr);
+  EXPECT_STREQ(exception, "unknown C++ exception");
+  free(exception);
+}
+
+}  // namespace
+}  // namespace crubit
@@ -0,0 +1,47 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#ifndef CRUBIT_RS_BINDINGS_FROM_CC_SUPPORT_PRIVATE_MEMBER_ACCESS_H_
+#define CRUBIT_RS_BINDINGS_FROM_CC_SUPPORT_PRIVATE_MEMBER_ACCESS_H_
+
+namespace crubit {
+
+// PrivateMemberAccessor provides access to private and protected members, which
+// is used by the thunks of friend crates (see `--friend_crate`).
+//
+// Access checking doesn't apply to the template arguments of explicit
+// instantiations ([temp.spec.general]/6), so an explicit instantiation like
+// `template struct PrivateMemberAccessor<Tag, &Class::member>;` may name a
+// private `member`. The instantiation defines the `GetPrivateMember(Tag)`
+// friend function, which returns the member pointer. `Tag` has to be a struct
+// in the `crubit` namespace which:
+// * provides the type of the member pointer as `Tag::type`, and
+// * declares `friend type GetPrivateMember(Tag);` so that the function can be
+//   found by argument-dependent lookup.
+//
+// Example:
+//
+//    class Foo {
+//      int Bar() const;
+//    };
+//
+//    namespace crubit {
+//    struct FooBarTag {
+//      using type = int (Foo::*)() const;
+//      friend type GetPrivateMember(FooBarTag);
+//    };
+//    template struct PrivateMemberAccessor<FooBarTag, &Foo::Bar>;
+//    }  // namespace crubit
+//
+//    int CallBar(const Foo& foo) {
+//      return std::invoke(GetPrivateMember(crubit::FooBarTag{}), foo);
+//    }
+template <typename Tag, typename Tag::type Member>
+struct PrivateMemberAccessor {
+  friend typename Tag::type GetPrivateMember(Tag) { return Member; }
+};
+
+}  // namespace crubit
+
+#endif  // CRUBIT_RS_BINDINGS_FROM_CC_SUPPORT_PRIVATE_MEMBER_ACCESS_H_
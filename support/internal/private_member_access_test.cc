@@ -0,0 +1,65 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#include "support/internal/private_member_access.h"
+
+#include <functional>
+
+#include "gtest/gtest.h"
+
+namespace {
+
+class ClassWithPrivateMethods {
+ public:
+  explicit ClassWithPrivateMethods(int value) : value_(value) {}
+
+ protected:
+  int GetValue() const { return value_; }
+
+ private:
+  void SetValue(int value) { value_ = value; }
+  static int Add(int x, int y) { return x + y; }
+
+  int value_;
+};
+
+}  // namespace
+
+namespace crubit {
+
+struct GetValueTag {
+  using type = int (ClassWithPrivateMethods::*)() const;
+  friend type GetPrivateMember(GetValueTag);
+};
+template struct PrivateMemberAccessor<GetValueTag,
+                                      &ClassWithPrivateMethods::GetValue>;
+
+struct SetValueTag {
+  using type = void (ClassWithPrivateMethods::*)(int);
+  friend type GetPrivateMember(SetValueTag);
+};
+template struct PrivateMemberAccessor<SetValueTag,
+                                      &ClassWithPrivateMethods::SetValue>;
+
+struct AddTag {
+  using type = int (*)(int, int);
+  friend type GetPrivateMember(AddTag);
+};
+template struct PrivateMemberAccessor<AddTag, &ClassWithPrivateMethods::Add>;
+
+namespace {
+
+TEST(PrivateMemberAccessTest, InstanceMethods) {
+  ClassWithPrivateMethods c(123);
+  EXPECT_EQ(std::invoke(GetPrivateMember(GetValueTag{}), c), 123);
+  std::invoke(GetPrivateMember(SetValueTag{}), c, 456);
+  EXPECT_EQ(std::invoke(GetPrivateMember(GetValueTag{}), c), 456);
+}
+
+TEST(PrivateMemberAccessTest, StaticMethods) {
+  EXPECT_EQ(GetPrivateMember(AddTag{})(1, 2), 3);
+}
+
+}  // namespace
+}  // namespace crubit
@@ -0,0 +1,35 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#ifndef CRUBIT_RS_BINDINGS_FROM_CC_SUPPORT_RETAINED_CALLBACK_H_
+#define CRUBIT_RS_BINDINGS_FROM_CC_SUPPORT_RETAINED_CALLBACK_H_
+
+#include <memory>
+
+namespace crubit {
+
+// Owns a Rust closure that was passed to a C++ function which may retain it
+// beyond the call (e.g. `void SetHandler(std::function<void()> handler)`).
+//
+// The closure is represented by an opaque `context` pointer (a leaked
+// `Box<Box<dyn FnMut(...)>>`), together with a `drop_fn` which frees it. The
+// generated bindings invoke the closure through a separate trampoline function.
+//
+// `RetainedCallback` is copyable (as required by `std::function`): all the
+// copies share ownership of the closure, and the closure is dropped when the
+// last copy is destroyed.
+class RetainedCallback {
+ public:
+  RetainedCallback(void* context, void (*drop_fn)(void*))
+      : context_(context, drop_fn) {}
+
+  void* context() const { return context_.get(); }
+
+ private:
+  std::shared_ptr<void> context_;
+};
+
+}  // namespace crubit
+
+#endif  // CRUBIT_RS_BINDINGS_FROM_CC_SUPPORT_RETAINED_CALLBACK_H_
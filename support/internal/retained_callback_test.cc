@@ -0,0 +1,55 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+#include "support/internal/retained_callback.h"
+
+#include <functional>
+#include <optional>
+
+#include "gtest/gtest.h"
+
+namespace crubit {
+namespace {
+
+int drop_count = 0;
+
+void CountDrop(void* context) {
+  ++drop_count;
+  ++*static_cast<int*>(context);
+}
+
+TEST(RetainedCallbackTest, DropsContextWhenLastCopyIsDestroyed) {
+  drop_count = 0;
+  int context = 0;
+  std::optional<RetainedCallback> original(
+      RetainedCallback(&context, CountDrop));
+  EXPECT_EQ(original->context(), &context);
+  {
+    RetainedCallback copy = *original;
+    EXPECT_EQ(copy.context(), &context);
+    original.reset();
+    EXPECT_EQ(drop_count, 0);
+  }
+  EXPECT_EQ(drop_count, 1);
+  EXPECT_EQ(context, 1);
+}
+
+TEST(RetainedCallbackTest, StoredInStdFunction) {
+  drop_count = 0;
+  int context = 0;
+  std::function<int()> function = [callback = RetainedCallback(&context,
+                                                               CountDrop)] {
+    return *static_cast<int*>(callback.context()) + 42;
+  };
+  std::function<int()> copy = function;
+  EXPECT_EQ(function(), 42);
+  function = nullptr;
+  EXPECT_EQ(drop_count, 0);
+  EXPECT_EQ(copy(), 42);
+  copy = nullptr;
+  EXPECT_EQ(drop_count, 1);
+}
+
+}  // namespace
+}  // namespace crubit
@@ -0,0 +1,182 @@
+// Part of the Crubit project, under the Apache License v2.0 with LLVM
+// Exceptions. See /LICENSE for license information.
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception
+
+//! # Pointers to C++ class members.
+//!
+//! Bindings generated by Crubit with the `member_pointers` Crubit feature map a
+//! pointer to a member function, e.g. `int (S::*)(int) const`, to
+//! `CcMemberFnPtr<fn(&S, i32) -> i32>`, and a pointer to a data member, e.g.
+//! `int S::*`, to `CcDataMemberPtr<S, i32>`.
+//! Both types are opaque values with the same layout as in the Itanium C++ ABI,
+//! so records containing them can be passed to and from C++ unchanged.
+//!
+//! Member functions are called through the `Invoke` trait, which is
+//! implemented by the generated bindings for each pointer to a member function
+//! of a class of the bindings' crate:
+//!
+//! ```ignore
+//! let result = unsafe { callback.invoke(&s, (42,)) };
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+
+/// The Rust equivalent of a pointer to a member function.
+///
+/// `F` is a function pointer type describing the member function. It takes the
+/// receiver as its first parameter: `&Class` for const member functions and
+/// `&mut Class` for other member functions.
+#[repr(C)]
+pub struct CcMemberFnPtr<F> {
+    ptr: usize,
+    adj: isize,
+    _phantom: PhantomData<F>,
+}
+
+impl<F> CcMemberFnPtr<F> {
+    /// Returns true if this is a null pointer to a member function.
+    pub fn is_null(&self) -> bool {
+        self.ptr == 0
+    }
+}
+
+// Implemented manually, because deriving would add bounds on `F`.
+impl<F> Clone for CcMemberFnPtr<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for CcMemberFnPtr<F> {}
+
+impl<F> PartialEq for CcMemberFnPtr<F> {
+    fn eq(&self, other: &Self) -> bool {
+        // Null pointers compare equal regardless of their adjustment.
+        self.ptr == other.ptr && (self.ptr == 0 || self.adj == other.adj)
+    }
+}
+
+impl<F> Eq for CcMemberFnPtr<F> {}
+
+impl<F> fmt::Debug for CcMemberFnPtr<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CcMemberFnPtr").field("ptr", &self.ptr).field("adj", &self.adj).finish()
+    }
+}
+
+/// Calls the member function of `Class` designated by `self`.
+///
+/// `Class` is a parameter of the trait (rather than an associated type) so
+/// that the bindings of the crate defining `Class` can implement it.
+pub trait Invoke<Class> {
+    /// The receiver of the call: `*const Class` for const member functions,
+    /// and `*mut Class` for other member functions.
+    type Receiver;
+    /// The arguments of the call, as a tuple.
+    type Args;
+    /// The return type of the member function.
+    type Output;
+
+    /// Calls the member function on `obj` with `args`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be null, and `obj` must point to a valid object whose
+    /// dynamic type has the member function.
+    unsafe fn invoke(self, obj: Self::Receiver, args: Self::Args) -> Self::Output;
+}
+
+/// The Rust equivalent of a pointer to a data member of type `T` of `Class`.
+#[repr(transparent)]
+pub struct CcDataMemberPtr<Class, T> {
+    /// The offset of the member in `Class`, or -1 for a null pointer.
+    offset: isize,
+    _phantom: PhantomData<(*const Class, *const T)>,
+}
+
+impl<Class, T> CcDataMemberPtr<Class, T> {
+    /// Returns true if this is a null pointer to a data member.
+    pub fn is_null(&self) -> bool {
+        self.offset == -1
+    }
+
+    /// Returns a pointer to the data member of `*obj`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be null, and `obj` must point to a valid object.
+    pub unsafe fn get(self, obj: *const Class) -> *const T {
+        obj.cast::<u8>().offset(self.offset).cast()
+    }
+
+    /// Returns a mutable pointer to the data member of `*obj`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be null, and `obj` must point to a valid object.
+    pub unsafe fn get_mut(self, obj: *mut Class) -> *mut T {
+        obj.cast::<u8>().offset(self.offset).cast()
+    }
+}
+
+impl<Class, T> Clone for CcDataMemberPtr<Class, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Class, T> Copy for CcDataMemberPtr<Class, T> {}
+
+impl<Class, T> PartialEq for CcDataMemberPtr<Class, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+    }
+}
+
+impl<Class, T> Eq for CcDataMemberPtr<Class, T> {}
+
+impl<Class, T> fmt::Debug for CcDataMemberPtr<Class, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CcDataMemberPtr").field("offset", &self.offset).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[repr(C)]
+    struct S {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn test_data_member_ptr() {
+        let mut s = S { x: 1, y: 2 };
+        let y = CcDataMemberPtr::<S, i32> { offset: 4, _phantom: PhantomData };
+        assert!(!y.is_null());
+        unsafe {
+            assert_eq!(*y.get(&s), 2);
+            *y.get_mut(&mut s) = 3;
+        }
+        assert_eq!(s.y, 3);
+        assert_eq!(s.x, 1);
+    }
+
+    #[test]
+    fn test_null_member_ptrs() {
+        let data = CcDataMemberPtr::<S, i32> { offset: -1, _phantom: PhantomData };
+        assert!(data.is_null());
+        let func = CcMemberFnPtr::<fn(&S) -> i32> { ptr: 0, adj: 8, _phantom: PhantomData };
+        assert!(func.is_null());
+        assert_eq!(func, CcMemberFnPtr { ptr: 0, adj: 0, _phantom: PhantomData });
+    }
+
+    #[test]
+    fn test_member_fn_ptr_layout() {
+        assert_eq!(std::mem::size_of::<CcMemberFnPtr<fn(&S)>>(), 2 * std::mem::size_of::<usize>());
+        assert_eq!(std::mem::size_of::<CcDataMemberPtr<S, i32>>(), std::mem::size_of::<isize>());
+    }
+}